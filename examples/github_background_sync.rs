@@ -54,6 +54,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         incremental_sync_interval: 300, // 5 minutes for demo (normally 1 hour)
         max_items_per_repo: Some(50),
         sync_on_startup: true,
+        max_jitter: 60, // keep demo syncs close to the interval
+        ..BackgroundSyncConfig::default()
     };
 
     println!("\n⚙️  Background Sync Configuration:");
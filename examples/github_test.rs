@@ -57,7 +57,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Initialize sync engine
     println!("\n🔄 Initializing sync engine...");
-    let sync_engine = SyncEngine::new(client.clone(), pool.clone());
+    let sync_engine = SyncEngine::new(Box::new(client.clone()), pool.clone());
 
     // Get authenticated user
     println!("\n👤 Getting authenticated user...");
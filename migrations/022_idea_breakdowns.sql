@@ -0,0 +1,11 @@
+-- Migration: 022_idea_breakdowns.sql
+-- Cache of LLM idea → task breakdowns, keyed by a hash of the idea content,
+-- so promoting identical ideas does not pay for a second LLM call.
+
+CREATE TABLE IF NOT EXISTS idea_breakdown_cache (
+    content_hash TEXT   PRIMARY KEY,
+    breakdown    TEXT   NOT NULL,       -- JSON-serialized IdeaBreakdown
+    model        TEXT   NOT NULL,
+    hit_count    BIGINT NOT NULL DEFAULT 0,
+    created_at   BIGINT NOT NULL DEFAULT EXTRACT(EPOCH FROM NOW())::BIGINT
+);
@@ -0,0 +1,18 @@
+-- Migration: 023_note_tag_suggestions.sql
+-- Tags suggested for notes at capture time (keyword or LLM pass), awaiting
+-- user confirmation before they are copied into note_tags.
+
+CREATE TABLE IF NOT EXISTS note_tag_suggestions (
+    note_id    TEXT             NOT NULL,
+    tag        TEXT             NOT NULL,
+    confidence DOUBLE PRECISION NOT NULL,
+    source     TEXT             NOT NULL DEFAULT 'keyword'
+                   CHECK(source IN ('keyword', 'llm')),
+    status     TEXT             NOT NULL DEFAULT 'pending'
+                   CHECK(status IN ('pending', 'confirmed', 'rejected')),
+    created_at BIGINT           NOT NULL DEFAULT EXTRACT(EPOCH FROM NOW())::BIGINT,
+    PRIMARY KEY (note_id, tag),
+    FOREIGN KEY (note_id) REFERENCES notes(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_note_tag_suggestions_status ON note_tag_suggestions(status);
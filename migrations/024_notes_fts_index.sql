@@ -0,0 +1,5 @@
+-- Migration: 024_notes_fts_index.sql
+-- Expression index backing full-text search in db::note_search.
+
+CREATE INDEX IF NOT EXISTS idx_notes_fts
+    ON notes USING GIN (to_tsvector('english', title || ' ' || content));
@@ -0,0 +1,21 @@
+-- Migration: 025_scan_jobs.sql
+-- On-demand scans requested over the API (POST /api/repos/:id/scan).
+-- Polled by clients such as CI via GET /api/jobs/:id.
+
+CREATE TABLE IF NOT EXISTS scan_jobs (
+    id             TEXT    PRIMARY KEY,
+    repo_id        TEXT    NOT NULL,
+    status         TEXT    NOT NULL DEFAULT 'queued'
+                       CHECK(status IN ('queued', 'running', 'completed', 'failed')),
+    force          BOOLEAN NOT NULL DEFAULT FALSE,
+    files_analyzed BIGINT  NOT NULL DEFAULT 0,
+    issues_found   BIGINT  NOT NULL DEFAULT 0,
+    tasks_created  BIGINT  NOT NULL DEFAULT 0,
+    error          TEXT,
+    created_at     BIGINT  NOT NULL,
+    started_at     BIGINT,
+    completed_at   BIGINT,
+    FOREIGN KEY (repo_id) REFERENCES repositories(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_scan_jobs_repo_status ON scan_jobs(repo_id, status);
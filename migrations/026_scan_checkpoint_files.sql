@@ -0,0 +1,11 @@
+-- Migration: 026_scan_checkpoint_files.sql
+-- Files completed by an interrupted scan, keyed by path and content hash so a
+-- resumed scan skips them however the changed-file list was reordered.
+
+CREATE TABLE IF NOT EXISTS scan_checkpoint_files (
+    repo_id      TEXT   NOT NULL,
+    file_path    TEXT   NOT NULL,
+    content_hash TEXT   NOT NULL,
+    completed_at BIGINT NOT NULL,
+    PRIMARY KEY (repo_id, file_path)
+);
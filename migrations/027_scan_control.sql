@@ -0,0 +1,13 @@
+-- Migration: 027_scan_control.sql
+-- Global kill switch for LLM scanning. While paused, the auto-scanner starts
+-- no new scan cycles and makes no new LLM calls; cache and static results
+-- are still served. Single row, toggled via `pause_scanning`/`resume_scanning`.
+
+CREATE TABLE IF NOT EXISTS scan_control (
+    id         INTEGER PRIMARY KEY DEFAULT 1 CHECK (id = 1),
+    paused     BOOLEAN NOT NULL DEFAULT FALSE,
+    reason     TEXT,
+    updated_at BIGINT  NOT NULL DEFAULT 0
+);
+
+INSERT INTO scan_control (id, paused) VALUES (1, FALSE) ON CONFLICT (id) DO NOTHING;
@@ -0,0 +1,5 @@
+-- Migration: 028_repo_scan_cost_budget.sql
+-- Per-repository cost budget for one scan, in dollars. NULL falls back to the
+-- scanner's global AUTO_SCAN_COST_BUDGET; 0 means unlimited.
+
+ALTER TABLE repositories ADD COLUMN IF NOT EXISTS scan_cost_budget DOUBLE PRECISION;
@@ -0,0 +1,14 @@
+-- Migration: 029_task_status_history.sql
+-- Audit trail of task status changes, written by `update_task_status` for
+-- every transition the task state machine allows.
+
+CREATE TABLE IF NOT EXISTS task_status_history (
+    id          BIGSERIAL PRIMARY KEY,
+    task_id     TEXT   NOT NULL,
+    from_status TEXT   NOT NULL,
+    to_status   TEXT   NOT NULL,
+    changed_at  BIGINT NOT NULL,
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_task_status_history_task ON task_status_history(task_id, changed_at);
@@ -0,0 +1,31 @@
+-- Migration: 030_tree_diffs.sql
+-- Persisted tree diffs, written by `TreeStateManager::save_diff` so the
+-- changelog timeline can be rendered without keeping every snapshot.
+-- A diff with no changes still gets a `tree_diffs` row ("scanned, no changes").
+
+CREATE TABLE IF NOT EXISTS tree_diffs (
+    id                 BIGSERIAL PRIMARY KEY,
+    root               TEXT   NOT NULL,
+    compared_at        BIGINT NOT NULL,
+    previous_snapshot  TEXT,
+    current_snapshot   TEXT   NOT NULL,
+    commit_range       TEXT,
+    summary            JSONB  NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_tree_diffs_root ON tree_diffs(root, compared_at);
+
+CREATE TABLE IF NOT EXISTS tree_diff_changes (
+    id                 BIGSERIAL PRIMARY KEY,
+    diff_id            BIGINT  NOT NULL,
+    path               TEXT    NOT NULL,
+    change_type        TEXT    NOT NULL,
+    category           TEXT    NOT NULL,
+    previous_hash      TEXT,
+    lines_added        INTEGER NOT NULL DEFAULT 0,
+    lines_removed      INTEGER NOT NULL DEFAULT 0,
+    needs_llm_analysis BOOLEAN NOT NULL DEFAULT FALSE,
+    FOREIGN KEY (diff_id) REFERENCES tree_diffs(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_tree_diff_changes_diff ON tree_diff_changes(diff_id);
@@ -0,0 +1,5 @@
+-- Migration: 031_tree_diff_renames.sql
+-- Renamed files are stored as one `renamed` change under their new path;
+-- `previous_path` holds the path they were moved from.
+
+ALTER TABLE tree_diff_changes ADD COLUMN IF NOT EXISTS previous_path TEXT;
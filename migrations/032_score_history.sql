@@ -0,0 +1,14 @@
+-- Migration: 032_score_history.sql
+-- One row per scoring event, written by `FileScorer::record_score`, so
+-- `detect_regressions` can compare a file's latest score to its recent ones.
+
+CREATE TABLE IF NOT EXISTS score_history (
+    id          BIGSERIAL PRIMARY KEY,
+    repo_id     TEXT             NOT NULL,
+    file_path   TEXT             NOT NULL,
+    score       DOUBLE PRECISION NOT NULL,
+    recorded_at BIGINT           NOT NULL,
+    FOREIGN KEY (repo_id) REFERENCES repositories(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_score_history_file ON score_history(repo_id, file_path, recorded_at);
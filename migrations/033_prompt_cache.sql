@@ -0,0 +1,16 @@
+-- Migration: 033_prompt_cache.sql
+-- LLM responses keyed by the SHA-256 of the model and fully-rendered prompt,
+-- so identical prompts (boilerplate files, review retries) share one API call.
+
+CREATE TABLE IF NOT EXISTS prompt_cache (
+    prompt_hash TEXT PRIMARY KEY,
+    model       TEXT   NOT NULL,
+    operation   TEXT   NOT NULL,
+    response    TEXT   NOT NULL,
+    tokens_used BIGINT NOT NULL DEFAULT 0,
+    hit_count   BIGINT NOT NULL DEFAULT 0,
+    created_at  BIGINT NOT NULL,
+    last_hit_at BIGINT
+);
+
+CREATE INDEX IF NOT EXISTS idx_prompt_cache_operation ON prompt_cache(operation);
@@ -0,0 +1,5 @@
+-- Migration: 034_repo_scan_path_filter.sql
+-- Per-repository glob patterns limiting scans to a subtree of a monorepo,
+-- e.g. {'services/payments/**'}. NULL scans the whole repository.
+
+ALTER TABLE repositories ADD COLUMN IF NOT EXISTS scan_path_filter TEXT[];
@@ -0,0 +1,11 @@
+-- Migration: 035_scan_cancelled_status.sql
+-- Scans stopped through POST /api/repos/:id/scan/cancel are recorded as
+-- 'cancelled' rather than failed; their checkpoint is kept for the next run.
+
+ALTER TABLE repositories DROP CONSTRAINT IF EXISTS repositories_scan_status_check;
+ALTER TABLE repositories ADD CONSTRAINT repositories_scan_status_check
+    CHECK (scan_status IN ('idle', 'scanning', 'error', 'cancelled'));
+
+ALTER TABLE scan_jobs DROP CONSTRAINT IF EXISTS scan_jobs_status_check;
+ALTER TABLE scan_jobs ADD CONSTRAINT scan_jobs_status_check
+    CHECK (status IN ('queued', 'running', 'completed', 'failed', 'cancelled'));
@@ -0,0 +1,115 @@
+-- Migration: 036_git_provider_keys.sql
+-- GitLab project, issue and merge request ids share an id space with
+-- GitHub's, so a GitLab sync could overwrite GitHub rows in the github_*
+-- tables. Every synced row now carries the provider it came from and is
+-- keyed by (provider, id); existing rows are GitHub's.
+
+-- Views select i.* / p.* / c.*, so they are rebuilt around the new columns
+DROP VIEW IF EXISTS github_active_repos;
+DROP VIEW IF EXISTS github_open_issues;
+DROP VIEW IF EXISTS github_open_prs;
+DROP VIEW IF EXISTS github_prs_needing_review;
+DROP VIEW IF EXISTS github_recent_commits;
+
+ALTER TABLE github_repositories ADD COLUMN IF NOT EXISTS provider TEXT NOT NULL DEFAULT 'github';
+ALTER TABLE github_issues ADD COLUMN IF NOT EXISTS provider TEXT NOT NULL DEFAULT 'github';
+ALTER TABLE github_pull_requests ADD COLUMN IF NOT EXISTS provider TEXT NOT NULL DEFAULT 'github';
+ALTER TABLE github_commits ADD COLUMN IF NOT EXISTS provider TEXT NOT NULL DEFAULT 'github';
+ALTER TABLE github_labels ADD COLUMN IF NOT EXISTS provider TEXT NOT NULL DEFAULT 'github';
+ALTER TABLE github_milestones ADD COLUMN IF NOT EXISTS provider TEXT NOT NULL DEFAULT 'github';
+-- Nullable so deleting the repository can clear the event's whole key (below)
+ALTER TABLE github_webhook_events ADD COLUMN IF NOT EXISTS provider TEXT DEFAULT 'github';
+
+-- Foreign keys on the bare repository id go before its primary key does
+ALTER TABLE github_issues DROP CONSTRAINT IF EXISTS github_issues_repo_id_fkey;
+ALTER TABLE github_pull_requests DROP CONSTRAINT IF EXISTS github_pull_requests_repo_id_fkey;
+ALTER TABLE github_commits DROP CONSTRAINT IF EXISTS github_commits_repo_id_fkey;
+ALTER TABLE github_labels DROP CONSTRAINT IF EXISTS github_labels_repo_id_fkey;
+ALTER TABLE github_milestones DROP CONSTRAINT IF EXISTS github_milestones_repo_id_fkey;
+ALTER TABLE github_webhook_events DROP CONSTRAINT IF EXISTS github_webhook_events_repo_id_fkey;
+
+-- Repositories: (provider, id), and full names are unique per provider
+ALTER TABLE github_repositories DROP CONSTRAINT IF EXISTS github_repositories_pkey;
+ALTER TABLE github_repositories ADD PRIMARY KEY (provider, id);
+ALTER TABLE github_repositories DROP CONSTRAINT IF EXISTS github_repositories_full_name_key;
+ALTER TABLE github_repositories ADD CONSTRAINT github_repositories_provider_full_name_key
+    UNIQUE (provider, full_name);
+
+-- Issues
+ALTER TABLE github_issues DROP CONSTRAINT IF EXISTS github_issues_pkey;
+ALTER TABLE github_issues ADD PRIMARY KEY (provider, id);
+ALTER TABLE github_issues DROP CONSTRAINT IF EXISTS github_issues_repo_id_number_key;
+ALTER TABLE github_issues ADD CONSTRAINT github_issues_provider_repo_id_number_key
+    UNIQUE (provider, repo_id, number);
+ALTER TABLE github_issues ADD FOREIGN KEY (provider, repo_id)
+    REFERENCES github_repositories(provider, id) ON DELETE CASCADE;
+
+-- Pull / merge requests
+ALTER TABLE github_pull_requests DROP CONSTRAINT IF EXISTS github_pull_requests_pkey;
+ALTER TABLE github_pull_requests ADD PRIMARY KEY (provider, id);
+ALTER TABLE github_pull_requests DROP CONSTRAINT IF EXISTS github_pull_requests_repo_id_number_key;
+ALTER TABLE github_pull_requests ADD CONSTRAINT github_pull_requests_provider_repo_id_number_key
+    UNIQUE (provider, repo_id, number);
+ALTER TABLE github_pull_requests ADD FOREIGN KEY (provider, repo_id)
+    REFERENCES github_repositories(provider, id) ON DELETE CASCADE;
+
+-- Commits: a repo mirrored on both forges has the same SHAs on each
+ALTER TABLE github_commits DROP CONSTRAINT IF EXISTS github_commits_pkey;
+ALTER TABLE github_commits ADD PRIMARY KEY (provider, sha);
+ALTER TABLE github_commits ADD FOREIGN KEY (provider, repo_id)
+    REFERENCES github_repositories(provider, id) ON DELETE CASCADE;
+
+-- Labels
+ALTER TABLE github_labels DROP CONSTRAINT IF EXISTS github_labels_pkey;
+ALTER TABLE github_labels ADD PRIMARY KEY (provider, id);
+ALTER TABLE github_labels DROP CONSTRAINT IF EXISTS github_labels_repo_id_name_key;
+ALTER TABLE github_labels ADD CONSTRAINT github_labels_provider_repo_id_name_key
+    UNIQUE (provider, repo_id, name);
+ALTER TABLE github_labels ADD FOREIGN KEY (provider, repo_id)
+    REFERENCES github_repositories(provider, id) ON DELETE CASCADE;
+
+-- Milestones
+ALTER TABLE github_milestones DROP CONSTRAINT IF EXISTS github_milestones_pkey;
+ALTER TABLE github_milestones ADD PRIMARY KEY (provider, id);
+ALTER TABLE github_milestones DROP CONSTRAINT IF EXISTS github_milestones_repo_id_number_key;
+ALTER TABLE github_milestones ADD CONSTRAINT github_milestones_provider_repo_id_number_key
+    UNIQUE (provider, repo_id, number);
+ALTER TABLE github_milestones ADD FOREIGN KEY (provider, repo_id)
+    REFERENCES github_repositories(provider, id) ON DELETE CASCADE;
+
+-- Webhook events outlive their repository. A composite key with any NULL
+-- column isn't checked, so clearing both columns detaches the event.
+ALTER TABLE github_webhook_events ADD FOREIGN KEY (provider, repo_id)
+    REFERENCES github_repositories(provider, id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_github_repos_provider ON github_repositories(provider);
+
+CREATE OR REPLACE VIEW github_active_repos AS
+SELECT * FROM github_repositories
+WHERE archived = 0 AND sync_enabled = 1;
+
+CREATE OR REPLACE VIEW github_open_issues AS
+SELECT i.*, r.full_name AS repo_full_name
+FROM github_issues i
+JOIN github_repositories r ON i.provider = r.provider AND i.repo_id = r.id
+WHERE i.state = 'open' AND i.is_pull_request = 0;
+
+CREATE OR REPLACE VIEW github_open_prs AS
+SELECT p.*, r.full_name AS repo_full_name
+FROM github_pull_requests p
+JOIN github_repositories r ON p.provider = r.provider AND p.repo_id = r.id
+WHERE p.state = 'open';
+
+CREATE OR REPLACE VIEW github_prs_needing_review AS
+SELECT p.*, r.full_name AS repo_full_name
+FROM github_pull_requests p
+JOIN github_repositories r ON p.provider = r.provider AND p.repo_id = r.id
+WHERE p.state = 'open' AND p.draft = 0
+ORDER BY p.updated_at DESC;
+
+CREATE OR REPLACE VIEW github_recent_commits AS
+SELECT c.*, r.full_name AS repo_full_name
+FROM github_commits c
+JOIN github_repositories r ON c.provider = r.provider AND c.repo_id = r.id
+WHERE c.author_date > (EXTRACT(EPOCH FROM NOW())::BIGINT - 604800)
+ORDER BY c.author_date DESC;
@@ -2,15 +2,19 @@
 
 use axum::{
     extract::{Request, State},
-    http::{HeaderMap, StatusCode},
+    http::{header, HeaderMap, StatusCode},
     middleware::Next,
     response::{IntoResponse, Response},
+    Json,
 };
+use base64::Engine;
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::sync::Arc;
 
+use crate::config::ApiAuthConfig;
+
 // ============================================================================
 // Types
 // ============================================================================
@@ -139,6 +143,120 @@ pub async fn auth_middleware(
     }
 }
 
+// ============================================================================
+// Server API Auth
+// ============================================================================
+
+/// Credentials presented by a request
+enum Credentials<'a> {
+    ApiKey(&'a str),
+    Basic { username: String, password: String },
+}
+
+fn extract_credentials(headers: &HeaderMap) -> Option<Credentials<'_>> {
+    if let Some(key) = headers.get("X-API-Key").and_then(|v| v.to_str().ok()) {
+        return Some(Credentials::ApiKey(key));
+    }
+
+    let value = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())?;
+    if let Some(token) = value.strip_prefix("Bearer ") {
+        return Some(Credentials::ApiKey(token.trim()));
+    }
+    // Malformed basic credentials count as present-but-invalid
+    let encoded = value.strip_prefix("Basic ")?;
+    let decoded = base64::engine::general_purpose::STANDARD
+        .decode(encoded.trim())
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .unwrap_or_default();
+    let (username, password) = decoded.split_once(':').unwrap_or(("", ""));
+    Some(Credentials::Basic {
+        username: username.to_string(),
+        password: password.to_string(),
+    })
+}
+
+/// Check a request against the server's [`ApiAuthConfig`]
+pub fn authorize_request(
+    config: &ApiAuthConfig,
+    headers: &HeaderMap,
+    method: &str,
+    path: &str,
+) -> AuthResult {
+    if !config.enabled || config.is_public(path) {
+        return AuthResult::Allowed;
+    }
+
+    let credentials = match extract_credentials(headers) {
+        Some(credentials) => credentials,
+        None if config.allow_anonymous_read && AuthConfig::is_read_only_method(method) => {
+            return AuthResult::Allowed
+        }
+        None => return AuthResult::MissingKey,
+    };
+
+    // Compare hashes so the check doesn't short-circuit on the raw secret
+    let valid = match credentials {
+        Credentials::ApiKey(key) => {
+            let hashed = hash_api_key(key);
+            config.api_keys.iter().any(|k| hash_api_key(k) == hashed)
+        }
+        Credentials::Basic { username, password } => {
+            config.basic_auth.as_ref().is_some_and(|(user, pass)| {
+                hash_api_key(&format!("{}:{}", user, pass))
+                    == hash_api_key(&format!("{}:{}", username, password))
+            })
+        }
+    };
+
+    if valid {
+        AuthResult::Allowed
+    } else {
+        AuthResult::InvalidKey
+    }
+}
+
+/// Auth middleware for the server API (`run_server` and the server binary)
+pub async fn api_auth_middleware(
+    State(config): State<Arc<ApiAuthConfig>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let result = authorize_request(
+        &config,
+        request.headers(),
+        request.method().as_str(),
+        request.uri().path(),
+    );
+
+    match result {
+        AuthResult::Allowed => next.run(request).await,
+        AuthResult::MissingKey => unauthorized(
+            &config,
+            "Missing credentials. Provide Authorization: Bearer <key>, X-API-Key: <key> \
+             or HTTP basic auth",
+        ),
+        AuthResult::InvalidKey => unauthorized(&config, "Invalid credentials"),
+    }
+}
+
+fn unauthorized(config: &ApiAuthConfig, message: &str) -> Response {
+    let challenge = if config.basic_auth.is_some() {
+        r#"Basic realm="rustassistant""#
+    } else {
+        r#"Bearer realm="rustassistant""#
+    };
+
+    (
+        StatusCode::UNAUTHORIZED,
+        [(header::WWW_AUTHENTICATE, challenge)],
+        Json(serde_json::json!({ "error": message, "status": 401 })),
+    )
+        .into_response()
+}
+
 // ============================================================================
 // API Key Management
 // ============================================================================
@@ -211,6 +329,115 @@ impl ApiKeyInfo {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use axum::body::Body;
+    use axum::routing::get;
+    use axum::{middleware, Router};
+    use tower::ServiceExt;
+
+    fn server_auth() -> ApiAuthConfig {
+        ApiAuthConfig {
+            enabled: true,
+            api_keys: vec!["secret-key".to_string()],
+            basic_auth: Some(("admin".to_string(), "hunter2".to_string())),
+            allow_anonymous_read: false,
+            ..ApiAuthConfig::default()
+        }
+    }
+
+    fn guarded_app(config: ApiAuthConfig) -> Router {
+        Router::new()
+            .route("/healthz", get(|| async { "ok" }))
+            .route(
+                "/api/repos",
+                get(|| async { "[]" }).post(|| async { "created" }),
+            )
+            .layer(middleware::from_fn_with_state(
+                Arc::new(config),
+                api_auth_middleware,
+            ))
+    }
+
+    async fn send(app: Router, method: &str, uri: &str, auth: Option<(&str, &str)>) -> Response {
+        let mut req = Request::builder().method(method).uri(uri);
+        if let Some((name, value)) = auth {
+            req = req.header(name, value);
+        }
+        app.oneshot(req.body(Body::empty()).unwrap()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_server_auth_rejects_missing_and_invalid_key() {
+        let resp = send(guarded_app(server_auth()), "POST", "/api/repos", None).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+        assert!(resp.headers().contains_key(header::WWW_AUTHENTICATE));
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["status"], 401);
+        assert!(json["error"]
+            .as_str()
+            .unwrap()
+            .contains("Missing credentials"));
+
+        let resp = send(
+            guarded_app(server_auth()),
+            "POST",
+            "/api/repos",
+            Some(("Authorization", "Bearer wrong")),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_server_auth_accepts_valid_credentials() {
+        for header_value in [
+            ("Authorization", "Bearer secret-key".to_string()),
+            ("X-API-Key", "secret-key".to_string()),
+            (
+                "Authorization",
+                format!(
+                    "Basic {}",
+                    base64::engine::general_purpose::STANDARD.encode("admin:hunter2")
+                ),
+            ),
+        ] {
+            let resp = send(
+                guarded_app(server_auth()),
+                "POST",
+                "/api/repos",
+                Some((header_value.0, &header_value.1)),
+            )
+            .await;
+            assert_eq!(resp.status(), StatusCode::OK, "{:?}", header_value);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_server_auth_health_bypass_and_opt_out() {
+        let resp = send(guarded_app(server_auth()), "GET", "/healthz", None).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        // Reads need a key unless anonymous read is allowed
+        let resp = send(guarded_app(server_auth()), "GET", "/api/repos", None).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+        let anon_read = ApiAuthConfig {
+            allow_anonymous_read: true,
+            ..server_auth()
+        };
+        let resp = send(guarded_app(anon_read.clone()), "GET", "/api/repos", None).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let resp = send(guarded_app(anon_read), "POST", "/api/repos", None).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+        let disabled = ApiAuthConfig {
+            enabled: false,
+            ..server_auth()
+        };
+        let resp = send(guarded_app(disabled), "POST", "/api/repos", None).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
 
     #[test]
     fn test_hash_api_key() {
@@ -11,10 +11,13 @@ pub mod admin;
 pub mod auth;
 pub mod handlers;
 pub mod jobs;
+pub mod notes;
+pub mod openapi;
 pub mod proxy;
 pub mod proxy_client;
 pub mod rate_limit;
 pub mod repos;
+pub mod scan_jobs;
 pub mod types;
 
 use axum::{
@@ -28,14 +31,19 @@ use crate::embeddings::{EmbeddingConfig, EmbeddingGenerator};
 use crate::indexing::IndexingConfig;
 use sqlx::PgPool;
 
-pub use auth::{generate_api_key, hash_api_key, AuthConfig, AuthResult};
+pub use auth::{
+    api_auth_middleware, authorize_request, generate_api_key, hash_api_key, AuthConfig, AuthResult,
+};
 pub use handlers::ApiState;
 pub use jobs::{JobQueue, JobQueueConfig, JobStatus};
+pub use notes::notes_router;
+pub use openapi::{openapi_router, ApiDoc};
 pub use proxy::{proxy_router, ProxyState};
 pub use proxy_client::{
     ChatMessage, ChatReply, ChatRequestBuilder, ProxyClient, ProxyClientConfig,
 };
 pub use rate_limit::{RateLimitConfig, RateLimiter};
+pub use scan_jobs::{scan_job_router, ScanJobService, ScanRunner};
 pub use types::*;
 
 // ============================================================================
@@ -0,0 +1,103 @@
+//! Note search endpoint
+//!
+//! `GET /notes/search` exposes [`search_notes`](crate::db::note_search::search_notes)
+//! over HTTP. Every query parameter maps onto a [`NoteQuery`] builder call:
+//!
+//! ```text
+//! GET /notes/search?q=sqlite&tags=performance,db&any_tag=true&status=active&limit=20
+//! ```
+
+use axum::{
+    extract::{Query, State},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde::Deserialize;
+use sqlx::PgPool;
+
+use crate::api::types::ApiError;
+use crate::db::note_search::{search_notes, NoteQuery};
+
+/// Query parameters for `GET /notes/search`
+#[derive(Debug, Default, Deserialize, utoipa::IntoParams)]
+pub struct NoteSearchParams {
+    /// Free-text query (Postgres full-text search)
+    pub q: Option<String>,
+    /// Comma-separated tags
+    pub tags: Option<String>,
+    /// Match notes carrying any of the tags instead of all of them
+    #[serde(default)]
+    pub any_tag: bool,
+    /// Combine text and tags with OR instead of AND
+    #[serde(default)]
+    pub match_any: bool,
+    /// Inclusive lower bound on `created_at` (Unix seconds)
+    pub after: Option<i64>,
+    /// Exclusive upper bound on `created_at` (Unix seconds)
+    pub before: Option<i64>,
+    pub status: Option<String>,
+    /// Maximum hits (default 50)
+    pub limit: Option<i64>,
+}
+
+impl NoteSearchParams {
+    pub fn to_query(&self) -> NoteQuery {
+        let mut query = NoteQuery::new();
+        if let Some(ref q) = self.q {
+            query = query.text(q.clone());
+        }
+        for tag in self.tags.iter().flat_map(|t| t.split(',')) {
+            let tag = tag.trim();
+            if !tag.is_empty() {
+                query = query.tag(tag);
+            }
+        }
+        if self.any_tag {
+            query = query.any_tag();
+        }
+        if self.match_any {
+            query = query.match_any();
+        }
+        if let Some(after) = self.after {
+            query = query.created_after(after);
+        }
+        if let Some(before) = self.before {
+            query = query.created_before(before);
+        }
+        if let Some(ref status) = self.status {
+            query = query.status(status.clone());
+        }
+        if let Some(limit) = self.limit {
+            query = query.limit(limit.clamp(1, 500));
+        }
+        query
+    }
+}
+
+/// `GET /notes/search`
+pub fn notes_router(pool: PgPool) -> Router {
+    Router::new()
+        .route("/notes/search", get(search_notes_handler))
+        .with_state(pool)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/notes/search",
+    tag = "search",
+    params(NoteSearchParams),
+    responses(
+        (status = 200, description = "Best matches first", body = [NoteSearchHit]),
+        (status = 500, body = ApiError)
+    )
+)]
+async fn search_notes_handler(
+    State(pool): State<PgPool>,
+    Query(params): Query<NoteSearchParams>,
+) -> Response {
+    match search_notes(&pool, &params.to_query()).await {
+        Ok(hits) => Json(hits).into_response(),
+        Err(e) => ApiError::from_error(e).into_response(),
+    }
+}
@@ -0,0 +1,204 @@
+//! OpenAPI 3 description of the server API
+//!
+//! [`ApiDoc`] is derived from the library handlers (`#[utoipa::path]`) and
+//! their serde types (`#[derive(ToSchema)]`), so it changes whenever they do.
+//! The server binary merges in its own routes and serves the result with
+//! [`openapi_router`]:
+//!
+//! - `GET /openapi.json` — the spec
+//! - `GET /docs` — Swagger UI pointed at `/openapi.json`
+//!
+//! Paths are recorded where the server mounts each router (`/api`, `/api/v1`,
+//! `/v1`), not relative to the router itself.
+
+use axum::{response::Html, routing::get, Json, Router};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi, ToSchema};
+
+/// Error body used by the auth middleware and the SSE endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StatusError {
+    pub error: String,
+    pub status: u16,
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "RustAssistant API",
+        description = "Repository scanning, tasks, notes and LLM chat"
+    ),
+    paths(
+        crate::health::liveness,
+        crate::health::readiness,
+        crate::api::scan_jobs::enqueue_scan,
+        crate::api::scan_jobs::cancel_scan,
+        crate::api::scan_jobs::analyze_file,
+        crate::api::scan_jobs::get_job,
+        crate::api::scan_jobs::get_scanning,
+        crate::api::scan_jobs::pause,
+        crate::api::scan_jobs::resume,
+        crate::scan_progress::stream_scan_events,
+        crate::scan_progress::stream_scan_lifecycle,
+        crate::api::notes::search_notes_handler,
+        crate::github::unified_search::unified_search,
+        crate::api::repos::list_repos,
+        crate::api::repos::register_repo,
+        crate::api::repos::get_repo,
+        crate::api::repos::remove_repo,
+        crate::api::repos::sync_repo,
+        crate::api::repos::get_repo_context,
+        crate::api::repos::get_repo_todos,
+        crate::api::repos::get_repo_symbols,
+        crate::api::repos::get_repo_tree,
+        crate::api::repos::chat,
+        crate::api::repos::chat_with_repo,
+        crate::api::repos::ollama_health,
+        crate::api::repos::ollama_models,
+        crate::api::proxy::handle_chat_completions,
+        crate::api::proxy::handle_list_models,
+    ),
+    components(schemas(
+        crate::api::types::ApiError,
+        StatusError,
+        crate::db::Repository,
+        crate::db::Task,
+        crate::db::RepositoryPage,
+        crate::db::TaskPage,
+        crate::db::Note,
+        crate::db::NoteSearchHit,
+        crate::db::MatchMode,
+        crate::github::UnifiedSearchResponse,
+        crate::github::UnifiedResult,
+        crate::github::RemoteStatus,
+        crate::github::SearchSource,
+        crate::github::ResultKind,
+        crate::github::SearchScope,
+        crate::github::SearchType,
+        crate::db::ScanJob,
+        crate::db::ScanJobStatus,
+        crate::api::scan_jobs::ScanningStatus,
+        crate::api::scan_jobs::ScanCancelRequested,
+        crate::api::scan_jobs::AnalyzeFileRequest,
+        crate::api::scan_jobs::FileAnalysisResponse,
+        crate::scan_progress::ScanUpdate,
+        crate::scan_progress::ScanPhase,
+        crate::health::ReadinessReport,
+        crate::health::DatabaseStatus,
+        crate::health::WorkerStatus,
+        crate::api::repos::RegisterRepoRequest,
+        crate::api::repos::RegisterRepoResponse,
+        crate::api::repos::ChatRequest,
+        crate::api::repos::ChatMessage,
+        crate::api::repos::ChatResponse,
+    )),
+    modifiers(&SecurityAddon),
+    tags(
+        (name = "health", description = "Liveness and readiness probes"),
+        (name = "scans", description = "On-demand scans and live progress"),
+        (name = "search", description = "Note search and unified GitHub + local search"),
+        (name = "repo-sync", description = "Registered repos and their caches"),
+        (name = "chat", description = "Chat with optional repo context"),
+        (name = "proxy", description = "OpenAI-compatible proxy")
+    )
+)]
+pub struct ApiDoc;
+
+/// Documents the `X-API-Key` / `Authorization: Bearer` and basic-auth schemes
+/// accepted by [`api_auth_middleware`](crate::api::auth::api_auth_middleware)
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "api_key",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("X-API-Key"))),
+        );
+        components.add_security_scheme(
+            "bearer",
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+        );
+        components.add_security_scheme(
+            "basic",
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Basic).build()),
+        );
+    }
+}
+
+/// `GET /openapi.json` and `GET /docs` serving `spec`
+pub fn openapi_router(spec: utoipa::openapi::OpenApi) -> Router {
+    let spec = Arc::new(spec);
+    Router::new()
+        .route(
+            "/openapi.json",
+            get(move || {
+                let spec = spec.clone();
+                async move { Json(spec.as_ref().clone()) }
+            }),
+        )
+        .route("/docs", get(|| async { Html(SWAGGER_UI) }))
+}
+
+/// Swagger UI from the jsDelivr CDN; nothing to vendor
+const SWAGGER_UI: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <title>RustAssistant API</title>
+    <link rel="stylesheet" href="https://cdn.jsdelivr.net/npm/swagger-ui-dist@5/swagger-ui.css">
+</head>
+<body>
+    <div id="swagger-ui"></div>
+    <script src="https://cdn.jsdelivr.net/npm/swagger-ui-dist@5/swagger-ui-bundle.js"></script>
+    <script>
+        window.ui = SwaggerUIBundle({ url: "/openapi.json", dom_id: "#swagger-ui" });
+    </script>
+</body>
+</html>"##;
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_openapi_json_lists_scan_and_search_endpoints() {
+        let app = openapi_router(ApiDoc::openapi());
+        let resp = app
+            .oneshot(
+                Request::builder()
+                    .uri("/openapi.json")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let spec: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+
+        assert!(spec["openapi"].as_str().unwrap().starts_with("3."));
+        let paths = &spec["paths"];
+        assert!(paths["/api/repos/{id}/scan"]["post"].is_object());
+        assert!(paths["/api/jobs/{id}"]["get"].is_object());
+        assert!(paths["/api/notes/search"]["get"].is_object());
+        assert!(paths["/api/search"]["get"].is_object());
+
+        let schemas = &spec["components"]["schemas"];
+        for name in ["Repository", "Task", "ScanJob", "NoteSearchHit", "ApiError"] {
+            assert!(schemas[name].is_object(), "missing schema {}", name);
+        }
+        assert!(schemas["Repository"]["properties"]["scan_status"].is_object());
+    }
+}
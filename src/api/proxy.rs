@@ -457,6 +457,13 @@ pub fn proxy_router(state: ProxyState) -> Router {
 // Handler — POST /v1/chat/completions
 // ---------------------------------------------------------------------------
 
+#[utoipa::path(
+    post,
+    path = "/v1/chat/completions",
+    tag = "proxy",
+    request_body(content = Object, description = "OpenAI chat completion request"),
+    responses((status = 200, description = "OpenAI chat completion (JSON or SSE when `stream`)", body = Object))
+)]
 async fn handle_chat_completions(
     State(state): State<ProxyState>,
     headers: HeaderMap,
@@ -955,6 +962,12 @@ impl ModelEntry {
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/v1/models",
+    tag = "proxy",
+    responses((status = 200, description = "OpenAI model list", body = Object))
+)]
 async fn handle_list_models(State(state): State<ProxyState>) -> impl IntoResponse {
     let now = unix_now();
 
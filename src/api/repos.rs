@@ -76,7 +76,7 @@ pub fn repo_router(state: RepoAppState) -> Router {
 // Request / Response types
 // ---------------------------------------------------------------------------
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
 pub struct RegisterRepoRequest {
     pub name: String,
     pub local_path: String,
@@ -86,15 +86,16 @@ pub struct RegisterRepoRequest {
     pub sync_on_register: Option<bool>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, utoipa::ToSchema)]
 pub struct RegisterRepoResponse {
     pub id: String,
     pub name: String,
     pub message: String,
+    #[schema(value_type = Option<Object>)]
     pub sync_result: Option<SyncResult>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
 pub struct ChatRequest {
     pub message: String,
     /// Optional: inject context from a specific registered repo.
@@ -107,13 +108,13 @@ pub struct ChatRequest {
     pub no_cache: Option<bool>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
 pub struct ChatMessage {
     pub role: String, // "user" | "assistant"
     pub content: String,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, utoipa::ToSchema)]
 pub struct ChatResponse {
     pub reply: String,
     pub task_kind: String,
@@ -133,6 +134,12 @@ pub use crate::api::types::ApiError;
 // Repo handlers
 // ---------------------------------------------------------------------------
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/repos",
+    tag = "repo-sync",
+    responses((status = 200, description = "Registered repos", body = [Object]))
+)]
 async fn list_repos(State(state): State<RepoAppState>) -> impl IntoResponse {
     let service = state.sync_service.read().await;
     let repos: Vec<_> = service
@@ -152,6 +159,16 @@ async fn list_repos(State(state): State<RepoAppState>) -> impl IntoResponse {
     Json(repos)
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/repos",
+    tag = "repo-sync",
+    request_body = RegisterRepoRequest,
+    responses(
+        (status = 200, body = RegisterRepoResponse),
+        (status = 500, body = ApiError)
+    )
+)]
 async fn register_repo(
     State(state): State<RepoAppState>,
     Json(req): Json<RegisterRepoRequest>,
@@ -197,6 +214,13 @@ async fn register_repo(
     .into_response()
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/repos/{id}",
+    tag = "repo-sync",
+    params(("id" = String, Path, description = "Registered repo id")),
+    responses((status = 200, body = Object), (status = 404, body = ApiError))
+)]
 async fn get_repo(State(state): State<RepoAppState>, Path(id): Path<String>) -> impl IntoResponse {
     let service = state.sync_service.read().await;
     match service.get_repo(&id) {
@@ -214,6 +238,13 @@ async fn get_repo(State(state): State<RepoAppState>, Path(id): Path<String>) ->
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/v1/repos/{id}",
+    tag = "repo-sync",
+    params(("id" = String, Path, description = "Registered repo id")),
+    responses((status = 200, body = Object), (status = 404, body = ApiError))
+)]
 async fn remove_repo(
     State(state): State<RepoAppState>,
     Path(id): Path<String>,
@@ -226,6 +257,13 @@ async fn remove_repo(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/repos/{id}/sync",
+    tag = "repo-sync",
+    params(("id" = String, Path, description = "Registered repo id")),
+    responses((status = 200, description = "Sync result", body = Object), (status = 500, body = ApiError))
+)]
 async fn sync_repo(State(state): State<RepoAppState>, Path(id): Path<String>) -> impl IntoResponse {
     info!(repo = %id, "Manual sync triggered via API");
     let mut service = state.sync_service.write().await;
@@ -235,6 +273,16 @@ async fn sync_repo(State(state): State<RepoAppState>, Path(id): Path<String>) ->
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/repos/{id}/context",
+    tag = "repo-sync",
+    params(("id" = String, Path, description = "Registered repo id")),
+    responses(
+        (status = 200, description = "Prompt context", body = String, content_type = "text/plain"),
+        (status = 404, body = ApiError)
+    )
+)]
 async fn get_repo_context(
     State(state): State<RepoAppState>,
     Path(id): Path<String>,
@@ -246,6 +294,13 @@ async fn get_repo_context(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/repos/{id}/todos",
+    tag = "repo-sync",
+    params(("id" = String, Path, description = "Registered repo id")),
+    responses((status = 200, description = "Cached TODO list", body = String), (status = 404, body = ApiError))
+)]
 async fn get_repo_todos(
     State(state): State<RepoAppState>,
     Path(id): Path<String>,
@@ -253,6 +308,13 @@ async fn get_repo_todos(
     serve_cache_file(&state, &id, |r| r.todos_path()).await
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/repos/{id}/symbols",
+    tag = "repo-sync",
+    params(("id" = String, Path, description = "Registered repo id")),
+    responses((status = 200, description = "Cached symbol index", body = String), (status = 404, body = ApiError))
+)]
 async fn get_repo_symbols(
     State(state): State<RepoAppState>,
     Path(id): Path<String>,
@@ -260,6 +322,13 @@ async fn get_repo_symbols(
     serve_cache_file(&state, &id, |r| r.symbols_path()).await
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/repos/{id}/tree",
+    tag = "repo-sync",
+    params(("id" = String, Path, description = "Registered repo id")),
+    responses((status = 200, description = "Cached file tree", body = String), (status = 404, body = ApiError))
+)]
 async fn get_repo_tree(
     State(state): State<RepoAppState>,
     Path(id): Path<String>,
@@ -271,6 +340,12 @@ async fn get_repo_tree(
 // Ollama status endpoints
 // ---------------------------------------------------------------------------
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/ollama/health",
+    tag = "chat",
+    responses((status = 200, body = Object))
+)]
 async fn ollama_health(State(state): State<RepoAppState>) -> impl IntoResponse {
     let reachable = state.ollama_client.health_check().await;
     let status = if reachable { "ok" } else { "unreachable" };
@@ -280,6 +355,12 @@ async fn ollama_health(State(state): State<RepoAppState>) -> impl IntoResponse {
     }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/ollama/models",
+    tag = "chat",
+    responses((status = 200, body = Object), (status = 500, body = ApiError))
+)]
 async fn ollama_models(State(state): State<RepoAppState>) -> impl IntoResponse {
     match state.ollama_client.list_models().await {
         Ok(models) => Json(serde_json::json!({ "models": models })).into_response(),
@@ -291,6 +372,13 @@ async fn ollama_models(State(state): State<RepoAppState>) -> impl IntoResponse {
 // Chat handlers
 // ---------------------------------------------------------------------------
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/chat",
+    tag = "chat",
+    request_body = ChatRequest,
+    responses((status = 200, body = ChatResponse), (status = 500, body = ApiError))
+)]
 async fn chat(
     State(state): State<RepoAppState>,
     Json(req): Json<ChatRequest>,
@@ -298,6 +386,14 @@ async fn chat(
     handle_chat(state, req, None).await
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/chat/repos/{id}",
+    tag = "chat",
+    params(("id" = String, Path, description = "Registered repo id to inject as context")),
+    request_body = ChatRequest,
+    responses((status = 200, body = ChatResponse), (status = 500, body = ApiError))
+)]
 async fn chat_with_repo(
     State(state): State<RepoAppState>,
     Path(repo_id): Path<String>,
@@ -25,7 +25,7 @@ use serde::{Deserialize, Serialize};
 ///     Ok(Json(row))
 /// }
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
 pub struct ApiError {
     pub error: String,
     pub code: String,
@@ -60,6 +60,20 @@ impl ApiError {
         }
     }
 
+    pub fn conflict(msg: impl Into<String>) -> Self {
+        Self {
+            error: msg.into(),
+            code: "CONFLICT".to_string(),
+        }
+    }
+
+    pub fn payload_too_large(msg: impl Into<String>) -> Self {
+        Self {
+            error: msg.into(),
+            code: "PAYLOAD_TOO_LARGE".to_string(),
+        }
+    }
+
     /// Convenience constructor: map any `Display` error into an internal error.
     pub fn from_error(e: impl std::fmt::Display) -> Self {
         Self::internal(e.to_string())
@@ -70,6 +84,8 @@ impl ApiError {
             "NOT_FOUND" => StatusCode::NOT_FOUND,
             "BAD_REQUEST" => StatusCode::BAD_REQUEST,
             "UNAUTHORIZED" => StatusCode::UNAUTHORIZED,
+            "CONFLICT" => StatusCode::CONFLICT,
+            "PAYLOAD_TOO_LARGE" => StatusCode::PAYLOAD_TOO_LARGE,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -107,12 +107,7 @@ impl BackupManager {
     pub fn create_backup(&self) -> Result<BackupResult> {
         info!("Starting backup to {}", self.config.remote_name);
 
-        let timestamp = Utc::now().format("%Y%m%d_%H%M%S").to_string();
-        let backup_name = format!("backup_{}", timestamp);
-        let remote_dest = format!(
-            "{}:{}/{}",
-            self.config.remote_name, self.config.remote_path, backup_name
-        );
+        let (timestamp, backup_name, remote_dest) = self.next_backup_target();
 
         // Create a local snapshot first (SQLite safe backup)
         let snapshot_dir = self.create_snapshot(&timestamp)?;
@@ -153,6 +148,53 @@ impl BackupManager {
         })
     }
 
+    /// Plan a backup without transferring anything
+    ///
+    /// Runs the snapshot step and measures it, then lists the remote to show
+    /// which existing backups retention would purge once this one lands.
+    /// Never invokes `rclone copy` or `rclone purge`; the snapshot is removed
+    /// before returning.
+    pub fn create_backup_dry_run(&self) -> Result<BackupPlan> {
+        info!("Planning backup to {} (dry run)", self.config.remote_name);
+
+        let (timestamp, backup_name, remote_dest) = self.next_backup_target();
+
+        let snapshot_dir = self.create_snapshot(&timestamp)?;
+        let stats = dir_stats(&snapshot_dir);
+        std::fs::remove_dir_all(&snapshot_dir).ok();
+        let (file_count, size_bytes) = stats.context("Failed to measure snapshot")?;
+
+        let mut existing = self.remote_backup_names();
+        existing.push(backup_name.clone());
+        let would_remove = backups_to_remove(existing, self.config.retention_count);
+
+        info!(
+            "Dry run: {} would sync {} files ({} bytes) to {}",
+            backup_name, file_count, size_bytes, remote_dest
+        );
+
+        Ok(BackupPlan {
+            name: backup_name,
+            timestamp,
+            remote_path: remote_dest,
+            file_count,
+            size_bytes,
+            retention_count: self.config.retention_count,
+            would_remove,
+        })
+    }
+
+    /// Timestamp, name and remote destination for a backup taken now
+    fn next_backup_target(&self) -> (String, String, String) {
+        let timestamp = Utc::now().format("%Y%m%d_%H%M%S").to_string();
+        let backup_name = format!("backup_{}", timestamp);
+        let remote_dest = format!(
+            "{}:{}/{}",
+            self.config.remote_name, self.config.remote_path, backup_name
+        );
+        (timestamp, backup_name, remote_dest)
+    }
+
     /// Create a local snapshot of databases
     fn create_snapshot(&self, timestamp: &str) -> Result<PathBuf> {
         let snapshot_dir = std::env::temp_dir()
@@ -216,39 +258,36 @@ impl BackupManager {
         }
     }
 
-    /// Remove old backups beyond retention count
-    fn cleanup_old_backups(&self) -> Result<()> {
+    /// Names of backups currently on the remote (empty if none or unreachable)
+    fn remote_backup_names(&self) -> Vec<String> {
         let remote_base = format!("{}:{}", self.config.remote_name, self.config.remote_path);
 
-        // List existing backups
-        let output = Command::new("rclone")
+        let output = match Command::new("rclone")
             .args(["lsf", &remote_base, "--dirs-only"])
-            .output()?;
-
-        if !output.status.success() {
-            return Ok(()); // No backups yet
-        }
+            .output()
+        {
+            Ok(output) if output.status.success() => output,
+            _ => return vec![], // No backups yet
+        };
 
-        let mut backups: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        String::from_utf8_lossy(&output.stdout)
             .lines()
             .filter(|l| l.starts_with("backup_"))
             .map(|l| l.trim_end_matches('/').to_string())
-            .collect();
+            .collect()
+    }
 
-        // Sort by name (which includes timestamp)
-        backups.sort();
-        backups.reverse();
+    /// Remove old backups beyond retention count
+    fn cleanup_old_backups(&self) -> Result<()> {
+        let remote_base = format!("{}:{}", self.config.remote_name, self.config.remote_path);
 
-        // Remove old ones
-        if backups.len() > self.config.retention_count {
-            let to_remove = &backups[self.config.retention_count..];
+        let to_remove = backups_to_remove(self.remote_backup_names(), self.config.retention_count);
 
-            for backup in to_remove {
-                let path = format!("{}/{}", remote_base, backup);
-                info!("Removing old backup: {}", backup);
+        for backup in to_remove {
+            let path = format!("{}/{}", remote_base, backup);
+            info!("Removing old backup: {}", backup);
 
-                Command::new("rclone").args(["purge", &path]).output().ok();
-            }
+            Command::new("rclone").args(["purge", &path]).output().ok();
         }
 
         Ok(())
@@ -366,6 +405,22 @@ pub struct BackupResult {
     pub remote_path: String,
 }
 
+/// What [`BackupManager::create_backup_dry_run`] would have done
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct BackupPlan {
+    pub name: String,
+    pub timestamp: String,
+    /// Remote destination the snapshot would be copied to
+    pub remote_path: String,
+    /// Number of files in the local snapshot
+    pub file_count: usize,
+    /// Total size of the local snapshot
+    pub size_bytes: u64,
+    pub retention_count: usize,
+    /// Existing backups retention would purge after this one, oldest last
+    pub would_remove: Vec<String>,
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct BackupInfo {
     pub name: String,
@@ -376,6 +431,37 @@ pub struct BackupInfo {
 // Utility Functions
 // ============================================================================
 
+/// Backups beyond the newest `retention_count`, newest first
+///
+/// Names embed a sortable timestamp, so name order is age order.
+fn backups_to_remove(mut backups: Vec<String>, retention_count: usize) -> Vec<String> {
+    backups.sort();
+    backups.reverse();
+    backups.split_off(retention_count.min(backups.len()))
+}
+
+/// Count files and sum their sizes under `dir`
+fn dir_stats(dir: &Path) -> Result<(usize, u64)> {
+    let mut files = 0;
+    let mut bytes = 0;
+
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+
+        if path.is_dir() {
+            let (sub_files, sub_bytes) = dir_stats(&path)?;
+            files += sub_files;
+            bytes += sub_bytes;
+        } else {
+            files += 1;
+            bytes += entry.metadata()?.len();
+        }
+    }
+
+    Ok((files, bytes))
+}
+
 fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<()> {
     std::fs::create_dir_all(dst)?;
 
@@ -443,3 +529,58 @@ Google Drive Backup Setup (No API Key Required!)
 "#
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backups_to_remove() {
+        let names = |v: &[&str]| v.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let existing = names(&[
+            "backup_20240103_020000",
+            "backup_20240101_020000",
+            "backup_20240104_020000",
+            "backup_20240102_020000",
+        ]);
+
+        assert_eq!(
+            backups_to_remove(existing.clone(), 2),
+            names(&["backup_20240102_020000", "backup_20240101_020000"])
+        );
+        assert!(backups_to_remove(existing.clone(), 4).is_empty());
+        assert!(backups_to_remove(existing, 10).is_empty());
+    }
+
+    #[test]
+    fn test_create_backup_dry_run() {
+        let data = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(data.path().join("cache/nested")).unwrap();
+        std::fs::write(data.path().join("cache/a.json"), "12345").unwrap();
+        std::fs::write(data.path().join("cache/nested/b.json"), "123").unwrap();
+        std::fs::write(data.path().join("config.toml"), "x = 1\n").unwrap();
+
+        let manager = BackupManager::new(BackupConfig {
+            data_dir: data.path().to_path_buf(),
+            remote_name: "dryrun-test-remote".to_string(),
+            remote_path: "backups".to_string(),
+            retention_count: 3,
+            schedule: None,
+        });
+
+        let plan = manager.create_backup_dry_run().unwrap();
+
+        assert_eq!(plan.file_count, 3);
+        assert_eq!(plan.size_bytes, 5 + 3 + 6);
+        assert_eq!(plan.name, format!("backup_{}", plan.timestamp));
+        assert_eq!(
+            plan.remote_path,
+            format!("dryrun-test-remote:backups/{}", plan.name)
+        );
+        assert!(plan.would_remove.is_empty());
+        assert!(!std::env::temp_dir()
+            .join("rustassistant-backup")
+            .join(&plan.timestamp)
+            .exists());
+    }
+}
@@ -61,6 +61,15 @@ async fn main() -> anyhow::Result<()> {
             .ok()
             .and_then(|s| s.parse().ok())
             .unwrap_or(true),
+        min_jitter: env::var("GITHUB_SYNC_MIN_JITTER")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(30),
+        max_jitter: env::var("GITHUB_SYNC_MAX_JITTER")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(300),
+        ..BackgroundSyncConfig::default()
     };
 
     tracing::info!("⚙️  Sync Configuration:");
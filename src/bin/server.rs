@@ -4,7 +4,17 @@
 //! Mounts:
 //!   /api/v1/*   — repo CRUD + chat with repo-context injection
 //!   /v1/*       — OpenAI-compatible proxy
-//!   /healthz    — health check
+//!   /healthz    — liveness probe
+//!   /readyz     — readiness probe (DB ping + background worker status)
+//!   /api/repos/:id/events — live scan progress (Server-Sent Events)
+//!   /api/scans/:id/events — scan lifecycle events for dashboards (SSE)
+//!   /api/repos/:id/scan   — enqueue an on-demand scan (poll /api/jobs/:id)
+//!   /api/notes/search     — note full-text / tag search
+//!   /api/search           — unified GitHub + local search (GitHub needs GITHUB_TOKEN)
+//!   /openapi.json, /docs  — OpenAPI 3 spec and Swagger UI
+//!
+//! SIGTERM / Ctrl-C stops accepting connections, then drains in-flight
+//! scans and syncs (bounded by SHUTDOWN_DRAIN_SECS) before exiting.
 
 use axum::response::Html;
 use axum::{
@@ -21,16 +31,31 @@ use std::sync::Arc;
 use std::time::Duration;
 use tower_http::cors::{Any, CorsLayer};
 use tracing::info;
+use utoipa::{IntoParams, OpenApi, ToSchema};
 
 // Import from our crate
+use rustassistant::api::auth::api_auth_middleware;
+use rustassistant::api::notes::notes_router;
+use rustassistant::api::openapi::{openapi_router, ApiDoc};
 use rustassistant::api::proxy::{proxy_router, ProxyState};
 use rustassistant::api::repos::{repo_router, RepoAppState};
-use rustassistant::auto_scanner::{AutoScanner, AutoScannerConfig};
+use rustassistant::api::scan_jobs::{scan_job_router, ScanJobService};
+use rustassistant::auto_scanner::{
+    AutoScanner, AutoScannerConfig, FirstScanSeed, SubmoduleMode, DEFAULT_FIRST_SCAN_COMMIT_DEPTH,
+};
+use rustassistant::config::ApiAuthConfig;
 use rustassistant::db::{
-    self, get_next_task, get_stats, list_repositories, list_tasks, update_task_status,
+    self, get_next_task, get_stats, get_task_history, list_repositories_page, list_tasks_page,
+    update_task_status, ListFilter, Page, PageRequest, Repository, Task, TaskStatusChange,
 };
+use rustassistant::git::CloneOptions;
+use rustassistant::github::{unified_search_router, GitHubClient, UnifiedSearcher};
+use rustassistant::health::{health_router, shutdown_signal, HealthState, Shutdown, WorkerHealth};
+use rustassistant::llm_config::{LlmConfig, LLM_CONFIG_FILE};
 use rustassistant::model_router::{ModelRouter, ModelRouterConfig};
+use rustassistant::prompt_router::PromptRouter;
 use rustassistant::repo_sync::RepoSyncService;
+use rustassistant::scan_progress::{scan_events_router, ScanEventsState, ScanProgressHub};
 use rustassistant::sync_scheduler::{SyncScheduler, SyncSchedulerConfig};
 // WebUI removed — RustAssistant is API-only (batch-015)
 
@@ -47,26 +72,62 @@ struct AppState {
 // Request/Response Types
 // ============================================================================
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 struct UpdateStatusRequest {
     status: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 struct AddRepoRequest {
     path: String,
     name: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
-struct ListTasksQuery {
+/// Paging and filters shared by the list endpoints
+#[derive(Debug, Deserialize, IntoParams)]
+struct ListQuery {
+    /// Page size (default 50, max 500)
     limit: Option<i64>,
+    /// `next_cursor` from the previous page
+    cursor: Option<String>,
+    /// Raw offset; ignored when `cursor` is set
+    offset: Option<i64>,
     status: Option<String>,
+    /// Include priorities up to this value (1 = critical); tasks only
     priority: Option<i32>,
+    /// Tasks only
     repo_id: Option<String>,
+    /// Inclusive lower bound on `created_at` (Unix seconds)
+    created_after: Option<i64>,
+    /// Exclusive upper bound on `created_at` (Unix seconds)
+    created_before: Option<i64>,
+}
+
+impl ListQuery {
+    fn page(&self) -> Result<PageRequest, db::DbError> {
+        PageRequest::from_params(self.limit, self.cursor.as_deref(), self.offset)
+    }
+
+    fn filter(&self) -> ListFilter {
+        ListFilter {
+            status: self.status.clone(),
+            repo_id: self.repo_id.clone(),
+            max_priority: self.priority,
+            created_after: self.created_after,
+            created_before: self.created_before,
+        }
+    }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
+#[aliases(
+    RepositoryResponse = ApiResponse<Repository>,
+    RepositoryListResponse = ApiResponse<Page<Repository>>,
+    TaskResponse = ApiResponse<Task>,
+    TaskListResponse = ApiResponse<Page<Task>>,
+    TaskHistoryResponse = ApiResponse<Vec<TaskStatusChange>>,
+    ErrorResponse = ApiResponse<String>
+)]
 struct ApiResponse<T> {
     success: bool,
     data: Option<T>,
@@ -112,6 +173,7 @@ impl ApiResponse<()> {
 // ============================================================================
 
 // Health check
+#[utoipa::path(get, path = "/health", tag = "health", responses((status = 200, body = Object)))]
 async fn health_check() -> impl IntoResponse {
     Json(serde_json::json!({
         "status": "ok",
@@ -223,6 +285,9 @@ async fn root_handler() -> impl IntoResponse {
         <div class="endpoint">
             <strong>PUT</strong> <code>/api/tasks/:id</code> - Update task
         </div>
+        <div class="endpoint">
+            <strong>GET</strong> <code>/api/tasks/:id/history</code> - Task status history
+        </div>
     </div>
 
     <p style="margin-top: 30px; color: #6b7280;">
@@ -235,6 +300,12 @@ async fn root_handler() -> impl IntoResponse {
 }
 
 // Stats
+#[utoipa::path(
+    get,
+    path = "/api/stats",
+    tag = "stats",
+    responses((status = 200, description = "Note, repo and task counts", body = Object))
+)]
 async fn get_statistics(State(state): State<AppState>) -> impl IntoResponse {
     match get_stats(&state.db).await {
         Ok(stats) => ApiResponse::ok(stats).into_response(),
@@ -246,6 +317,13 @@ async fn get_statistics(State(state): State<AppState>) -> impl IntoResponse {
 
 // --- Repositories ---
 
+#[utoipa::path(
+    post,
+    path = "/api/repos",
+    tag = "repositories",
+    request_body = AddRepoRequest,
+    responses((status = 201, body = RepositoryResponse), (status = 400, body = ErrorResponse))
+)]
 async fn add_repo_handler(
     State(state): State<AppState>,
     Json(req): Json<AddRepoRequest>,
@@ -265,13 +343,34 @@ async fn add_repo_handler(
     }
 }
 
-async fn list_repos_handler(State(state): State<AppState>) -> impl IntoResponse {
-    match list_repositories(&state.db).await {
+#[utoipa::path(
+    get,
+    path = "/api/repos",
+    tag = "repositories",
+    params(ListQuery),
+    responses((status = 200, body = RepositoryListResponse), (status = 400, body = ErrorResponse))
+)]
+async fn list_repos_handler(
+    State(state): State<AppState>,
+    Query(query): Query<ListQuery>,
+) -> impl IntoResponse {
+    let page = match query.page() {
+        Ok(page) => page,
+        Err(e) => return ApiResponse::error(e.to_string()).into_response(),
+    };
+    match list_repositories_page(&state.db, &query.filter(), page).await {
         Ok(repos) => ApiResponse::ok(repos).into_response(),
         Err(e) => ApiResponse::error(e.to_string()).into_response(),
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/repos/{id}",
+    tag = "repositories",
+    params(("id" = String, Path, description = "Repository id")),
+    responses((status = 200, body = RepositoryResponse), (status = 404, body = ErrorResponse))
+)]
 async fn get_repo_handler(
     State(state): State<AppState>,
     Path(id): Path<String>,
@@ -283,6 +382,13 @@ async fn get_repo_handler(
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/repos/{id}",
+    tag = "repositories",
+    params(("id" = String, Path, description = "Repository id")),
+    responses((status = 200, body = Object), (status = 404, body = ErrorResponse))
+)]
 async fn delete_repo_handler(
     State(state): State<AppState>,
     Path(id): Path<String>,
@@ -300,25 +406,36 @@ async fn delete_repo_handler(
 
 // --- Tasks ---
 
+#[utoipa::path(
+    get,
+    path = "/api/tasks",
+    tag = "tasks",
+    params(ListQuery),
+    responses((status = 200, body = TaskListResponse), (status = 400, body = ErrorResponse))
+)]
 async fn list_tasks_handler(
     State(state): State<AppState>,
-    Query(query): Query<ListTasksQuery>,
+    Query(query): Query<ListQuery>,
 ) -> impl IntoResponse {
-    let limit = query.limit.unwrap_or(50);
-    match list_tasks(
-        &state.db,
-        limit,
-        query.status.as_deref(),
-        query.priority,
-        query.repo_id.as_deref(),
-    )
-    .await
-    {
+    let page = match query.page() {
+        Ok(page) => page,
+        Err(e) => return ApiResponse::error(e.to_string()).into_response(),
+    };
+    match list_tasks_page(&state.db, &query.filter(), page).await {
         Ok(tasks) => ApiResponse::ok(tasks).into_response(),
         Err(e) => ApiResponse::error(e.to_string()).into_response(),
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/tasks/next",
+    tag = "tasks",
+    responses(
+        (status = 200, description = "Highest-priority pending task, or a message when none", body = TaskResponse),
+        (status = 400, body = ErrorResponse)
+    )
+)]
 async fn get_next_task_handler(State(state): State<AppState>) -> impl IntoResponse {
     match get_next_task(&state.db).await {
         Ok(Some(task)) => ApiResponse::ok(task).into_response(),
@@ -329,6 +446,14 @@ async fn get_next_task_handler(State(state): State<AppState>) -> impl IntoRespon
     }
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/tasks/{id}",
+    tag = "tasks",
+    params(("id" = String, Path, description = "Task id")),
+    request_body = UpdateStatusRequest,
+    responses((status = 200, body = Object), (status = 404, body = ErrorResponse))
+)]
 async fn update_task_handler(
     State(state): State<AppState>,
     Path(id): Path<String>,
@@ -341,6 +466,67 @@ async fn update_task_handler(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{id}/history",
+    tag = "tasks",
+    params(("id" = String, Path, description = "Task id")),
+    responses((status = 200, description = "Status changes, oldest first", body = TaskHistoryResponse))
+)]
+async fn task_history_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    match get_task_history(&state.db, &id).await {
+        Ok(history) => ApiResponse::ok(history).into_response(),
+        Err(e) => ApiResponse::error(e.to_string()).into_response(),
+    }
+}
+
+// ============================================================================
+// OpenAPI
+// ============================================================================
+
+/// Routes defined in this binary; merged with the library's [`ApiDoc`]
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        health_check,
+        get_statistics,
+        add_repo_handler,
+        list_repos_handler,
+        get_repo_handler,
+        delete_repo_handler,
+        list_tasks_handler,
+        get_next_task_handler,
+        update_task_handler,
+        task_history_handler,
+    ),
+    components(schemas(
+        AddRepoRequest,
+        UpdateStatusRequest,
+        RepositoryResponse,
+        RepositoryListResponse,
+        TaskResponse,
+        TaskListResponse,
+        TaskHistoryResponse,
+        TaskStatusChange,
+        ErrorResponse,
+    )),
+    tags(
+        (name = "stats", description = "Aggregate counts"),
+        (name = "repositories", description = "Tracked repositories"),
+        (name = "tasks", description = "Generated and manual tasks")
+    )
+)]
+struct ServerDoc;
+
+fn openapi_spec() -> utoipa::openapi::OpenApi {
+    let mut spec = ApiDoc::openapi();
+    spec.merge(ServerDoc::openapi());
+    spec
+}
+
 // ============================================================================
 // Router
 // ============================================================================
@@ -366,6 +552,7 @@ fn create_api_router(state: AppState) -> Router {
         .route("/api/tasks", get(list_tasks_handler))
         .route("/api/tasks/next", get(get_next_task_handler))
         .route("/api/tasks/:id", put(update_task_handler))
+        .route("/api/tasks/:id/history", get(task_history_handler))
         .layer(cors)
         .with_state(state)
 }
@@ -396,6 +583,13 @@ async fn main() -> anyhow::Result<()> {
     let repos_dir = std::env::var("REPOS_DIR").unwrap_or_else(|_| "/app/repos".into());
     let addr = format!("{}:{}", host, port);
 
+    // API auth (API_KEYS / API_BASIC_AUTH; API_AUTH_ENABLED=false to opt out)
+    let auth_config = ApiAuthConfig::from_env();
+    auth_config.validate()?;
+    if !auth_config.enabled {
+        tracing::warn!("API auth disabled (API_AUTH_ENABLED=false) — only bind to localhost");
+    }
+
     // Ensure repos directory exists
     std::fs::create_dir_all(&repos_dir).expect("Failed to create repos directory");
 
@@ -467,28 +661,23 @@ async fn main() -> anyhow::Result<()> {
         .ok()
         .and_then(|v| v.parse::<u64>().ok())
         .unwrap_or(300);
-    SyncScheduler::new(
+    // Shared by the probes, the background workers and the signal handler
+    let shutdown = Shutdown::new();
+    let sync_health = WorkerHealth::new();
+    let scanner_health = WorkerHealth::new();
+
+    let sync_handle = SyncScheduler::new(
         SyncSchedulerConfig {
             interval: Duration::from_secs(sync_interval_secs),
             ..SyncSchedulerConfig::default()
         },
         Arc::clone(&sync_service),
     )
+    .with_shutdown(shutdown.clone())
+    .with_health(sync_health.clone())
     .start();
     info!(interval_secs = sync_interval_secs, "SyncScheduler started");
 
-    // Build combined router (API-only — no WebUI)
-    let api_router = create_api_router(api_state);
-
-    let app = Router::new()
-        .merge(api_router)
-        // Repo CRUD + chat with repo context + /api/v1/repos/:id/sync etc.
-        .nest("/api/v1", repo_router(repo_app_state.clone()))
-        // OpenAI-compatible proxy at /v1 (for OpenClaw, futures bot, curl, etc.)
-        .nest("/v1", proxy_router(ProxyState::new(repo_app_state)))
-        // Health check for OpenClaw / external probes
-        .route("/healthz", get(health_check));
-
     // Start auto-scanner in background if enabled
     let scanner_config = AutoScannerConfig {
         enabled: std::env::var("AUTO_SCAN_ENABLED")
@@ -507,32 +696,203 @@ async fn main() -> anyhow::Result<()> {
             .unwrap_or_else(|_| "3.00".into())
             .parse()
             .unwrap_or(3.00),
-    };
+        // AUTO_SCAN_CLONE_DEPTH=0 clones full history
+        clone_options: CloneOptions {
+            depth: match std::env::var("AUTO_SCAN_CLONE_DEPTH")
+                .unwrap_or_else(|_| "1".into())
+                .parse()
+                .unwrap_or(1)
+            {
+                0 => None,
+                depth => Some(depth),
+            },
+            sparse_paths: std::env::var("AUTO_SCAN_SPARSE_PATHS")
+                .map(|v| {
+                    v.split(',')
+                        .map(|p| p.trim().to_string())
+                        .filter(|p| !p.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            branch: None,
+        },
+        // AUTO_SCAN_FIRST_SCAN_SEED=full_tree seeds first scans with every
+        // tracked file (needs a cost budget); otherwise the last
+        // AUTO_SCAN_FIRST_SCAN_DEPTH commits
+        first_scan_seed: match std::env::var("AUTO_SCAN_FIRST_SCAN_SEED").as_deref() {
+            Ok("full_tree") => FirstScanSeed::FullTree,
+            _ => FirstScanSeed::RecentCommits,
+        },
+        first_scan_commit_depth: std::env::var("AUTO_SCAN_FIRST_SCAN_DEPTH")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_FIRST_SCAN_COMMIT_DEPTH),
+        // AUTO_SCAN_SUBMODULES=recurse scans submodules as their own repos
+        submodules: match std::env::var("AUTO_SCAN_SUBMODULES").as_deref() {
+            Ok("recurse") => SubmoduleMode::Recurse,
+            _ => SubmoduleMode::Skip,
+        },
+        // AUTO_SCAN_MINIFIED_THRESHOLD=0.8 skips only files that are clearly minified
+        minified_threshold: std::env::var("AUTO_SCAN_MINIFIED_THRESHOLD")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(rustassistant::minification::DEFAULT_MINIFIED_THRESHOLD),
+        // AUTO_SCAN_MINIFIED_AVG_LINE=2000 keeps long generated tables in scope
+        minified_avg_line_threshold: std::env::var("AUTO_SCAN_MINIFIED_AVG_LINE")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(rustassistant::minification::DEFAULT_MINIFIED_AVG_LINE_LEN),
+        minified_max_lines: std::env::var("AUTO_SCAN_MINIFIED_MAX_LINES")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(rustassistant::minification::DEFAULT_MINIFIED_MAX_LINES),
+        // AUTO_SCAN_REDACT_SECRETS=true masks detected secrets before LLM calls
+        redact_secrets: std::env::var("AUTO_SCAN_REDACT_SECRETS")
+            .unwrap_or_else(|_| "false".into())
+            .parse()
+            .unwrap_or(false),
+        // AUTO_SCAN_PROMPT_CACHE=true reuses responses to identical rendered prompts
+        prompt_cache: std::env::var("AUTO_SCAN_PROMPT_CACHE")
+            .unwrap_or_else(|_| "false".into())
+            .parse()
+            .unwrap_or(false),
+        // AUTO_SCAN_REPORT_PATH=reports/{repo}/ writes scan_report.json after each scan
+        scan_report_path: std::env::var("AUTO_SCAN_REPORT_PATH").ok(),
+        ..AutoScannerConfig::default()
+    }
+    // AUTO_SCAN_EXTRA_EXTENSIONS=php,scala analyzes file types beyond the built-in list
+    .with_extra_extensions(
+        std::env::var("AUTO_SCAN_EXTRA_EXTENSIONS")
+            .unwrap_or_default()
+            .split(','),
+    );
 
-    if scanner_config.enabled {
-        info!(
-            "🔍 Starting auto-scanner (interval: {} minutes)",
-            scanner_config.default_interval_minutes
-        );
-        let scanner = Arc::new(AutoScanner::new(
+    // [[prompt_overrides]] in ./.llm-audit.toml pin path globs to a prompt tier
+    let mut prompt_router = PromptRouter::new();
+    if std::path::Path::new(LLM_CONFIG_FILE).exists() {
+        match LlmConfig::load(std::path::Path::new("."))
+            .and_then(|config| PromptRouter::new().with_override_config(&config.prompt_overrides))
+        {
+            Ok(router) => prompt_router = router,
+            Err(e) => tracing::warn!("Ignoring prompt overrides: {}", e),
+        }
+    }
+
+    let progress_hub = ScanProgressHub::new();
+    let mut health_state =
+        HealthState::new(db.clone(), shutdown.clone()).with_sync(sync_health.clone());
+
+    let auto_scan_enabled = scanner_config.enabled;
+    let scan_interval_minutes = scanner_config.default_interval_minutes;
+    let scanner = Arc::new(
+        AutoScanner::new(
             scanner_config,
             db.clone(),
             std::path::PathBuf::from(&repos_dir),
-        ));
-        let scanner_clone = scanner.clone();
-        tokio::spawn(async move {
-            if let Err(e) = scanner_clone.start().await {
+        )
+        .with_shutdown(shutdown.clone())
+        .with_health(scanner_health.clone())
+        .with_progress_hub(progress_hub.clone())
+        .with_prompt_router(prompt_router),
+    );
+
+    // On-demand scans reuse the scanner even when the background loop is off
+    let scan_jobs = ScanJobService::new(db.clone(), scanner.clone());
+    if let Err(e) = scan_jobs.recover().await {
+        tracing::warn!("Failed to recover interrupted scan jobs: {}", e);
+    }
+
+    let scanner_handle = if auto_scan_enabled {
+        info!(
+            "🔍 Starting auto-scanner (interval: {} minutes)",
+            scan_interval_minutes
+        );
+        health_state = health_state.with_scanner(scanner_health.clone());
+        Some(tokio::spawn(async move {
+            if let Err(e) = scanner.start().await {
                 tracing::error!("Auto-scanner error: {}", e);
             }
-        });
+        }))
     } else {
         info!("Auto-scanner is disabled");
+        None
+    };
+
+    // Unified search falls back to local-only without a GitHub token
+    let mut searcher = UnifiedSearcher::new(db.clone());
+    if let Ok(token) = std::env::var("GITHUB_TOKEN") {
+        match GitHubClient::new(token) {
+            Ok(client) => searcher = searcher.with_remote(Arc::new(client)),
+            Err(e) => tracing::warn!("GitHub search disabled: {}", e),
+        }
     }
 
+    // Build combined router (API-only — no WebUI)
+    let api_router = create_api_router(api_state);
+
+    let app = Router::new()
+        .merge(api_router)
+        // Live scan progress: /api/repos/:id/events, /api/scans/:id/events (SSE)
+        .nest(
+            "/api",
+            scan_events_router(ScanEventsState {
+                hub: progress_hub,
+                pool: db.clone(),
+            }),
+        )
+        // Note search: /api/notes/search
+        .nest("/api", notes_router(db.clone()))
+        // GitHub code/issues/PRs + local notes/tasks/analyses: /api/search
+        .nest("/api", unified_search_router(searcher))
+        // On-demand scans: POST /api/repos/:id/scan, GET /api/jobs/:id
+        .nest("/api", scan_job_router(scan_jobs))
+        // Repo CRUD + chat with repo context + /api/v1/repos/:id/sync etc.
+        .nest("/api/v1", repo_router(repo_app_state.clone()))
+        // OpenAI-compatible proxy at /v1 (for OpenClaw, futures bot, curl, etc.)
+        .nest("/v1", proxy_router(ProxyState::new(repo_app_state)))
+        // Machine-readable spec + Swagger UI: /openapi.json, /docs
+        .merge(openapi_router(openapi_spec()))
+        // Liveness / readiness probes for OpenClaw and container orchestrators
+        .merge(health_router(health_state))
+        // API key / basic auth on everything but the public paths
+        .layer(axum::middleware::from_fn_with_state(
+            Arc::new(auth_config),
+            api_auth_middleware,
+        ));
+
     // Start server
     info!("🚀 Rustassistant server starting on http://{}", addr);
     let listener = tokio::net::TcpListener::bind(&addr).await?;
-    axum::serve(listener, app).await?;
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal(shutdown.clone()))
+        .await?;
+
+    // Drain background work: in-flight scans stop at the next file boundary
+    // with their checkpoint saved; a running sync pass completes.
+    let drain_secs: u64 = std::env::var("SHUTDOWN_DRAIN_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(30);
+    info!(
+        scans_in_flight = scanner_health.in_flight(),
+        drain_secs, "Draining background workers"
+    );
+    let drain = async {
+        if let Some(handle) = scanner_handle {
+            let _ = handle.await;
+        }
+        let _ = sync_handle.await;
+    };
+    if tokio::time::timeout(Duration::from_secs(drain_secs), drain)
+        .await
+        .is_err()
+    {
+        tracing::warn!(
+            scans_in_flight = scanner_health.in_flight(),
+            "Drain timed out — remaining scans resume from checkpoint on restart"
+        );
+    }
 
+    info!("✅ Rustassistant server stopped");
     Ok(())
 }
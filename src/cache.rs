@@ -312,6 +312,77 @@ impl AuditCache {
         Ok(removed)
     }
 
+    /// Remove every entry for `file_path`, whatever its content hash
+    ///
+    /// `file_path` may be relative to `repo_path` or absolute; entries stored
+    /// under either form are removed. Returns the number of entries removed.
+    pub fn invalidate_file(&self, repo_path: &Path, file_path: &Path) -> Result<usize> {
+        if !self.enabled {
+            return Ok(0);
+        }
+
+        let relative = file_path.strip_prefix(repo_path).unwrap_or(file_path);
+        let absolute = repo_path.join(relative);
+        let matches = |path: &str| {
+            let path = Path::new(path);
+            path == relative || path == absolute
+        };
+
+        let removed = self.remove_where(|key, entry| matches(key) || matches(&entry.file_path))?;
+        if removed > 0 {
+            info!(
+                "Invalidated {} cache entries for {}",
+                removed,
+                relative.display()
+            );
+        }
+
+        Ok(removed)
+    }
+
+    /// Remove entries analyzed more than `max_age_secs` ago
+    ///
+    /// Entries with an unparseable `analyzed_at` are treated as stale.
+    /// Returns the number of entries removed.
+    pub fn invalidate_stale(&self, max_age_secs: u64) -> Result<usize> {
+        if !self.enabled {
+            return Ok(0);
+        }
+
+        let cutoff = chrono::Utc::now()
+            - chrono::Duration::seconds(i64::try_from(max_age_secs).unwrap_or(i64::MAX));
+        let removed = self.remove_where(|_, entry| {
+            chrono::DateTime::parse_from_rfc3339(&entry.analyzed_at)
+                .map(|analyzed_at| analyzed_at < cutoff)
+                .unwrap_or(true)
+        })?;
+        if removed > 0 {
+            info!(
+                "Invalidated {} cache entries older than {}s",
+                removed, max_age_secs
+            );
+        }
+
+        Ok(removed)
+    }
+
+    /// Remove entries matching `predicate`, saving if anything changed
+    fn remove_where(&self, predicate: impl Fn(&str, &CacheEntry) -> bool) -> Result<usize> {
+        let removed = {
+            let mut entries = self.entries.borrow_mut();
+            let before = entries.len();
+            entries.retain(|key, entry| !predicate(key, entry));
+            before - entries.len()
+        };
+
+        if removed > 0 {
+            self.stats.borrow_mut().total_entries = self.entries.borrow().len();
+            self.save()?;
+        }
+
+        Ok(removed)
+    }
+
     /// Get cache hit rate as percentage
     pub fn hit_rate(&self) -> f64 {
         let stats = self.stats.borrow();
@@ -476,4 +547,92 @@ mod tests {
             assert_eq!(entry.analysis, analysis);
         }
     }
+
+    fn entry(cache: &AuditCache, path: &str, content: &str, analyzed_at: String) -> CacheEntry {
+        CacheEntry {
+            file_path: path.to_string(),
+            content_hash: cache.hash_content(content),
+            analyzed_at,
+            provider: "xai".to_string(),
+            model: "grok-4".to_string(),
+            analysis: serde_json::json!({"score": 85}),
+            tokens_used: Some(100),
+            file_size: content.len(),
+        }
+    }
+
+    #[test]
+    fn test_invalidate_file() {
+        let temp = TempDir::new().unwrap();
+        let config = crate::llm_config::CacheConfig::default();
+        let cache = AuditCache::new(temp.path(), &config).unwrap();
+        let now = chrono::Utc::now().to_rfc3339();
+
+        let absolute = temp.path().join("src/lib.rs").to_string_lossy().to_string();
+        cache
+            .set(
+                "src/lib.rs".to_string(),
+                entry(&cache, "src/lib.rs", "v1", now.clone()),
+            )
+            .unwrap();
+        cache
+            .set(
+                absolute.clone(),
+                entry(&cache, &absolute, "v2", now.clone()),
+            )
+            .unwrap();
+        cache
+            .set(
+                "src/main.rs".to_string(),
+                entry(&cache, "src/main.rs", "v1", now),
+            )
+            .unwrap();
+
+        let removed = cache
+            .invalidate_file(temp.path(), Path::new("src/lib.rs"))
+            .unwrap();
+        assert_eq!(removed, 2);
+        assert_eq!(cache.entry_count(), 1);
+        assert!(cache.get("src/main.rs", "v1").unwrap().is_some());
+
+        // Removal is persisted
+        let reloaded = AuditCache::new(temp.path(), &config).unwrap();
+        assert_eq!(reloaded.entry_count(), 1);
+        assert_eq!(
+            reloaded
+                .invalidate_file(temp.path(), Path::new("src/lib.rs"))
+                .unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_invalidate_stale() {
+        let temp = TempDir::new().unwrap();
+        let config = crate::llm_config::CacheConfig::default();
+        let cache = AuditCache::new(temp.path(), &config).unwrap();
+
+        let old = (chrono::Utc::now() - chrono::Duration::hours(2)).to_rfc3339();
+        let fresh = chrono::Utc::now().to_rfc3339();
+        cache
+            .set("old.rs".to_string(), entry(&cache, "old.rs", "a", old))
+            .unwrap();
+        cache
+            .set(
+                "fresh.rs".to_string(),
+                entry(&cache, "fresh.rs", "b", fresh),
+            )
+            .unwrap();
+        cache
+            .set(
+                "bad.rs".to_string(),
+                entry(&cache, "bad.rs", "c", "not a date".to_string()),
+            )
+            .unwrap();
+
+        assert_eq!(cache.invalidate_stale(3600).unwrap(), 2);
+        assert_eq!(cache.entry_count(), 1);
+        assert!(cache.get("fresh.rs", "b").unwrap().is_some());
+        assert_eq!(cache.stats().total_entries, 1);
+    }
 }
@@ -0,0 +1,384 @@
+//! CI quality gates
+//!
+//! `rustassistant check` runs a static-only pass over a working tree and
+//! fails the build when a gate is breached:
+//!
+//! - **security** — new potential secrets above `max_new_security_findings`
+//! - **score** — [`CodebaseScore::overall_health`] below `min_health_score`
+//! - **high_priority** — new high-priority TODO/FIXME markers above
+//!   `max_new_high_priority`
+//!
+//! "New" is relative to a baseline git ref when one is configured: findings
+//! already present there (matched by [`Finding`] fingerprint, so moved lines
+//! don't count) are ignored. Without a baseline every finding is new.
+//!
+//! Thresholds live in the `[gates]` table of `.llm-audit.toml`; no LLM calls
+//! or database are involved.
+
+use crate::auto_scanner::{AuditIgnore, AutoScannerConfig};
+use crate::findings_diff::{Finding, FindingCategory, FindingsDiff};
+use crate::scoring::{CodebaseScore, FileScorer};
+use crate::static_analysis::StaticAnalyzer;
+use crate::tags::TagScanner;
+use crate::todo_scanner::{TodoPriority, TodoScanner};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+/// Process exit code when every gate passes
+pub const EXIT_PASS: i32 = 0;
+
+/// Process exit code when a gate is breached
+pub const EXIT_FAIL: i32 = 1;
+
+/// Gate thresholds (`[gates]` in `.llm-audit.toml`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GateConfig {
+    /// Most new security findings allowed (default 0)
+    pub max_new_security_findings: usize,
+    /// Lowest acceptable overall health (0-100); `None` disables the gate
+    pub min_health_score: Option<f64>,
+    /// Most new high-priority findings allowed; `None` disables the gate
+    pub max_new_high_priority: Option<usize>,
+    /// Git ref findings are compared against, e.g. `origin/main`
+    pub baseline: Option<String>,
+}
+
+/// Findings of one static pass
+#[derive(Debug, Clone, Default)]
+pub struct Snapshot {
+    pub files: usize,
+    pub security: Vec<Finding>,
+    pub high_priority: Vec<Finding>,
+}
+
+impl Snapshot {
+    fn add_file(
+        &mut self,
+        analyzer: &StaticAnalyzer,
+        todo_scanner: &TodoScanner,
+        rel_path: &str,
+        content: &str,
+    ) {
+        self.files += 1;
+        let result = analyzer.analyze(rel_path, content);
+        for secret in &result.signals.potential_secrets {
+            let message = format!("{} {}", secret.pattern, secret.matched_text);
+            self.security
+                .push(Finding::new(rel_path, FindingCategory::Security, &message));
+        }
+        for todo in todo_scanner.scan_content(Path::new(rel_path), content) {
+            if todo.priority == TodoPriority::High {
+                self.high_priority.push(Finding::new(
+                    rel_path,
+                    FindingCategory::Improvement,
+                    &todo.text,
+                ));
+            }
+        }
+    }
+}
+
+/// One gate's verdict
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GateResult {
+    pub gate: String,
+    pub passed: bool,
+    pub actual: String,
+    pub threshold: String,
+}
+
+/// Verdicts of every enabled gate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckOutcome {
+    pub files: usize,
+    pub baseline: Option<String>,
+    pub gates: Vec<GateResult>,
+    /// Findings that counted against the gates
+    pub new_security: Vec<Finding>,
+    pub new_high_priority: Vec<Finding>,
+    pub score: CodebaseScore,
+}
+
+impl CheckOutcome {
+    pub fn passed(&self) -> bool {
+        self.gates.iter().all(|g| g.passed)
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        if self.passed() {
+            EXIT_PASS
+        } else {
+            EXIT_FAIL
+        }
+    }
+
+    /// One line per gate, then the findings behind any failure
+    pub fn summary(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{} files checked{}",
+            self.files,
+            self.baseline
+                .as_ref()
+                .map(|b| format!(" against {}", b))
+                .unwrap_or_default()
+        );
+        for gate in &self.gates {
+            let _ = writeln!(
+                out,
+                "  {} {:<14} {} (threshold: {})",
+                if gate.passed { "PASS" } else { "FAIL" },
+                gate.gate,
+                gate.actual,
+                gate.threshold
+            );
+        }
+        for (gate, findings) in [
+            ("security", &self.new_security),
+            ("high_priority", &self.new_high_priority),
+        ] {
+            if self.gates.iter().any(|g| g.gate == gate && !g.passed) {
+                for f in findings {
+                    let _ = writeln!(out, "    {}: {}", f.file.display(), f.message);
+                }
+            }
+        }
+        let _ = write!(
+            out,
+            "{}",
+            if self.passed() {
+                "check passed"
+            } else {
+                "check FAILED"
+            }
+        );
+        out
+    }
+}
+
+/// Static pass over the analyzable files of a working tree (respecting
+/// `.gitignore`), plus its [`CodebaseScore`]
+pub fn snapshot_worktree(root: &Path) -> Result<(Snapshot, CodebaseScore)> {
+    let analyzer = StaticAnalyzer::new();
+    let todo_scanner = TodoScanner::new()?;
+    let tag_scanner = TagScanner::new()?;
+    let scorer = FileScorer::new();
+    let filter = AutoScannerConfig::default();
+    let ignore = AuditIgnore::load(root);
+
+    let mut snapshot = Snapshot::default();
+    let mut scores = Vec::new();
+    for entry in ignore::WalkBuilder::new(root).build() {
+        let entry = entry?;
+        if !entry.file_type().is_some_and(|t| t.is_file()) {
+            continue;
+        }
+        let rel = entry.path().strip_prefix(root).unwrap_or(entry.path());
+        let rel_str = rel.to_string_lossy().replace('\\', "/");
+        if !filter.should_analyze_file_in(&rel_str, &ignore) {
+            continue;
+        }
+        // Binary or unreadable files have nothing to gate on
+        let Ok(content) = std::fs::read_to_string(entry.path()) else {
+            continue;
+        };
+
+        snapshot.add_file(&analyzer, &todo_scanner, &rel_str, &content);
+        let tags = tag_scanner.scan_file(entry.path()).unwrap_or_default();
+        let todos = todo_scanner.scan_content(rel, &content);
+        scores.push(scorer.score_file(rel, &content, &tags, &todos)?);
+    }
+    Ok((snapshot, CodebaseScore::from_file_scores(&scores)))
+}
+
+/// Static pass over the files of a git revision, read from the object store
+pub fn snapshot_at_ref(repo_root: &Path, rev: &str) -> Result<Snapshot> {
+    let repo = git2::Repository::discover(repo_root)
+        .with_context(|| format!("{} is not a git repository", repo_root.display()))?;
+    let tree = repo
+        .revparse_single(rev)
+        .and_then(|obj| obj.peel_to_tree())
+        .with_context(|| format!("Unknown baseline ref {}", rev))?;
+
+    let analyzer = StaticAnalyzer::new();
+    let todo_scanner = TodoScanner::new()?;
+    let filter = AutoScannerConfig::default();
+    // The working tree's rules apply to both sides of the comparison
+    let ignore = AuditIgnore::load(repo_root);
+
+    // Paths in the tree are relative to the repo root; make them relative
+    // to `repo_root` like the working-tree pass
+    let prefix = repo
+        .workdir()
+        .and_then(|wd| {
+            let wd = wd.canonicalize().ok()?;
+            let root = repo_root.canonicalize().ok()?;
+            root.strip_prefix(&wd).ok().map(PathBuf::from)
+        })
+        .unwrap_or_default();
+
+    let mut snapshot = Snapshot::default();
+    let mut walk_err = None;
+    tree.walk(git2::TreeWalkMode::PreOrder, |dir, entry| {
+        if entry.kind() != Some(git2::ObjectType::Blob) {
+            return git2::TreeWalkResult::Ok;
+        }
+        let path = PathBuf::from(format!("{}{}", dir, entry.name().unwrap_or_default()));
+        let Ok(rel) = path.strip_prefix(&prefix) else {
+            return git2::TreeWalkResult::Ok;
+        };
+        let rel_str = rel.to_string_lossy().to_string();
+        if !filter.should_analyze_file_in(&rel_str, &ignore) {
+            return git2::TreeWalkResult::Ok;
+        }
+        match entry.to_object(&repo).and_then(|o| o.peel_to_blob()) {
+            Ok(blob) => {
+                if let Ok(content) = std::str::from_utf8(blob.content()) {
+                    snapshot.add_file(&analyzer, &todo_scanner, &rel_str, content);
+                }
+                git2::TreeWalkResult::Ok
+            }
+            Err(e) => {
+                walk_err = Some(e);
+                git2::TreeWalkResult::Abort
+            }
+        }
+    })?;
+    if let Some(e) = walk_err {
+        return Err(e.into());
+    }
+    Ok(snapshot)
+}
+
+/// Run every enabled gate over the working tree at `root`
+pub fn run_check(root: &Path, config: &GateConfig) -> Result<CheckOutcome> {
+    let (current, score) = snapshot_worktree(root)?;
+    let (new_security, new_high_priority) = match &config.baseline {
+        Some(rev) => {
+            let baseline = snapshot_at_ref(root, rev)?;
+            (
+                FindingsDiff::between(&baseline.security, &current.security).new,
+                FindingsDiff::between(&baseline.high_priority, &current.high_priority).new,
+            )
+        }
+        None => (current.security, current.high_priority),
+    };
+
+    let mut gates = vec![GateResult {
+        gate: "security".into(),
+        passed: new_security.len() <= config.max_new_security_findings,
+        actual: format!("{} new finding(s)", new_security.len()),
+        threshold: format!("<= {}", config.max_new_security_findings),
+    }];
+    if let Some(floor) = config.min_health_score {
+        gates.push(GateResult {
+            gate: "score".into(),
+            passed: score.overall_health >= floor,
+            actual: format!("health {:.1}", score.overall_health),
+            threshold: format!(">= {:.1}", floor),
+        });
+    }
+    if let Some(max) = config.max_new_high_priority {
+        gates.push(GateResult {
+            gate: "high_priority".into(),
+            passed: new_high_priority.len() <= max,
+            actual: format!("{} new finding(s)", new_high_priority.len()),
+            threshold: format!("<= {}", max),
+        });
+    }
+
+    Ok(CheckOutcome {
+        files: current.files,
+        baseline: config.baseline.clone(),
+        gates,
+        new_security,
+        new_high_priority,
+        score,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args([
+                "-c",
+                "user.name=Fixture",
+                "-c",
+                "user.email=fixture@example.com",
+            ])
+            .args(args)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    const CLEAN: &str = "/// Adds two numbers\npub fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n";
+    const LEAKY: &str = "pub fn client() -> Client {\n    let api_key = \"sk_live_0123456789abcdefXYZ\";\n    Client::new(api_key)\n}\n";
+
+    #[test]
+    fn test_security_gate_sets_exit_code() {
+        let config = GateConfig::default();
+
+        let clean = tempfile::TempDir::new().unwrap();
+        std::fs::write(clean.path().join("lib.rs"), CLEAN).unwrap();
+        let outcome = run_check(clean.path(), &config).unwrap();
+        assert!(outcome.passed(), "{}", outcome.summary());
+        assert_eq!(outcome.exit_code(), EXIT_PASS);
+
+        let leaky = tempfile::TempDir::new().unwrap();
+        std::fs::write(leaky.path().join("lib.rs"), CLEAN).unwrap();
+        std::fs::write(leaky.path().join("client.rs"), LEAKY).unwrap();
+        let outcome = run_check(leaky.path(), &config).unwrap();
+        assert_eq!(outcome.exit_code(), EXIT_FAIL);
+        assert!(outcome.summary().contains("FAIL security"));
+        assert!(outcome
+            .new_security
+            .iter()
+            .all(|f| f.file == Path::new("client.rs")));
+    }
+
+    #[test]
+    fn test_baseline_only_counts_new_findings() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        git(root, &["init", "-q", "-b", "main"]);
+        std::fs::write(root.join("client.rs"), LEAKY).unwrap();
+        std::fs::write(
+            root.join("lib.rs"),
+            "// FIXME: handle overflow\n".to_string() + CLEAN,
+        )
+        .unwrap();
+        git(root, &["add", "."]);
+        git(root, &["commit", "-q", "-m", "base"]);
+
+        let config = GateConfig {
+            baseline: Some("HEAD".into()),
+            max_new_high_priority: Some(0),
+            ..GateConfig::default()
+        };
+
+        // Existing findings, shifted down a line, are not new
+        std::fs::write(root.join("client.rs"), format!("\n{}", LEAKY)).unwrap();
+        let outcome = run_check(root, &config).unwrap();
+        assert!(outcome.passed(), "{}", outcome.summary());
+
+        std::fs::write(root.join("util.rs"), "// FIXME: this leaks memory\n").unwrap();
+        let outcome = run_check(root, &config).unwrap();
+        assert_eq!(outcome.exit_code(), EXIT_FAIL);
+        assert_eq!(outcome.new_high_priority.len(), 1);
+        assert!(outcome
+            .gates
+            .iter()
+            .any(|g| g.gate == "high_priority" && !g.passed));
+    }
+}
@@ -109,7 +109,7 @@ pub async fn handle_github_command(command: GithubCommands, pool: &PgPool) -> Re
     })?;
 
     let client = GitHubClient::new(token)?;
-    let sync_engine = SyncEngine::new(client.clone(), pool.clone());
+    let sync_engine = SyncEngine::new(Box::new(client.clone()), pool.clone());
     let searcher = GitHubSearcher::new(pool.clone());
 
     match command {
@@ -172,7 +172,7 @@ pub async fn handle_github_command(command: GithubCommands, pool: &PgPool) -> Re
 
                 if let Some(ref _repo_name) = repo {
                     query.push_str(
-                        " WHERE repo_id = (SELECT id FROM github_repositories WHERE full_name = $1)",
+                        " WHERE (provider, repo_id) IN (SELECT provider, id FROM github_repositories WHERE full_name = $1)",
                     );
                 }
 
@@ -207,7 +207,7 @@ pub async fn handle_github_command(command: GithubCommands, pool: &PgPool) -> Re
 
                 if let Some(ref _repo_name) = repo {
                     query.push_str(
-                        " WHERE repo_id = (SELECT id FROM github_repositories WHERE full_name = $1)",
+                        " WHERE (provider, repo_id) IN (SELECT provider, id FROM github_repositories WHERE full_name = $1)",
                     );
                 }
 
@@ -254,9 +254,9 @@ pub async fn handle_github_command(command: GithubCommands, pool: &PgPool) -> Re
                         let (commits, issues, prs): (i64, i64, i64) = sqlx::query_as(
                             r#"
                             SELECT
-                                (SELECT COUNT(*) FROM github_commits WHERE repo_id = (SELECT id FROM github_repositories WHERE full_name = $1)),
-                                (SELECT COUNT(*) FROM github_issues WHERE repo_id = (SELECT id FROM github_repositories WHERE full_name = $2)),
-                                (SELECT COUNT(*) FROM github_pull_requests WHERE repo_id = (SELECT id FROM github_repositories WHERE full_name = $3))
+                                (SELECT COUNT(*) FROM github_commits WHERE (provider, repo_id) IN (SELECT provider, id FROM github_repositories WHERE full_name = $1)),
+                                (SELECT COUNT(*) FROM github_issues WHERE (provider, repo_id) IN (SELECT provider, id FROM github_repositories WHERE full_name = $2)),
+                                (SELECT COUNT(*) FROM github_pull_requests WHERE (provider, repo_id) IN (SELECT provider, id FROM github_repositories WHERE full_name = $3))
                             "#
                         )
                         .bind(repo_name)
@@ -290,7 +290,7 @@ pub async fn handle_github_command(command: GithubCommands, pool: &PgPool) -> Re
 
                 if let Some(ref _repo_name) = repo {
                     query.push_str(
-                        " AND repo_id = (SELECT id FROM github_repositories WHERE full_name = $1)",
+                        " AND (provider, repo_id) IN (SELECT provider, id FROM github_repositories WHERE full_name = $1)",
                     );
                 }
 
@@ -70,7 +70,7 @@ pub enum QueueCommands {
 
     /// Retry failed items
     Retry {
-        /// Specific item ID to retry (or all if not specified)
+        /// Dead-lettered item ID to requeue (or retry all failed items if not specified)
         id: Option<String>,
     },
 }
@@ -224,6 +224,11 @@ pub async fn handle_queue_command(pool: &PgPool, cmd: QueueCommands) -> Result<(
                 "Failed:".dimmed(),
                 format!("{}", stats.failed).red()
             );
+            println!(
+                "  {} {}",
+                "Dead Letter:".dimmed(),
+                format!("{}", stats.dead_letter).red()
+            );
             println!("  {} {}", "Archived:".dimmed(), stats.archived);
             println!();
             println!("  {} {}", "Total Pending:".cyan(), stats.total_pending());
@@ -289,10 +294,15 @@ pub async fn handle_queue_command(pool: &PgPool, cmd: QueueCommands) -> Result<(
         }
 
         QueueCommands::Retry { id } => {
-            if let Some(_id) = id {
-                println!("{} Retry by ID not yet implemented", "⚠".yellow());
+            if let Some(id) = id {
+                let item = crate::queue::processor::requeue_dead_letter(pool, &id).await?;
+                println!("{} Requeued {} ({})", "✓".green(), item.id, item.stage);
             } else {
-                let items = crate::queue::processor::get_retriable_items(pool, 3).await?;
+                let items = crate::queue::processor::get_retriable_items(
+                    pool,
+                    crate::queue::processor::DEFAULT_MAX_ATTEMPTS,
+                )
+                .await?;
                 println!("🔄 Found {} retriable items", items.len());
 
                 for item in items {
@@ -862,6 +872,7 @@ fn parse_stage(s: &str) -> QueueStage {
         "tagging" | "pending_tagging" => QueueStage::PendingTagging,
         "ready" | "done" => QueueStage::Ready,
         "failed" | "error" => QueueStage::Failed,
+        "dead" | "dead_letter" => QueueStage::DeadLetter,
         "archived" => QueueStage::Archived,
         _ => QueueStage::Inbox,
     }
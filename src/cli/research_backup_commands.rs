@@ -1,6 +1,7 @@
 //! Research and Backup CLI Commands
 
 use crate::backup::{print_rclone_setup_instructions, BackupConfig, BackupManager};
+use crate::db::{ListFilter, PageRequest};
 use crate::llm::GrokClient;
 use crate::research::aggregator::Aggregator;
 use crate::research::worker::{ResearchOrchestrator, WorkerConfig};
@@ -144,7 +145,13 @@ pub async fn handle_research_command(pool: &PgPool, cmd: ResearchCommands) -> Re
         }
 
         ResearchCommands::List { limit } => {
-            let research = list_research(pool, limit).await?;
+            let research = list_research(
+                pool,
+                &ListFilter::default(),
+                PageRequest::new(limit as i64, 0),
+            )
+            .await?
+            .items;
 
             if research.is_empty() {
                 println!("{}", "No research projects found".yellow());
@@ -210,6 +217,7 @@ pub async fn handle_research_command(pool: &PgPool, cmd: ResearchCommands) -> Re
                     timeout_secs: 60,
                     max_tokens: 2048,
                     retry_failed: false,
+                    max_sources: 3,
                 },
             );
 
@@ -231,7 +239,11 @@ pub async fn handle_research_command(pool: &PgPool, cmd: ResearchCommands) -> Re
 #[derive(Subcommand)]
 pub enum BackupCommands {
     /// Create a new backup
-    Create,
+    Create {
+        /// Snapshot and measure without uploading or pruning anything
+        #[arg(long)]
+        dry_run: bool,
+    },
 
     /// List available backups
     List,
@@ -254,7 +266,31 @@ pub async fn handle_backup_command(cmd: BackupCommands) -> Result<()> {
     let manager = BackupManager::new(config.clone());
 
     match cmd {
-        BackupCommands::Create => {
+        BackupCommands::Create { dry_run: true } => {
+            println!("\n{} Planning backup (dry run)...\n", "📦".bold());
+
+            match manager.create_backup_dry_run() {
+                Ok(plan) => {
+                    println!("  Name:  {}", plan.name.cyan());
+                    println!("  Files: {}", plan.file_count);
+                    println!("  Size:  {} bytes", plan.size_bytes);
+                    println!("  Path:  {}", plan.remote_path.dimmed());
+                    println!(
+                        "  Retention: keep {}, would remove {}",
+                        plan.retention_count,
+                        plan.would_remove.len()
+                    );
+                    for name in &plan.would_remove {
+                        println!("    - {}", name.dimmed());
+                    }
+                }
+                Err(e) => {
+                    println!("{} Dry run failed: {}", "✗".red(), e);
+                }
+            }
+        }
+
+        BackupCommands::Create { dry_run: false } => {
             // Check rclone first
             if !manager.check_rclone()? {
                 println!(
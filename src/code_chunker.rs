@@ -32,6 +32,9 @@
 //!   content_hash → (embedding, analysis_result, [(repo_a, path_a), (repo_b, path_b)])
 //! ```
 //!
+//! Embeddings are tagged with their [`VectorSpace`] (model + dimension); the
+//! same hash embedded by two models is two entries, never one.
+//!
 //! When scanning repo B and a chunk matches a hash from repo A's cache:
 //! - Skip embedding generation (free)
 //! - Skip LLM analysis (free)
@@ -50,10 +53,14 @@
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::Path;
+use std::sync::Arc;
 use tracing::{debug, warn};
 
-use crate::static_analysis::FileLanguage;
+use crate::embeddings::Embedder;
+use crate::language::FileLanguage;
+use crate::progress::{self, ProgressReporter};
 
 // ============================================================================
 // Core Types
@@ -131,6 +138,37 @@ pub struct CodeChunk {
     /// Empty until the embedding pipeline runs.
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub vector: Vec<f32>,
+
+    /// Model and dimension `vector` was produced with; `None` until embedded
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub vector_space: Option<VectorSpace>,
+}
+
+/// The embedding model a vector belongs to.
+///
+/// Vectors from different models (or dimensions) are not comparable, so
+/// chunks carry this tag alongside their embedding and similarity search
+/// only ever compares vectors within one space.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct VectorSpace {
+    /// Embedding model identifier (e.g., "bge-small-en-v1.5")
+    pub model_id: String,
+    /// Vector dimension
+    pub dim: usize,
+}
+
+impl VectorSpace {
+    pub fn new(model_id: impl Into<String>, dim: usize) -> Self {
+        Self {
+            model_id: model_id.into(),
+            dim,
+        }
+    }
+
+    /// Whether `vector` could belong to this space
+    pub fn fits(&self, vector: &[f32]) -> bool {
+        vector.len() == self.dim
+    }
 }
 
 impl CodeChunk {
@@ -170,6 +208,7 @@ impl CodeChunk {
             issue_count: 0,
             last_analyzed: 0,
             vector: Vec::new(),
+            vector_space: None,
         }
     }
 
@@ -197,6 +236,14 @@ impl CodeChunk {
         self
     }
 
+    /// Attach an embedding produced by `model_id`; the vector space's
+    /// dimension is taken from the vector itself
+    pub fn with_embedding(mut self, model_id: impl Into<String>, vector: Vec<f32>) -> Self {
+        self.vector_space = Some(VectorSpace::new(model_id, vector.len()));
+        self.vector = vector;
+        self
+    }
+
     /// Set the complexity score
     pub fn with_complexity(mut self, score: f32) -> Self {
         self.complexity_score = score;
@@ -264,6 +311,76 @@ impl std::fmt::Display for EntityType {
     }
 }
 
+/// How a chunk changed between two versions of a file.
+///
+/// Chunks are matched by `entity_type` + `entity_name`, not line position,
+/// and compared by [`normalized_content_hash`], so a function that only
+/// moved (or only gained surrounding whitespace) is `Unchanged`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ChunkDelta {
+    /// Entity only exists in the new version
+    Added { chunk: CodeChunk },
+    /// Entity only exists in the old version
+    Removed { chunk: CodeChunk },
+    /// Entity exists in both with the same normalized content; its
+    /// embedding and analysis can be reused
+    Unchanged { old: CodeChunk, new: CodeChunk },
+    /// Entity exists in both but its content changed
+    Modified { old: CodeChunk, new: CodeChunk },
+}
+
+impl ChunkDelta {
+    /// The chunk as it is now, or as it was for `Removed`
+    pub fn chunk(&self) -> &CodeChunk {
+        match self {
+            Self::Added { chunk } | Self::Removed { chunk } => chunk,
+            Self::Unchanged { new, .. } | Self::Modified { new, .. } => new,
+        }
+    }
+
+    /// Whether the current chunk needs a fresh embedding
+    pub fn needs_embedding(&self) -> bool {
+        matches!(self, Self::Added { .. } | Self::Modified { .. })
+    }
+}
+
+/// A heuristic `caller -> callee` edge between two Rust chunks, found by
+/// [`CodeChunker::extract_call_edges`]
+///
+/// Names are chunk `entity_name`s: `parse` for a free function,
+/// `Config::load` for a method chunk, `Config` for an unsplit impl block.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CallEdge {
+    pub caller: String,
+    pub caller_file: String,
+    pub callee: String,
+    pub callee_file: String,
+}
+
+/// Adjacency list of call edges: caller name → sorted, deduplicated callees
+pub fn call_adjacency(edges: &[CallEdge]) -> BTreeMap<String, Vec<String>> {
+    let mut adjacency: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for edge in edges {
+        adjacency
+            .entry(edge.caller.clone())
+            .or_default()
+            .push(edge.callee.clone());
+    }
+    for callees in adjacency.values_mut() {
+        callees.sort();
+        callees.dedup();
+    }
+    adjacency
+}
+
+/// A function a call can resolve to; `owner` is the impl type for methods
+struct Callable<'a> {
+    owner: Option<&'a str>,
+    name: &'a str,
+    chunk: usize,
+}
+
 // ============================================================================
 // Configuration
 // ============================================================================
@@ -294,6 +411,11 @@ pub struct ChunkerConfig {
 
     /// Maximum number of chunks per file (safety limit, default: 500)
     pub max_chunks_per_file: usize,
+
+    /// Whether `content_hash` ignores single-line and doc comments, so the
+    /// same code documented differently dedups to one chunk (default: false).
+    /// The stored `content` keeps its comments either way.
+    pub hash_ignores_comments: bool,
 }
 
 impl Default for ChunkerConfig {
@@ -307,6 +429,7 @@ impl Default for ChunkerConfig {
             group_imports: true,
             separate_tests: true,
             max_chunks_per_file: 500,
+            hash_ignores_comments: false,
         }
     }
 }
@@ -324,6 +447,8 @@ pub struct CodeChunker {
     kotlin_patterns: KotlinPatterns,
     /// Pre-compiled patterns for general boundary detection
     general_patterns: GeneralPatterns,
+    /// Observer for [`CodeChunker::chunk_directory`]
+    progress: Arc<dyn ProgressReporter>,
 }
 
 /// Pre-compiled regex patterns for Rust code boundaries
@@ -341,6 +466,9 @@ struct RustPatterns {
     test_mod: Regex,
     doc_comment: Regex,
     attribute: Regex,
+    /// A call site: optional `self.` / `.` / `Path::` qualifier, then
+    /// `name(` or `name::<T>(`
+    call_site: Regex,
 }
 
 /// Pre-compiled regex patterns for Kotlin code boundaries
@@ -383,6 +511,10 @@ impl RustPatterns {
             test_mod: Regex::new(r"#\[cfg\(test\)\]").unwrap(),
             doc_comment: Regex::new(r"^\s*(///|//!)").unwrap(),
             attribute: Regex::new(r"^\s*#\[").unwrap(),
+            call_site: Regex::new(
+                r"(?:(?P<self_recv>\bself\s*\.)|(?P<recv>\.)|(?P<path>\w+)\s*::)?\s*\b(?P<name>[A-Za-z_]\w*)\s*(?:::\s*<[^()]*>\s*)?\(",
+            )
+            .unwrap(),
         }
     }
 }
@@ -434,6 +566,7 @@ impl CodeChunker {
             rust_patterns: RustPatterns::new(),
             kotlin_patterns: KotlinPatterns::new(),
             general_patterns: GeneralPatterns::new(),
+            progress: progress::noop(),
         }
     }
 
@@ -444,6 +577,7 @@ impl CodeChunker {
             rust_patterns: RustPatterns::new(),
             kotlin_patterns: KotlinPatterns::new(),
             general_patterns: GeneralPatterns::new(),
+            progress: progress::noop(),
         }
     }
 
@@ -498,6 +632,11 @@ impl CodeChunker {
 
             // Compute simple complexity score
             chunk.complexity_score = self.compute_chunk_complexity(&chunk.content);
+
+            if self.config.hash_ignores_comments {
+                chunk.content_hash =
+                    compute_content_hash(&strip_comments(&chunk.content, language));
+            }
         }
 
         // Enforce max chunks limit
@@ -521,6 +660,66 @@ impl CodeChunker {
         chunks
     }
 
+    /// Chunk both versions of a file and report per-entity changes.
+    ///
+    /// Deltas for the new chunks come first, in file order, followed by
+    /// `Removed` deltas in old file order. Entities sharing a type and name
+    /// (e.g. several `impl Foo` blocks) are paired in order of appearance,
+    /// preferring a pair whose content is unchanged.
+    pub fn chunk_file_diff(
+        &self,
+        old_content: &str,
+        new_content: &str,
+        file_path: &str,
+        repo_id: &str,
+    ) -> Vec<ChunkDelta> {
+        let old_chunks = self.chunk_file(file_path, old_content, repo_id);
+        let new_chunks = self.chunk_file(file_path, new_content, repo_id);
+
+        let mut unmatched: HashMap<(EntityType, String), Vec<(String, CodeChunk)>> = HashMap::new();
+        let mut old_order = Vec::with_capacity(old_chunks.len());
+        for chunk in old_chunks {
+            let key = (chunk.entity_type, chunk.entity_name.clone());
+            old_order.push(key.clone());
+            let hash = normalized_content_hash(&chunk.content);
+            unmatched.entry(key).or_default().push((hash, chunk));
+        }
+
+        let mut deltas = Vec::with_capacity(new_chunks.len());
+        for new in new_chunks {
+            let key = (new.entity_type, new.entity_name.clone());
+            let hash = normalized_content_hash(&new.content);
+            let candidates = unmatched.get_mut(&key).filter(|c| !c.is_empty());
+            deltas.push(match candidates {
+                Some(candidates) => match candidates.iter().position(|(h, _)| *h == hash) {
+                    Some(idx) => Self::paired(candidates.remove(idx).1, new, true),
+                    None => Self::paired(candidates.remove(0).1, new, false),
+                },
+                None => ChunkDelta::Added { chunk: new },
+            });
+        }
+
+        // Whatever is left was removed; report it in old file order
+        for key in old_order {
+            if let Some(candidates) = unmatched.get_mut(&key) {
+                if !candidates.is_empty() {
+                    let (_, chunk) = candidates.remove(0);
+                    deltas.push(ChunkDelta::Removed { chunk });
+                }
+            }
+        }
+
+        deltas
+    }
+
+    fn paired(old: CodeChunk, new: CodeChunk, unchanged: bool) -> ChunkDelta {
+        if unchanged {
+            ChunkDelta::Unchanged { old, new }
+        } else {
+            ChunkDelta::Modified { old, new }
+        }
+    }
+
     /// Chunk a file by reading it from disk.
     pub fn chunk_file_from_path(
         &self,
@@ -532,11 +731,190 @@ impl CodeChunker {
         Ok(self.chunk_file(&rel_path, &content, repo_id))
     }
 
+    /// Report [`CodeChunker::chunk_directory`] progress to `reporter`
+    pub fn with_progress(mut self, reporter: Arc<dyn ProgressReporter>) -> Self {
+        self.progress = reporter;
+        self
+    }
+
+    /// Chunk every code file under `root`, respecting `.gitignore`.
+    ///
+    /// Files are visited in path order and chunk paths are relative to
+    /// `root`. Unreadable files are skipped.
+    pub fn chunk_directory(&self, root: &Path, repo_id: &str) -> std::io::Result<Vec<CodeChunk>> {
+        let mut files = Vec::new();
+        for entry in ignore::WalkBuilder::new(root).build() {
+            let entry = entry.map_err(std::io::Error::other)?;
+            if !entry.file_type().is_some_and(|t| t.is_file()) {
+                continue;
+            }
+            let rel = entry.path().strip_prefix(root).unwrap_or(entry.path());
+            let rel = rel.to_string_lossy().replace('\\', "/");
+            if FileLanguage::from_extension(&rel).is_code() {
+                files.push((rel, entry.into_path()));
+            }
+        }
+        files.sort();
+
+        self.progress
+            .start(&format!("Chunking {}", root.display()), files.len() as u64);
+        let mut chunks = Vec::new();
+        for (idx, (rel, path)) in files.iter().enumerate() {
+            match std::fs::read_to_string(path) {
+                Ok(content) => chunks.extend(self.chunk_file(rel, &content, repo_id)),
+                Err(e) => warn!("Skipping {}: {}", path.display(), e),
+            }
+            self.progress.advance(rel, (idx + 1) as u64);
+        }
+        self.progress.finish(&format!(
+            "Chunked {} files into {} chunks",
+            files.len(),
+            chunks.len()
+        ));
+
+        Ok(chunks)
+    }
+
     /// Get the chunker configuration
     pub fn config(&self) -> &ChunkerConfig {
         &self.config
     }
 
+    /// Find `caller -> callee` edges between Rust function chunks
+    ///
+    /// Each function, method, test and impl chunk is scanned (comments
+    /// stripped) for call sites naming another chunk. There is no type
+    /// resolution, so method calls are resolved conservatively:
+    /// - `name(...)` and `module::name(...)` match free functions, preferring
+    ///   one in the caller's file when several share the name
+    /// - `Type::name(...)`, `Self::name(...)` and `self.name(...)` match only
+    ///   methods of that type (or of the caller's own type)
+    /// - `value.name(...)` matches a method only if exactly one type whose
+    ///   name appears in the caller defines it
+    ///
+    /// Self-calls are skipped and each edge is reported once. Use
+    /// [`call_adjacency`] for the adjacency-list form.
+    pub fn extract_call_edges(&self, chunks: &[CodeChunk]) -> Vec<CallEdge> {
+        let is_code = |c: &CodeChunk| {
+            c.language == FileLanguage::Rust
+                && matches!(
+                    c.entity_type,
+                    EntityType::Function | EntityType::Test | EntityType::ImplBlock
+                )
+        };
+
+        // Everything a call can resolve to
+        let mut callables: Vec<Callable> = Vec::new();
+        for (idx, chunk) in chunks.iter().enumerate() {
+            if !is_code(chunk) {
+                continue;
+            }
+            match chunk.entity_type {
+                EntityType::ImplBlock => {
+                    // Unsplit impl block: each method resolves to the block
+                    let owner = impl_owner(&chunk.entity_name);
+                    for line in chunk.content.lines() {
+                        if let Some(caps) = self.rust_patterns.fn_def.captures(line) {
+                            if let Some(name) = caps.get(6) {
+                                callables.push(Callable {
+                                    owner: Some(owner),
+                                    name: name.as_str(),
+                                    chunk: idx,
+                                });
+                            }
+                        }
+                    }
+                }
+                _ => {
+                    let (owner, name) = match chunk.entity_name.rsplit_once("::") {
+                        Some((owner, name)) => (Some(impl_owner(owner)), name),
+                        None => (None, chunk.entity_name.as_str()),
+                    };
+                    callables.push(Callable {
+                        owner,
+                        name,
+                        chunk: idx,
+                    });
+                }
+            }
+        }
+
+        let mut edges: Vec<CallEdge> = Vec::new();
+        let mut seen: std::collections::HashSet<(usize, usize)> = std::collections::HashSet::new();
+        for (caller_idx, caller) in chunks.iter().enumerate() {
+            if !is_code(caller) {
+                continue;
+            }
+            let caller_owner = match caller.entity_type {
+                EntityType::ImplBlock => Some(impl_owner(&caller.entity_name)),
+                _ => caller
+                    .entity_name
+                    .rsplit_once("::")
+                    .map(|(owner, _)| impl_owner(owner)),
+            };
+            let body = strip_comments(&caller.content, FileLanguage::Rust);
+
+            for caps in self.rust_patterns.call_site.captures_iter(&body) {
+                let name = &caps["name"];
+                let start = caps.get(0).map_or(0, |m| m.start());
+                // `fn name(` is a definition, not a call
+                if body[..start].trim_end().ends_with("fn") {
+                    continue;
+                }
+
+                let methods_of = |owner: &str| -> Vec<usize> {
+                    callables
+                        .iter()
+                        .filter(|c| c.name == name && c.owner == Some(owner))
+                        .map(|c| c.chunk)
+                        .collect()
+                };
+
+                let targets: Vec<usize> = if caps.name("self_recv").is_some() {
+                    caller_owner.map(methods_of).unwrap_or_default()
+                } else if let Some(path) = caps.name("path").map(|m| m.as_str()) {
+                    if path == "Self" {
+                        caller_owner.map(methods_of).unwrap_or_default()
+                    } else if path.starts_with(char::is_uppercase) {
+                        methods_of(path)
+                    } else {
+                        free_functions(&callables, chunks, name, &caller.file_path)
+                    }
+                } else if caps.name("recv").is_some() {
+                    // Unknown receiver: only trust a method whose type the
+                    // caller mentions, and only if that is unambiguous
+                    let candidates: Vec<usize> = callables
+                        .iter()
+                        .filter(|c| c.name == name && c.owner.is_some_and(|o| mentions(&body, o)))
+                        .map(|c| c.chunk)
+                        .collect();
+                    if candidates.len() == 1 {
+                        candidates
+                    } else {
+                        Vec::new()
+                    }
+                } else {
+                    free_functions(&callables, chunks, name, &caller.file_path)
+                };
+
+                for callee_idx in targets {
+                    if callee_idx == caller_idx || !seen.insert((caller_idx, callee_idx)) {
+                        continue;
+                    }
+                    let callee = &chunks[callee_idx];
+                    edges.push(CallEdge {
+                        caller: caller.entity_name.clone(),
+                        caller_file: caller.file_path.clone(),
+                        callee: callee.entity_name.clone(),
+                        callee_file: callee.file_path.clone(),
+                    });
+                }
+            }
+        }
+
+        edges
+    }
+
     // ========================================================================
     // Rust Boundary Detection
     // ========================================================================
@@ -548,10 +926,15 @@ impl CodeChunker {
         let mut pending_doc_start: Option<usize> = None;
         let mut pending_attr_start: Option<usize> = None;
         let mut is_next_test_fn = false;
+        // Name of the enclosing impl block and the line its body ends on
+        let mut impl_scope: Option<(String, usize)> = None;
 
         while i < lines.len() {
             let line = lines[i];
             let trimmed = line.trim();
+            if impl_scope.as_ref().is_some_and(|(_, end)| i >= *end) {
+                impl_scope = None;
+            }
 
             // Track doc comments
             if self.rust_patterns.doc_comment.is_match(line) {
@@ -633,6 +1016,11 @@ impl CodeChunker {
                 let name = caps.get(6).map(|m| m.as_str()).unwrap_or("anonymous");
                 let is_pub = line.contains("pub ");
                 let is_test = is_next_test_fn || in_test_module;
+                // Methods are named after their impl, as in split impl blocks
+                let entity_name = match impl_scope {
+                    Some((ref impl_name, _)) => format!("{}::{}", impl_name, name),
+                    None => name.to_string(),
+                };
 
                 boundaries.push(Boundary {
                     start_line: entity_start,
@@ -642,7 +1030,7 @@ impl CodeChunker {
                     } else {
                         EntityType::Function
                     },
-                    entity_name: name.to_string(),
+                    entity_name,
                     is_public: is_pub,
                     is_test,
                 });
@@ -723,6 +1111,7 @@ impl CodeChunker {
                     name.to_string()
                 };
 
+                impl_scope = Some((full_name.clone(), self.find_block_end(lines, i)));
                 boundaries.push(Boundary {
                     start_line: entity_start,
                     entity_start_line: i,
@@ -1622,6 +2011,54 @@ struct Boundary {
 // Utility Functions
 // ============================================================================
 
+/// Type an impl chunk name belongs to: `Display for Config` → `Config`
+fn impl_owner(impl_name: &str) -> &str {
+    impl_name.rsplit(" for ").next().unwrap_or(impl_name).trim()
+}
+
+/// Free functions named `name`, narrowed to `file_path` when the name is
+/// defined in more than one file
+fn free_functions(
+    callables: &[Callable],
+    chunks: &[CodeChunk],
+    name: &str,
+    file_path: &str,
+) -> Vec<usize> {
+    let all: Vec<usize> = callables
+        .iter()
+        .filter(|c| c.owner.is_none() && c.name == name)
+        .map(|c| c.chunk)
+        .collect();
+    if all.len() <= 1 {
+        return all;
+    }
+    all.into_iter()
+        .filter(|&idx| chunks[idx].file_path == file_path)
+        .collect()
+}
+
+/// Whether `ident` appears in `body` as a whole word
+fn mentions(body: &str, ident: &str) -> bool {
+    body.match_indices(ident).any(|(at, _)| {
+        let before = body[..at].chars().next_back();
+        let after = body[at + ident.len()..].chars().next();
+        let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+        !before.is_some_and(is_ident) && !after.is_some_and(is_ident)
+    })
+}
+
+/// Cosine similarity of two equal-length vectors (0.0 if either is zero)
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
 /// Compute a SHA-256 content hash for deduplication
 pub fn compute_content_hash(content: &str) -> String {
     let mut hasher = Sha256::new();
@@ -1629,6 +2066,132 @@ pub fn compute_content_hash(content: &str) -> String {
     hex::encode(hasher.finalize())
 }
 
+/// Content hash that ignores leading/trailing whitespace on each line and
+/// blank lines around the chunk, so re-indenting or padding an entity does
+/// not count as a change
+pub fn normalized_content_hash(content: &str) -> String {
+    let normalized: Vec<&str> = content.lines().map(str::trim).collect();
+    let start = normalized.iter().position(|l| !l.is_empty());
+    let end = normalized.iter().rposition(|l| !l.is_empty());
+    match (start, end) {
+        (Some(start), Some(end)) => compute_content_hash(&normalized[start..=end].join("\n")),
+        _ => compute_content_hash(""),
+    }
+}
+
+/// String literal the comment scanner is inside, carried across lines
+#[derive(Clone, Copy)]
+enum Literal {
+    Code,
+    Quoted { quote: char, triple: bool },
+    RustRaw { hashes: usize },
+}
+
+/// Remove single-line and doc comments (`//`, `///`, `//!`, `#`) from
+/// `content`
+///
+/// Comment markers inside string literals are left alone. Lines that held
+/// only a comment are dropped and trailing whitespace left behind by a
+/// stripped comment is trimmed. Block comments and Python docstrings are
+/// kept.
+pub fn strip_comments(content: &str, language: FileLanguage) -> String {
+    let prefix: Vec<char> = language.comment_prefix().chars().collect();
+    let triple_quotes = matches!(language, FileLanguage::Python | FileLanguage::Kotlin);
+    let backtick_strings = matches!(
+        language,
+        FileLanguage::TypeScript
+            | FileLanguage::JavaScript
+            | FileLanguage::Go
+            | FileLanguage::Shell
+    );
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+
+    let mut state = Literal::Code;
+    let mut out: Vec<String> = Vec::new();
+    for line in content.lines() {
+        let chars: Vec<char> = line.chars().collect();
+        let mut comment_at = None;
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            match state {
+                Literal::Quoted { quote, triple } => {
+                    if c == '\\' {
+                        i += 2;
+                    } else if c == quote && (!triple || chars[i..].starts_with(&[quote; 3])) {
+                        state = Literal::Code;
+                        i += if triple { 3 } else { 1 };
+                    } else {
+                        i += 1;
+                    }
+                }
+                Literal::RustRaw { hashes } => {
+                    let closes = c == '"'
+                        && chars[i + 1..].iter().take_while(|&&h| h == '#').count() >= hashes;
+                    if closes {
+                        state = Literal::Code;
+                        i += 1 + hashes;
+                    } else {
+                        i += 1;
+                    }
+                }
+                Literal::Code => {
+                    let starts_word = i == 0 || !is_ident(chars[i - 1]);
+                    if chars[i..].starts_with(&prefix)
+                        && (language != FileLanguage::Shell
+                            || i == 0
+                            || chars[i - 1].is_whitespace())
+                    {
+                        comment_at = Some(i);
+                        break;
+                    }
+                    if language == FileLanguage::Rust && c == 'r' && starts_word {
+                        let hashes = chars[i + 1..].iter().take_while(|&&h| h == '#').count();
+                        if chars.get(i + 1 + hashes) == Some(&'"') {
+                            state = Literal::RustRaw { hashes };
+                            i += 2 + hashes;
+                            continue;
+                        }
+                    }
+                    if language == FileLanguage::Rust && c == '\'' {
+                        // Char literal ('x' or '\n'); otherwise a lifetime
+                        i += if chars.get(i + 1) == Some(&'\\') {
+                            chars[i + 2..]
+                                .iter()
+                                .position(|&q| q == '\'')
+                                .map_or(chars.len() - i, |p| p + 3)
+                        } else if chars.get(i + 2) == Some(&'\'') {
+                            3
+                        } else {
+                            1
+                        };
+                        continue;
+                    }
+                    if c == '"' || c == '\'' || (c == '`' && backtick_strings) {
+                        let triple = triple_quotes && chars[i..].starts_with(&[c; 3]);
+                        state = Literal::Quoted { quote: c, triple };
+                        i += if triple { 3 } else { 1 };
+                        continue;
+                    }
+                    i += 1;
+                }
+            }
+        }
+
+        match comment_at {
+            Some(at) => {
+                let code: String = chars[..at].iter().collect();
+                let code = code.trim_end();
+                if !code.trim_start().is_empty() {
+                    out.push(code.to_string());
+                }
+            }
+            None => out.push(line.to_string()),
+        }
+    }
+    out.join("\n")
+}
+
 /// Summary statistics for a batch of chunks
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ChunkingStats {
@@ -1693,6 +2256,9 @@ pub struct DedupEntry {
     pub content_hash: String,
     /// The embedding vector (shared across all locations)
     pub vector: Vec<f32>,
+    /// Space `vector` lives in; `None` until embedded
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub vector_space: Option<VectorSpace>,
     /// All locations where this exact code appears
     pub locations: Vec<ChunkLocation>,
     /// Analysis result (shared)
@@ -1711,35 +2277,100 @@ pub struct ChunkLocation {
     pub entity_name: String,
 }
 
+/// Default estimated cost of embedding one chunk, in USD
+pub const DEFAULT_EMBEDDING_COST_USD: f64 = 0.0001;
+
+/// Number of chunks listed in [`DedupReport::top_duplicates`]
+const REPORT_TOP_DUPLICATES: usize = 20;
+
+/// Summary of the deduplication index, serializable for the server API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DedupReport {
+    /// Number of unique chunks (embeddings actually computed)
+    pub unique_chunks: usize,
+    /// Number of locations linked to an existing embedding
+    pub links_saved: usize,
+    /// Cost assumed per embedding, in USD
+    pub embedding_cost_usd: f64,
+    /// `links_saved * embedding_cost_usd`
+    pub estimated_cost_saved_usd: f64,
+    /// Most-duplicated chunks, highest occurrence count first
+    pub top_duplicates: Vec<DuplicateSummary>,
+}
+
+/// One duplicated chunk in a [`DedupReport`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateSummary {
+    pub content_hash: String,
+    pub entity_name: String,
+    /// Number of locations sharing this chunk
+    pub occurrences: usize,
+    /// Repos the chunk appears in, sorted
+    pub repos: Vec<String>,
+}
+
 /// A simple in-memory dedup index for tracking cross-repo duplicates.
 ///
 /// In production, this would be backed by SQLite/LanceDB, but this provides
 /// the interface and logic for the dedup strategy.
-#[derive(Debug, Default)]
+///
+/// Entries are kept per content hash *and* [`VectorSpace`]: the same code
+/// embedded by two different models gets two entries, never one.
+#[derive(Debug)]
 pub struct DedupIndex {
-    entries: std::collections::HashMap<String, DedupEntry>,
+    entries: std::collections::HashMap<String, Vec<DedupEntry>>,
+    embedding_cost_usd: f64,
+}
+
+impl Default for DedupIndex {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl DedupIndex {
     pub fn new() -> Self {
         Self {
             entries: std::collections::HashMap::new(),
+            embedding_cost_usd: DEFAULT_EMBEDDING_COST_USD,
         }
     }
 
+    /// Set the per-embedding cost used by [`report`](Self::report)
+    pub fn with_embedding_cost(mut self, cost_usd: f64) -> Self {
+        self.embedding_cost_usd = cost_usd;
+        self
+    }
+
     /// Check if a content hash already exists in the index
     pub fn contains(&self, content_hash: &str) -> bool {
         self.entries.contains_key(content_hash)
     }
 
-    /// Get an existing entry by content hash
+    /// Get an existing entry by content hash (the first one, if the hash was
+    /// embedded in several vector spaces)
     pub fn get(&self, content_hash: &str) -> Option<&DedupEntry> {
-        self.entries.get(content_hash)
+        self.entries.get(content_hash)?.first()
+    }
+
+    /// Get the entry for a content hash in one vector space
+    pub fn get_in(&self, content_hash: &str, space: &VectorSpace) -> Option<&DedupEntry> {
+        self.entries
+            .get(content_hash)?
+            .iter()
+            .find(|entry| entry.vector_space.as_ref() == Some(space))
+    }
+
+    fn all_entries(&self) -> impl Iterator<Item = &DedupEntry> {
+        self.entries.values().flatten()
     }
 
     /// Insert or update a chunk in the index.
     /// If the hash already exists, adds the new location. Returns true if this
     /// was a new entry (needs embedding), false if it was a duplicate (free).
+    ///
+    /// A chunk is only linked to an entry in the same [`VectorSpace`]; a chunk
+    /// not yet embedded links to any entry for its hash.
     pub fn insert_or_link(&mut self, chunk: &CodeChunk) -> bool {
         let location = ChunkLocation {
             repo_id: chunk.repo_id.clone(),
@@ -1749,7 +2380,16 @@ impl DedupIndex {
             entity_name: chunk.entity_name.clone(),
         };
 
-        if let Some(entry) = self.entries.get_mut(&chunk.content_hash) {
+        let candidates = self.entries.entry(chunk.content_hash.clone()).or_default();
+        let compatible =
+            candidates
+                .iter_mut()
+                .find(|entry| match (&entry.vector_space, &chunk.vector_space) {
+                    (Some(existing), Some(incoming)) => existing == incoming,
+                    _ => true,
+                });
+
+        if let Some(entry) = compatible {
             // Duplicate — just add the new location
             let already_linked = entry
                 .locations
@@ -1758,27 +2398,118 @@ impl DedupIndex {
             if !already_linked {
                 entry.locations.push(location);
             }
+            if entry.vector_space.is_none() && chunk.vector_space.is_some() {
+                entry.vector = chunk.vector.clone();
+                entry.vector_space = chunk.vector_space.clone();
+            }
             false // Was duplicate — skip embedding
         } else {
             // New entry — needs embedding
-            self.entries.insert(
-                chunk.content_hash.clone(),
-                DedupEntry {
-                    content_hash: chunk.content_hash.clone(),
-                    vector: chunk.vector.clone(),
-                    locations: vec![location],
-                    issue_count: chunk.issue_count,
-                    last_analyzed: chunk.last_analyzed,
-                },
-            );
+            candidates.push(DedupEntry {
+                content_hash: chunk.content_hash.clone(),
+                vector: chunk.vector.clone(),
+                vector_space: chunk.vector_space.clone(),
+                locations: vec![location],
+                issue_count: chunk.issue_count,
+                last_analyzed: chunk.last_analyzed,
+            });
             true // New — needs embedding
         }
     }
 
+    /// Embed `chunks` with `embedder` and add them to the index.
+    ///
+    /// Each distinct content hash is embedded at most once: hashes already
+    /// embedded by the same model reuse the stored vector. Chunks get their
+    /// `vector` and `vector_space` filled in. Returns the number of texts
+    /// sent to the embedder.
+    pub async fn embed_and_insert(
+        &mut self,
+        embedder: &dyn Embedder,
+        chunks: &mut [CodeChunk],
+    ) -> anyhow::Result<usize> {
+        let model_id = embedder.model_id().to_string();
+        let mut vectors: HashMap<String, Vec<f32>> = HashMap::new();
+        let mut pending: Vec<(String, String)> = Vec::new();
+        let mut pending_hashes: HashSet<&str> = HashSet::new();
+
+        for chunk in chunks.iter() {
+            if vectors.contains_key(&chunk.content_hash)
+                || pending_hashes.contains(chunk.content_hash.as_str())
+            {
+                continue;
+            }
+            let existing = self.entries.get(&chunk.content_hash).and_then(|entries| {
+                entries.iter().find(|entry| {
+                    entry.vector_space.as_ref().is_some_and(|space| {
+                        space.model_id == model_id && space.fits(&entry.vector)
+                    })
+                })
+            });
+            match existing {
+                Some(entry) => {
+                    vectors.insert(chunk.content_hash.clone(), entry.vector.clone());
+                }
+                None => {
+                    pending_hashes.insert(&chunk.content_hash);
+                    pending.push((chunk.content_hash.clone(), chunk.content.clone()));
+                }
+            }
+        }
+
+        let texts: Vec<String> = pending.iter().map(|(_, text)| text.clone()).collect();
+        let embedded = if texts.is_empty() {
+            Vec::new()
+        } else {
+            embedder.embed(&texts).await?
+        };
+        if embedded.len() != texts.len() {
+            anyhow::bail!(
+                "Embedder returned {} vectors for {} chunks",
+                embedded.len(),
+                texts.len()
+            );
+        }
+        for ((hash, _), vector) in pending.into_iter().zip(embedded) {
+            vectors.insert(hash, vector);
+        }
+
+        for chunk in chunks.iter_mut() {
+            let vector = vectors[&chunk.content_hash].clone();
+            chunk.vector_space = Some(VectorSpace::new(model_id.clone(), vector.len()));
+            chunk.vector = vector;
+            self.insert_or_link(chunk);
+        }
+
+        Ok(texts.len())
+    }
+
+    /// The `top_k` entries most similar to `query` by cosine similarity,
+    /// highest first. Only entries embedded in `space` are compared; a query
+    /// that does not fit `space` matches nothing.
+    pub fn search_similar(
+        &self,
+        space: &VectorSpace,
+        query: &[f32],
+        top_k: usize,
+    ) -> Vec<(&DedupEntry, f32)> {
+        if !space.fits(query) {
+            return Vec::new();
+        }
+
+        let mut scored: Vec<(&DedupEntry, f32)> = self
+            .all_entries()
+            .filter(|entry| entry.vector_space.as_ref() == Some(space) && space.fits(&entry.vector))
+            .map(|entry| (entry, cosine_similarity(query, &entry.vector)))
+            .collect();
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        scored.truncate(top_k);
+        scored
+    }
+
     /// Get all entries that appear in multiple repos (cross-repo duplicates)
     pub fn cross_repo_duplicates(&self) -> Vec<&DedupEntry> {
-        self.entries
-            .values()
+        self.all_entries()
             .filter(|entry| {
                 let unique_repos: std::collections::HashSet<&str> = entry
                     .locations
@@ -1792,16 +2523,57 @@ impl DedupIndex {
 
     /// Get total number of unique chunks
     pub fn unique_count(&self) -> usize {
-        self.entries.len()
+        self.entries.values().map(Vec::len).sum()
     }
 
     /// Get total number of duplicate links saved
     pub fn duplicates_saved(&self) -> usize {
-        self.entries
-            .values()
+        self.all_entries()
             .map(|e| e.locations.len().saturating_sub(1))
             .sum()
     }
+
+    /// Summarize the index: totals, estimated savings and the
+    /// most-duplicated chunks
+    pub fn report(&self) -> DedupReport {
+        let mut duplicated: Vec<&DedupEntry> = self
+            .all_entries()
+            .filter(|entry| entry.locations.len() > 1)
+            .collect();
+        duplicated.sort_by(|a, b| {
+            b.locations
+                .len()
+                .cmp(&a.locations.len())
+                .then_with(|| a.content_hash.cmp(&b.content_hash))
+        });
+
+        let top_duplicates = duplicated
+            .into_iter()
+            .take(REPORT_TOP_DUPLICATES)
+            .map(|entry| {
+                let repos: std::collections::BTreeSet<&str> = entry
+                    .locations
+                    .iter()
+                    .map(|loc| loc.repo_id.as_str())
+                    .collect();
+                DuplicateSummary {
+                    content_hash: entry.content_hash.clone(),
+                    entity_name: entry.locations[0].entity_name.clone(),
+                    occurrences: entry.locations.len(),
+                    repos: repos.into_iter().map(String::from).collect(),
+                }
+            })
+            .collect();
+
+        let links_saved = self.duplicates_saved();
+        DedupReport {
+            unique_chunks: self.unique_count(),
+            links_saved,
+            embedding_cost_usd: self.embedding_cost_usd,
+            estimated_cost_saved_usd: links_saved as f64 * self.embedding_cost_usd,
+            top_duplicates,
+        }
+    }
 }
 
 // ============================================================================
@@ -1972,6 +2744,178 @@ mod tests {
         assert_eq!(cross[0].locations.len(), 2);
     }
 
+    #[test]
+    fn test_dedup_index_keeps_vector_spaces_apart() {
+        let mut index = DedupIndex::new();
+        let small = VectorSpace::new("bge-small-en-v1.5", 384);
+        let base = VectorSpace::new("bge-base-en-v1.5", 768);
+
+        let chunk = CodeChunk::new(
+            "pub fn shared() -> i32 { 42 }".to_string(),
+            "repo_a".to_string(),
+            "src/utils.rs".to_string(),
+            EntityType::Function,
+            "shared".to_string(),
+            FileLanguage::Rust,
+            1,
+            1,
+        );
+        let in_small = chunk
+            .clone()
+            .with_embedding(&small.model_id, vec![1.0; 384]);
+        let mut in_base = chunk.with_embedding(&base.model_id, vec![1.0; 768]);
+        in_base.repo_id = "repo_b".to_string();
+
+        // Same content hash, different model: not linked
+        assert!(index.insert_or_link(&in_small));
+        assert!(index.insert_or_link(&in_base));
+        assert_eq!(index.unique_count(), 2);
+        assert_eq!(index.duplicates_saved(), 0);
+        assert_eq!(
+            index
+                .get_in(&in_small.content_hash, &base)
+                .unwrap()
+                .vector
+                .len(),
+            768
+        );
+
+        // Each search only sees its own space
+        let hits = index.search_similar(&small, &vec![1.0; 384], 10);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].0.vector_space.as_ref(), Some(&small));
+        assert!((hits[0].1 - 1.0).abs() < 1e-6);
+
+        let hits = index.search_similar(&base, &vec![1.0; 768], 10);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].0.locations[0].repo_id, "repo_b");
+
+        // A query of the wrong dimension never reaches a comparison
+        assert!(index.search_similar(&small, &vec![1.0; 768], 10).is_empty());
+    }
+
+    /// Deterministic 3-dim vectors derived from the text
+    struct MockEmbedder;
+
+    #[async_trait::async_trait]
+    impl Embedder for MockEmbedder {
+        async fn embed(&self, texts: &[String]) -> anyhow::Result<Vec<Vec<f32>>> {
+            Ok(texts
+                .iter()
+                .map(|t| vec![t.len() as f32, t.lines().count() as f32, 1.0])
+                .collect())
+        }
+
+        fn model_id(&self) -> &str {
+            "mock-embed"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dedup_index_embed_and_insert() {
+        let mut index = DedupIndex::new();
+        let shared = CodeChunk::new(
+            "pub fn shared() -> i32 { 42 }".to_string(),
+            "repo_a".to_string(),
+            "src/utils.rs".to_string(),
+            EntityType::Function,
+            "shared".to_string(),
+            FileLanguage::Rust,
+            1,
+            1,
+        );
+        let mut copy = shared.clone();
+        copy.repo_id = "repo_b".to_string();
+        let other = CodeChunk::new(
+            "pub fn other() {}".to_string(),
+            "repo_a".to_string(),
+            "src/other.rs".to_string(),
+            EntityType::Function,
+            "other".to_string(),
+            FileLanguage::Rust,
+            1,
+            1,
+        );
+
+        let mut chunks = vec![shared, copy, other];
+        let sent = index
+            .embed_and_insert(&MockEmbedder, &mut chunks)
+            .await
+            .unwrap();
+        assert_eq!(sent, 2);
+        assert_eq!(index.unique_count(), 2);
+        assert_eq!(index.duplicates_saved(), 1);
+
+        let space = VectorSpace::new("mock-embed", 3);
+        assert!(chunks
+            .iter()
+            .all(|c| c.vector_space.as_ref() == Some(&space)));
+        assert_eq!(chunks[0].vector, vec![29.0, 1.0, 1.0]);
+        assert_eq!(chunks[0].vector, chunks[1].vector);
+
+        // Already embedded by this model: nothing is sent again
+        let mut again = vec![chunks[2].clone()];
+        again[0].repo_id = "repo_c".to_string();
+        again[0].vector.clear();
+        again[0].vector_space = None;
+        let sent = index
+            .embed_and_insert(&MockEmbedder, &mut again)
+            .await
+            .unwrap();
+        assert_eq!(sent, 0);
+        assert_eq!(again[0].vector, chunks[2].vector);
+        assert_eq!(index.duplicates_saved(), 2);
+    }
+
+    #[test]
+    fn test_dedup_report() {
+        let mut index = DedupIndex::new().with_embedding_cost(0.5);
+
+        let shared = CodeChunk::new(
+            "pub fn shared() -> i32 { 42 }".to_string(),
+            "repo_a".to_string(),
+            "src/utils.rs".to_string(),
+            EntityType::Function,
+            "shared".to_string(),
+            FileLanguage::Rust,
+            1,
+            1,
+        );
+        index.insert_or_link(&shared);
+        for (repo, path) in [("repo_c", "src/a.rs"), ("repo_b", "src/b.rs")] {
+            let mut copy = shared.clone();
+            copy.repo_id = repo.to_string();
+            copy.file_path = path.to_string();
+            index.insert_or_link(&copy);
+        }
+
+        let single = CodeChunk::new(
+            "pub fn single() {}".to_string(),
+            "repo_a".to_string(),
+            "src/single.rs".to_string(),
+            EntityType::Function,
+            "single".to_string(),
+            FileLanguage::Rust,
+            1,
+            1,
+        );
+        index.insert_or_link(&single);
+
+        let report = index.report();
+        assert_eq!(report.unique_chunks, 2);
+        assert_eq!(report.links_saved, 2);
+        assert!((report.estimated_cost_saved_usd - 1.0).abs() < f64::EPSILON);
+        assert_eq!(report.top_duplicates.len(), 1);
+
+        let top = &report.top_duplicates[0];
+        assert_eq!(top.entity_name, "shared");
+        assert_eq!(top.occurrences, 3);
+        assert_eq!(top.repos, vec!["repo_a", "repo_b", "repo_c"]);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["top_duplicates"][0]["occurrences"], 3);
+    }
+
     #[test]
     fn test_empty_file() {
         let chunks = chunker().chunk_file("empty.rs", "", "repo");
@@ -2110,6 +3054,124 @@ pub fn process(items: &[Item]) -> Result<Vec<Output>, Error> {
         assert!(c.compute_chunk_complexity(complex) > 0.4);
     }
 
+    #[test]
+    fn test_extract_call_edges() {
+        let content = r#"pub fn a(input: &str) -> usize {
+    // c() is only mentioned here
+    let parsed = b(input);
+    parsed.len()
+}
+
+fn b(input: &str) -> Vec<String> {
+    input.split(',').map(String::from).collect()
+}
+
+pub fn c() -> usize {
+    42
+}
+
+pub struct Counter {
+    hits: usize,
+}
+
+impl Counter {
+    pub fn new() -> Self {
+        Self { hits: c() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.hits
+    }
+}
+"#;
+        let chunker = chunker();
+        let chunks = chunker.chunk_file("src/lib.rs", content, "repo");
+        let edges = chunker.extract_call_edges(&chunks);
+        let pairs: Vec<(&str, &str)> = edges
+            .iter()
+            .map(|e| (e.caller.as_str(), e.callee.as_str()))
+            .collect();
+
+        assert!(pairs.contains(&("a", "b")), "edges: {:?}", pairs);
+        // Methods are chunked on their own, named after their impl
+        assert!(pairs.contains(&("Counter::new", "c")), "edges: {:?}", pairs);
+        // Commented-out call and `Vec::len` on an unrelated type
+        assert!(!pairs.contains(&("a", "c")), "edges: {:?}", pairs);
+        assert!(!pairs.contains(&("a", "Counter")), "edges: {:?}", pairs);
+        assert!(!pairs.iter().any(|(caller, _)| *caller == "c"));
+
+        let adjacency = call_adjacency(&edges);
+        assert_eq!(adjacency["a"], vec!["b".to_string()]);
+    }
+
+    #[test]
+    fn test_call_edges_resolve_self_and_type_paths_to_methods() {
+        let content = r#"pub struct Config {
+    path: String,
+}
+
+impl Config {
+    pub fn load(path: &str) -> Self {
+        Self::validate(path);
+        Config { path: path.to_string() }
+    }
+
+    fn validate(path: &str) {
+        assert!(!path.is_empty());
+    }
+
+    pub fn reload(&self) -> Self {
+        self.check();
+        Config::load(&self.path)
+    }
+
+    fn check(&self) {
+        assert!(!self.path.is_empty());
+    }
+}
+
+pub struct Other;
+
+impl Other {
+    fn validate(path: &str) {}
+}
+
+pub fn start() -> Config {
+    Config::load("app.toml")
+}
+"#;
+        let chunker = chunker();
+        let chunks = chunker.chunk_file("src/config.rs", content, "repo");
+        let edges = chunker.extract_call_edges(&chunks);
+        let pairs: Vec<(&str, &str)> = edges
+            .iter()
+            .map(|e| (e.caller.as_str(), e.callee.as_str()))
+            .collect();
+
+        assert!(
+            pairs.contains(&("Config::load", "Config::validate")),
+            "edges: {:?}",
+            pairs
+        );
+        assert!(
+            pairs.contains(&("Config::reload", "Config::check")),
+            "edges: {:?}",
+            pairs
+        );
+        assert!(
+            pairs.contains(&("Config::reload", "Config::load")),
+            "edges: {:?}",
+            pairs
+        );
+        assert!(
+            pairs.contains(&("start", "Config::load")),
+            "edges: {:?}",
+            pairs
+        );
+        // `Self::validate` in Config never reaches the other type's method
+        assert!(!pairs.iter().any(|(_, callee)| *callee == "Other::validate"));
+    }
+
     #[test]
     fn test_code_chunk_display_id() {
         let chunk = CodeChunk::new(
@@ -2146,4 +3208,198 @@ pub fn process(items: &[Item]) -> Result<Vec<Output>, Error> {
             "main"
         );
     }
+
+    const DIFF_BASE: &str = r#"pub fn parse(input: &str) -> u32 {
+    let trimmed = input.trim();
+    trimmed.len() as u32
+}
+
+pub fn render(value: u32) -> String {
+    let text = value.to_string();
+    format!("<{}>", text)
+}
+"#;
+
+    fn delta_kind(delta: &ChunkDelta) -> &'static str {
+        match delta {
+            ChunkDelta::Added { .. } => "added",
+            ChunkDelta::Removed { .. } => "removed",
+            ChunkDelta::Unchanged { .. } => "unchanged",
+            ChunkDelta::Modified { .. } => "modified",
+        }
+    }
+
+    fn kinds(deltas: &[ChunkDelta]) -> Vec<(String, &'static str)> {
+        deltas
+            .iter()
+            .map(|d| (d.chunk().entity_name.clone(), delta_kind(d)))
+            .collect()
+    }
+
+    #[test]
+    fn test_chunk_diff_moved_function_is_unchanged() {
+        let filler: String = (0..10)
+            .map(|i| format!("pub const C{}: u32 = {};\n", i, i))
+            .collect();
+        let moved = format!("{}\n{}", filler, DIFF_BASE);
+
+        let deltas = chunker().chunk_file_diff(DIFF_BASE, &moved, "src/lib.rs", "repo");
+        let parse = deltas
+            .iter()
+            .find(|d| d.chunk().entity_name == "parse")
+            .unwrap();
+        match parse {
+            ChunkDelta::Unchanged { old, new } => {
+                assert!(new.start_line >= old.start_line + 10);
+                assert_eq!(old.content_hash, new.content_hash);
+            }
+            other => panic!("expected unchanged, got {:?}", other),
+        }
+        assert!(!parse.needs_embedding());
+        assert!(deltas
+            .iter()
+            .filter(|d| d.chunk().entity_type == EntityType::Function)
+            .all(|d| matches!(d, ChunkDelta::Unchanged { .. })));
+    }
+
+    #[test]
+    fn test_chunk_diff_reports_modified_added_and_removed() {
+        let new = DIFF_BASE
+            .replace("trimmed.len() as u32", "trimmed.len() as u32 + 1")
+            .replace("pub fn render", "pub fn display")
+            + "\n    \n";
+
+        let deltas = chunker().chunk_file_diff(DIFF_BASE, &new, "src/lib.rs", "repo");
+        assert_eq!(
+            kinds(&deltas),
+            vec![
+                ("parse".to_string(), "modified"),
+                ("display".to_string(), "added"),
+                ("render".to_string(), "removed"),
+            ]
+        );
+        assert!(deltas[0].needs_embedding());
+    }
+
+    #[test]
+    fn test_chunk_diff_ignores_surrounding_whitespace() {
+        let reindented: String = DIFF_BASE.lines().map(|l| format!("{}   \n", l)).collect();
+        let deltas = chunker().chunk_file_diff(DIFF_BASE, &reindented, "src/lib.rs", "repo");
+        assert!(deltas
+            .iter()
+            .all(|d| matches!(d, ChunkDelta::Unchanged { .. })));
+        assert_eq!(
+            normalized_content_hash("  fn a() {}  \n\n"),
+            normalized_content_hash("fn a() {}")
+        );
+    }
+
+    #[test]
+    fn test_hash_ignores_rust_comments() {
+        let chunker = CodeChunker::with_config(ChunkerConfig {
+            hash_ignores_comments: true,
+            ..ChunkerConfig::default()
+        });
+        let a = "/// Join two path segments\npub fn join(a: &str, b: &str) -> String {\n    format!(\"{}//{}\", a, b) // keep the slashes\n}\n";
+        let b = "/// Concatenates `a` and `b`\n/// with a separator.\npub fn join(a: &str, b: &str) -> String {\n    // no trailing comment here\n    format!(\"{}//{}\", a, b)\n}\n";
+        let c = "/// Join two path segments\npub fn join(a: &str, b: &str) -> String {\n    format!(\"{}/{}\", a, b)\n}\n";
+
+        let chunk = |content: &str| chunker.chunk_file("src/lib.rs", content, "repo")[0].clone();
+        let (chunk_a, chunk_b) = (chunk(a), chunk(b));
+        assert_eq!(chunk_a.content_hash, chunk_b.content_hash);
+        assert!(chunk_a.content.contains("keep the slashes"));
+        // The `//` inside the string literal is code, not a comment
+        assert_ne!(chunk_a.content_hash, chunk(c).content_hash);
+
+        // Off by default
+        let plain = CodeChunker::new();
+        assert_ne!(
+            plain.chunk_file("src/lib.rs", a, "repo")[0].content_hash,
+            plain.chunk_file("src/lib.rs", b, "repo")[0].content_hash
+        );
+    }
+
+    #[test]
+    fn test_strip_comments_rust_literals() {
+        let src = "let s = r#\"a // b\"#; // gone\nlet c = '\"'; let l: &'a str = \"//\"; // gone too\n//! inner doc";
+        assert_eq!(
+            strip_comments(src, FileLanguage::Rust),
+            "let s = r#\"a // b\"#;\nlet c = '\"'; let l: &'a str = \"//\";"
+        );
+    }
+
+    #[test]
+    fn test_hash_ignores_python_comments() {
+        let chunker = CodeChunker::with_config(ChunkerConfig {
+            hash_ignores_comments: true,
+            ..ChunkerConfig::default()
+        });
+        let a = "def tag(name):\n    # Build a hashtag\n    name = name.strip()\n    return '#' + name  # prefix\n";
+        let b = "def tag(name):\n    name = name.strip()\n    return '#' + name\n";
+        let c = "def tag(name):\n    name = name.strip()\n    return '' + name\n";
+
+        let chunk = |content: &str| chunker.chunk_file("app.py", content, "repo")[0].clone();
+        assert_eq!(chunk(a).content_hash, chunk(b).content_hash);
+        assert_ne!(chunk(b).content_hash, chunk(c).content_hash);
+        assert_eq!(
+            strip_comments(
+                "x = \"\"\"\n# not a comment\n\"\"\"  # comment",
+                FileLanguage::Python
+            ),
+            "x = \"\"\"\n# not a comment\n\"\"\""
+        );
+    }
+
+    #[derive(Default)]
+    struct Capture(std::sync::Mutex<Vec<String>>);
+
+    impl ProgressReporter for Capture {
+        fn start(&self, _message: &str, total: u64) {
+            self.0.lock().unwrap().push(format!("start {}", total));
+        }
+        fn advance(&self, message: &str, done: u64) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("advance {} {}", done, message));
+        }
+        fn finish(&self, _message: &str) {
+            self.0.lock().unwrap().push("finish".to_string());
+        }
+    }
+
+    #[test]
+    fn test_chunk_directory_reports_progress() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(dir.path().join("src")).unwrap();
+        std::fs::write(
+            dir.path().join("src/lib.rs"),
+            "pub fn a() -> u32 {\n    let x = 1;\n    x + 1\n}\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("src/util.py"),
+            "def b():\n    x = 1\n    return x + 1\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("README.md"), "# Not code\n").unwrap();
+
+        let capture = Arc::new(Capture::default());
+        let chunks = CodeChunker::new()
+            .with_progress(capture.clone())
+            .chunk_directory(dir.path(), "repo")
+            .unwrap();
+
+        assert!(chunks.iter().any(|c| c.file_path == "src/lib.rs"));
+        assert!(chunks.iter().any(|c| c.file_path == "src/util.py"));
+        assert_eq!(
+            *capture.0.lock().unwrap(),
+            vec![
+                "start 2",
+                "advance 1 src/lib.rs",
+                "advance 2 src/util.py",
+                "finish",
+            ]
+        );
+    }
 }
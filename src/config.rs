@@ -1,9 +1,14 @@
 //! Configuration for the audit service
 
 use crate::error::{AuditError, Result};
+use crate::tests_runner::RustTestRunner;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming the profile [`Config::load_profile`] applies
+/// when none is passed
+pub const PROFILE_ENV_VAR: &str = "AUDIT_PROFILE";
 
 /// Audit service configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +27,9 @@ pub struct Config {
     pub research: Option<ResearchConfig>,
     /// Security configuration
     pub security: SecurityConfig,
+    /// API authentication for the HTTP server
+    #[serde(default)]
+    pub auth: ApiAuthConfig,
 }
 
 impl Config {
@@ -100,6 +108,11 @@ impl Config {
                 "__pycache__/".to_string(),
                 "*.lock".to_string(),
             ],
+            rust_test_runner: std::env::var("SCANNER_RUST_TEST_RUNNER")
+                .ok()
+                .map(|s| s.parse())
+                .transpose()?
+                .unwrap_or_default(),
         };
 
         let storage = StorageConfig {
@@ -149,9 +162,77 @@ impl Config {
             storage,
             research,
             security,
+            auth: ApiAuthConfig::from_env(),
         })
     }
 
+    /// Load configuration from a TOML file with named profiles.
+    ///
+    /// The `[default]` table is applied over [`Config::default`], then the
+    /// `[profile.<name>]` table over that; tables merge key by key, so a
+    /// profile only lists what it changes:
+    ///
+    /// ```toml
+    /// [default.llm]
+    /// model = "grok-4-1-fast-reasoning"
+    ///
+    /// [profile.ci.llm]
+    /// max_tokens = 1024
+    /// ```
+    ///
+    /// `profile` falls back to `AUDIT_PROFILE`; with neither set (or the
+    /// name `default`) only the base table applies. An unknown profile name
+    /// is an error.
+    pub fn load_profile(path: impl AsRef<Path>, profile: Option<&str>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| AuditError::config(format!("Failed to read {}: {}", path.display(), e)))?;
+        let mut file: toml::Table = text.parse().map_err(|e| {
+            AuditError::config(format!("Failed to parse {}: {}", path.display(), e))
+        })?;
+
+        let name = match profile {
+            Some(name) => Some(name.to_string()),
+            None => std::env::var(PROFILE_ENV_VAR)
+                .ok()
+                .filter(|name| !name.is_empty()),
+        };
+
+        let mut merged = toml::Value::try_from(Config::default())
+            .map_err(|e| AuditError::config(format!("Failed to encode defaults: {}", e)))?;
+        match file.remove("default") {
+            Some(toml::Value::Table(base)) => merge_toml(&mut merged, toml::Value::Table(base)),
+            Some(_) => {
+                return Err(AuditError::config(format!(
+                    "[default] in {} must be a table",
+                    path.display()
+                )))
+            }
+            None => {}
+        }
+
+        if let Some(name) = name.filter(|name| name != "default") {
+            let mut profiles = match file.remove("profile") {
+                Some(toml::Value::Table(profiles)) => profiles,
+                _ => toml::Table::new(),
+            };
+            let Some(overrides) = profiles.remove(&name) else {
+                let available: Vec<&String> = profiles.keys().collect();
+                return Err(AuditError::config(format!(
+                    "Unknown config profile '{}' in {} (available: {:?})",
+                    name,
+                    path.display(),
+                    available
+                )));
+            };
+            merge_toml(&mut merged, overrides);
+        }
+
+        merged
+            .try_into()
+            .map_err(|e| AuditError::config(format!("Invalid config in {}: {}", path.display(), e)))
+    }
+
     /// Get a research prompt by key, falling back to defaults
     pub fn get_research_prompt(&self, key: &str) -> Option<String> {
         self.research.as_ref()?.prompts.get(key).cloned()
@@ -175,10 +256,30 @@ impl Config {
             return Err(AuditError::config("Server port cannot be 0"));
         }
 
+        self.auth.validate()?;
+
         Ok(())
     }
 }
 
+/// Merge `overlay` into `base`: tables merge recursively, anything else
+/// replaces the base value
+fn merge_toml(base: &mut toml::Value, overlay: toml::Value) {
+    match (base, overlay) {
+        (toml::Value::Table(base), toml::Value::Table(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge_toml(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -189,10 +290,122 @@ impl Default for Config {
             storage: StorageConfig::default(),
             research: Some(ResearchConfig::default()),
             security: SecurityConfig::default(),
+            auth: ApiAuthConfig::default(),
+        }
+    }
+}
+
+/// API authentication for the HTTP server
+///
+/// Requests must carry one of `api_keys` (as `Authorization: Bearer <key>` or
+/// `X-API-Key: <key>`) or the `basic_auth` credentials, except for
+/// `public_paths` and, when `allow_anonymous_read` is set, read-only methods.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiAuthConfig {
+    /// Set to false to run without auth (pure-local use only)
+    pub enabled: bool,
+    /// Accepted API keys / bearer tokens
+    pub api_keys: Vec<String>,
+    /// Accepted basic-auth `(username, password)`
+    pub basic_auth: Option<(String, String)>,
+    /// Let GET/HEAD/OPTIONS through without credentials
+    pub allow_anonymous_read: bool,
+    /// Exact paths that bypass auth (health probes, signed webhooks)
+    pub public_paths: Vec<String>,
+}
+
+impl Default for ApiAuthConfig {
+    /// Disabled, like the other development defaults; [`ApiAuthConfig::from_env`]
+    /// enables it unless `API_AUTH_ENABLED=false`.
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            api_keys: Vec::new(),
+            basic_auth: None,
+            allow_anonymous_read: true,
+            public_paths: vec![
+                "/health".to_string(),
+                "/healthz".to_string(),
+                "/readyz".to_string(),
+                // Authenticated by its HMAC signature instead
+                "/api/github/webhook".to_string(),
+            ],
         }
     }
 }
 
+impl ApiAuthConfig {
+    /// Load from the environment:
+    ///
+    /// - `API_AUTH_ENABLED` (default `true`)
+    /// - `API_KEYS` — comma-separated; falls back to `RA_PROXY_API_KEYS`
+    /// - `API_BASIC_AUTH` — `username:password`
+    /// - `API_AUTH_ANONYMOUS_READ` (default `true`)
+    /// - `API_AUTH_PUBLIC_PATHS` — comma-separated, added to the defaults
+    pub fn from_env() -> Self {
+        let mut config = Self {
+            enabled: std::env::var("API_AUTH_ENABLED")
+                .ok()
+                .and_then(|e| e.parse().ok())
+                .unwrap_or(true),
+            ..Self::default()
+        };
+
+        let keys = std::env::var("API_KEYS")
+            .or_else(|_| std::env::var("RA_PROXY_API_KEYS"))
+            .unwrap_or_default();
+        config.api_keys = split_list(&keys);
+
+        config.basic_auth = std::env::var("API_BASIC_AUTH").ok().and_then(|v| {
+            v.split_once(':')
+                .map(|(user, pass)| (user.to_string(), pass.to_string()))
+        });
+
+        if let Some(anon) = std::env::var("API_AUTH_ANONYMOUS_READ")
+            .ok()
+            .and_then(|v| v.parse().ok())
+        {
+            config.allow_anonymous_read = anon;
+        }
+
+        if let Ok(paths) = std::env::var("API_AUTH_PUBLIC_PATHS") {
+            config.public_paths.extend(split_list(&paths));
+        }
+
+        config
+    }
+
+    /// Whether any credentials are configured
+    pub fn has_credentials(&self) -> bool {
+        !self.api_keys.is_empty() || self.basic_auth.is_some()
+    }
+
+    /// Whether `path` bypasses auth
+    pub fn is_public(&self, path: &str) -> bool {
+        self.public_paths.iter().any(|p| p == path)
+    }
+
+    /// Refuse to start with auth enabled but nothing to authenticate against
+    pub fn validate(&self) -> Result<()> {
+        if self.enabled && !self.has_credentials() {
+            return Err(AuditError::config(
+                "API auth is enabled but no credentials are configured. \
+                 Set API_KEYS or API_BASIC_AUTH, or API_AUTH_ENABLED=false for local-only use.",
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
 /// Security configuration for SSRF prevention and access control
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SecurityConfig {
@@ -413,6 +626,9 @@ pub struct ScannerConfig {
     pub include_tests: bool,
     /// Patterns to exclude from scanning
     pub exclude_patterns: Vec<String>,
+    /// Tool used to run Rust tests (`cargo` or `nextest`)
+    #[serde(default)]
+    pub rust_test_runner: RustTestRunner,
 }
 
 impl Default for ScannerConfig {
@@ -427,6 +643,7 @@ impl Default for ScannerConfig {
                 "__pycache__/".to_string(),
                 "*.lock".to_string(),
             ],
+            rust_test_runner: RustTestRunner::default(),
         }
     }
 }
@@ -548,6 +765,78 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_validate_auth_requires_credentials() {
+        let mut config = Config::default();
+        config.auth.enabled = true;
+        assert!(config.validate().is_err());
+
+        config.auth.api_keys.push("secret".to_string());
+        assert!(config.validate().is_ok());
+
+        config.auth.api_keys.clear();
+        config.auth.enabled = false;
+        assert!(config.validate().is_ok());
+    }
+
+    const PROFILES: &str = r#"
+[default.server]
+port = 9000
+
+[default.llm]
+model = "grok-base"
+max_tokens = 8192
+enabled = true
+
+[profile.ci.llm]
+max_tokens = 1024
+temperature = 0.0
+
+[profile.ci.scanner]
+include_tests = false
+"#;
+
+    #[test]
+    fn test_load_profile_merges_over_default() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("audit.toml");
+        std::fs::write(&path, PROFILES).unwrap();
+
+        let base = Config::load_profile(&path, Some("default")).unwrap();
+        assert_eq!(base.server.port, 9000);
+        assert_eq!(base.llm.max_tokens, 8192);
+        assert!(base.scanner.include_tests);
+
+        let ci = Config::load_profile(&path, Some("ci")).unwrap();
+        // Overridden by the profile
+        assert_eq!(ci.llm.max_tokens, 1024);
+        assert_eq!(ci.llm.temperature, 0.0);
+        assert!(!ci.scanner.include_tests);
+        // Inherited from [default]
+        assert_eq!(ci.server.port, 9000);
+        assert_eq!(ci.llm.model, "grok-base");
+        assert!(ci.llm.enabled);
+        // Untouched by either: built-in defaults
+        assert_eq!(ci.server.host, "0.0.0.0");
+        assert_eq!(ci.scanner.max_file_size, 1_000_000);
+    }
+
+    #[test]
+    fn test_load_profile_unknown_name_errors() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("audit.toml");
+        std::fs::write(&path, PROFILES).unwrap();
+
+        let err = Config::load_profile(&path, Some("prod")).unwrap_err();
+        let message = err.to_string();
+        assert!(
+            message.contains("Unknown config profile 'prod'"),
+            "{}",
+            message
+        );
+        assert!(message.contains("ci"), "{}", message);
+    }
+
     #[test]
     fn test_validate_invalid_port() {
         let mut config = Config::default();
@@ -8,7 +8,9 @@
 //! - Test Coverage: Test results and metrics
 
 use crate::error::{AuditError, Result};
+use crate::scoring::{FileScore, FileScorer};
 use crate::tests_runner::{TestResults, TestRunner};
+use crate::todo_scanner::TodoScanner;
 use crate::types::{Category, SystemMap};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -53,6 +55,22 @@ pub struct ProjectMetadata {
     pub languages: Vec<String>,
     /// Build timestamp
     pub built_at: chrono::DateTime<chrono::Utc>,
+    /// Token budget the bundle was fitted to, if any
+    #[serde(default)]
+    pub token_budget: Option<usize>,
+    /// Estimated tokens of the formatted bundle (see
+    /// [`ContextBuilder::format_for_llm`]); set when a budget is applied
+    #[serde(default)]
+    pub estimated_tokens: usize,
+    /// Files left out of the source bundle to meet the token budget
+    #[serde(default)]
+    pub dropped_files: Vec<String>,
+    /// Files reduced to a signature summary to meet the token budget
+    #[serde(default)]
+    pub summarized_files: Vec<String>,
+    /// Files cut off part-way to meet the token budget
+    #[serde(default)]
+    pub truncated_files: Vec<String>,
 }
 
 /// Signature map containing all code symbols
@@ -266,6 +284,59 @@ pub struct SourceBundle {
     pub content: String,
 }
 
+impl SourceBundle {
+    /// Concatenate `files` into a bundle
+    fn from_files(files: Vec<SourceFile>) -> Self {
+        let mut content = String::from(SOURCE_BUNDLE_HEADER);
+        for file in &files {
+            content.push_str(&file_segment(file));
+        }
+        Self {
+            total_size: files.iter().map(|f| f.content.len()).sum(),
+            files,
+            content,
+        }
+    }
+}
+
+/// One file's section of [`SourceBundle::content`]
+fn file_segment(file: &SourceFile) -> String {
+    format!("\n--- FILE: {} ---\n{}\n\n", file.path, file.content)
+}
+
+fn segment_tokens(file: &SourceFile) -> usize {
+    estimate_tokens(&file_segment(file))
+}
+
+/// Signature-only stand-in for a file's content
+fn summarize_source(content: &str, lines: usize) -> String {
+    const SIGNATURE_PREFIXES: &[&str] = &[
+        "pub ",
+        "fn ",
+        "struct ",
+        "enum ",
+        "trait ",
+        "impl",
+        "mod ",
+        "def ",
+        "class ",
+        "async def ",
+        "function ",
+        "interface ",
+        "export ",
+    ];
+    let mut summary = format!("[summarized: {} lines, signatures only]\n", lines);
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if line.len() == trimmed.len() && SIGNATURE_PREFIXES.iter().any(|p| trimmed.starts_with(p))
+        {
+            summary.push_str(trimmed.trim_end_matches('{').trim_end());
+            summary.push('\n');
+        }
+    }
+    summary
+}
+
 /// Single source file
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SourceFile {
@@ -277,6 +348,38 @@ pub struct SourceFile {
     pub lines: usize,
     /// File content
     pub content: String,
+    /// Last modification time (seconds since epoch)
+    #[serde(default)]
+    pub modified: Option<i64>,
+}
+
+/// Header opening [`SourceBundle::content`]
+const SOURCE_BUNDLE_HEADER: &str = "=== COMPLETE SOURCE CODE BUNDLE ===\n\n";
+
+/// Marker appended to a file cut off by [`TruncationStrategy::HardTruncate`]
+const TRUNCATED_MARKER: &str = "\n... [truncated to fit token budget]";
+
+/// Rough token count of `text` (~4 bytes per token, rounded up)
+fn estimate_tokens(text: &str) -> usize {
+    text.len().div_ceil(4)
+}
+
+/// How [`ContextBuilder`] brings a bundle over its token budget back under it
+///
+/// Whatever the strategy, files are given up in order of their
+/// [`FileScore`] (lowest maintenance priority first).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TruncationStrategy {
+    /// Drop whole files, lowest scored first
+    #[default]
+    DropLowestScored,
+    /// Reduce the least recently modified files to their signatures, then
+    /// drop lowest scored files if that is not enough
+    SummarizeOldest,
+    /// Keep the highest scored files in full and cut the bundle off at the
+    /// budget, part-way through a file if needed
+    HardTruncate,
 }
 
 /// Context builder
@@ -285,6 +388,9 @@ pub struct ContextBuilder {
     root: PathBuf,
     include_tests: bool,
     max_file_size: usize,
+    token_budget: Option<usize>,
+    truncation: TruncationStrategy,
+    file_scores: HashMap<String, f64>,
 }
 
 impl ContextBuilder {
@@ -294,6 +400,9 @@ impl ContextBuilder {
             root: root.into(),
             include_tests: false,
             max_file_size: 1_000_000, // 1MB default
+            token_budget: None,
+            truncation: TruncationStrategy::default(),
+            file_scores: HashMap::new(),
         }
     }
 
@@ -309,6 +418,37 @@ impl ContextBuilder {
         self
     }
 
+    /// Keep the formatted bundle under `max_tokens`
+    ///
+    /// When the assembled context is larger, source files are given up
+    /// according to the [`TruncationStrategy`]; [`build`](Self::build) fails
+    /// rather than return a bundle over budget.
+    pub fn with_token_budget(mut self, max_tokens: usize) -> Self {
+        self.token_budget = Some(max_tokens);
+        self
+    }
+
+    /// Set how an over-budget bundle is reduced
+    pub fn with_truncation_strategy(mut self, strategy: TruncationStrategy) -> Self {
+        self.truncation = strategy;
+        self
+    }
+
+    /// Rank files by these scores instead of scoring them while building
+    ///
+    /// Paths may be absolute or relative to the root. Files without a score
+    /// rank lowest.
+    pub fn with_file_scores(mut self, scores: &[FileScore]) -> Self {
+        self.file_scores = scores
+            .iter()
+            .map(|score| {
+                let path = score.path.strip_prefix(&self.root).unwrap_or(&score.path);
+                (path.display().to_string(), score.maintenance_priority)
+            })
+            .collect();
+        self
+    }
+
     /// Build the complete global context bundle
     pub fn build(&self, system_map: SystemMap) -> Result<GlobalContextBundle> {
         tracing::info!("Building global context bundle for 2M window");
@@ -321,7 +461,7 @@ impl ContextBuilder {
         let test_coverage = self.build_test_coverage().ok();
         let source_bundle = self.build_source_bundle()?;
 
-        Ok(GlobalContextBundle {
+        let mut bundle = GlobalContextBundle {
             metadata,
             signature_map,
             dependency_graph,
@@ -330,7 +470,160 @@ impl ContextBuilder {
             test_coverage,
             system_map,
             source_bundle,
-        })
+        };
+        if let Some(max_tokens) = self.token_budget {
+            self.fit_to_budget(&mut bundle, max_tokens)?;
+        }
+
+        Ok(bundle)
+    }
+
+    /// Reduce the source bundle until the formatted bundle fits `max_tokens`
+    fn fit_to_budget(&self, bundle: &mut GlobalContextBundle, max_tokens: usize) -> Result<()> {
+        bundle.metadata.token_budget = Some(max_tokens);
+        let total = estimate_tokens(&Self::format_for_llm(bundle));
+        if total <= max_tokens {
+            bundle.metadata.estimated_tokens = total;
+            return Ok(());
+        }
+
+        // Everything but the source files is fixed
+        let files = std::mem::take(&mut bundle.source_bundle.files);
+        bundle.source_bundle = SourceBundle::from_files(Vec::new());
+        let overhead = estimate_tokens(&Self::format_for_llm(bundle));
+        if overhead > max_tokens {
+            return Err(AuditError::config(format!(
+                "Context without any source files needs ~{} tokens, over the {} token budget",
+                overhead, max_tokens
+            )));
+        }
+        let available = max_tokens - overhead;
+
+        // Highest value first
+        let todo_scanner = TodoScanner::new().ok();
+        let mut ranked: Vec<(f64, SourceFile)> = files
+            .into_iter()
+            .map(|file| (self.file_value(&file, todo_scanner.as_ref()), file))
+            .collect();
+        ranked.sort_by(|a, b| {
+            b.0.partial_cmp(&a.0)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.1.path.cmp(&b.1.path))
+        });
+
+        let metadata = &mut bundle.metadata;
+        let kept = match self.truncation {
+            TruncationStrategy::DropLowestScored => {
+                Self::drop_lowest(ranked, available, &mut metadata.dropped_files)
+            }
+            TruncationStrategy::SummarizeOldest => {
+                let mut by_age: Vec<usize> = (0..ranked.len()).collect();
+                by_age.sort_by_key(|&i| (ranked[i].1.modified.unwrap_or(i64::MIN), i));
+                let mut used: usize = ranked.iter().map(|(_, f)| segment_tokens(f)).sum();
+                for i in by_age {
+                    if used <= available {
+                        break;
+                    }
+                    let file = &mut ranked[i].1;
+                    let summary = summarize_source(&file.content, file.lines);
+                    if summary.len() >= file.content.len() {
+                        continue;
+                    }
+                    let before = segment_tokens(file);
+                    file.content = summary;
+                    used = used - before + segment_tokens(file);
+                    metadata.summarized_files.push(file.path.clone());
+                }
+                let kept = Self::drop_lowest(ranked, available, &mut metadata.dropped_files);
+                metadata
+                    .summarized_files
+                    .retain(|path| !metadata.dropped_files.contains(path));
+                kept
+            }
+            TruncationStrategy::HardTruncate => {
+                let mut kept = Vec::new();
+                let mut remaining = available;
+                for (_, mut file) in ranked {
+                    let tokens = segment_tokens(&file);
+                    if tokens <= remaining {
+                        remaining -= tokens;
+                        kept.push(file);
+                        continue;
+                    }
+                    // Room for part of this file, then nothing more fits
+                    let framing = segment_tokens(&SourceFile {
+                        content: TRUNCATED_MARKER.to_string(),
+                        ..file.clone()
+                    });
+                    if remaining > framing {
+                        let mut cut = (remaining - framing) * 4;
+                        while !file.content.is_char_boundary(cut) {
+                            cut -= 1;
+                        }
+                        file.content.truncate(cut);
+                        file.content.push_str(TRUNCATED_MARKER);
+                        metadata.truncated_files.push(file.path.clone());
+                        kept.push(file);
+                    } else {
+                        metadata.dropped_files.push(file.path);
+                    }
+                    remaining = 0;
+                }
+                kept
+            }
+        };
+
+        tracing::info!(
+            "Fitted context to {} tokens: {} dropped, {} summarized, {} truncated",
+            max_tokens,
+            bundle.metadata.dropped_files.len(),
+            bundle.metadata.summarized_files.len(),
+            bundle.metadata.truncated_files.len()
+        );
+        bundle.source_bundle = SourceBundle::from_files(kept);
+        let total = estimate_tokens(&Self::format_for_llm(bundle));
+        bundle.metadata.estimated_tokens = total;
+        if total > max_tokens {
+            return Err(AuditError::other(format!(
+                "Context still needs ~{} tokens after truncation, over the {} token budget",
+                total, max_tokens
+            )));
+        }
+        Ok(())
+    }
+
+    /// Keep files from the front of `ranked` (highest value first), dropping
+    /// from the back until the rest fit in `available` tokens
+    fn drop_lowest(
+        mut ranked: Vec<(f64, SourceFile)>,
+        available: usize,
+        dropped: &mut Vec<String>,
+    ) -> Vec<SourceFile> {
+        let mut used: usize = ranked.iter().map(|(_, f)| segment_tokens(f)).sum();
+        while used > available {
+            let Some((_, file)) = ranked.pop() else {
+                break;
+            };
+            used -= segment_tokens(&file);
+            dropped.push(file.path);
+        }
+        ranked.into_iter().map(|(_, file)| file).collect()
+    }
+
+    /// Value of keeping `file` in the bundle: its [`FileScore`] maintenance
+    /// priority, from [`Self::with_file_scores`] or scored on the spot
+    fn file_value(&self, file: &SourceFile, todo_scanner: Option<&TodoScanner>) -> f64 {
+        if !self.file_scores.is_empty() {
+            return self.file_scores.get(&file.path).copied().unwrap_or(-1.0);
+        }
+        let path = Path::new(&file.path);
+        let todos = todo_scanner
+            .map(|scanner| scanner.scan_content(path, &file.content))
+            .unwrap_or_default();
+        FileScorer::new()
+            .score_file(path, &file.content, &[], &todos)
+            .map(|score| score.maintenance_priority)
+            .unwrap_or(0.0)
     }
 
     /// Build project metadata
@@ -376,6 +669,11 @@ impl ContextBuilder {
             total_lines,
             languages: languages.into_iter().collect(),
             built_at: chrono::Utc::now(),
+            token_budget: None,
+            estimated_tokens: 0,
+            dropped_files: Vec::new(),
+            summarized_files: Vec::new(),
+            truncated_files: Vec::new(),
         })
     }
 
@@ -874,10 +1172,6 @@ impl ContextBuilder {
     /// Build source code bundle
     fn build_source_bundle(&self) -> Result<SourceBundle> {
         let mut files = Vec::new();
-        let mut total_size = 0;
-        let mut content = String::new();
-
-        content.push_str("=== COMPLETE SOURCE CODE BUNDLE ===\n\n");
 
         for entry in WalkDir::new(&self.root)
             .into_iter()
@@ -901,28 +1195,23 @@ impl ContextBuilder {
 
                 let category = Category::from_path(&rel_path);
                 let lines = file_content.lines().count();
-
-                // Add to bundle
-                content.push_str(&format!("\n--- FILE: {} ---\n", rel_path));
-                content.push_str(&file_content);
-                content.push_str("\n\n");
-
-                total_size += file_content.len();
+                let modified = metadata
+                    .modified()
+                    .ok()
+                    .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                    .map(|d| d.as_secs() as i64);
 
                 files.push(SourceFile {
                     path: rel_path,
                     category,
                     lines,
                     content: file_content,
+                    modified,
                 });
             }
         }
 
-        Ok(SourceBundle {
-            files,
-            total_size,
-            content,
-        })
+        Ok(SourceBundle::from_files(files))
     }
 
     /// Generate formatted context for LLM prompt
@@ -1014,3 +1303,132 @@ impl ContextBuilder {
         prompt
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, SystemTime};
+
+    /// Three ~500-token files scored a.rs > b.rs > c.rs, with a.rs the
+    /// least recently modified
+    fn scored_repo() -> (tempfile::TempDir, Vec<FileScore>) {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut scores = Vec::new();
+        for (i, (name, priority)) in [("a.rs", 90.0), ("b.rs", 50.0), ("c.rs", 10.0)]
+            .into_iter()
+            .enumerate()
+        {
+            let body = "    let value = compute(1);\n".repeat(70);
+            let path = dir.path().join(name);
+            std::fs::write(&path, format!("pub fn run_{}() {{\n{}}}\n", i, body)).unwrap();
+            let modified = SystemTime::now() - Duration::from_secs(3600 * (3 - i as u64));
+            std::fs::File::options()
+                .write(true)
+                .open(&path)
+                .unwrap()
+                .set_modified(modified)
+                .unwrap();
+
+            let mut score = FileScore::new(path);
+            score.maintenance_priority = priority;
+            scores.push(score);
+        }
+        (dir, scores)
+    }
+
+    /// Assemble a bundle without the git and test-runner sections
+    fn bundle(builder: &ContextBuilder) -> GlobalContextBundle {
+        let signature_map = builder.build_signature_map().unwrap();
+        GlobalContextBundle {
+            metadata: builder.build_metadata().unwrap(),
+            dependency_graph: builder.build_dependency_graph(&signature_map).unwrap(),
+            signature_map,
+            architectural_rules: builder.load_architectural_rules().unwrap(),
+            diff_context: None,
+            test_coverage: None,
+            system_map: SystemMap {
+                total_files: 0,
+                files_by_category: HashMap::new(),
+                lines_by_category: HashMap::new(),
+                dependencies: Vec::new(),
+                mermaid_diagram: None,
+            },
+            source_bundle: builder.build_source_bundle().unwrap(),
+        }
+    }
+
+    fn fit(strategy: TruncationStrategy, slack: isize) -> (GlobalContextBundle, usize) {
+        let (dir, scores) = scored_repo();
+        let builder = ContextBuilder::new(dir.path())
+            .with_file_scores(&scores)
+            .with_truncation_strategy(strategy);
+        let mut bundle = bundle(&builder);
+        let full = estimate_tokens(&ContextBuilder::format_for_llm(&bundle));
+        let budget = (full as isize + slack) as usize;
+        builder.fit_to_budget(&mut bundle, budget).unwrap();
+        assert!(estimate_tokens(&ContextBuilder::format_for_llm(&bundle)) <= budget);
+        assert!(bundle.metadata.estimated_tokens <= budget);
+        (bundle, budget)
+    }
+
+    fn kept(bundle: &GlobalContextBundle) -> Vec<&str> {
+        let mut paths: Vec<&str> = bundle
+            .source_bundle
+            .files
+            .iter()
+            .map(|f| f.path.as_str())
+            .collect();
+        paths.sort();
+        paths
+    }
+
+    #[test]
+    fn test_under_budget_bundle_is_untouched() {
+        let (bundle, budget) = fit(TruncationStrategy::DropLowestScored, 10);
+        assert_eq!(kept(&bundle), vec!["a.rs", "b.rs", "c.rs"]);
+        assert!(bundle.metadata.dropped_files.is_empty());
+        assert_eq!(bundle.metadata.token_budget, Some(budget));
+    }
+
+    #[test]
+    fn test_drop_lowest_scored_drops_low_value_files_first() {
+        let (bundle, _) = fit(TruncationStrategy::DropLowestScored, -100);
+        assert_eq!(kept(&bundle), vec!["a.rs", "b.rs"]);
+        assert_eq!(bundle.metadata.dropped_files, vec!["c.rs"]);
+
+        let (bundle, _) = fit(TruncationStrategy::DropLowestScored, -700);
+        assert_eq!(kept(&bundle), vec!["a.rs"]);
+        assert_eq!(bundle.metadata.dropped_files, vec!["c.rs", "b.rs"]);
+    }
+
+    #[test]
+    fn test_summarize_oldest_keeps_every_file() {
+        let (bundle, _) = fit(TruncationStrategy::SummarizeOldest, -100);
+        assert_eq!(kept(&bundle), vec!["a.rs", "b.rs", "c.rs"]);
+        assert_eq!(bundle.metadata.summarized_files, vec!["a.rs"]);
+        assert!(bundle.metadata.dropped_files.is_empty());
+
+        let a = &bundle.source_bundle.files[0];
+        assert_eq!(a.path, "a.rs");
+        assert!(a.content.starts_with("[summarized: 72 lines"));
+        assert!(a.content.contains("pub fn run_0()"));
+    }
+
+    #[test]
+    fn test_hard_truncate_cuts_the_lowest_scored_file() {
+        let (bundle, _) = fit(TruncationStrategy::HardTruncate, -100);
+        assert_eq!(kept(&bundle), vec!["a.rs", "b.rs", "c.rs"]);
+        assert_eq!(bundle.metadata.truncated_files, vec!["c.rs"]);
+        let c = &bundle.source_bundle.files[2];
+        assert!(c.content.ends_with(TRUNCATED_MARKER));
+        assert!(bundle.source_bundle.files[0].content.ends_with("}\n"));
+    }
+
+    #[test]
+    fn test_budget_below_fixed_sections_is_an_error() {
+        let (dir, _) = scored_repo();
+        let builder = ContextBuilder::new(dir.path());
+        let mut bundle = bundle(&builder);
+        assert!(builder.fit_to_budget(&mut bundle, 10).is_err());
+    }
+}
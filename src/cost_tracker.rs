@@ -6,7 +6,8 @@
 //!
 //! - Per-query cost tracking
 //! - Daily/weekly/monthly aggregations
-//! - Budget alerts
+//! - Monthly rollups by repo and prompt tier ([`CostTracker::monthly_summary`])
+//! - Budget alerts with end-of-month projection ([`CostTracker::check_budget`])
 //! - Cost breakdown by operation type
 //! - Cache hit/miss impact analysis
 //!
@@ -38,15 +39,16 @@
 
 use crate::error::AuditError;
 use anyhow::{Context, Result};
-use chrono::{DateTime, Datelike, Duration, Utc};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
+use std::collections::HashMap;
 use tracing::{debug, info, warn};
 
 /// Grok 4.1 Fast pricing (per million tokens)
-const GROK_COST_PER_MILLION_INPUT: f64 = 0.20;
-const GROK_COST_PER_MILLION_OUTPUT: f64 = 0.50;
-const GROK_COST_PER_MILLION_CACHED: f64 = 0.05;
+pub(crate) const GROK_COST_PER_MILLION_INPUT: f64 = 0.20;
+pub(crate) const GROK_COST_PER_MILLION_OUTPUT: f64 = 0.50;
+pub(crate) const GROK_COST_PER_MILLION_CACHED: f64 = 0.05;
 
 /// Default budget alert threshold (USD)
 const DEFAULT_DAILY_BUDGET: f64 = 1.0;
@@ -96,9 +98,37 @@ pub struct BudgetStatus {
     pub monthly_budget: f64,
     pub monthly_remaining: f64,
     pub monthly_percent_used: f64,
+    /// Average spend per day so far this month
+    pub daily_rate: f64,
+    /// Month-end spend if the current daily rate holds
+    pub projected_monthly_spend: f64,
     pub alerts: Vec<String>,
 }
 
+impl BudgetStatus {
+    /// Whether the month is on track to exceed its budget
+    pub fn projected_over_budget(&self) -> bool {
+        self.projected_monthly_spend > self.monthly_budget
+    }
+}
+
+/// Spend rollup for one calendar month
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MonthlySpend {
+    pub year: i32,
+    pub month: u32,
+    /// Total LLM spend across all operations
+    pub total_cost_usd: f64,
+    pub total_queries: u64,
+    /// LLM spend on scanned files, by repository
+    pub cost_by_repo: HashMap<String, f64>,
+    /// LLM spend on scanned files, by prompt tier
+    pub cost_by_tier: HashMap<String, f64>,
+    /// Estimated spend avoided by files the static pre-filter skipped
+    pub saved_by_static_skips_usd: f64,
+    pub files_skipped: u64,
+}
+
 /// Record of a static analysis decision for cost tracking
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaticDecisionRecord {
@@ -646,14 +676,45 @@ impl CostTracker {
 
     /// Get budget status
     pub async fn get_budget_status(&self) -> Result<BudgetStatus> {
-        let daily_stats = self.get_daily_stats().await?;
-        let monthly_stats = self.get_monthly_stats().await?;
+        self.budget_status_at(self.monthly_budget, Utc::now()).await
+    }
+
+    /// Budget status against `monthly_limit`, with the month-end spend
+    /// projected from this month's daily rate
+    pub async fn check_budget(&self, monthly_limit: f64) -> Result<BudgetStatus> {
+        self.budget_status_at(monthly_limit, Utc::now()).await
+    }
+
+    async fn budget_status_at(
+        &self,
+        monthly_limit: f64,
+        now: DateTime<Utc>,
+    ) -> Result<BudgetStatus> {
+        let today = now.date_naive();
+        let day_start = today.and_hms_opt(0, 0, 0).unwrap().and_utc();
+        let month_start = today
+            .with_day(1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc();
+
+        let daily_stats = self
+            .get_stats_for_period(&day_start.to_rfc3339(), &now.to_rfc3339())
+            .await?;
+        let monthly_stats = self
+            .get_stats_for_period(&month_start.to_rfc3339(), &now.to_rfc3339())
+            .await?;
 
         let daily_remaining = self.daily_budget - daily_stats.total_cost_usd;
         let daily_percent = (daily_stats.total_cost_usd / self.daily_budget) * 100.0;
 
-        let monthly_remaining = self.monthly_budget - monthly_stats.total_cost_usd;
-        let monthly_percent = (monthly_stats.total_cost_usd / self.monthly_budget) * 100.0;
+        let monthly_remaining = monthly_limit - monthly_stats.total_cost_usd;
+        let monthly_percent = (monthly_stats.total_cost_usd / monthly_limit) * 100.0;
+
+        let days_in_month = days_in_month(today.year(), today.month())?;
+        let daily_rate = monthly_stats.total_cost_usd / today.day() as f64;
+        let projected = project_month_end(monthly_stats.total_cost_usd, today.day(), days_in_month);
 
         let mut alerts = Vec::new();
 
@@ -672,12 +733,17 @@ impl CostTracker {
         if monthly_percent >= 100.0 {
             alerts.push(format!(
                 "⛔ Monthly budget exceeded! ${:.2} / ${:.2}",
-                monthly_stats.total_cost_usd, self.monthly_budget
+                monthly_stats.total_cost_usd, monthly_limit
             ));
         } else if monthly_percent >= 80.0 {
             alerts.push(format!(
                 "⚠️  Monthly budget at {:.0}%! ${:.2} / ${:.2}",
-                monthly_percent, monthly_stats.total_cost_usd, self.monthly_budget
+                monthly_percent, monthly_stats.total_cost_usd, monthly_limit
+            ));
+        } else if projected > monthly_limit {
+            alerts.push(format!(
+                "📈 On pace to spend ${:.2} this month (budget ${:.2}, ${:.2}/day)",
+                projected, monthly_limit, daily_rate
             ));
         }
 
@@ -687,13 +753,92 @@ impl CostTracker {
             daily_remaining,
             daily_percent_used: daily_percent,
             monthly_spend: monthly_stats.total_cost_usd,
-            monthly_budget: self.monthly_budget,
+            monthly_budget: monthly_limit,
             monthly_remaining,
             monthly_percent_used: monthly_percent,
+            daily_rate,
+            projected_monthly_spend: projected,
             alerts,
         })
     }
 
+    /// Roll up spend for one calendar month (`month` is 1-12)
+    pub async fn monthly_summary(&self, year: i32, month: u32) -> Result<MonthlySpend> {
+        let start = NaiveDate::from_ymd_opt(year, month, 1)
+            .ok_or_else(|| AuditError::other(format!("Invalid month: {}-{}", year, month)))?;
+        let end = start + Duration::days(days_in_month(year, month)? as i64);
+        let start = start.and_hms_opt(0, 0, 0).unwrap().and_utc().to_rfc3339();
+        let end = end.and_hms_opt(0, 0, 0).unwrap().and_utc().to_rfc3339();
+
+        let (total_queries, total_cost_usd) = sqlx::query_as::<_, (i64, f64)>(
+            r#"
+            SELECT COUNT(*), COALESCE(SUM(cost_usd), 0.0)
+            FROM llm_costs
+            WHERE timestamp >= $1::TIMESTAMPTZ AND timestamp < $2::TIMESTAMPTZ
+            "#,
+        )
+        .bind(&start)
+        .bind(&end)
+        .fetch_one(&self.pool)
+        .await
+        .context("Failed to fetch monthly cost")?;
+
+        let cost_by_repo = sqlx::query_as::<_, (String, f64)>(
+            r#"
+            SELECT repo_id, SUM(actual_cost_usd)::DOUBLE PRECISION
+            FROM static_decisions
+            WHERE timestamp >= $1::TIMESTAMPTZ AND timestamp < $2::TIMESTAMPTZ
+              AND llm_called = TRUE
+            GROUP BY repo_id
+            "#,
+        )
+        .bind(&start)
+        .bind(&end)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch monthly cost by repo")?;
+
+        let cost_by_tier = sqlx::query_as::<_, (String, f64)>(
+            r#"
+            SELECT COALESCE(prompt_tier, recommendation), SUM(actual_cost_usd)::DOUBLE PRECISION
+            FROM static_decisions
+            WHERE timestamp >= $1::TIMESTAMPTZ AND timestamp < $2::TIMESTAMPTZ
+              AND llm_called = TRUE
+            GROUP BY 1
+            "#,
+        )
+        .bind(&start)
+        .bind(&end)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch monthly cost by tier")?;
+
+        let (files_skipped, saved) = sqlx::query_as::<_, (i64, f64)>(
+            r#"
+            SELECT COUNT(*), COALESCE(SUM(estimated_cost_saved_usd), 0.0)::DOUBLE PRECISION
+            FROM static_decisions
+            WHERE timestamp >= $1::TIMESTAMPTZ AND timestamp < $2::TIMESTAMPTZ
+              AND recommendation = 'SKIP'
+            "#,
+        )
+        .bind(&start)
+        .bind(&end)
+        .fetch_one(&self.pool)
+        .await
+        .context("Failed to fetch monthly skip savings")?;
+
+        Ok(MonthlySpend {
+            year,
+            month,
+            total_cost_usd,
+            total_queries: total_queries as u64,
+            cost_by_repo: cost_by_repo.into_iter().collect(),
+            cost_by_tier: cost_by_tier.into_iter().collect(),
+            saved_by_static_skips_usd: saved,
+            files_skipped: files_skipped as u64,
+        })
+    }
+
     /// Check budget and emit warnings
     async fn check_budget_alerts(&self) -> Result<()> {
         let status = self.get_budget_status().await?;
@@ -856,6 +1001,28 @@ impl CostTracker {
     }
 }
 
+/// Number of days in `month` of `year`
+fn days_in_month(year: i32, month: u32) -> Result<u32> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1)
+        .ok_or_else(|| AuditError::other(format!("Invalid month: {}-{}", year, month)))?;
+    let next = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    }
+    .ok_or_else(|| AuditError::other("Invalid date"))?;
+    Ok((next - first).num_days() as u32)
+}
+
+/// Month-end spend if `spend` over the first `elapsed_days` (counting the
+/// current, partial day) continues at the same daily rate
+fn project_month_end(spend: f64, elapsed_days: u32, days_in_month: u32) -> f64 {
+    if elapsed_days == 0 {
+        return spend;
+    }
+    spend / elapsed_days as f64 * days_in_month.max(elapsed_days) as f64
+}
+
 impl SavingsReport {
     /// Format as a human-readable summary
     pub fn format_summary(&self) -> String {
@@ -977,4 +1144,124 @@ mod tests {
 
         Ok(())
     }
+
+    /// Clear `month` of 2031 and seed it: LLM calls on the 1st, 3rd and 5th,
+    /// two scanned files in `repo-a`, one in `repo-b` and two static skips
+    async fn seed_month(tracker: &CostTracker, month: u32) -> Result<()> {
+        let start = format!("2031-{:02}-01T00:00:00Z", month);
+        let end = format!("2031-{:02}-01T00:00:00Z", month + 1);
+        for table in ["llm_costs", "static_decisions"] {
+            sqlx::query(&format!(
+                "DELETE FROM {} WHERE timestamp >= $1::TIMESTAMPTZ AND timestamp < $2::TIMESTAMPTZ",
+                table
+            ))
+            .bind(&start)
+            .bind(&end)
+            .execute(&tracker.pool)
+            .await?;
+        }
+
+        for (day, cost) in [(1, 1.0), (3, 2.0), (5, 3.0)] {
+            sqlx::query(
+                r#"
+                INSERT INTO llm_costs (timestamp, operation, model, input_tokens, output_tokens, cost_usd)
+                VALUES ($1::TIMESTAMPTZ, 'file_analysis', 'grok', 0, 0, $2)
+                "#,
+            )
+            .bind(format!("2031-{:02}-{:02}T12:00:00Z", month, day))
+            .bind(cost)
+            .execute(&tracker.pool)
+            .await?;
+        }
+
+        let decisions = [
+            ("repo-a", "STANDARD", Some("STANDARD"), true, 0.0, 1.5),
+            ("repo-a", "DEEP_DIVE", Some("DEEP_DIVE"), true, 0.0, 2.5),
+            ("repo-b", "MINIMAL", Some("MINIMAL"), true, 0.0, 0.25),
+            ("repo-b", "SKIP", None, false, 0.4, 0.0),
+            ("repo-a", "SKIP", None, false, 0.1, 0.0),
+        ];
+        for (repo, recommendation, tier, llm_called, saved, actual) in decisions {
+            sqlx::query(
+                r#"
+                INSERT INTO static_decisions (
+                    timestamp, file_path, repo_id, recommendation, llm_called,
+                    estimated_cost_saved_usd, actual_cost_usd, prompt_tier
+                )
+                VALUES ($1::TIMESTAMPTZ, 'src/lib.rs', $2, $3, $4, $5, $6, $7)
+                "#,
+            )
+            .bind(format!("2031-{:02}-02T08:00:00Z", month))
+            .bind(repo)
+            .bind(recommendation)
+            .bind(llm_called)
+            .bind(saved)
+            .bind(actual)
+            .bind(tier)
+            .execute(&tracker.pool)
+            .await?;
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_monthly_summary() -> Result<()> {
+        let pool = create_test_pool().await;
+        let tracker = CostTracker::new(pool).await?;
+        seed_month(&tracker, 3).await?;
+
+        let summary = tracker.monthly_summary(2031, 3).await?;
+        assert_eq!(summary.total_queries, 3);
+        assert!((summary.total_cost_usd - 6.0).abs() < 1e-9);
+        assert!((summary.cost_by_repo["repo-a"] - 4.0).abs() < 1e-9);
+        assert!((summary.cost_by_repo["repo-b"] - 0.25).abs() < 1e-9);
+        assert_eq!(summary.cost_by_tier.len(), 3);
+        assert!((summary.cost_by_tier["DEEP_DIVE"] - 2.5).abs() < 1e-9);
+        assert_eq!(summary.files_skipped, 2);
+        assert!((summary.saved_by_static_skips_usd - 0.5).abs() < 1e-9);
+
+        // Neighbouring months are excluded
+        assert_eq!(tracker.monthly_summary(2031, 4).await?.total_queries, 0);
+        assert!(tracker.monthly_summary(2031, 13).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_check_budget_projects_partial_month() -> Result<()> {
+        let pool = create_test_pool().await;
+        let tracker = CostTracker::with_budgets(pool, 100.0, 10.0).await?;
+        seed_month(&tracker, 6).await?;
+
+        // $6 over the first 6 of June's 30 days -> $1/day -> $30 projected
+        let now = DateTime::parse_from_rfc3339("2031-06-06T18:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let status = tracker.budget_status_at(50.0, now).await?;
+        assert!((status.monthly_spend - 6.0).abs() < 1e-9);
+        assert!((status.monthly_remaining - 44.0).abs() < 1e-9);
+        assert!((status.daily_rate - 1.0).abs() < 1e-9);
+        assert!((status.projected_monthly_spend - 30.0).abs() < 1e-9);
+        assert!(!status.projected_over_budget());
+        assert!(status.alerts.is_empty());
+
+        let tight = tracker.budget_status_at(20.0, now).await?;
+        assert!(tight.projected_over_budget());
+        assert!(tight.alerts.iter().any(|a| a.contains("On pace")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_month_end_projection() {
+        assert_eq!(days_in_month(2031, 2).unwrap(), 28);
+        assert_eq!(days_in_month(2032, 2).unwrap(), 29);
+        assert_eq!(days_in_month(2031, 12).unwrap(), 31);
+        assert!(days_in_month(2031, 0).is_err());
+
+        // Day 10 of 31 at $5 -> $0.50/day
+        assert!((project_month_end(5.0, 10, 31) - 15.5).abs() < 1e-9);
+        // Full month: projection equals actual spend
+        assert!((project_month_end(12.0, 28, 28) - 12.0).abs() < 1e-9);
+    }
 }
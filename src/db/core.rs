@@ -7,6 +7,9 @@ use serde::{Deserialize, Serialize};
 use sqlx::{postgres::PgPoolOptions, FromRow, PgPool};
 use thiserror::Error;
 
+use super::pagination::{ListFilter, Page, PageRequest};
+use crate::task::TaskStatus;
+
 // ============================================================================
 // Error Types
 // ============================================================================
@@ -21,6 +24,9 @@ pub enum DbError {
 
     #[error("Invalid input: {0}")]
     InvalidInput(String),
+
+    #[error("Invalid status transition: {from} -> {to}")]
+    InvalidTransition { from: String, to: String },
 }
 
 pub type DbResult<T> = Result<T, DbError>;
@@ -33,7 +39,7 @@ pub type DbPool = PgPool;
 // ============================================================================
 
 /// A note/thought captured by the user
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
 pub struct Note {
     pub id: String,
     pub title: String,
@@ -98,8 +104,21 @@ pub struct NoteTag {
     pub created_at: i64,
 }
 
-/// A tracked repository
+/// A suggested (not yet confirmed) tag for a note
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct NoteTagSuggestion {
+    pub note_id: String,
+    pub tag: String,
+    pub confidence: f64,
+    /// "keyword" or "llm"
+    pub source: String,
+    /// "pending", "confirmed" or "rejected"
+    pub status: String,
+    pub created_at: i64,
+}
+
+/// A tracked repository
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
 pub struct Repository {
     pub id: String,
     #[sqlx(rename = "local_path")]
@@ -144,6 +163,12 @@ pub struct Repository {
     /// Flag set by the web UI to request a project review re-run
     #[sqlx(default)]
     pub review_requested: Option<bool>,
+    /// Per-scan cost budget in dollars; `None` uses the scanner's default
+    #[sqlx(default)]
+    pub scan_cost_budget: Option<f64>,
+    /// Globs limiting scans to a subtree; `None` scans the whole repo
+    #[sqlx(default)]
+    pub scan_path_filter: Option<Vec<String>>,
 }
 
 impl Repository {
@@ -180,7 +205,7 @@ impl Repository {
 }
 
 /// A generated or manual task
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
 pub struct Task {
     pub id: String,
     /// Title — may be NULL for rows created by the legacy (migration-001) schema,
@@ -666,6 +691,11 @@ pub async fn search_notes_by_tags(pool: &PgPool, tags: &[&str], limit: i64) -> D
         return list_notes(pool, limit, None, None, None).await;
     }
 
+    // A repeated tag would make the distinct-tag count unreachable
+    let mut tags = tags.to_vec();
+    tags.sort_unstable();
+    tags.dedup();
+
     // Build query with multiple tag filters
     let tag_count = tags.len();
     let query = format!(
@@ -698,7 +728,7 @@ pub async fn search_notes_by_tags(pool: &PgPool, tags: &[&str], limit: i64) -> D
         .replace("LIMIT $2", &format!("LIMIT ${}", limit_idx));
 
     let mut q = sqlx::query_as::<_, Note>(&query);
-    for tag in tags {
+    for tag in &tags {
         q = q.bind(*tag);
     }
     q = q.bind(tag_count as i64).bind(limit);
@@ -750,6 +780,124 @@ pub async fn count_repo_notes(pool: &PgPool, repo_id: &str) -> DbResult<i64> {
     Ok(count.0)
 }
 
+// ============================================================================
+// Tag Suggestion Operations
+// ============================================================================
+
+/// Record a suggested tag for a note (keeps the higher confidence on conflict)
+pub async fn add_tag_suggestion(
+    pool: &PgPool,
+    note_id: &str,
+    tag: &str,
+    confidence: f64,
+    source: &str,
+) -> DbResult<()> {
+    let now = chrono::Utc::now().timestamp();
+
+    sqlx::query(
+        r#"
+        INSERT INTO note_tag_suggestions (note_id, tag, confidence, source, status, created_at)
+        VALUES ($1, $2, $3, $4, 'pending', $5)
+        ON CONFLICT (note_id, tag) DO UPDATE SET
+            confidence = GREATEST(note_tag_suggestions.confidence, EXCLUDED.confidence),
+            source = CASE
+                WHEN EXCLUDED.confidence > note_tag_suggestions.confidence THEN EXCLUDED.source
+                ELSE note_tag_suggestions.source
+            END
+        "#,
+    )
+    .bind(note_id)
+    .bind(tag)
+    .bind(confidence)
+    .bind(source)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Get tag suggestions for a note, highest confidence first
+pub async fn list_tag_suggestions(
+    pool: &PgPool,
+    note_id: &str,
+) -> DbResult<Vec<NoteTagSuggestion>> {
+    Ok(sqlx::query_as::<_, NoteTagSuggestion>(
+        r#"
+        SELECT note_id, tag, confidence, source, status, created_at
+        FROM note_tag_suggestions
+        WHERE note_id = $1
+        ORDER BY confidence DESC, tag ASC
+        "#,
+    )
+    .bind(note_id)
+    .fetch_all(pool)
+    .await?)
+}
+
+/// Accept a suggested tag: attach it to the note and mark the suggestion confirmed
+pub async fn confirm_tag_suggestion(pool: &PgPool, note_id: &str, tag: &str) -> DbResult<()> {
+    let now = chrono::Utc::now().timestamp();
+    let mut tx = pool.begin().await?;
+
+    let result = sqlx::query(
+        "UPDATE note_tag_suggestions SET status = 'confirmed' WHERE note_id = $1 AND tag = $2",
+    )
+    .bind(note_id)
+    .bind(tag)
+    .execute(&mut *tx)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(DbError::NotFound(format!(
+            "No suggestion '{}' for note {}",
+            tag, note_id
+        )));
+    }
+
+    // note_tags references tags(name), so make sure the tag exists first
+    sqlx::query("INSERT INTO tags (name) VALUES ($1) ON CONFLICT (name) DO NOTHING")
+        .bind(tag)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO note_tags (note_id, tag, created_at)
+        VALUES ($1, $2, $3)
+        ON CONFLICT DO NOTHING
+        "#,
+    )
+    .bind(note_id)
+    .bind(tag)
+    .bind(now)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Dismiss a suggested tag
+pub async fn reject_tag_suggestion(pool: &PgPool, note_id: &str, tag: &str) -> DbResult<()> {
+    let result = sqlx::query(
+        "UPDATE note_tag_suggestions SET status = 'rejected' WHERE note_id = $1 AND tag = $2",
+    )
+    .bind(note_id)
+    .bind(tag)
+    .execute(pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(DbError::NotFound(format!(
+            "No suggestion '{}' for note {}",
+            tag, note_id
+        )));
+    }
+
+    Ok(())
+}
+
 // ============================================================================
 // Repository Operations
 // ============================================================================
@@ -804,6 +952,8 @@ pub async fn add_repository(
         last_scan_issues_found: Some(0i32),
         last_error: None,
         review_requested: None,
+        scan_cost_budget: None,
+        scan_path_filter: None,
     })
 }
 
@@ -835,6 +985,65 @@ pub async fn list_repositories(pool: &PgPool) -> DbResult<Vec<Repository>> {
     )
 }
 
+/// List repositories a page at a time. Applies `status` and the
+/// `created_at` range from `filter`.
+pub async fn list_repositories_page(
+    pool: &PgPool,
+    filter: &ListFilter,
+    page: PageRequest,
+) -> DbResult<Page<Repository>> {
+    let mut conditions: Vec<String> = Vec::new();
+    let mut param_idx = 1usize;
+
+    if filter.status.is_some() {
+        conditions.push(format!("status = ${}", param_idx));
+        param_idx += 1;
+    }
+    if filter.created_after.is_some() {
+        conditions.push(format!("created_at >= ${}", param_idx));
+        param_idx += 1;
+    }
+    if filter.created_before.is_some() {
+        conditions.push(format!("created_at < ${}", param_idx));
+        param_idx += 1;
+    }
+
+    let where_clause = if conditions.is_empty() {
+        String::new()
+    } else {
+        format!("WHERE {}", conditions.join(" AND "))
+    };
+
+    let count_sql = format!("SELECT COUNT(*) FROM repositories {}", where_clause);
+    let sql = format!(
+        "SELECT * FROM repositories {} ORDER BY name ASC, id ASC LIMIT ${} OFFSET ${}",
+        where_clause,
+        param_idx,
+        param_idx + 1
+    );
+
+    let mut count_q = sqlx::query_scalar::<_, i64>(&count_sql);
+    let mut q = sqlx::query_as::<_, Repository>(&sql);
+
+    if let Some(ref status) = filter.status {
+        count_q = count_q.bind(status);
+        q = q.bind(status);
+    }
+    if let Some(after) = filter.created_after {
+        count_q = count_q.bind(after);
+        q = q.bind(after);
+    }
+    if let Some(before) = filter.created_before {
+        count_q = count_q.bind(before);
+        q = q.bind(before);
+    }
+    q = q.bind(page.limit).bind(page.offset);
+
+    let total = count_q.fetch_one(pool).await?;
+    let items = q.fetch_all(pool).await?;
+    Ok(Page::new(items, total, page))
+}
+
 /// Update repository analysis timestamp and metadata
 pub async fn update_repository_analysis(
     pool: &PgPool,
@@ -1167,9 +1376,12 @@ impl ScanEvent {
 // ============================================================================
 
 /// Create a new task
+///
+/// Accepts a pool or an open transaction so callers can create several
+/// tasks atomically.
 #[allow(clippy::too_many_arguments)]
-pub async fn create_task(
-    pool: &PgPool,
+pub async fn create_task<'e, E>(
+    executor: E,
     title: &str,
     description: Option<&str>,
     priority: i32,
@@ -1178,7 +1390,10 @@ pub async fn create_task(
     repo_id: Option<&str>,
     file_path: Option<&str>,
     line_number: Option<i32>,
-) -> DbResult<Task> {
+) -> DbResult<Task>
+where
+    E: sqlx::PgExecutor<'e>,
+{
     let id = format!(
         "TASK-{}",
         &uuid::Uuid::new_v4().to_string()[..8].to_uppercase()
@@ -1207,7 +1422,7 @@ pub async fn create_task(
     .bind(line_number)
     .bind(now)
     .bind(now)
-    .execute(pool)
+    .execute(executor)
     .await?;
 
     Ok(Task {
@@ -1286,24 +1501,210 @@ pub async fn list_tasks(
     Ok(q.fetch_all(pool).await?)
 }
 
-/// Update task status
+/// List tasks a page at a time, highest priority first. Applies `status`,
+/// `repo_id`, `max_priority` and the `created_at` range from `filter`.
+pub async fn list_tasks_page(
+    pool: &PgPool,
+    filter: &ListFilter,
+    page: PageRequest,
+) -> DbResult<Page<Task>> {
+    let mut conditions: Vec<String> = Vec::new();
+    let mut param_idx = 1usize;
+
+    if filter.status.is_some() {
+        conditions.push(format!("status = ${}", param_idx));
+        param_idx += 1;
+    }
+    if filter.max_priority.is_some() {
+        conditions.push(format!("priority <= ${}", param_idx));
+        param_idx += 1;
+    }
+    if filter.repo_id.is_some() {
+        conditions.push(format!("repo_id = ${}", param_idx));
+        param_idx += 1;
+    }
+    if filter.created_after.is_some() {
+        conditions.push(format!("created_at >= ${}", param_idx));
+        param_idx += 1;
+    }
+    if filter.created_before.is_some() {
+        conditions.push(format!("created_at < ${}", param_idx));
+        param_idx += 1;
+    }
+
+    let where_clause = if conditions.is_empty() {
+        String::new()
+    } else {
+        format!("WHERE {}", conditions.join(" AND "))
+    };
+
+    let count_sql = format!("SELECT COUNT(*) FROM tasks {}", where_clause);
+    let sql = format!(
+        "SELECT id, \
+                COALESCE(title, 'Untitled') as title, \
+                description, \
+                priority, \
+                status, \
+                COALESCE(source, 'manual') as source, \
+                source_id, \
+                repo_id, \
+                file_path, \
+                line_number, \
+                created_at, \
+                updated_at \
+         FROM tasks {} \
+         ORDER BY priority ASC, created_at DESC, id ASC \
+         LIMIT ${} OFFSET ${}",
+        where_clause,
+        param_idx,
+        param_idx + 1
+    );
+
+    let mut count_q = sqlx::query_scalar::<_, i64>(&count_sql);
+    let mut q = sqlx::query_as::<_, Task>(&sql);
+
+    if let Some(ref status) = filter.status {
+        count_q = count_q.bind(status);
+        q = q.bind(status);
+    }
+    if let Some(priority) = filter.max_priority {
+        count_q = count_q.bind(priority);
+        q = q.bind(priority);
+    }
+    if let Some(ref repo_id) = filter.repo_id {
+        count_q = count_q.bind(repo_id);
+        q = q.bind(repo_id);
+    }
+    if let Some(after) = filter.created_after {
+        count_q = count_q.bind(after);
+        q = q.bind(after);
+    }
+    if let Some(before) = filter.created_before {
+        count_q = count_q.bind(before);
+        q = q.bind(before);
+    }
+    q = q.bind(page.limit).bind(page.offset);
+
+    let total = count_q.fetch_one(pool).await?;
+    let items = q.fetch_all(pool).await?;
+    Ok(Page::new(items, total, page))
+}
+
+/// List tasks generated from a given source (e.g. all tasks promoted from one idea)
+pub async fn list_tasks_by_source(
+    pool: &PgPool,
+    source: &str,
+    source_id: &str,
+) -> DbResult<Vec<Task>> {
+    Ok(sqlx::query_as::<_, Task>(
+        r#"
+        SELECT id,
+               COALESCE(title, 'Untitled') as title,
+               description,
+               priority,
+               status,
+               COALESCE(source, 'manual') as source,
+               source_id,
+               repo_id,
+               file_path,
+               line_number,
+               created_at,
+               updated_at
+        FROM tasks
+        WHERE source = $1 AND source_id = $2
+        ORDER BY created_at ASC, id ASC
+        "#,
+    )
+    .bind(source)
+    .bind(source_id)
+    .fetch_all(pool)
+    .await?)
+}
+
+/// A recorded task status change
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct TaskStatusChange {
+    pub id: i64,
+    pub task_id: String,
+    pub from_status: String,
+    pub to_status: String,
+    pub changed_at: i64,
+}
+
+/// Update task status and record the change in `task_status_history`
+///
+/// Transitions are checked against [`TaskStatus::allowed_transitions`];
+/// setting the current status again is a no-op. Tasks in a status outside
+/// the state machine (legacy rows) may move to any known status.
 pub async fn update_task_status(pool: &PgPool, id: &str, status: &str) -> DbResult<()> {
+    let next = TaskStatus::parse(status)
+        .ok_or_else(|| DbError::InvalidInput(format!("Unknown task status: {}", status)))?;
     let now = chrono::Utc::now().timestamp();
 
-    let result = sqlx::query("UPDATE tasks SET status = $1, updated_at = $2 WHERE id = $3")
-        .bind(status)
-        .bind(now)
+    let mut tx = pool.begin().await?;
+    let current: String = sqlx::query_scalar("SELECT status FROM tasks WHERE id = $1 FOR UPDATE")
         .bind(id)
-        .execute(pool)
-        .await?;
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| DbError::NotFound(format!("Task not found: {}", id)))?;
 
-    if result.rows_affected() == 0 {
-        return Err(DbError::NotFound(format!("Task not found: {}", id)));
+    if current == next.as_str() {
+        return Ok(());
+    }
+    if let Some(from) = TaskStatus::parse(&current) {
+        if !from.can_transition_to(next) {
+            return Err(DbError::InvalidTransition {
+                from: current,
+                to: next.as_str().to_string(),
+            });
+        }
     }
 
+    sqlx::query(
+        r#"
+        UPDATE tasks
+        SET status = $1,
+            updated_at = $2,
+            completed_at = CASE WHEN $1 = 'done' THEN $2 ELSE completed_at END
+        WHERE id = $3
+        "#,
+    )
+    .bind(next.as_str())
+    .bind(now)
+    .bind(id)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        "INSERT INTO task_status_history (task_id, from_status, to_status, changed_at)
+         VALUES ($1, $2, $3, $4)",
+    )
+    .bind(id)
+    .bind(&current)
+    .bind(next.as_str())
+    .bind(now)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
     Ok(())
 }
 
+/// Status changes of a task, oldest first
+pub async fn get_task_history(pool: &PgPool, id: &str) -> DbResult<Vec<TaskStatusChange>> {
+    Ok(sqlx::query_as::<_, TaskStatusChange>(
+        r#"
+        SELECT id, task_id, from_status, to_status, changed_at
+        FROM task_status_history
+        WHERE task_id = $1
+        ORDER BY changed_at ASC, id ASC
+        "#,
+    )
+    .bind(id)
+    .fetch_all(pool)
+    .await?)
+}
+
 /// Get the next recommended task (highest priority pending task)
 pub async fn get_next_task(pool: &PgPool) -> DbResult<Option<Task>> {
     Ok(sqlx::query_as::<_, Task>(
@@ -1375,6 +1776,204 @@ pub async fn get_stats(pool: &PgPool) -> DbResult<DbStats> {
     })
 }
 
+// ============================================================================
+// Repository Overview
+// ============================================================================
+
+/// Window used for `RepoOverview::recent_cost_usd`
+pub const OVERVIEW_COST_WINDOW_SECS: i64 = 30 * 24 * 60 * 60;
+
+/// Task counts bucketed by priority (1=critical … 4=low; anything lower counts as low)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct PriorityCounts {
+    pub critical: i64,
+    pub high: i64,
+    pub medium: i64,
+    pub low: i64,
+}
+
+impl PriorityCounts {
+    pub fn total(&self) -> i64 {
+        self.critical + self.high + self.medium + self.low
+    }
+}
+
+/// Everything a dashboard needs for one repository, fetched in a single call
+#[derive(Debug, Clone, Serialize)]
+pub struct RepoOverview {
+    pub repo_id: String,
+    pub name: String,
+    /// idle/scanning/error
+    pub scan_status: Option<String>,
+    pub last_scanned_at: Option<i64>,
+    pub last_scan_duration_ms: Option<i64>,
+    pub last_scan_files_found: Option<i32>,
+    pub last_scan_issues_found: Option<i32>,
+    pub last_error: Option<String>,
+    /// Built from the per-file scores in `file_analysis`
+    pub score: crate::scoring::CodebaseScore,
+    pub open_tasks: PriorityCounts,
+    pub done_tasks: PriorityCounts,
+    /// Open TODO comments picked up by the scanner (`source = 'github_scanner'`)
+    pub todos: crate::scoring::TodoBreakdown,
+    /// Audit and LLM spend over the last `OVERVIEW_COST_WINDOW_SECS`
+    pub recent_cost_usd: f64,
+}
+
+#[derive(FromRow)]
+struct OverviewRow {
+    id: String,
+    name: String,
+    scan_status: Option<String>,
+    last_scanned_at: Option<i64>,
+    last_scan_duration_ms: Option<i64>,
+    last_scan_files_found: Option<i32>,
+    last_scan_issues_found: Option<i32>,
+    last_error: Option<String>,
+    open_critical: i64,
+    open_high: i64,
+    open_medium: i64,
+    open_low: i64,
+    done_critical: i64,
+    done_high: i64,
+    done_medium: i64,
+    done_low: i64,
+    todo_high: i64,
+    todo_medium: i64,
+    todo_low: i64,
+    recent_cost_usd: f64,
+}
+
+#[derive(FromRow)]
+struct FileScoreRow {
+    file_path: String,
+    line_count: i32,
+    complexity_score: Option<i32>,
+    quality_score: Option<i32>,
+    needs_attention: i32,
+}
+
+/// Aggregate scan state, score, tasks, TODOs and recent cost for a repository
+///
+/// Runs two queries concurrently: one row of counts from `repositories`,
+/// `tasks`, `audit_runs` and `llm_usage`, and the `file_analysis` rows the
+/// score is computed from.
+pub async fn repo_overview(pool: &PgPool, repo_id: &str) -> DbResult<RepoOverview> {
+    let since = chrono::Utc::now().timestamp() - OVERVIEW_COST_WINDOW_SECS;
+
+    let summary = sqlx::query_as::<_, OverviewRow>(
+        r#"
+        SELECT r.id, r.name, r.scan_status, r.last_scanned_at, r.last_scan_duration_ms,
+               r.last_scan_files_found, r.last_scan_issues_found, r.last_error,
+               t.open_critical, t.open_high, t.open_medium, t.open_low,
+               t.done_critical, t.done_high, t.done_medium, t.done_low,
+               t.todo_high, t.todo_medium, t.todo_low,
+               COALESCE(a.cost, 0) + COALESCE(u.cost, 0) AS recent_cost_usd
+        FROM repositories r
+        CROSS JOIN LATERAL (
+            SELECT
+                COUNT(*) FILTER (WHERE status <> 'done' AND priority <= 1) AS open_critical,
+                COUNT(*) FILTER (WHERE status <> 'done' AND priority = 2)  AS open_high,
+                COUNT(*) FILTER (WHERE status <> 'done' AND priority = 3)  AS open_medium,
+                COUNT(*) FILTER (WHERE status <> 'done' AND priority >= 4) AS open_low,
+                COUNT(*) FILTER (WHERE status = 'done' AND priority <= 1)  AS done_critical,
+                COUNT(*) FILTER (WHERE status = 'done' AND priority = 2)   AS done_high,
+                COUNT(*) FILTER (WHERE status = 'done' AND priority = 3)   AS done_medium,
+                COUNT(*) FILTER (WHERE status = 'done' AND priority >= 4)  AS done_low,
+                COUNT(*) FILTER (WHERE source = 'github_scanner' AND status <> 'done'
+                                   AND priority <= 2) AS todo_high,
+                COUNT(*) FILTER (WHERE source = 'github_scanner' AND status <> 'done'
+                                   AND priority = 3)  AS todo_medium,
+                COUNT(*) FILTER (WHERE source = 'github_scanner' AND status <> 'done'
+                                   AND priority >= 4) AS todo_low
+            FROM tasks
+            WHERE repo_id = r.id
+        ) t
+        CROSS JOIN LATERAL (
+            SELECT SUM(estimated_cost_usd)::FLOAT8 AS cost
+            FROM audit_runs
+            WHERE repo_id = r.id AND created_at >= $2
+        ) a
+        CROSS JOIN LATERAL (
+            SELECT SUM(l.cost_usd)::FLOAT8 AS cost
+            FROM llm_usage l
+            JOIN tasks lt ON lt.id = l.task_id
+            WHERE lt.repo_id = r.id AND l.created_at >= $2
+        ) u
+        WHERE r.id = $1
+        "#,
+    )
+    .bind(repo_id)
+    .bind(since)
+    .fetch_optional(pool);
+
+    let files = sqlx::query_as::<_, FileScoreRow>(
+        r#"
+        SELECT file_path, line_count, complexity_score, quality_score, needs_attention
+        FROM file_analysis
+        WHERE repo_id = $1
+        "#,
+    )
+    .bind(repo_id)
+    .fetch_all(pool);
+
+    let (summary, files) = tokio::try_join!(summary, files)?;
+    let row =
+        summary.ok_or_else(|| DbError::NotFound(format!("Repository not found: {}", repo_id)))?;
+
+    let todos = crate::scoring::TodoBreakdown {
+        high: row.todo_high as usize,
+        medium: row.todo_medium as usize,
+        low: row.todo_low as usize,
+        total: (row.todo_high + row.todo_medium + row.todo_low) as usize,
+    };
+
+    // file_analysis stores 1-10 ratings; FileScore works on 0-100
+    let file_scores: Vec<crate::scoring::FileScore> = files
+        .into_iter()
+        .map(|f| {
+            let mut score = crate::scoring::FileScore::new(f.file_path.into());
+            if let Some(quality) = f.quality_score {
+                score.quality = (quality * 10).clamp(0, 100) as f64;
+            }
+            if let Some(complexity) = f.complexity_score {
+                score.complexity = (complexity * 10).clamp(0, 100) as f64;
+            }
+            score.risk = if f.needs_attention != 0 { 50.0 } else { 0.0 };
+            score.breakdown.lines_of_code = f.line_count.max(0) as usize;
+            score
+        })
+        .collect();
+    let mut score = crate::scoring::CodebaseScore::from_file_scores(&file_scores);
+    score.total_todos = todos.clone();
+
+    Ok(RepoOverview {
+        repo_id: row.id,
+        name: row.name,
+        scan_status: row.scan_status,
+        last_scanned_at: row.last_scanned_at,
+        last_scan_duration_ms: row.last_scan_duration_ms,
+        last_scan_files_found: row.last_scan_files_found,
+        last_scan_issues_found: row.last_scan_issues_found,
+        last_error: row.last_error,
+        score,
+        open_tasks: PriorityCounts {
+            critical: row.open_critical,
+            high: row.open_high,
+            medium: row.open_medium,
+            low: row.open_low,
+        },
+        done_tasks: PriorityCounts {
+            critical: row.done_critical,
+            high: row.done_high,
+            medium: row.done_medium,
+            low: row.done_low,
+        },
+        todos,
+        recent_cost_usd: row.recent_cost_usd,
+    })
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -1570,6 +2169,142 @@ mod tests {
         assert!(all.iter().any(|t| t.id == critical.id));
     }
 
+    #[tokio::test]
+    async fn test_list_tasks_page_cursor_and_status_filter() {
+        let pool = setup_test_db().await;
+        let s = uid();
+        let repo = add_repository(
+            &pool,
+            &format!("/tmp/page-{}", s),
+            &format!("page-{}", s),
+            None,
+        )
+        .await
+        .unwrap();
+
+        let mut created = Vec::new();
+        for i in 0..5 {
+            let task = create_task(
+                &pool,
+                &format!("page-{}-{}", s, i),
+                None,
+                3,
+                "manual",
+                None,
+                Some(&repo.id),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+            created.push(task.id);
+        }
+        update_task_status(&pool, &created[0], "done")
+            .await
+            .unwrap();
+
+        let filter = ListFilter::new().repo_id(repo.id.clone());
+        let first = list_tasks_page(&pool, &filter, PageRequest::new(2, 0))
+            .await
+            .unwrap();
+        assert_eq!(first.total, 5);
+        assert_eq!(first.items.len(), 2);
+        assert_eq!(first.next_cursor.as_deref(), Some("2"));
+
+        let second = list_tasks_page(
+            &pool,
+            &filter,
+            PageRequest::from_params(Some(2), first.next_cursor.as_deref(), None).unwrap(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(second.items.len(), 2);
+        assert_eq!(second.next_cursor.as_deref(), Some("4"));
+        let last = list_tasks_page(&pool, &filter, PageRequest::new(2, 4))
+            .await
+            .unwrap();
+        assert_eq!(last.items.len(), 1);
+        assert!(last.next_cursor.is_none());
+
+        let mut seen: Vec<_> = [first.items, second.items, last.items]
+            .concat()
+            .into_iter()
+            .map(|t| t.id)
+            .collect();
+        seen.sort();
+        created.sort();
+        assert_eq!(seen, created, "pages must not overlap or skip rows");
+
+        let done = list_tasks_page(
+            &pool,
+            &filter.clone().status("done"),
+            PageRequest::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(done.total, 1);
+        assert_eq!(done.items[0].status, "done");
+
+        sqlx::query("DELETE FROM tasks WHERE repo_id = $1")
+            .bind(&repo.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        remove_repository(&pool, &repo.id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_task_status_transitions_are_validated_and_recorded() {
+        let pool = setup_test_db().await;
+        let task = create_task(
+            &pool,
+            &format!("history-{}", uid()),
+            None,
+            3,
+            "manual",
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        update_task_status(&pool, &task.id, "in_progress")
+            .await
+            .unwrap();
+        update_task_status(&pool, &task.id, "done").await.unwrap();
+
+        // Done -> InProgress is not allowed and leaves no trace
+        let err = update_task_status(&pool, &task.id, "in_progress")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DbError::InvalidTransition { .. }), "{}", err);
+        assert!(matches!(
+            update_task_status(&pool, &task.id, "bogus").await,
+            Err(DbError::InvalidInput(_))
+        ));
+
+        let history = get_task_history(&pool, &task.id).await.unwrap();
+        let steps: Vec<(&str, &str)> = history
+            .iter()
+            .map(|h| (h.from_status.as_str(), h.to_status.as_str()))
+            .collect();
+        assert_eq!(
+            steps,
+            vec![("pending", "in_progress"), ("in_progress", "done")]
+        );
+
+        let stored: (String, Option<i64>) =
+            sqlx::query_as("SELECT status, completed_at FROM tasks WHERE id = $1")
+                .bind(&task.id)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(stored.0, "done");
+        assert!(stored.1.is_some());
+    }
+
     #[tokio::test]
     async fn test_stats() {
         let pool = setup_test_db().await;
@@ -1608,6 +2343,135 @@ mod tests {
         assert!(stats.total_tasks >= 1);
         assert!(stats.pending_tasks >= 1);
     }
+
+    #[tokio::test]
+    async fn test_repo_overview_aggregates() {
+        let pool = setup_test_db().await;
+
+        let s = uid();
+        let repo = add_repository(
+            &pool,
+            &format!("/tmp/overview-repo-{}", s),
+            &format!("overview-repo-{}", s),
+            None,
+        )
+        .await
+        .unwrap();
+
+        start_scan(&pool, &repo.id, 3).await.unwrap();
+        complete_scan(&pool, &repo.id, 1200, 3, 4).await.unwrap();
+
+        for (priority, source) in [
+            (1, "file_scan"),
+            (2, "github_scanner"),
+            (3, "github_scanner"),
+            (4, "github_scanner"),
+            (4, "manual"),
+        ] {
+            create_task(
+                &pool,
+                &format!("Overview task {} {}", priority, s),
+                None,
+                priority,
+                source,
+                None,
+                Some(&repo.id),
+                Some("src/lib.rs"),
+                None,
+            )
+            .await
+            .unwrap();
+        }
+        let done = create_task(
+            &pool,
+            &format!("Overview done {}", s),
+            None,
+            2,
+            "github_scanner",
+            None,
+            Some(&repo.id),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+        update_task_status(&pool, &done.id, "done").await.unwrap();
+
+        let now = chrono::Utc::now().timestamp();
+        for (path, quality, complexity) in [("src/lib.rs", 8, 4), ("src/main.rs", 6, 6)] {
+            sqlx::query(
+                r#"
+                INSERT INTO file_analysis (id, repo_id, file_path, content_hash, size_bytes,
+                    line_count, complexity_score, quality_score, created_at, updated_at)
+                VALUES ($1, $2, $3, 'hash', 100, 10, $4, $5, $6, $6)
+                "#,
+            )
+            .bind(uuid::Uuid::new_v4().to_string())
+            .bind(&repo.id)
+            .bind(path)
+            .bind(complexity)
+            .bind(quality)
+            .bind(now)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO audit_runs (id, repo_id, repo_path, repo_name, estimated_cost_usd, created_at)
+            VALUES ($1, $2, $3, $4, 0.25, $5), ($6, $2, $3, $4, 9.0, $7)
+            "#,
+        )
+        .bind(format!("overview-recent-{}", s))
+        .bind(&repo.id)
+        .bind(&repo.path)
+        .bind(&repo.name)
+        .bind(now)
+        .bind(format!("overview-old-{}", s))
+        .bind(now - OVERVIEW_COST_WINDOW_SECS - 60)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let overview = repo_overview(&pool, &repo.id).await.unwrap();
+
+        assert_eq!(overview.scan_status.as_deref(), Some("idle"));
+        assert!(overview.last_scanned_at.is_some());
+        assert_eq!(overview.last_scan_files_found, Some(3));
+        assert_eq!(overview.last_scan_issues_found, Some(4));
+
+        assert_eq!(
+            overview.open_tasks,
+            PriorityCounts {
+                critical: 1,
+                high: 1,
+                medium: 1,
+                low: 2,
+            }
+        );
+        assert_eq!(overview.done_tasks.high, 1);
+        assert_eq!(overview.done_tasks.total(), 1);
+
+        // The done TODO isn't counted
+        assert_eq!(overview.todos.high, 1);
+        assert_eq!(overview.todos.medium, 1);
+        assert_eq!(overview.todos.low, 1);
+        assert_eq!(overview.todos.total, 3);
+        assert_eq!(overview.score.total_todos.total, 3);
+
+        assert_eq!(overview.score.total_files, 2);
+        assert!((overview.score.averages.quality - 70.0).abs() < 1e-9);
+        assert!((overview.score.averages.complexity - 50.0).abs() < 1e-9);
+
+        // Only the run inside the window counts
+        assert!((overview.recent_cost_usd - 0.25).abs() < 1e-6);
+
+        assert!(matches!(
+            repo_overview(&pool, &format!("missing-{}", s)).await,
+            Err(DbError::NotFound(_))
+        ));
+    }
 }
 
 // ============================================================================
@@ -6,8 +6,11 @@ pub mod chunks;
 pub mod config;
 pub mod core;
 pub mod documents;
+pub mod note_search;
+pub mod pagination;
 pub mod queue;
 pub mod scan_events;
+pub mod scan_jobs;
 
 // Re-export chunk store types and functions
 pub use chunks::{
@@ -28,6 +31,16 @@ pub use sqlx::PgPool;
 // Re-export core database types and functions
 pub use core::*;
 
+// Re-export unified note search types (the function is `note_search::search_notes`,
+// distinct from the simpler `core::search_notes`)
+pub use note_search::{MatchMode, NoteQuery, NoteSearchHit};
+
+// Re-export pagination types
+pub use pagination::{ListFilter, Page, PageRequest, RepositoryPage, TaskPage};
+
+// Re-export on-demand scan job types
+pub use scan_jobs::{ScanJob, ScanJobStatus};
+
 // Re-export queue types and functions
 pub use queue::{
     create_queue_tables, FileAnalysis, QueueItem, QueuePriority, QueueSource, QueueStage,
@@ -52,13 +65,17 @@ pub use documents::{
     get_document_chunks,
     get_document_embeddings,
     get_document_tags,
+    get_idea,
     get_unindexed_documents,
+    link_idea_task,
     list_documents,
     list_ideas,
     // Tags functions
     list_tags,
     mark_document_indexed,
-    // FTS5 search
+    // Full-text search
+    rebuild_fts_index,
+    reindex_document,
     search_documents,
     search_documents_by_tags,
     search_documents_by_title,
@@ -0,0 +1,162 @@
+//! Pagination and common list filters
+//!
+//! Paged `list_*` functions take a [`ListFilter`] and a [`PageRequest`] and
+//! return a [`Page`]:
+//!
+//! ```json
+//! { "items": [...], "next_cursor": "100", "total": 312 }
+//! ```
+//!
+//! `next_cursor` is opaque to clients: pass it back as `cursor` to fetch the
+//! following page. It is `null` on the last page.
+
+use super::{DbError, DbResult};
+use serde::{Deserialize, Serialize};
+
+/// Page size when the client doesn't ask for one
+pub const DEFAULT_PAGE_SIZE: i64 = 50;
+
+/// Upper bound on a single page
+pub const MAX_PAGE_SIZE: i64 = 500;
+
+/// Which slice of a result set to fetch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageRequest {
+    pub limit: i64,
+    pub offset: i64,
+}
+
+impl Default for PageRequest {
+    fn default() -> Self {
+        Self {
+            limit: DEFAULT_PAGE_SIZE,
+            offset: 0,
+        }
+    }
+}
+
+impl PageRequest {
+    /// `limit` is clamped to `1..=MAX_PAGE_SIZE`, `offset` to `>= 0`
+    pub fn new(limit: i64, offset: i64) -> Self {
+        Self {
+            limit: limit.clamp(1, MAX_PAGE_SIZE),
+            offset: offset.max(0),
+        }
+    }
+
+    /// Build from request parameters. `cursor` (from a previous page's
+    /// `next_cursor`) wins over a raw `offset`.
+    pub fn from_params(
+        limit: Option<i64>,
+        cursor: Option<&str>,
+        offset: Option<i64>,
+    ) -> DbResult<Self> {
+        let offset = match cursor.filter(|c| !c.is_empty()) {
+            Some(cursor) => cursor
+                .parse::<i64>()
+                .ok()
+                .filter(|o| *o >= 0)
+                .ok_or_else(|| DbError::InvalidInput(format!("Invalid cursor: {}", cursor)))?,
+            None => offset.unwrap_or(0),
+        };
+        Ok(Self::new(limit.unwrap_or(DEFAULT_PAGE_SIZE), offset))
+    }
+}
+
+/// One page of a list plus what's needed to fetch the next
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[aliases(
+    RepositoryPage = Page<crate::db::Repository>,
+    TaskPage = Page<crate::db::Task>
+)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Pass back as `cursor` for the next page; `null` on the last page
+    pub next_cursor: Option<String>,
+    /// Rows matching the filters across all pages
+    pub total: i64,
+}
+
+impl<T> Page<T> {
+    pub fn new(items: Vec<T>, total: i64, request: PageRequest) -> Self {
+        let next_offset = request.offset + items.len() as i64;
+        let next_cursor =
+            (!items.is_empty() && next_offset < total).then(|| next_offset.to_string());
+        Self {
+            items,
+            next_cursor,
+            total,
+        }
+    }
+}
+
+/// Filters shared by the paged list endpoints. Each `list_*` applies the
+/// fields that exist on its table and ignores the rest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListFilter {
+    pub status: Option<String>,
+    pub repo_id: Option<String>,
+    /// Highest priority number to include (1 = critical), i.e. `priority <= n`
+    pub max_priority: Option<i32>,
+    /// Inclusive lower bound on `created_at` (Unix seconds)
+    pub created_after: Option<i64>,
+    /// Exclusive upper bound on `created_at` (Unix seconds)
+    pub created_before: Option<i64>,
+}
+
+impl ListFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.status = Some(status.into());
+        self
+    }
+
+    pub fn repo_id(mut self, repo_id: impl Into<String>) -> Self {
+        self.repo_id = Some(repo_id.into());
+        self
+    }
+
+    pub fn max_priority(mut self, priority: i32) -> Self {
+        self.max_priority = Some(priority);
+        self
+    }
+
+    pub fn created_after(mut self, timestamp: i64) -> Self {
+        self.created_after = Some(timestamp);
+        self
+    }
+
+    pub fn created_before(mut self, timestamp: i64) -> Self {
+        self.created_before = Some(timestamp);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_request_from_params() {
+        let page = PageRequest::from_params(Some(10), Some("30"), Some(5)).unwrap();
+        assert_eq!(page, PageRequest::new(10, 30));
+        let page = PageRequest::from_params(Some(10_000), None, Some(-3)).unwrap();
+        assert_eq!(page, PageRequest::new(MAX_PAGE_SIZE, 0));
+        assert!(PageRequest::from_params(None, Some("abc"), None).is_err());
+    }
+
+    #[test]
+    fn test_next_cursor_only_when_more_rows() {
+        let req = PageRequest::new(2, 0);
+        assert_eq!(
+            Page::new(vec![1, 2], 5, req).next_cursor.as_deref(),
+            Some("2")
+        );
+        let last = PageRequest::new(2, 4);
+        assert!(Page::new(vec![5], 5, last).next_cursor.is_none());
+        assert!(Page::<i32>::new(vec![], 0, req).next_cursor.is_none());
+    }
+}
@@ -45,6 +45,8 @@ pub enum QueueStage {
     Ready,
     /// Processing failed (with retry count)
     Failed,
+    /// Failed too many times; parked until manually requeued
+    DeadLetter,
     /// Archived/inactive
     Archived,
 }
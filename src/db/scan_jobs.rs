@@ -0,0 +1,198 @@
+//! On-demand scan jobs
+//!
+//! A job is created by `POST /api/repos/:id/scan`, run by the scan job
+//! service and polled through `GET /api/jobs/:id`.
+
+use super::DbResult;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+
+// ============================================================================
+// Models
+// ============================================================================
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
+#[sqlx(type_name = "TEXT", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ScanJobStatus {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+    /// Stopped through `POST /api/repos/:id/scan/cancel`; the next scan
+    /// resumes from its checkpoint
+    Cancelled,
+}
+
+impl ScanJobStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScanJobStatus::Queued => "queued",
+            ScanJobStatus::Running => "running",
+            ScanJobStatus::Completed => "completed",
+            ScanJobStatus::Failed => "failed",
+            ScanJobStatus::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            ScanJobStatus::Completed | ScanJobStatus::Failed | ScanJobStatus::Cancelled
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct ScanJob {
+    pub id: String,
+    pub repo_id: String,
+    pub status: ScanJobStatus,
+    pub force: bool,
+    pub files_analyzed: i64,
+    pub issues_found: i64,
+    pub tasks_created: i64,
+    pub error: Option<String>,
+    pub created_at: i64,
+    pub started_at: Option<i64>,
+    pub completed_at: Option<i64>,
+}
+
+// ============================================================================
+// Scan Job CRUD
+// ============================================================================
+
+/// Create a queued job
+pub async fn create_scan_job(pool: &PgPool, repo_id: &str, force: bool) -> DbResult<ScanJob> {
+    let job = sqlx::query_as::<_, ScanJob>(
+        r#"
+        INSERT INTO scan_jobs (id, repo_id, status, force, created_at)
+        VALUES ($1, $2, 'queued', $3, $4)
+        RETURNING *
+        "#,
+    )
+    .bind(uuid::Uuid::new_v4().to_string())
+    .bind(repo_id)
+    .bind(force)
+    .bind(chrono::Utc::now().timestamp())
+    .fetch_one(pool)
+    .await?;
+
+    Ok(job)
+}
+
+pub async fn get_scan_job(pool: &PgPool, id: &str) -> DbResult<Option<ScanJob>> {
+    Ok(
+        sqlx::query_as::<_, ScanJob>("SELECT * FROM scan_jobs WHERE id = $1")
+            .bind(id)
+            .fetch_optional(pool)
+            .await?,
+    )
+}
+
+/// The queued or running job for a repo, if any
+pub async fn get_active_scan_job(pool: &PgPool, repo_id: &str) -> DbResult<Option<ScanJob>> {
+    Ok(sqlx::query_as::<_, ScanJob>(
+        r#"
+        SELECT * FROM scan_jobs
+        WHERE repo_id = $1 AND status IN ('queued', 'running')
+        ORDER BY created_at DESC
+        LIMIT 1
+        "#,
+    )
+    .bind(repo_id)
+    .fetch_optional(pool)
+    .await?)
+}
+
+pub async fn mark_scan_job_running(pool: &PgPool, id: &str) -> DbResult<()> {
+    sqlx::query("UPDATE scan_jobs SET status = 'running', started_at = $1 WHERE id = $2")
+        .bind(chrono::Utc::now().timestamp())
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+pub async fn complete_scan_job(
+    pool: &PgPool,
+    id: &str,
+    files_analyzed: i64,
+    issues_found: i64,
+    tasks_created: i64,
+) -> DbResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE scan_jobs
+        SET status = 'completed',
+            files_analyzed = $1,
+            issues_found = $2,
+            tasks_created = $3,
+            completed_at = $4
+        WHERE id = $5
+        "#,
+    )
+    .bind(files_analyzed)
+    .bind(issues_found)
+    .bind(tasks_created)
+    .bind(chrono::Utc::now().timestamp())
+    .bind(id)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn fail_scan_job(pool: &PgPool, id: &str, error: &str) -> DbResult<()> {
+    sqlx::query(
+        "UPDATE scan_jobs SET status = 'failed', error = $1, completed_at = $2 WHERE id = $3",
+    )
+    .bind(error)
+    .bind(chrono::Utc::now().timestamp())
+    .bind(id)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Record a job whose scan was cancelled part-way, with what it got through
+pub async fn cancel_scan_job(
+    pool: &PgPool,
+    id: &str,
+    files_analyzed: i64,
+    issues_found: i64,
+) -> DbResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE scan_jobs
+        SET status = 'cancelled',
+            files_analyzed = $1,
+            issues_found = $2,
+            completed_at = $3
+        WHERE id = $4
+        "#,
+    )
+    .bind(files_analyzed)
+    .bind(issues_found)
+    .bind(chrono::Utc::now().timestamp())
+    .bind(id)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Fail jobs left queued/running by a previous process. Call once at startup.
+pub async fn fail_interrupted_scan_jobs(pool: &PgPool) -> DbResult<u64> {
+    let result = sqlx::query(
+        r#"
+        UPDATE scan_jobs
+        SET status = 'failed', error = 'interrupted by server restart', completed_at = $1
+        WHERE status IN ('queued', 'running')
+        "#,
+    )
+    .bind(chrono::Utc::now().timestamp())
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
@@ -5,15 +5,20 @@
 //! - Issue counts
 //! - Code statistics
 //! - Age/status indicators
+//! - Churn × complexity hotspots
 
-use crate::error::Result;
+use crate::error::{AuditError, Result};
+use crate::static_analysis::StaticAnalyzer;
 use crate::tag_schema::{
     CodeStatus, DirectoryNode, IssuesSummary, NodeStats, NodeType, SimpleIssueDetector,
 };
 use crate::types::AuditTag;
+use chrono::{DateTime, Utc};
 use regex::Regex;
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::process::Command;
 
 /// Directory tree builder
 pub struct DirectoryTreeBuilder {
@@ -312,6 +317,9 @@ impl DirectoryTreeBuilder {
                 critical: node.issues.critical,
                 high: node.issues.high,
                 lines_of_code: node.stats.lines_of_code,
+                churn: 0,
+                complexity: 0,
+                hotspot_score: 0.0,
             });
         }
 
@@ -320,6 +328,59 @@ impl DirectoryTreeBuilder {
         }
     }
 
+    /// Rank source files by churn × complexity, highest first.
+    ///
+    /// Churn is the number of commits touching a file since `since`;
+    /// complexity is the static analyzer's `estimated_complexity`. Files with
+    /// zero churn (or zero complexity) score zero and are left out, as are
+    /// files that no longer exist or are excluded.
+    pub fn hotspots_by_churn(
+        &self,
+        repo_path: &Path,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<Hotspot>> {
+        let churn = file_churn(repo_path, since)?;
+        let analyzer = StaticAnalyzer::new();
+
+        let mut hotspots = Vec::new();
+        for (relative, commits) in churn {
+            let path = repo_path.join(&relative);
+            if self.should_exclude(Path::new(&relative)) || !self.is_source_file(&path) {
+                continue;
+            }
+
+            let content = fs::read_to_string(&path)?;
+            let complexity = analyzer
+                .analyze(&relative, &content)
+                .signals
+                .estimated_complexity;
+            if complexity == 0 {
+                continue;
+            }
+
+            let node = self.build_node(&path)?;
+            hotspots.push(Hotspot {
+                path: node.path,
+                name: node.name,
+                node_type: NodeType::File,
+                total_issues: node.issues.total(),
+                critical: node.issues.critical,
+                high: node.issues.high,
+                lines_of_code: node.stats.lines_of_code,
+                churn: commits,
+                complexity,
+                hotspot_score: (commits * complexity) as f64,
+            });
+        }
+
+        hotspots.sort_by(|a, b| {
+            b.hotspot_score
+                .total_cmp(&a.hotspot_score)
+                .then_with(|| a.path.cmp(&b.path))
+        });
+        Ok(hotspots)
+    }
+
     /// Generate ASCII tree visualization
     pub fn to_ascii_tree(&self, node: &DirectoryNode, max_depth: usize) -> String {
         let mut output = String::new();
@@ -394,6 +455,32 @@ impl DirectoryTreeBuilder {
     }
 }
 
+/// Commits per file (relative to `repo_path`) since `since`
+fn file_churn(repo_path: &Path, since: DateTime<Utc>) -> Result<HashMap<String, usize>> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_path)
+        .args(["log", "--relative", "--format=", "--name-only"])
+        .arg(format!("--since={}", since.to_rfc3339()))
+        .output()
+        .map_err(|e| AuditError::other(format!("Failed to run git log: {}", e)))?;
+    if !output.status.success() {
+        return Err(AuditError::other(format!(
+            "git log failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    let mut churn = HashMap::new();
+    for file in String::from_utf8_lossy(&output.stdout).lines() {
+        let file = file.trim();
+        if !file.is_empty() {
+            *churn.entry(file.to_string()).or_insert(0) += 1;
+        }
+    }
+    Ok(churn)
+}
+
 /// Tree summary statistics
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct TreeSummary {
@@ -408,7 +495,8 @@ pub struct TreeSummary {
     pub directories_analyzed: usize,
 }
 
-/// Code hotspot (file or directory with many issues)
+/// Code hotspot (file or directory with many issues, or a frequently
+/// changed complex file)
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Hotspot {
     pub path: PathBuf,
@@ -418,6 +506,16 @@ pub struct Hotspot {
     pub critical: usize,
     pub high: usize,
     pub lines_of_code: usize,
+    /// Commits touching the file in the churn window (0 unless from
+    /// [`DirectoryTreeBuilder::hotspots_by_churn`])
+    #[serde(default)]
+    pub churn: usize,
+    /// Estimated cyclomatic complexity
+    #[serde(default)]
+    pub complexity: usize,
+    /// `churn * complexity`
+    #[serde(default)]
+    pub hotspot_score: f64,
 }
 
 #[cfg(test)]
@@ -480,4 +578,72 @@ fn foo() {
         assert!(ascii.contains("📁"));
         assert!(ascii.contains("main.rs"));
     }
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args([
+                "-c",
+                "user.name=Fixture",
+                "-c",
+                "user.email=fixture@example.com",
+            ])
+            .args(args)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    fn complex_source(variant: usize) -> String {
+        let mut source = String::new();
+        for i in 0..5 {
+            source.push_str(&format!(
+                "pub fn f{i}(x: i32) -> i32 {{\n    if x > {v} {{\n        return 1;\n    }}\n    match x {{\n        0 => 2,\n        _ => 3,\n    }}\n}}\n",
+                v = variant
+            ));
+        }
+        source
+    }
+
+    #[test]
+    fn test_hotspots_by_churn() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        git(root, &["init", "-q", "-b", "main"]);
+        fs::create_dir(root.join("src")).unwrap();
+
+        // Both files are equally complex; only `busy.rs` keeps changing
+        fs::write(root.join("src/busy.rs"), complex_source(0)).unwrap();
+        fs::write(root.join("src/quiet.rs"), complex_source(0)).unwrap();
+        git(root, &["add", "."]);
+        git(root, &["commit", "-q", "-m", "initial"]);
+        for i in 1..5 {
+            fs::write(root.join("src/busy.rs"), complex_source(i)).unwrap();
+            git(root, &["commit", "-q", "-am", &format!("change {}", i)]);
+        }
+
+        // Untouched since the cutoff: no churn, no score
+        fs::write(root.join("src/untracked.rs"), complex_source(0)).unwrap();
+
+        let builder = DirectoryTreeBuilder::new(root);
+        let since = Utc::now() - chrono::Duration::days(30);
+        let hotspots = builder.hotspots_by_churn(root, since).unwrap();
+
+        let names: Vec<&str> = hotspots.iter().map(|h| h.name.as_str()).collect();
+        assert_eq!(names, vec!["busy.rs", "quiet.rs"]);
+        assert_eq!(hotspots[0].churn, 5);
+        assert_eq!(hotspots[1].churn, 1);
+        assert_eq!(hotspots[0].complexity, hotspots[1].complexity);
+        assert!(hotspots[0].complexity > 0);
+        assert_eq!(
+            hotspots[0].hotspot_score,
+            (5 * hotspots[0].complexity) as f64
+        );
+
+        // Nothing changed after the cutoff
+        let future = Utc::now() + chrono::Duration::days(1);
+        assert!(builder.hotspots_by_churn(root, future).unwrap().is_empty());
+    }
 }
@@ -21,8 +21,10 @@
 //! ```
 
 use crate::db::Database;
+use crate::schema::parse_response;
 use crate::GrokClient;
 use anyhow::Result;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
@@ -30,7 +32,7 @@ use std::path::Path;
 // Data Types
 // ============================================================================
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ModuleDoc {
     pub module_name: String,
     pub summary: String,
@@ -38,7 +40,7 @@ pub struct ModuleDoc {
     pub examples: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FunctionDoc {
     pub name: String,
     pub signature: String,
@@ -48,14 +50,14 @@ pub struct FunctionDoc {
     pub examples: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ParameterDoc {
     pub name: String,
     pub param_type: String,
     pub description: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ReadmeContent {
     pub title: String,
     pub description: String,
@@ -97,7 +99,7 @@ impl DocGenerator {
         let response = self.grok_client.ask(&prompt, None).await?;
 
         // Try to parse JSON response
-        let doc: ModuleDoc = serde_json::from_str(&response).map_err(|e| {
+        let doc: ModuleDoc = parse_response("ModuleDoc", &response).map_err(|e| {
             anyhow::anyhow!(
                 "Failed to parse module doc JSON: {}.\nResponse preview: {}",
                 e,
@@ -117,7 +119,7 @@ impl DocGenerator {
 
         let response = self.grok_client.ask(&prompt, None).await?;
 
-        let readme: ReadmeContent = serde_json::from_str(&response).map_err(|e| {
+        let readme: ReadmeContent = parse_response("ReadmeContent", &response).map_err(|e| {
             anyhow::anyhow!(
                 "Failed to parse README JSON: {}.\nResponse preview: {}",
                 e,
@@ -0,0 +1,706 @@
+//! Stable serialization boundary
+//!
+//! The structs in [`crate::types`] are free to change shape; anything that
+//! leaves the process — HTTP responses, JSON written to disk, data a
+//! downstream consumer may have persisted — goes through the versioned DTOs
+//! here instead. Every field and variant carries an explicit serde name, so
+//! renaming an internal field or variant cannot change the JSON.
+//!
+//! A breaking change to the wire format means a new `vN` module next to
+//! [`v1`], not an edit to an existing one.
+//!
+//! ```rust
+//! use rustassistant::dto::v1::TaskDto;
+//! use rustassistant::types::{Category, Task, TaskPriority};
+//!
+//! let task = Task::new("Fix it", "", "src/lib.rs".into(), Some(3), TaskPriority::High, Category::Other);
+//! let json = serde_json::to_string(&TaskDto::from(&task)).unwrap();
+//! let back: Task = serde_json::from_str::<TaskDto>(&json).unwrap().into();
+//! assert_eq!(back.id, task.id);
+//! ```
+
+/// Version of the newest DTO module, written as `schema_version` in
+/// top-level documents
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Version 1 of the serialized API
+pub mod v1 {
+    use crate::types::{
+        AuditReport, AuditSummary, AuditTag, AuditTagType, Category, DependencyType, FileAnalysis,
+        FilePriority, Issue, IssueCategory, IssueSeverity, SecurityRating, ServiceDependency,
+        SystemMap, Task, TaskPriority,
+    };
+    use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+
+    /// Field-less enum mirrored one-to-one, with a pinned name per variant
+    macro_rules! dto_enum {
+        ($(#[$meta:meta])* $dto:ident <=> $internal:ident { $($variant:ident = $name:literal),+ $(,)? }) => {
+            $(#[$meta])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+            pub enum $dto {
+                $(#[serde(rename = $name)] $variant),+
+            }
+
+            impl From<$internal> for $dto {
+                fn from(value: $internal) -> Self {
+                    match value {
+                        $($internal::$variant => $dto::$variant),+
+                    }
+                }
+            }
+
+            impl From<$dto> for $internal {
+                fn from(value: $dto) -> Self {
+                    match value {
+                        $($dto::$variant => $internal::$variant),+
+                    }
+                }
+            }
+        };
+    }
+
+    dto_enum! {
+        /// File category
+        CategoryDto <=> Category {
+            Janus = "janus",
+            Execution = "execution",
+            Clients = "clients",
+            Audit = "audit",
+            Infra = "infra",
+            Config = "config",
+            Documentation = "documentation",
+            Tests = "tests",
+            Other = "other",
+        }
+    }
+
+    dto_enum! {
+        /// File review priority
+        FilePriorityDto <=> FilePriority {
+            Critical = "critical",
+            High = "high",
+            Medium = "medium",
+            Low = "low",
+            Exclude = "exclude",
+        }
+    }
+
+    dto_enum! {
+        /// Security letter grade
+        SecurityRatingDto <=> SecurityRating {
+            A = "A",
+            B = "B",
+            C = "C",
+            D = "D",
+            F = "F",
+        }
+    }
+
+    dto_enum! {
+        /// Kind of `@audit-*` tag
+        AuditTagTypeDto <=> AuditTagType {
+            Tag = "tag",
+            Todo = "todo",
+            Freeze = "freeze",
+            Review = "review",
+            Security = "security",
+        }
+    }
+
+    dto_enum! {
+        /// Task priority
+        TaskPriorityDto <=> TaskPriority {
+            Critical = "critical",
+            High = "high",
+            Medium = "medium",
+            Low = "low",
+        }
+    }
+
+    dto_enum! {
+        /// Issue severity
+        IssueSeverityDto <=> IssueSeverity {
+            Critical = "critical",
+            High = "high",
+            Medium = "medium",
+            Low = "low",
+            Info = "info",
+        }
+    }
+
+    dto_enum! {
+        /// Issue category
+        IssueCategoryDto <=> IssueCategory {
+            Security = "security",
+            Performance = "performance",
+            TypeSafety = "type-safety",
+            AsyncSafety = "async-safety",
+            RiskManagement = "risk-management",
+            CodeQuality = "code-quality",
+            Documentation = "documentation",
+            Testing = "testing",
+        }
+    }
+
+    dto_enum! {
+        /// Service dependency transport
+        DependencyTypeDto <=> DependencyType {
+            Grpc = "grpc",
+            Http = "http",
+            Internal = "internal",
+        }
+    }
+
+    /// Audit tag found in code
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct AuditTagDto {
+        #[serde(rename = "tag_type")]
+        pub tag_type: AuditTagTypeDto,
+        #[serde(rename = "file")]
+        pub file: PathBuf,
+        #[serde(rename = "line")]
+        pub line: usize,
+        #[serde(rename = "value")]
+        pub value: String,
+        #[serde(rename = "context", default)]
+        pub context: Option<String>,
+    }
+
+    impl From<&AuditTag> for AuditTagDto {
+        fn from(tag: &AuditTag) -> Self {
+            Self {
+                tag_type: tag.tag_type.into(),
+                file: tag.file.clone(),
+                line: tag.line,
+                value: tag.value.clone(),
+                context: tag.context.clone(),
+            }
+        }
+    }
+
+    impl From<AuditTagDto> for AuditTag {
+        fn from(dto: AuditTagDto) -> Self {
+            Self {
+                tag_type: dto.tag_type.into(),
+                file: dto.file,
+                line: dto.line,
+                value: dto.value,
+                context: dto.context,
+            }
+        }
+    }
+
+    /// Task generated by an audit
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct TaskDto {
+        #[serde(rename = "id")]
+        pub id: String,
+        #[serde(rename = "title")]
+        pub title: String,
+        #[serde(rename = "description")]
+        pub description: String,
+        #[serde(rename = "file")]
+        pub file: PathBuf,
+        #[serde(rename = "line", default)]
+        pub line: Option<usize>,
+        #[serde(rename = "priority")]
+        pub priority: TaskPriorityDto,
+        #[serde(rename = "category")]
+        pub category: CategoryDto,
+        #[serde(rename = "created_at")]
+        pub created_at: DateTime<Utc>,
+        #[serde(rename = "tags", default)]
+        pub tags: Vec<String>,
+    }
+
+    impl From<&Task> for TaskDto {
+        fn from(task: &Task) -> Self {
+            Self {
+                id: task.id.clone(),
+                title: task.title.clone(),
+                description: task.description.clone(),
+                file: task.file.clone(),
+                line: task.line,
+                priority: task.priority.into(),
+                category: task.category.into(),
+                created_at: task.created_at,
+                tags: task.tags.clone(),
+            }
+        }
+    }
+
+    impl From<TaskDto> for Task {
+        fn from(dto: TaskDto) -> Self {
+            Self {
+                id: dto.id,
+                title: dto.title,
+                description: dto.description,
+                file: dto.file,
+                line: dto.line,
+                priority: dto.priority.into(),
+                category: dto.category.into(),
+                created_at: dto.created_at,
+                tags: dto.tags,
+            }
+        }
+    }
+
+    /// Code issue
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct IssueDto {
+        #[serde(rename = "severity")]
+        pub severity: IssueSeverityDto,
+        #[serde(rename = "category")]
+        pub category: IssueCategoryDto,
+        #[serde(rename = "file")]
+        pub file: PathBuf,
+        #[serde(rename = "line")]
+        pub line: usize,
+        #[serde(rename = "message")]
+        pub message: String,
+        #[serde(rename = "suggestion", default)]
+        pub suggestion: Option<String>,
+    }
+
+    impl From<&Issue> for IssueDto {
+        fn from(issue: &Issue) -> Self {
+            Self {
+                severity: issue.severity.into(),
+                category: issue.category.into(),
+                file: issue.file.clone(),
+                line: issue.line,
+                message: issue.message.clone(),
+                suggestion: issue.suggestion.clone(),
+            }
+        }
+    }
+
+    impl From<IssueDto> for Issue {
+        fn from(dto: IssueDto) -> Self {
+            Self {
+                severity: dto.severity.into(),
+                category: dto.category.into(),
+                file: dto.file,
+                line: dto.line,
+                message: dto.message,
+                suggestion: dto.suggestion,
+            }
+        }
+    }
+
+    /// Per-file analysis
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct FileAnalysisDto {
+        #[serde(rename = "path")]
+        pub path: PathBuf,
+        #[serde(rename = "category")]
+        pub category: CategoryDto,
+        #[serde(rename = "priority")]
+        pub priority: FilePriorityDto,
+        #[serde(rename = "lines")]
+        pub lines: usize,
+        #[serde(rename = "doc_blocks")]
+        pub doc_blocks: usize,
+        #[serde(rename = "security_rating", default)]
+        pub security_rating: Option<SecurityRatingDto>,
+        #[serde(rename = "issues", default)]
+        pub issues: Vec<IssueDto>,
+        #[serde(rename = "llm_analysis", default)]
+        pub llm_analysis: Option<String>,
+        #[serde(rename = "tags", default)]
+        pub tags: Vec<AuditTagDto>,
+    }
+
+    impl From<&FileAnalysis> for FileAnalysisDto {
+        fn from(file: &FileAnalysis) -> Self {
+            Self {
+                path: file.path.clone(),
+                category: file.category.into(),
+                priority: file.priority.into(),
+                lines: file.lines,
+                doc_blocks: file.doc_blocks,
+                security_rating: file.security_rating.map(Into::into),
+                issues: file.issues.iter().map(Into::into).collect(),
+                llm_analysis: file.llm_analysis.clone(),
+                tags: file.tags.iter().map(Into::into).collect(),
+            }
+        }
+    }
+
+    impl From<FileAnalysisDto> for FileAnalysis {
+        fn from(dto: FileAnalysisDto) -> Self {
+            Self {
+                path: dto.path,
+                category: dto.category.into(),
+                priority: dto.priority.into(),
+                lines: dto.lines,
+                doc_blocks: dto.doc_blocks,
+                security_rating: dto.security_rating.map(Into::into),
+                issues: dto.issues.into_iter().map(Into::into).collect(),
+                llm_analysis: dto.llm_analysis,
+                tags: dto.tags.into_iter().map(Into::into).collect(),
+            }
+        }
+    }
+
+    /// Dependency between two services
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct ServiceDependencyDto {
+        #[serde(rename = "from")]
+        pub from: String,
+        #[serde(rename = "to")]
+        pub to: String,
+        #[serde(rename = "dep_type")]
+        pub dep_type: DependencyTypeDto,
+    }
+
+    impl From<&ServiceDependency> for ServiceDependencyDto {
+        fn from(dep: &ServiceDependency) -> Self {
+            Self {
+                from: dep.from.clone(),
+                to: dep.to.clone(),
+                dep_type: dep.dep_type.into(),
+            }
+        }
+    }
+
+    impl From<ServiceDependencyDto> for ServiceDependency {
+        fn from(dto: ServiceDependencyDto) -> Self {
+            Self {
+                from: dto.from,
+                to: dto.to,
+                dep_type: dto.dep_type.into(),
+            }
+        }
+    }
+
+    /// System architecture map; per-category counts are keyed in sorted order
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct SystemMapDto {
+        #[serde(rename = "total_files")]
+        pub total_files: usize,
+        #[serde(rename = "files_by_category", default)]
+        pub files_by_category: BTreeMap<CategoryDto, usize>,
+        #[serde(rename = "lines_by_category", default)]
+        pub lines_by_category: BTreeMap<CategoryDto, usize>,
+        #[serde(rename = "dependencies", default)]
+        pub dependencies: Vec<ServiceDependencyDto>,
+        #[serde(rename = "mermaid_diagram", default)]
+        pub mermaid_diagram: Option<String>,
+    }
+
+    impl From<&SystemMap> for SystemMapDto {
+        fn from(map: &SystemMap) -> Self {
+            Self {
+                total_files: map.total_files,
+                files_by_category: map
+                    .files_by_category
+                    .iter()
+                    .map(|(k, v)| ((*k).into(), *v))
+                    .collect(),
+                lines_by_category: map
+                    .lines_by_category
+                    .iter()
+                    .map(|(k, v)| ((*k).into(), *v))
+                    .collect(),
+                dependencies: map.dependencies.iter().map(Into::into).collect(),
+                mermaid_diagram: map.mermaid_diagram.clone(),
+            }
+        }
+    }
+
+    impl From<SystemMapDto> for SystemMap {
+        fn from(dto: SystemMapDto) -> Self {
+            Self {
+                total_files: dto.total_files,
+                files_by_category: dto
+                    .files_by_category
+                    .into_iter()
+                    .map(|(k, v)| (k.into(), v))
+                    .collect(),
+                lines_by_category: dto
+                    .lines_by_category
+                    .into_iter()
+                    .map(|(k, v)| (k.into(), v))
+                    .collect(),
+                dependencies: dto.dependencies.into_iter().map(Into::into).collect(),
+                mermaid_diagram: dto.mermaid_diagram,
+            }
+        }
+    }
+
+    /// Audit totals
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+    pub struct AuditSummaryDto {
+        #[serde(rename = "total_files")]
+        pub total_files: usize,
+        #[serde(rename = "total_lines")]
+        pub total_lines: usize,
+        #[serde(rename = "total_issues")]
+        pub total_issues: usize,
+        #[serde(rename = "total_tasks")]
+        pub total_tasks: usize,
+        #[serde(rename = "critical_files")]
+        pub critical_files: usize,
+        #[serde(rename = "avg_security_rating", default)]
+        pub avg_security_rating: Option<f64>,
+        #[serde(rename = "total_tests", default)]
+        pub total_tests: Option<usize>,
+        #[serde(rename = "test_pass_rate", default)]
+        pub test_pass_rate: Option<f64>,
+        #[serde(rename = "code_coverage", default)]
+        pub code_coverage: Option<f64>,
+        #[serde(
+            rename = "cache_hits",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub cache_hits: Option<usize>,
+        #[serde(
+            rename = "cache_hit_rate",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub cache_hit_rate: Option<f64>,
+    }
+
+    impl From<&AuditSummary> for AuditSummaryDto {
+        fn from(summary: &AuditSummary) -> Self {
+            Self {
+                total_files: summary.total_files,
+                total_lines: summary.total_lines,
+                total_issues: summary.total_issues,
+                total_tasks: summary.total_tasks,
+                critical_files: summary.critical_files,
+                avg_security_rating: summary.avg_security_rating,
+                total_tests: summary.total_tests,
+                test_pass_rate: summary.test_pass_rate,
+                code_coverage: summary.code_coverage,
+                cache_hits: summary.cache_hits,
+                cache_hit_rate: summary.cache_hit_rate,
+            }
+        }
+    }
+
+    impl From<AuditSummaryDto> for AuditSummary {
+        fn from(dto: AuditSummaryDto) -> Self {
+            Self {
+                total_files: dto.total_files,
+                total_lines: dto.total_lines,
+                total_issues: dto.total_issues,
+                total_tasks: dto.total_tasks,
+                critical_files: dto.critical_files,
+                avg_security_rating: dto.avg_security_rating,
+                total_tests: dto.total_tests,
+                test_pass_rate: dto.test_pass_rate,
+                code_coverage: dto.code_coverage,
+                cache_hits: dto.cache_hits,
+                cache_hit_rate: dto.cache_hit_rate,
+            }
+        }
+    }
+
+    /// Audit report
+    ///
+    /// Raw test results and the deep-analysis context bundle are internal and
+    /// not part of v1; their totals are in [`AuditSummaryDto`].
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct AuditReportDto {
+        #[serde(rename = "schema_version")]
+        pub schema_version: u32,
+        #[serde(rename = "id")]
+        pub id: String,
+        #[serde(rename = "repository")]
+        pub repository: String,
+        #[serde(rename = "branch")]
+        pub branch: String,
+        #[serde(rename = "created_at")]
+        pub created_at: DateTime<Utc>,
+        #[serde(rename = "system_map")]
+        pub system_map: SystemMapDto,
+        #[serde(rename = "files", default)]
+        pub files: Vec<FileAnalysisDto>,
+        #[serde(rename = "tasks", default)]
+        pub tasks: Vec<TaskDto>,
+        #[serde(rename = "issues_by_severity", default)]
+        pub issues_by_severity: BTreeMap<IssueSeverityDto, usize>,
+        #[serde(rename = "summary")]
+        pub summary: AuditSummaryDto,
+    }
+
+    impl From<&AuditReport> for AuditReportDto {
+        fn from(report: &AuditReport) -> Self {
+            Self {
+                schema_version: 1,
+                id: report.id.clone(),
+                repository: report.repository.clone(),
+                branch: report.branch.clone(),
+                created_at: report.created_at,
+                system_map: (&report.system_map).into(),
+                files: report.files.iter().map(Into::into).collect(),
+                tasks: report.tasks.iter().map(Into::into).collect(),
+                issues_by_severity: severity_counts(&report.issues_by_severity),
+                summary: (&report.summary).into(),
+            }
+        }
+    }
+
+    impl From<AuditReportDto> for AuditReport {
+        fn from(dto: AuditReportDto) -> Self {
+            Self {
+                id: dto.id,
+                repository: dto.repository,
+                branch: dto.branch,
+                created_at: dto.created_at,
+                system_map: dto.system_map.into(),
+                files: dto.files.into_iter().map(Into::into).collect(),
+                tasks: dto.tasks.into_iter().map(Into::into).collect(),
+                issues_by_severity: dto
+                    .issues_by_severity
+                    .into_iter()
+                    .map(|(k, v)| (k.into(), v))
+                    .collect(),
+                summary: dto.summary.into(),
+                test_results: None,
+                context_bundle: None,
+            }
+        }
+    }
+
+    /// Issue counts keyed by DTO severity, in severity order
+    pub fn severity_counts<'a>(
+        counts: impl IntoIterator<Item = (&'a IssueSeverity, &'a usize)>,
+    ) -> BTreeMap<IssueSeverityDto, usize> {
+        counts.into_iter().map(|(k, v)| ((*k).into(), *v)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::v1::*;
+    use crate::types::*;
+    use std::collections::HashMap;
+
+    fn sample_report() -> AuditReport {
+        let issue = Issue {
+            severity: IssueSeverity::High,
+            category: IssueCategory::TypeSafety,
+            file: "src/lib.rs".into(),
+            line: 7,
+            message: "unchecked cast".into(),
+            suggestion: Some("use try_from".into()),
+        };
+        let tag = AuditTag {
+            tag_type: AuditTagType::Security,
+            file: "src/lib.rs".into(),
+            line: 2,
+            value: "validate input".into(),
+            context: None,
+        };
+        let task = Task::new(
+            "Fix cast",
+            "unchecked cast",
+            "src/lib.rs".into(),
+            Some(7),
+            TaskPriority::High,
+            Category::Audit,
+        )
+        .with_tag("security");
+        AuditReport {
+            id: "report-1".into(),
+            repository: "repo".into(),
+            branch: "main".into(),
+            created_at: chrono::Utc::now(),
+            system_map: SystemMap {
+                total_files: 1,
+                files_by_category: HashMap::from([(Category::Audit, 1)]),
+                lines_by_category: HashMap::from([(Category::Audit, 40)]),
+                dependencies: vec![ServiceDependency {
+                    from: "audit".into(),
+                    to: "db".into(),
+                    dep_type: DependencyType::Internal,
+                }],
+                mermaid_diagram: None,
+            },
+            files: vec![FileAnalysis {
+                path: "src/lib.rs".into(),
+                category: Category::Audit,
+                priority: FilePriority::High,
+                lines: 40,
+                doc_blocks: 3,
+                security_rating: Some(SecurityRating::B),
+                issues: vec![issue],
+                llm_analysis: None,
+                tags: vec![tag],
+            }],
+            tasks: vec![task],
+            issues_by_severity: HashMap::from([(IssueSeverity::High, 1)]),
+            summary: AuditSummary {
+                total_files: 1,
+                total_issues: 1,
+                ..AuditSummary::default()
+            },
+            test_results: None,
+            context_bundle: None,
+        }
+    }
+
+    #[test]
+    fn test_report_round_trips_through_dto() {
+        let dto = AuditReportDto::from(&sample_report());
+        let json = serde_json::to_string(&dto).unwrap();
+        let parsed: AuditReportDto = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, dto);
+
+        let report = AuditReport::from(parsed);
+        assert_eq!(AuditReportDto::from(&report), dto);
+    }
+
+    #[test]
+    fn test_dto_json_is_pinned() {
+        // Serialized with field names and variants spelled out by the DTO, not
+        // derived from the internal types: renaming `Task::created_at` or
+        // `IssueCategory::TypeSafety` leaves this JSON unchanged
+        let report = sample_report();
+        let json = serde_json::to_value(AuditReportDto::from(&report)).unwrap();
+
+        assert_eq!(json["schema_version"], 1);
+        assert_eq!(json["files"][0]["issues"][0]["category"], "type-safety");
+        assert_eq!(json["files"][0]["security_rating"], "B");
+        assert_eq!(json["files"][0]["tags"][0]["tag_type"], "security");
+        assert_eq!(json["system_map"]["files_by_category"]["audit"], 1);
+        assert_eq!(
+            json["system_map"]["dependencies"][0]["dep_type"],
+            "internal"
+        );
+        assert_eq!(json["issues_by_severity"]["high"], 1);
+        assert_eq!(json["tasks"][0]["priority"], "high");
+        assert_eq!(json["tasks"][0]["tags"][0], "security");
+        assert!(json["tasks"][0]["created_at"].is_string());
+        assert!(json.get("test_results").is_none());
+
+        let keys: Vec<&str> = json["tasks"][0]
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        assert_eq!(
+            keys,
+            [
+                "category",
+                "created_at",
+                "description",
+                "file",
+                "id",
+                "line",
+                "priority",
+                "tags",
+                "title"
+            ]
+        );
+    }
+}
@@ -3,6 +3,12 @@
 //! This module provides embedding generation for the RAG system using fastembed.
 //! It handles model initialization, caching, and batch embedding generation.
 //!
+//! Pipelines take an [`Embedder`] so the backend can be swapped: the local
+//! fastembed (ONNX) [`EmbeddingGenerator`], or [`HttpEmbedder`] for any
+//! OpenAI-compatible `/embeddings` endpoint. [`BatchedEmbedder`] adds
+//! batching and sub-batch retries to any backend; it is the only layer that
+//! does, so wrap a backend in it once.
+//!
 //! # Features
 //!
 //! - **Multiple models**: Support for various embedding models
@@ -30,9 +36,25 @@
 use anyhow::{Context, Result};
 use fastembed::{EmbeddingModel, InitOptions, TextEmbedding};
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 
+// ============================================================================
+// Embedder Trait
+// ============================================================================
+
+/// A text embedding backend
+#[async_trait::async_trait]
+pub trait Embedder: Send + Sync {
+    /// Embed `texts`, returning one vector per text in the same order
+    async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>>;
+
+    /// Identifier of the model producing the vectors (e.g. "bge-small-en-v1.5")
+    fn model_id(&self) -> &str;
+}
+
 // ============================================================================
 // Configuration
 // ============================================================================
@@ -306,6 +328,232 @@ impl EmbeddingGenerator {
     }
 }
 
+#[async_trait::async_trait]
+impl Embedder for EmbeddingGenerator {
+    async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        let texts: Vec<&str> = texts.iter().map(String::as_str).collect();
+        let embeddings = self.embed_batch(&texts).await?;
+        Ok(embeddings.into_iter().map(|e| e.vector).collect())
+    }
+
+    fn model_id(&self) -> &str {
+        self.model_name()
+    }
+}
+
+// ============================================================================
+// Batching
+// ============================================================================
+
+/// Default number of texts per embedding request
+pub const DEFAULT_EMBED_BATCH_SIZE: usize = 64;
+
+/// Default retries for a failed sub-batch
+pub const DEFAULT_EMBED_MAX_RETRIES: usize = 2;
+
+/// Split `texts` into batches of `batch_size` and embed each with `call`.
+/// A batch that fails (or returns the wrong number of vectors) is retried
+/// on its own up to `max_retries` times; batches that succeeded are not
+/// resent.
+async fn embed_batched<'a, F, Fut>(
+    texts: &'a [String],
+    batch_size: usize,
+    max_retries: usize,
+    retry_delay: Duration,
+    mut call: F,
+) -> Result<Vec<Vec<f32>>>
+where
+    F: FnMut(&'a [String]) -> Fut,
+    Fut: Future<Output = Result<Vec<Vec<f32>>>>,
+{
+    let mut vectors = Vec::with_capacity(texts.len());
+    for (idx, batch) in texts.chunks(batch_size.max(1)).enumerate() {
+        let mut attempt = 0;
+        loop {
+            let outcome = call(batch).await.and_then(|v| {
+                anyhow::ensure!(
+                    v.len() == batch.len(),
+                    "expected {} vectors, got {}",
+                    batch.len(),
+                    v.len()
+                );
+                Ok(v)
+            });
+            match outcome {
+                Ok(batch_vectors) => {
+                    vectors.extend(batch_vectors);
+                    break;
+                }
+                Err(e) if attempt < max_retries => {
+                    attempt += 1;
+                    tracing::warn!(
+                        "Embedding batch {} failed (attempt {}/{}): {}",
+                        idx,
+                        attempt,
+                        max_retries,
+                        e
+                    );
+                    tokio::time::sleep(retry_delay * attempt as u32).await;
+                }
+                Err(e) => {
+                    return Err(e.context(format!(
+                        "Embedding batch {} failed after {} retries",
+                        idx, max_retries
+                    )))
+                }
+            }
+        }
+    }
+    Ok(vectors)
+}
+
+/// Wraps any [`Embedder`], sending at most `batch_size` texts per call and
+/// retrying a failed sub-batch without resending the others
+pub struct BatchedEmbedder {
+    inner: Arc<dyn Embedder>,
+    batch_size: usize,
+    max_retries: usize,
+    retry_delay: Duration,
+}
+
+impl BatchedEmbedder {
+    pub fn new(inner: Arc<dyn Embedder>) -> Self {
+        Self {
+            inner,
+            batch_size: DEFAULT_EMBED_BATCH_SIZE,
+            max_retries: DEFAULT_EMBED_MAX_RETRIES,
+            retry_delay: Duration::from_millis(500),
+        }
+    }
+
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Base delay before a retry; the n-th retry waits n times this
+    pub fn with_retry_delay(mut self, delay: Duration) -> Self {
+        self.retry_delay = delay;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl Embedder for BatchedEmbedder {
+    async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        embed_batched(
+            texts,
+            self.batch_size,
+            self.max_retries,
+            self.retry_delay,
+            |batch| self.inner.embed(batch),
+        )
+        .await
+    }
+
+    fn model_id(&self) -> &str {
+        self.inner.model_id()
+    }
+}
+
+// ============================================================================
+// HTTP Embedder
+// ============================================================================
+
+/// Embedder for an OpenAI-compatible `POST {base_url}/embeddings` endpoint
+/// (OpenAI, llama.cpp, Ollama, vLLM, ...)
+///
+/// Each `embed` call is a single request; wrap it in a [`BatchedEmbedder`]
+/// to split large inputs and retry failed batches.
+pub struct HttpEmbedder {
+    client: reqwest::Client,
+    base_url: String,
+    model: String,
+    api_key: Option<String>,
+}
+
+#[derive(Serialize)]
+struct EmbeddingsRequest<'a> {
+    model: &'a str,
+    input: &'a [String],
+}
+
+#[derive(Deserialize)]
+struct EmbeddingsResponse {
+    data: Vec<EmbeddingsItem>,
+}
+
+#[derive(Deserialize)]
+struct EmbeddingsItem {
+    embedding: Vec<f32>,
+    #[serde(default)]
+    index: usize,
+}
+
+impl HttpEmbedder {
+    pub fn new(base_url: impl Into<String>, model: impl Into<String>) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(60))
+            .build()
+            .context("Failed to create HTTP client")?;
+        Ok(Self {
+            client,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            model: model.into(),
+            api_key: None,
+        })
+    }
+
+    /// Send `Authorization: Bearer <key>`; local servers usually need none
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl Embedder for HttpEmbedder {
+    /// One `/embeddings` request for all of `texts`
+    async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut request = self
+            .client
+            .post(format!("{}/embeddings", self.base_url))
+            .json(&EmbeddingsRequest {
+                model: &self.model,
+                input: texts,
+            });
+        if let Some(key) = &self.api_key {
+            request = request.bearer_auth(key);
+        }
+
+        let response = request.send().await.context("Embedding request failed")?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            anyhow::bail!("Embedding API error {}: {}", status, body);
+        }
+
+        let mut body: EmbeddingsResponse = response
+            .json()
+            .await
+            .context("Failed to parse embedding response")?;
+        body.data.sort_by_key(|item| item.index);
+        Ok(body.data.into_iter().map(|item| item.embedding).collect())
+    }
+
+    fn model_id(&self) -> &str {
+        &self.model
+    }
+}
+
 // ============================================================================
 // Statistics
 // ============================================================================
@@ -360,6 +608,73 @@ impl EmbeddingStats {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Mutex;
+
+    /// Deterministic vectors; fails the first call whose batch contains
+    /// "flaky"
+    #[derive(Default)]
+    struct MockEmbedder {
+        calls: Mutex<Vec<Vec<String>>>,
+    }
+
+    fn mock_vector(text: &str) -> Vec<f32> {
+        vec![
+            text.len() as f32,
+            text.bytes().map(u32::from).sum::<u32>() as f32,
+        ]
+    }
+
+    #[async_trait::async_trait]
+    impl Embedder for MockEmbedder {
+        async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+            let mut calls = self.calls.lock().unwrap();
+            let first_try = !calls.iter().any(|c| c.as_slice() == texts);
+            calls.push(texts.to_vec());
+            if first_try && texts.iter().any(|t| t == "flaky") {
+                anyhow::bail!("503 Service Unavailable");
+            }
+            Ok(texts.iter().map(|t| mock_vector(t)).collect())
+        }
+
+        fn model_id(&self) -> &str {
+            "mock-embed"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batched_embedder_retries_failed_sub_batch() {
+        let mock = Arc::new(MockEmbedder::default());
+        let embedder = BatchedEmbedder::new(mock.clone())
+            .with_batch_size(2)
+            .with_retry_delay(Duration::ZERO);
+        let texts: Vec<String> = ["alpha", "beta", "flaky", "gamma", "delta"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+
+        let vectors = embedder.embed(&texts).await.unwrap();
+        let expected: Vec<Vec<f32>> = texts.iter().map(|t| mock_vector(t)).collect();
+        assert_eq!(vectors, expected);
+        assert_eq!(embedder.model_id(), "mock-embed");
+
+        // Only the failed sub-batch was sent twice
+        let calls = mock.calls.lock().unwrap().clone();
+        assert_eq!(
+            calls,
+            vec![
+                vec!["alpha".to_string(), "beta".to_string()],
+                vec!["flaky".to_string(), "gamma".to_string()],
+                vec!["flaky".to_string(), "gamma".to_string()],
+                vec!["delta".to_string()],
+            ]
+        );
+
+        // Out of retries: the error surfaces
+        let strict = BatchedEmbedder::new(Arc::new(MockEmbedder::default()))
+            .with_max_retries(0)
+            .with_retry_delay(Duration::ZERO);
+        assert!(strict.embed(&texts).await.is_err());
+    }
 
     #[test]
     fn test_embedding_serialization() {
@@ -1,20 +1,81 @@
 //! Enhanced scanner with test running and deep context analysis
 
+// Static analysis still runs through the deprecated compat scanner for one
+// release so reports stay identical
+#![allow(deprecated)]
+
 use crate::context::{ContextBuilder, GlobalContextBundle};
 use crate::error::Result;
+use crate::language::FileLanguage;
 use crate::llm::{FileAuditResult, LlmClient};
+use crate::repo_cache_sql::RepoCacheSql;
+use crate::scanner::compat::StaticResultCache;
 use crate::scanner::Scanner;
-use crate::tests_runner::{TestResults, TestRunner};
+use crate::static_analysis::StaticAnalyzer;
+use crate::tests_runner::{RustTestRunner, TestResults, TestRunner};
 use crate::types::{AuditReport, AuditRequest, AuditSummary, Task, TaskPriority};
-use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use tracing::{info, warn};
 
+/// Bump when static detection changes so cached results are recomputed
+const STATIC_CACHE_SCHEMA_VERSION: i32 = 1;
+
+/// Configuration for [`EnhancedScanner::from_config`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnhancedScannerConfig {
+    /// Root directory to scan
+    pub root: PathBuf,
+    /// Maximum file size to scan (bytes)
+    pub max_file_size: usize,
+    /// Whether to include test files
+    pub include_tests: bool,
+    /// Whether to run the project's tests
+    pub run_tests: bool,
+    /// Whether to use deep analysis (needs an LLM client)
+    pub deep_analysis: bool,
+}
+
+/// Line counts for one language, from [`EnhancedScanner::language_stats`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LanguageLoc {
+    pub language: FileLanguage,
+    pub files: usize,
+    pub total_lines: usize,
+    pub code_lines: usize,
+    pub comment_lines: usize,
+    pub blank_lines: usize,
+}
+
+/// Per-language line counts for a repository
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LanguageStats {
+    /// Most code lines first; unrecognised extensions under
+    /// [`FileLanguage::Unknown`]
+    pub languages: Vec<LanguageLoc>,
+}
+
+impl LanguageStats {
+    /// Counts for `language`, if any of its files were scanned
+    pub fn get(&self, language: FileLanguage) -> Option<&LanguageLoc> {
+        self.languages.iter().find(|loc| loc.language == language)
+    }
+
+    pub fn total_files(&self) -> usize {
+        self.languages.iter().map(|loc| loc.files).sum()
+    }
+
+    pub fn total_code_lines(&self) -> usize {
+        self.languages.iter().map(|loc| loc.code_lines).sum()
+    }
+}
+
 /// Enhanced scanner with test running and 2M context window analysis
 pub struct EnhancedScanner {
     /// Base scanner
     scanner: Scanner,
     /// Root directory
-    #[allow(dead_code)]
     root: PathBuf,
     /// Test runner
     test_runner: TestRunner,
@@ -26,6 +87,8 @@ pub struct EnhancedScanner {
     run_tests: bool,
     /// Whether to use deep analysis
     use_deep_analysis: bool,
+    /// Per-file static result cache (optional)
+    cache: Option<RepoCacheSql>,
 }
 
 impl EnhancedScanner {
@@ -52,28 +115,160 @@ impl EnhancedScanner {
             llm_client,
             run_tests: include_tests,
             use_deep_analysis,
+            cache: None,
         })
     }
 
+    /// Create an enhanced scanner from a config
+    pub fn from_config(
+        config: EnhancedScannerConfig,
+        llm_client: Option<LlmClient>,
+    ) -> Result<Self> {
+        Ok(Self::new(
+            config.root,
+            config.max_file_size,
+            config.include_tests,
+            llm_client,
+        )?
+        .with_run_tests(config.run_tests)
+        .with_deep_analysis(config.deep_analysis))
+    }
+
     /// Set whether to run tests
     pub fn with_run_tests(mut self, run: bool) -> Self {
         self.run_tests = run;
         self
     }
 
+    /// Run Rust tests with `runner` (e.g. nextest)
+    pub fn with_rust_test_runner(mut self, runner: RustTestRunner) -> Self {
+        self.test_runner = TestRunner::new(self.root.clone()).with_rust_runner(runner);
+        self
+    }
+
     /// Set whether to use deep analysis
     pub fn with_deep_analysis(mut self, use_deep: bool) -> Self {
         self.use_deep_analysis = use_deep;
         self
     }
 
+    /// Reuse static results for unchanged files across scans
+    pub fn with_cache(mut self, cache: RepoCacheSql) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Static analysis only — the report the deprecated
+    /// [`scanner::compat::Scanner`](crate::scanner::compat::Scanner) produced
+    pub fn scan(&self, request: &AuditRequest) -> Result<AuditReport> {
+        self.scanner.scan(request)
+    }
+
+    /// [`scan`](Self::scan), re-analyzing only files whose content hash
+    /// changed since the cached result. Without a cache this is `scan`.
+    pub async fn scan_cached(&self, request: &AuditRequest) -> Result<AuditReport> {
+        let Some(cache) = &self.cache else {
+            return self.scan(request);
+        };
+        let repo_path = self.root.to_string_lossy();
+
+        let previous: StaticResultCache = match cache
+            .static_results(&repo_path, STATIC_CACHE_SCHEMA_VERSION)
+            .await
+        {
+            Ok(rows) => rows
+                .into_iter()
+                .filter_map(|(path, (hash, value))| {
+                    Some((path, (hash, serde_json::from_value(value).ok()?)))
+                })
+                .collect(),
+            Err(e) => {
+                warn!("Static result cache unavailable: {}", e);
+                StaticResultCache::new()
+            }
+        };
+
+        let scan = self.scanner.scan_cached(request, &previous)?;
+
+        let fresh: Vec<_> = scan
+            .fresh
+            .into_iter()
+            .filter_map(|(path, hash, analysis)| {
+                Some((path, hash, serde_json::to_value(analysis).ok()?))
+            })
+            .collect();
+        if !fresh.is_empty() {
+            if let Err(e) = cache
+                .store_static_results(&repo_path, STATIC_CACHE_SCHEMA_VERSION, &fresh)
+                .await
+            {
+                warn!("Failed to store static scan results: {}", e);
+            }
+        }
+
+        let mut report = scan.report;
+        let total = report.summary.total_files;
+        report.summary.cache_hits = Some(scan.hits);
+        report.summary.cache_hit_rate = Some(if total > 0 {
+            scan.hits as f64 / total as f64
+        } else {
+            0.0
+        });
+        info!(
+            "Static scan: {}/{} files from cache, {} re-scanned",
+            scan.hits,
+            total,
+            fresh.len()
+        );
+
+        Ok(report)
+    }
+
+    /// Lines of code per language under `repo_path`, skipping the files
+    /// [`scan`](Self::scan) skips (gitignored, oversized, non-UTF-8, tests
+    /// unless included)
+    pub fn language_stats(&self, repo_path: &Path) -> Result<LanguageStats> {
+        let analyzer = StaticAnalyzer::new();
+        let mut by_language: HashMap<FileLanguage, LanguageLoc> = HashMap::new();
+
+        self.scanner
+            .for_each_source_file(repo_path, |path, content| {
+                let path = path.to_string_lossy();
+                let language = FileLanguage::from_extension(&path);
+                let metrics = analyzer.content_metrics(&path, &content);
+
+                let loc = by_language.entry(language).or_insert(LanguageLoc {
+                    language,
+                    files: 0,
+                    total_lines: 0,
+                    code_lines: 0,
+                    comment_lines: 0,
+                    blank_lines: 0,
+                });
+                loc.files += 1;
+                loc.total_lines += metrics.code_lines + metrics.comment_lines + metrics.blank_lines;
+                loc.code_lines += metrics.code_lines;
+                loc.comment_lines += metrics.comment_lines;
+                loc.blank_lines += metrics.blank_lines;
+                Ok(())
+            })?;
+
+        let mut languages: Vec<LanguageLoc> = by_language.into_values().collect();
+        languages.sort_by(|a, b| {
+            b.code_lines
+                .cmp(&a.code_lines)
+                .then_with(|| a.language.to_string().cmp(&b.language.to_string()))
+        });
+        Ok(LanguageStats { languages })
+    }
+
     /// Run complete audit with all features
     pub async fn run_complete_audit(&self, request: &AuditRequest) -> Result<AuditReport> {
         info!("Starting enhanced audit with test running and deep analysis");
 
         // Step 1: Run base scanner for static analysis
         info!("Step 1: Running static analysis...");
-        let mut report = self.scanner.scan(request)?;
+        let mut report = self.scan_cached(request).await?;
 
         // Step 2: Run tests if enabled
         let mut test_results = None;
@@ -355,4 +550,65 @@ mod tests {
         assert!(scanner.run_tests);
         assert!(!scanner.use_deep_analysis);
     }
+
+    #[test]
+    fn test_language_stats_over_mixed_fixture() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::create_dir_all(root.join("tests")).unwrap();
+        std::fs::write(
+            root.join("src/lib.rs"),
+            "//! Crate docs\n\npub fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n",
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("src/main.rs"),
+            "fn main() {\n    // greet\n    println!(\"hi\");\n}\n",
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("tool.py"),
+            "# helper\nimport sys\n\nprint(sys.argv)\n",
+        )
+        .unwrap();
+        std::fs::write(root.join("NOTES.txt"), "first\n\nsecond\n").unwrap();
+        // Skipped: a test file, and one over the size limit
+        std::fs::write(root.join("tests/api.rs"), "fn t() {}\n").unwrap();
+        std::fs::write(root.join("src/big.rs"), "// x\n".repeat(1_000)).unwrap();
+
+        let scanner = EnhancedScanner::new(root.to_path_buf(), 1_000, false, None).unwrap();
+        let stats = scanner.language_stats(root).unwrap();
+
+        let rust = stats.get(FileLanguage::Rust).unwrap();
+        assert_eq!(rust.files, 2);
+        assert_eq!(rust.code_lines, 6);
+        assert_eq!(rust.comment_lines, 2);
+        assert_eq!(rust.blank_lines, 1);
+        assert_eq!(rust.total_lines, 9);
+
+        let python = stats.get(FileLanguage::Python).unwrap();
+        assert_eq!(
+            (
+                python.files,
+                python.code_lines,
+                python.comment_lines,
+                python.blank_lines
+            ),
+            (1, 2, 1, 1)
+        );
+
+        // Unknown extensions are counted, not dropped
+        let unknown = stats.get(FileLanguage::Unknown).unwrap();
+        assert_eq!(
+            (unknown.files, unknown.code_lines, unknown.blank_lines),
+            (1, 2, 1)
+        );
+
+        assert_eq!(stats.total_files(), 4);
+        assert_eq!(stats.languages[0].language, FileLanguage::Rust);
+
+        let json = serde_json::to_value(&stats).unwrap();
+        assert_eq!(json["languages"][0]["language"], "Rust");
+    }
 }
@@ -2,6 +2,7 @@
 
 use std::io;
 use std::path::PathBuf;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Result type alias for audit operations
@@ -62,9 +63,21 @@ pub enum AuditError {
     #[error("Task generation failed: {0}")]
     TaskGeneration(String),
 
-    /// Rate limit exceeded
-    #[error("Rate limit exceeded for LLM API")]
-    RateLimitExceeded,
+    /// The LLM API is throttling requests; safe to retry after `retry_after`
+    #[error("LLM API rate limit hit; {}", retry_hint(.retry_after))]
+    RateLimited { retry_after: Option<Duration> },
+
+    /// The LLM API rejected the credentials; retrying will not help
+    #[error("LLM API rejected the credentials; check that the provider API key (e.g. XAI_API_KEY) is set and valid")]
+    AuthFailed,
+
+    /// An LLM response could not be parsed
+    #[error("Could not parse LLM response ({context}); the model may have returned truncated or malformed output")]
+    ResponseParse { context: String },
+
+    /// The LLM spending limit has been reached
+    #[error("LLM budget exceeded; raise the budget or wait for the next budget period")]
+    BudgetExceeded,
 
     /// Invalid API key
     #[error("Invalid or missing API key for {service}")]
@@ -74,6 +87,10 @@ pub enum AuditError {
     #[error("Operation timed out: {0}")]
     Timeout(String),
 
+    /// An LLM response does not match its JSON Schema
+    #[error("Response does not match schema at {path}: {message}")]
+    SchemaViolation { path: String, message: String },
+
     /// Generic error with context
     #[error("{context}: {source}")]
     WithContext {
@@ -109,6 +126,80 @@ impl AuditError {
     pub fn other(msg: impl Into<String>) -> Self {
         AuditError::Other(msg.into())
     }
+
+    /// Create a response parse error
+    pub fn response_parse(context: impl Into<String>) -> Self {
+        AuditError::ResponseParse {
+            context: context.into(),
+        }
+    }
+
+    /// Classify a non-success LLM API response from `provider`
+    pub fn llm_status(
+        provider: &str,
+        status: u16,
+        retry_after: Option<Duration>,
+        body: &str,
+    ) -> Self {
+        match status {
+            401 | 403 => AuditError::AuthFailed,
+            429 => AuditError::RateLimited { retry_after },
+            _ => AuditError::LlmApi(format!("{} API error {}: {}", provider, status, body)),
+        }
+    }
+
+    /// Consume a non-success LLM API response into a classified error
+    pub async fn from_llm_response(provider: &str, response: reqwest::Response) -> Self {
+        let status = response.status().as_u16();
+        let retry_after = retry_after(response.headers());
+        let body = response.text().await.unwrap_or_default();
+        Self::llm_status(provider, status, retry_after, &body)
+    }
+
+    /// Worth retrying the same request later
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            AuditError::RateLimited { .. } | AuditError::Timeout(_) => true,
+            AuditError::Http(e) => e.is_timeout() || e.is_connect(),
+            AuditError::WithContext { source, .. } => source.is_retryable(),
+            _ => false,
+        }
+    }
+
+    /// Further LLM calls will fail the same way until someone intervenes
+    pub fn is_terminal(&self) -> bool {
+        match self {
+            AuditError::AuthFailed
+            | AuditError::InvalidApiKey { .. }
+            | AuditError::BudgetExceeded => true,
+            AuditError::WithContext { source, .. } => source.is_terminal(),
+            _ => false,
+        }
+    }
+
+    /// The first `AuditError` in an `anyhow` error chain
+    pub fn find(err: &anyhow::Error) -> Option<&AuditError> {
+        err.chain().find_map(|e| e.downcast_ref::<AuditError>())
+    }
+}
+
+/// Delay from a `Retry-After` header given in seconds
+pub fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+fn retry_hint(retry_after: &Option<Duration>) -> String {
+    match retry_after {
+        Some(delay) => format!("retry after {}s", delay.as_secs()),
+        None => "retry later or lower the request rate".to_string(),
+    }
 }
 
 /// Extension trait for adding context to Results
@@ -122,3 +213,39 @@ impl<T> ResultExt<T> for Result<T> {
         self.map_err(|e| e.context(context))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_llm_status_classification() {
+        let limited = AuditError::llm_status("xai", 429, Some(Duration::from_secs(7)), "");
+        assert!(matches!(
+            limited,
+            AuditError::RateLimited { retry_after: Some(d) } if d.as_secs() == 7
+        ));
+        assert!(limited.is_retryable() && !limited.is_terminal());
+        assert!(limited.to_string().contains("retry after 7s"));
+
+        for status in [401, 403] {
+            let auth = AuditError::llm_status("xai", status, None, "bad key");
+            assert!(matches!(auth, AuditError::AuthFailed));
+            assert!(auth.is_terminal() && !auth.is_retryable());
+        }
+
+        let server = AuditError::llm_status("xai", 500, None, "oops");
+        assert!(matches!(&server, AuditError::LlmApi(msg) if msg.contains("oops")));
+        assert!(AuditError::BudgetExceeded.context("scan").is_terminal());
+    }
+
+    #[test]
+    fn test_find_through_anyhow_context() {
+        let err = anyhow::Error::new(AuditError::AuthFailed).context("Failed to ask Grok");
+        assert!(matches!(
+            AuditError::find(&err),
+            Some(AuditError::AuthFailed)
+        ));
+        assert!(AuditError::find(&anyhow::anyhow!("plain")).is_none());
+    }
+}
@@ -0,0 +1,249 @@
+//! Findings diff between two scans of a repository
+//!
+//! A current list of issues says nothing about progress. [`FindingsDiff`]
+//! compares the findings of two scan runs and splits them into **resolved**
+//! (only in the earlier run), **new** (only in the later run) and
+//! **persisting** (in both).
+//!
+//! Findings are matched by a fingerprint of file + category + normalized
+//! message, so rewording noise the LLM commonly produces — case, punctuation,
+//! shifted line numbers — doesn't turn one finding into a resolved/new pair.
+
+use crate::llm_audit::{FileLlmAnalysis, FullAuditResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Which list of a [`FileLlmAnalysis`] a finding came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingCategory {
+    Security,
+    Improvement,
+}
+
+impl std::fmt::Display for FindingCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FindingCategory::Security => write!(f, "security"),
+            FindingCategory::Improvement => write!(f, "improvement"),
+        }
+    }
+}
+
+/// A single issue reported for a file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Finding {
+    /// Stable identity across scans; see [`finding_fingerprint`]
+    pub fingerprint: String,
+    pub file: PathBuf,
+    pub category: FindingCategory,
+    /// Message as the LLM reported it
+    pub message: String,
+}
+
+impl Finding {
+    pub fn new(file: impl Into<PathBuf>, category: FindingCategory, message: &str) -> Self {
+        let file = file.into();
+        Self {
+            fingerprint: finding_fingerprint(&file, category, message),
+            file,
+            category,
+            message: message.trim().to_string(),
+        }
+    }
+}
+
+/// Resolved, new and persisting findings between two scans
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FindingsDiff {
+    /// In the earlier scan only
+    pub resolved: Vec<Finding>,
+    /// In the later scan only
+    pub new: Vec<Finding>,
+    /// In both scans (as worded in the later one)
+    pub persisting: Vec<Finding>,
+}
+
+impl FindingsDiff {
+    /// Compare two finding sets; duplicates within a set count once
+    pub fn between(before: &[Finding], after: &[Finding]) -> Self {
+        let before_by_fp: HashMap<&str, &Finding> =
+            before.iter().map(|f| (f.fingerprint.as_str(), f)).collect();
+        let after_by_fp: HashMap<&str, &Finding> =
+            after.iter().map(|f| (f.fingerprint.as_str(), f)).collect();
+
+        let mut diff = Self::default();
+        for (fp, finding) in &after_by_fp {
+            if before_by_fp.contains_key(fp) {
+                diff.persisting.push((*finding).clone());
+            } else {
+                diff.new.push((*finding).clone());
+            }
+        }
+        for (fp, finding) in &before_by_fp {
+            if !after_by_fp.contains_key(fp) {
+                diff.resolved.push((*finding).clone());
+            }
+        }
+
+        for list in [&mut diff.resolved, &mut diff.new, &mut diff.persisting] {
+            list.sort_by(|a, b| {
+                (&a.file, a.category, &a.message).cmp(&(&b.file, b.category, &b.message))
+            });
+        }
+        diff
+    }
+
+    /// Compare the per-file analyses of two full audits
+    pub fn between_audits(before: &FullAuditResult, after: &FullAuditResult) -> Self {
+        let findings = |audit: &FullAuditResult| {
+            findings_from_analyses(
+                audit
+                    .file_analyses
+                    .iter()
+                    .map(|a| (a.path.as_path(), &a.llm_analysis)),
+            )
+        };
+        Self::between(&findings(before), &findings(after))
+    }
+
+    /// Whether nothing changed between the scans
+    pub fn is_unchanged(&self) -> bool {
+        self.resolved.is_empty() && self.new.is_empty()
+    }
+}
+
+/// Flatten per-file analyses into findings: security observations and
+/// improvement suggestions
+pub fn findings_from_analyses<'a>(
+    analyses: impl IntoIterator<Item = (&'a Path, &'a FileLlmAnalysis)>,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    for (path, analysis) in analyses {
+        let lists = [
+            (FindingCategory::Security, &analysis.security_observations),
+            (
+                FindingCategory::Improvement,
+                &analysis.improvement_suggestions,
+            ),
+        ];
+        for (category, messages) in lists {
+            findings.extend(
+                messages
+                    .iter()
+                    .filter(|m| !m.trim().is_empty())
+                    .map(|m| Finding::new(path, category, m)),
+            );
+        }
+    }
+    findings
+}
+
+/// SHA-256 (first 16 hex chars) of file, category and normalized message
+pub fn finding_fingerprint(file: &Path, category: FindingCategory, message: &str) -> String {
+    use sha2::{Digest, Sha256};
+
+    let file = file.to_string_lossy().replace('\\', "/");
+    let mut hasher = Sha256::new();
+    hasher.update(file.as_bytes());
+    hasher.update([0]);
+    hasher.update(category.to_string().as_bytes());
+    hasher.update([0]);
+    hasher.update(normalize_message(message).as_bytes());
+    format!("{:x}", hasher.finalize())[..16].to_string()
+}
+
+/// Lowercase, digit runs collapsed to `#` (line numbers move), punctuation
+/// dropped and whitespace collapsed
+fn normalize_message(message: &str) -> String {
+    let mut out = String::with_capacity(message.len());
+    let mut last_was_digit = false;
+    for c in message.chars() {
+        if c.is_ascii_digit() {
+            if !last_was_digit {
+                out.push('#');
+            }
+            last_was_digit = true;
+            continue;
+        }
+        last_was_digit = false;
+        if c.is_alphanumeric() || c == '_' {
+            out.extend(c.to_lowercase());
+        } else if c.is_whitespace() || c.is_ascii_punctuation() {
+            out.push(' ');
+        }
+    }
+    out.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analysis(security: &[&str], improvements: &[&str]) -> FileLlmAnalysis {
+        FileLlmAnalysis {
+            purpose: String::new(),
+            importance: "Medium".to_string(),
+            key_functionality: vec![],
+            dependencies: vec![],
+            security_observations: security.iter().map(|s| s.to_string()).collect(),
+            quality_assessment: String::new(),
+            improvement_suggestions: improvements.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_diff_classifies_resolved_new_and_persisting() {
+        let auth = Path::new("src/auth.rs");
+        let db = Path::new("src/db.rs");
+
+        let before_auth = analysis(
+            &["SQL built with format! on line 42"],
+            &["Replace unwrap() with proper error handling"],
+        );
+        let before_db = analysis(&[], &["Connection pool size is hard-coded"]);
+        let before = findings_from_analyses([(auth, &before_auth), (db, &before_db)]);
+
+        // The SQL issue was fixed, the unwrap note only changed case and
+        // punctuation, and a new issue appeared
+        let after_auth = analysis(&[], &["replace unwrap()  with proper error handling."]);
+        let after_db = analysis(
+            &["Password logged at debug level on line 17"],
+            &["Connection pool size is hard-coded"],
+        );
+        let after = findings_from_analyses([(auth, &after_auth), (db, &after_db)]);
+
+        let diff = FindingsDiff::between(&before, &after);
+
+        assert_eq!(diff.resolved.len(), 1);
+        assert_eq!(diff.resolved[0].file, auth);
+        assert_eq!(diff.resolved[0].category, FindingCategory::Security);
+        assert!(diff.resolved[0].message.contains("SQL"));
+
+        assert_eq!(diff.new.len(), 1);
+        assert_eq!(diff.new[0].file, db);
+        assert!(diff.new[0].message.contains("Password"));
+
+        assert_eq!(diff.persisting.len(), 2);
+        assert!(!diff.is_unchanged());
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_line_numbers_but_not_file_or_category() {
+        let path = Path::new("src/lib.rs");
+        let a = finding_fingerprint(path, FindingCategory::Security, "Panic on line 10");
+        let b = finding_fingerprint(path, FindingCategory::Security, "panic on line 312!");
+        assert_eq!(a, b);
+
+        let other_file = finding_fingerprint(
+            Path::new("src/main.rs"),
+            FindingCategory::Security,
+            "Panic on line 10",
+        );
+        let other_category =
+            finding_fingerprint(path, FindingCategory::Improvement, "Panic on line 10");
+        assert_ne!(a, other_file);
+        assert_ne!(a, other_category);
+    }
+}
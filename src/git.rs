@@ -2,16 +2,194 @@
 
 use crate::error::{AuditError, Result};
 use git2::Repository;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
-use tracing::info;
+use std::process::Command;
+use std::sync::Mutex;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Options controlling how a repository is cloned
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CloneOptions {
+    /// History depth (`git clone --depth`); `None` clones full history
+    pub depth: Option<u32>,
+    /// Path globs to materialize via sparse-checkout; empty checks out everything
+    pub sparse_paths: Vec<String>,
+    /// Branch or tag to check out instead of the remote default
+    pub branch: Option<String>,
+}
+
+impl CloneOptions {
+    /// Full clone of the default branch
+    pub fn full() -> Self {
+        Self::default()
+    }
+
+    /// Clone only the last `depth` commits
+    pub fn shallow(depth: u32) -> Self {
+        Self {
+            depth: Some(depth),
+            ..Self::default()
+        }
+    }
+
+    /// Only check out paths matching these globs
+    pub fn with_sparse_paths<I, S>(mut self, paths: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.sparse_paths = paths.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Check out a specific branch or tag
+    pub fn with_branch(mut self, branch: impl Into<String>) -> Self {
+        self.branch = Some(branch.into());
+        self
+    }
+
+    /// Whether sparse-checkout is requested
+    pub fn is_sparse(&self) -> bool {
+        !self.sparse_paths.is_empty()
+    }
+}
+
+/// stderr fragments (lowercased) of git failures worth retrying
+const TRANSIENT_GIT_ERRORS: &[&str] = &["could not resolve host", "timed out", "connection reset"];
+
+/// stderr fragments (lowercased) of auth failures, never retried
+const AUTH_GIT_ERRORS: &[&str] = &[
+    "authentication failed",
+    "could not read username",
+    "permission denied",
+    "access denied",
+];
+
+/// How network-bound git operations (clone, pull, fetch) are retried
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first (minimum 1)
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub base_delay: Duration,
+    /// Factor applied to the delay after each retry
+    pub multiplier: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_secs(1),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Run each operation exactly once
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Delay before retry number `retry` (1-based)
+    pub fn delay_for(&self, retry: u32) -> Duration {
+        let factor = self
+            .multiplier
+            .max(1.0)
+            .powi(retry.saturating_sub(1) as i32);
+        self.base_delay.mul_f64(factor)
+    }
+
+    /// Whether a git error message describes a transient network failure
+    pub fn is_transient(message: &str) -> bool {
+        let message = message.to_lowercase();
+        !AUTH_GIT_ERRORS.iter().any(|m| message.contains(m))
+            && TRANSIENT_GIT_ERRORS.iter().any(|m| message.contains(m))
+    }
+
+    /// Run `op`, retrying transient failures with exponential backoff
+    ///
+    /// Returns the first success, the first non-transient error, or the
+    /// last error once `max_attempts` is exhausted. Sleeps the calling
+    /// thread between attempts; async callers use [`RetryPolicy::run_async`].
+    pub fn run<T, E, F>(&self, mut op: F) -> std::result::Result<T, E>
+    where
+        E: std::fmt::Display,
+        F: FnMut() -> std::result::Result<T, E>,
+    {
+        let mut attempt = 1;
+        loop {
+            match op() {
+                Ok(value) => return Ok(value),
+                Err(e) => match self.retry_after(attempt, &e) {
+                    Some(delay) => {
+                        std::thread::sleep(delay);
+                        attempt += 1;
+                    }
+                    None => return Err(e),
+                },
+            }
+        }
+    }
+
+    /// [`RetryPolicy::run`] for async callers: waits with `tokio::time::sleep`
+    /// instead of blocking the runtime thread
+    pub async fn run_async<T, E, F, Fut>(&self, mut op: F) -> std::result::Result<T, E>
+    where
+        E: std::fmt::Display,
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = std::result::Result<T, E>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match op().await {
+                Ok(value) => return Ok(value),
+                Err(e) => match self.retry_after(attempt, &e) {
+                    Some(delay) => {
+                        tokio::time::sleep(delay).await;
+                        attempt += 1;
+                    }
+                    None => return Err(e),
+                },
+            }
+        }
+    }
+
+    /// Delay before retrying after `error` on attempt `attempt`, or `None`
+    /// if it should be returned
+    fn retry_after(&self, attempt: u32, error: &impl std::fmt::Display) -> Option<Duration> {
+        let max_attempts = self.max_attempts.max(1);
+        if attempt >= max_attempts || !Self::is_transient(&error.to_string()) {
+            return None;
+        }
+        let delay = self.delay_for(attempt);
+        warn!(
+            "Transient git failure (attempt {}/{}), retrying in {:?}: {}",
+            attempt, max_attempts, delay, error
+        );
+        Some(delay)
+    }
+}
+
+/// Blame cache key: (repository path, file path, HEAD commit)
+type BlameKey = (PathBuf, String, String);
 
 /// Git repository manager
 pub struct GitManager {
     /// Workspace directory where repos are cloned
     workspace_dir: PathBuf,
     /// Whether to do shallow clones
-    #[allow(dead_code)]
     shallow_clone: bool,
+    /// Blamed lines per (repo, file, HEAD) for this session, keyed by line number
+    blame_cache: Mutex<HashMap<BlameKey, BTreeMap<usize, BlameLine>>>,
+    /// Retry behavior for clone and fetch
+    retry_policy: RetryPolicy,
 }
 
 impl GitManager {
@@ -23,11 +201,58 @@ impl GitManager {
         Ok(Self {
             workspace_dir,
             shallow_clone,
+            blame_cache: Mutex::new(HashMap::new()),
+            retry_policy: RetryPolicy::default(),
         })
     }
 
-    /// Clone a repository
+    /// Set how clone and fetch retry transient network failures
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Clone a repository (shallow when the manager was created with `shallow_clone`)
     pub fn clone_repo(&self, url: &str, name: Option<&str>) -> Result<PathBuf> {
+        self.clone_with_options(url, name, &self.default_clone_options())
+    }
+
+    /// [`GitManager::clone_repo`] for async callers; retries wait without
+    /// blocking the runtime thread
+    pub async fn clone_repo_async(&self, url: &str, name: Option<&str>) -> Result<PathBuf> {
+        let options = self.default_clone_options();
+        let target_path = self.clone_target(url, name)?;
+        self.retry_policy
+            .run_async(|| async { Self::clone_into(url, &target_path, &options) })
+            .await?;
+
+        Ok(target_path)
+    }
+
+    fn default_clone_options(&self) -> CloneOptions {
+        if self.shallow_clone {
+            CloneOptions::shallow(1)
+        } else {
+            CloneOptions::full()
+        }
+    }
+
+    /// Clone a repository into the workspace with explicit options
+    pub fn clone_with_options(
+        &self,
+        url: &str,
+        name: Option<&str>,
+        options: &CloneOptions,
+    ) -> Result<PathBuf> {
+        let target_path = self.clone_target(url, name)?;
+        self.retry_policy
+            .run(|| Self::clone_into(url, &target_path, options))?;
+
+        Ok(target_path)
+    }
+
+    /// Workspace directory a clone of `url` goes to, emptied first
+    fn clone_target(&self, url: &str, name: Option<&str>) -> Result<PathBuf> {
         let repo_name = name.unwrap_or_else(|| {
             url.split('/')
                 .next_back()
@@ -44,13 +269,289 @@ impl GitManager {
         }
 
         info!("Cloning repository {} to {}", url, target_path.display());
+        Ok(target_path)
+    }
+
+    /// Clone `url` into `target` honoring depth, branch and sparse-checkout
+    ///
+    /// Uses the git CLI: libgit2 has no sparse-checkout support.
+    pub fn clone_into(url: &str, target: &Path, options: &CloneOptions) -> Result<()> {
+        // The URL may carry credentials, so it is kept out of logs and errors
+        info!(
+            "Cloning into {} (depth: {:?}, sparse paths: {})",
+            target.display(),
+            options.depth,
+            options.sparse_paths.len()
+        );
+
+        reject_option_like("repository URL", url)?;
+        if let Some(ref branch) = options.branch {
+            reject_option_like("branch", branch)?;
+        }
+        for path in &options.sparse_paths {
+            reject_option_like("sparse path", path)?;
+        }
 
-        // For now, use simple clone (can be enhanced with shallow clone options)
-        Repository::clone(url, &target_path).map_err(|e| {
-            AuditError::other(format!("Failed to clone repository from {}: {}", url, e))
+        let mut args: Vec<String> = vec!["clone".into()];
+        if let Some(depth) = options.depth {
+            args.push(format!("--depth={}", depth));
+        }
+        if let Some(ref branch) = options.branch {
+            args.push("--branch".into());
+            args.push(branch.clone());
+        }
+        if options.is_sparse() {
+            args.push("--sparse".into());
+        }
+        args.push("--".into());
+        args.push(url.to_string());
+        args.push(target.to_string_lossy().to_string());
+
+        run_git(None, &args).map_err(|e| {
+            AuditError::other(format!(
+                "Failed to clone repository into {}: {}",
+                target.display(),
+                e
+            ))
         })?;
 
-        Ok(target_path)
+        if options.is_sparse() {
+            let mut sparse_args: Vec<String> =
+                vec!["sparse-checkout".into(), "set".into(), "--no-cone".into()];
+            sparse_args.extend(options.sparse_paths.iter().cloned());
+            run_git(Some(target), &sparse_args)?;
+        }
+
+        Ok(())
+    }
+
+    /// List files that differ between two refs (branches, tags or commits)
+    ///
+    /// Works on the object database only, so neither ref needs to be checked out.
+    pub fn diff_refs(&self, repo_path: &Path, base: &str, head: &str) -> Result<Vec<ChangedFile>> {
+        let repo = self.open(repo_path)?;
+
+        let tree_for = |rev: &str| -> Result<git2::Tree<'_>> {
+            repo.revparse_single(rev)
+                .and_then(|obj| obj.peel_to_tree())
+                .map_err(|e| AuditError::other(format!("Failed to resolve ref {}: {}", rev, e)))
+        };
+        let base_tree = tree_for(base)?;
+        let head_tree = tree_for(head)?;
+
+        let mut diff = repo
+            .diff_tree_to_tree(Some(&base_tree), Some(&head_tree), None)
+            .map_err(|e| AuditError::other(format!("Failed to create diff: {}", e)))?;
+        diff.find_similar(None)
+            .map_err(|e| AuditError::other(format!("Failed to detect renames: {}", e)))?;
+
+        let files = diff
+            .deltas()
+            .filter_map(|delta| {
+                let is_gitlink = delta.new_file().mode() == git2::FileMode::Commit
+                    || delta.old_file().mode() == git2::FileMode::Commit;
+                let kind = if is_gitlink {
+                    ChangeKind::Submodule
+                } else {
+                    ChangeKind::from(delta.status())
+                };
+                let file = if kind == ChangeKind::Deleted {
+                    delta.old_file()
+                } else {
+                    delta.new_file()
+                };
+                let path = file.path()?.to_string_lossy().to_string();
+                let old_path = match kind {
+                    ChangeKind::Renamed => delta
+                        .old_file()
+                        .path()
+                        .map(|p| p.to_string_lossy().to_string()),
+                    _ => None,
+                };
+                Some(ChangedFile {
+                    path,
+                    old_path,
+                    kind,
+                })
+            })
+            .collect();
+
+        Ok(files)
+    }
+
+    /// Check out `rev` into a detached worktree at `target`, leaving the
+    /// repository's own working tree untouched
+    pub fn add_worktree(&self, repo_path: &Path, rev: &str, target: &Path) -> Result<()> {
+        reject_option_like("revision", rev)?;
+        if target.exists() {
+            self.remove_worktree(repo_path, target)?;
+        }
+        let target = target.to_string_lossy();
+        run_git(
+            Some(repo_path),
+            &["worktree", "add", "--detach", target.as_ref(), rev],
+        )?;
+        Ok(())
+    }
+
+    /// Remove a worktree created by [`GitManager::add_worktree`]
+    pub fn remove_worktree(&self, repo_path: &Path, target: &Path) -> Result<()> {
+        let target = target.to_string_lossy();
+        run_git(
+            Some(repo_path),
+            &["worktree", "remove", "--force", target.as_ref()],
+        )?;
+        Ok(())
+    }
+
+    /// List the submodules registered in a repository
+    pub fn submodules(&self, repo_path: &Path) -> Result<Vec<SubmoduleInfo>> {
+        let repo = self.open(repo_path)?;
+        let submodules = repo
+            .submodules()
+            .map_err(|e| AuditError::other(format!("Failed to list submodules: {}", e)))?;
+
+        Ok(submodules
+            .iter()
+            .map(|sm| {
+                let path = sm.path().to_string_lossy().to_string();
+                SubmoduleInfo {
+                    name: sm.name().unwrap_or(&path).to_string(),
+                    initialized: repo_path.join(&path).join(".git").exists(),
+                    url: sm.url().map(|u| u.to_string()),
+                    head: sm.head_id().map(|id| id.to_string()),
+                    path,
+                }
+            })
+            .collect())
+    }
+
+    /// Blame lines `start_line..=end_line` (1-based) of `path` at HEAD
+    ///
+    /// Runs a single `git blame --porcelain` for the range. Results are cached
+    /// per (path, HEAD) for the lifetime of the manager, so repeated lookups
+    /// into the same file are free until HEAD moves.
+    pub fn blame_range(
+        &self,
+        repo_path: &Path,
+        path: &str,
+        start_line: usize,
+        end_line: usize,
+    ) -> Result<Vec<BlameLine>> {
+        if start_line == 0 || end_line < start_line {
+            return Err(AuditError::other(format!(
+                "Invalid blame range {}..{}",
+                start_line, end_line
+            )));
+        }
+
+        let head = run_git(Some(repo_path), &["rev-parse", "HEAD"])?
+            .trim()
+            .to_string();
+        let key = (repo_path.to_path_buf(), path.to_string(), head.clone());
+
+        {
+            let cache = self.blame_cache.lock().unwrap();
+            if let Some(lines) = cache.get(&key) {
+                let cached: Vec<BlameLine> = lines
+                    .range(start_line..=end_line)
+                    .map(|(_, line)| line.clone())
+                    .collect();
+                if cached.len() == end_line - start_line + 1 {
+                    return Ok(cached);
+                }
+            }
+        }
+
+        let range = format!("{},{}", start_line, end_line);
+        let output = run_git(
+            Some(repo_path),
+            &["blame", "--porcelain", "-L", &range, &head, "--", path],
+        )?;
+        let lines = parse_blame_porcelain(&output);
+
+        let mut cache = self.blame_cache.lock().unwrap();
+        let entry = cache.entry(key).or_default();
+        for line in &lines {
+            entry.insert(line.line, line.clone());
+        }
+
+        Ok(lines)
+    }
+
+    /// The last `limit` commits that touched `file_path`, newest first
+    ///
+    /// Uses `git log --follow`, so history continues across renames. A file
+    /// git has never tracked has no history rather than an error.
+    pub fn file_history(
+        &self,
+        repo_path: &Path,
+        file_path: &str,
+        limit: usize,
+    ) -> Result<Vec<FileCommit>> {
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let count = limit.to_string();
+        let output = run_git(
+            Some(repo_path),
+            &[
+                "log",
+                "--follow",
+                "-n",
+                &count,
+                "--name-only",
+                "--format=%x1e%H%x1f%an%x1f%at%x1f%s",
+                "--",
+                file_path,
+            ],
+        )?;
+        Ok(parse_file_log(&output))
+    }
+
+    /// Whether the repository is a shallow clone
+    pub fn is_shallow(&self, repo_path: &Path) -> bool {
+        Repository::open(repo_path)
+            .map(|repo| repo.is_shallow())
+            .unwrap_or(false)
+    }
+
+    /// Make sure `rev` is present locally, unshallowing the clone if needed
+    ///
+    /// Returns `true` if history had to be fetched.
+    pub fn ensure_revision(&self, repo_path: &Path, rev: &str) -> Result<bool> {
+        reject_option_like("revision", rev)?;
+        if revision_exists(repo_path, rev) {
+            return Ok(false);
+        }
+        if !self.is_shallow(repo_path) {
+            return Err(AuditError::other(format!(
+                "Revision {} not found in {}",
+                rev,
+                repo_path.display()
+            )));
+        }
+
+        info!(
+            "Revision {} missing from shallow clone {}, unshallowing",
+            rev,
+            repo_path.display()
+        );
+        run_git(
+            Some(repo_path),
+            &["fetch", "--unshallow", "--tags", "origin"],
+        )?;
+
+        if revision_exists(repo_path, rev) {
+            Ok(true)
+        } else {
+            Err(AuditError::other(format!(
+                "Revision {} not found in {} even after unshallowing",
+                rev,
+                repo_path.display()
+            )))
+        }
     }
 
     /// Open an existing repository
@@ -224,15 +725,269 @@ impl GitManager {
             .find_remote("origin")
             .map_err(|e| AuditError::other(format!("Failed to find remote 'origin': {}", e)))?;
 
-        remote
-            .fetch(&["main", "master"], None, None)
-            .map_err(|e| AuditError::other(format!("Failed to fetch from origin: {}", e)))?;
+        self.retry_policy.run(|| {
+            remote
+                .fetch(&["main", "master"], None, None)
+                .map_err(|e| AuditError::other(format!("Failed to fetch from origin: {}", e)))
+        })?;
 
         info!("Repository updated successfully");
         Ok(())
     }
 }
 
+/// Blame lines `start_line..=end_line` (1-based) of the working copy of `path`
+///
+/// Unlike [`GitManager::blame_range`] this blames the file as it is on disk,
+/// so line numbers match what a scanner just read. Lines that are not
+/// committed yet carry the all-zero commit. Fails for untracked files.
+pub(crate) fn blame_working_copy(
+    repo_path: &Path,
+    path: &Path,
+    start_line: usize,
+    end_line: usize,
+) -> Result<Vec<BlameLine>> {
+    let range = format!("{},{}", start_line, end_line);
+    let output = run_git(
+        Some(repo_path),
+        &[
+            "blame".as_ref(),
+            "--porcelain".as_ref(),
+            "-L".as_ref(),
+            range.as_ref(),
+            "--".as_ref(),
+            path.as_os_str(),
+        ],
+    )?;
+    Ok(parse_blame_porcelain(&output))
+}
+
+/// Parse `git log --name-only` output with records starting `\x1e` and
+/// fields `hash\x1fauthor\x1ftime\x1fsubject`
+fn parse_file_log(output: &str) -> Vec<FileCommit> {
+    output
+        .split('\x1e')
+        .filter_map(|record| {
+            let mut lines = record.lines();
+            let mut fields = lines.next()?.splitn(4, '\x1f');
+            let hash = fields.next()?.to_string();
+            if hash.is_empty() {
+                return None;
+            }
+            let author = fields.next().unwrap_or_default().to_string();
+            let timestamp = fields.next().and_then(|t| t.parse().ok()).unwrap_or(0);
+            let subject = fields.next().unwrap_or_default().to_string();
+            let path = lines
+                .map(str::trim)
+                .find(|line| !line.is_empty())
+                .unwrap_or_default()
+                .to_string();
+            Some(FileCommit {
+                hash,
+                author,
+                timestamp,
+                subject,
+                path,
+            })
+        })
+        .collect()
+}
+
+/// Parse `git blame --porcelain` output into one entry per line
+fn parse_blame_porcelain(output: &str) -> Vec<BlameLine> {
+    #[derive(Default, Clone)]
+    struct CommitMeta {
+        author: String,
+        author_email: String,
+        author_time: i64,
+        summary: String,
+    }
+
+    let mut commits: HashMap<String, CommitMeta> = HashMap::new();
+    let mut lines = Vec::new();
+    let mut current: Option<(String, usize)> = None;
+
+    for raw in output.lines() {
+        if let Some(content) = raw.strip_prefix('\t') {
+            if let Some((commit, line)) = current.take() {
+                let meta = commits.get(&commit).cloned().unwrap_or_default();
+                lines.push(BlameLine {
+                    line,
+                    commit,
+                    author: meta.author,
+                    author_email: meta.author_email,
+                    author_time: meta.author_time,
+                    summary: meta.summary,
+                    content: content.to_string(),
+                });
+            }
+            continue;
+        }
+
+        let mut parts = raw.splitn(2, ' ');
+        let field = parts.next().unwrap_or_default();
+        let value = parts.next().unwrap_or_default();
+
+        // Header: "<40-hex sha> <orig line> <final line> [<group size>]"
+        if field.len() == 40 && field.chars().all(|c| c.is_ascii_hexdigit()) {
+            let final_line = value
+                .split(' ')
+                .nth(1)
+                .and_then(|n| n.parse().ok())
+                .unwrap_or(0);
+            commits.entry(field.to_string()).or_default();
+            current = Some((field.to_string(), final_line));
+            continue;
+        }
+
+        if let Some((ref commit, _)) = current {
+            let meta = commits.entry(commit.clone()).or_default();
+            match field {
+                "author" => meta.author = value.to_string(),
+                "author-mail" => {
+                    meta.author_email = value.trim_matches(|c| c == '<' || c == '>').to_string()
+                }
+                "author-time" => meta.author_time = value.parse().unwrap_or(0),
+                "summary" => meta.summary = value.to_string(),
+                _ => {}
+            }
+        }
+    }
+
+    lines
+}
+
+/// Run a git command, returning stdout on success
+fn run_git<S: AsRef<std::ffi::OsStr>>(dir: Option<&Path>, args: &[S]) -> Result<String> {
+    let mut cmd = Command::new("git");
+    if let Some(dir) = dir {
+        cmd.arg("-C").arg(dir);
+    }
+    cmd.args(args).env("GIT_TERMINAL_PROMPT", "0");
+
+    let output = cmd
+        .output()
+        .map_err(|e| AuditError::other(format!("Failed to run git: {}", e)))?;
+
+    if !output.status.success() {
+        return Err(AuditError::other(format!(
+            "git {} failed: {}",
+            args.first()
+                .map(|a| a.as_ref().to_string_lossy().to_string())
+                .unwrap_or_default(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Reject a value git would parse as an option rather than an operand,
+/// e.g. a URL of `--upload-pack=<cmd>`
+fn reject_option_like(what: &str, value: &str) -> Result<()> {
+    if value.starts_with('-') {
+        return Err(AuditError::config(format!(
+            "Invalid {} {:?}: must not start with '-'",
+            what, value
+        )));
+    }
+    Ok(())
+}
+
+/// Whether a revision resolves to a commit in the repository
+fn revision_exists(repo_path: &Path, rev: &str) -> bool {
+    if rev.starts_with('-') {
+        return false;
+    }
+    run_git(
+        Some(repo_path),
+        &[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("{}^{{commit}}", rev),
+        ],
+    )
+    .is_ok()
+}
+
+/// How a file changed between two refs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Modified,
+    Deleted,
+    Renamed,
+    /// Submodule pointer moved (no file content changed in this repo)
+    Submodule,
+    Other,
+}
+
+impl From<git2::Delta> for ChangeKind {
+    fn from(delta: git2::Delta) -> Self {
+        match delta {
+            git2::Delta::Added | git2::Delta::Copied => Self::Added,
+            git2::Delta::Modified | git2::Delta::Typechange => Self::Modified,
+            git2::Delta::Deleted => Self::Deleted,
+            git2::Delta::Renamed => Self::Renamed,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// A file changed between two refs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangedFile {
+    /// Path relative to the repository root (the old path for deletions)
+    pub path: String,
+    /// Previous path for renames
+    pub old_path: Option<String>,
+    pub kind: ChangeKind,
+}
+
+/// Blame information for a single line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlameLine {
+    /// 1-based line number in the blamed revision
+    pub line: usize,
+    /// Commit that last touched the line
+    pub commit: String,
+    pub author: String,
+    pub author_email: String,
+    /// Author timestamp (seconds since epoch)
+    pub author_time: i64,
+    /// First line of the commit message
+    pub summary: String,
+    /// Line content
+    pub content: String,
+}
+
+/// A commit in a file's history, from [`GitManager::file_history`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileCommit {
+    pub hash: String,
+    pub author: String,
+    /// Author timestamp (seconds since epoch)
+    pub timestamp: i64,
+    /// First line of the commit message
+    pub subject: String,
+    /// The file's path as of this commit (differs before a rename)
+    pub path: String,
+}
+
+/// A submodule registered in a repository
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmoduleInfo {
+    pub name: String,
+    /// Path relative to the parent repository root
+    pub path: String,
+    pub url: Option<String>,
+    /// Commit recorded in the parent's HEAD
+    pub head: Option<String>,
+    /// Whether the submodule is checked out locally
+    pub initialized: bool,
+}
+
 /// Repository statistics
 #[derive(Debug, Clone)]
 pub struct RepoStats {
@@ -269,6 +1024,84 @@ mod tests {
         assert!(temp.path().exists());
     }
 
+    fn instant_retries(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            base_delay: Duration::ZERO,
+            multiplier: 2.0,
+        }
+    }
+
+    #[test]
+    fn test_retry_policy_retries_transient_failures() {
+        let mut attempts = 0;
+        let result: std::result::Result<&str, String> = instant_retries(3).run(|| {
+            attempts += 1;
+            if attempts < 3 {
+                Err("fatal: unable to access: Could not resolve host: github.com".to_string())
+            } else {
+                Ok("cloned")
+            }
+        });
+
+        assert_eq!(result.unwrap(), "cloned");
+        assert_eq!(attempts, 3);
+    }
+
+    #[test]
+    fn test_retry_policy_stops_on_auth_failure_and_exhaustion() {
+        let mut attempts = 0;
+        let result: std::result::Result<(), String> = instant_retries(3).run(|| {
+            attempts += 1;
+            Err("fatal: Authentication failed for 'https://github.com/x/y'".to_string())
+        });
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+
+        let mut attempts = 0;
+        let result: std::result::Result<(), String> = instant_retries(2).run(|| {
+            attempts += 1;
+            Err(format!("Connection reset by peer ({})", attempts))
+        });
+        assert_eq!(result.unwrap_err(), "Connection reset by peer (2)");
+        assert_eq!(attempts, 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_run_async_retries_transient_failures() {
+        let attempts = std::cell::Cell::new(0);
+        let result: std::result::Result<&str, String> = instant_retries(3)
+            .run_async(|| async {
+                attempts.set(attempts.get() + 1);
+                if attempts.get() < 2 {
+                    Err("fatal: unable to access: Operation timed out".to_string())
+                } else {
+                    Ok("fetched")
+                }
+            })
+            .await;
+        assert_eq!(result.unwrap(), "fetched");
+        assert_eq!(attempts.get(), 2);
+
+        attempts.set(0);
+        let result: std::result::Result<(), String> = instant_retries(3)
+            .run_async(|| async {
+                attempts.set(attempts.get() + 1);
+                Err("remote: Permission denied".to_string())
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.get(), 1);
+    }
+
+    #[test]
+    fn test_retry_policy_backoff() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.delay_for(1), Duration::from_secs(1));
+        assert_eq!(policy.delay_for(2), Duration::from_secs(2));
+        assert_eq!(policy.delay_for(3), Duration::from_secs(4));
+    }
+
     #[test]
     fn test_is_repository() {
         let temp = TempDir::new().unwrap();
@@ -283,4 +1116,264 @@ mod tests {
         // Now it is a repo
         assert!(manager.is_repository(temp.path()));
     }
+
+    fn git(dir: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args([
+                "-c",
+                "user.name=Fixture",
+                "-c",
+                "user.email=fixture@example.com",
+            ])
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    /// Build a small repo with three commits touching `src/`, `docs/` and `assets/`,
+    /// returning its `file://` URL (needed for `--depth` on local clones)
+    fn fixture_repo(root: &Path) -> String {
+        let origin = root.join("origin");
+        std::fs::create_dir_all(&origin).unwrap();
+        git(&origin, &["init", "-q", "-b", "main"]);
+
+        for (i, (path, body)) in [
+            ("src/lib.rs", "pub fn a() {}"),
+            ("docs/guide.md", "# Guide"),
+            ("assets/big.bin", "0000"),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let file = origin.join(path);
+            std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+            std::fs::write(&file, body).unwrap();
+            git(&origin, &["add", "."]);
+            git(&origin, &["commit", "-q", "-m", &format!("commit {}", i)]);
+        }
+
+        format!("file://{}", origin.display())
+    }
+
+    #[test]
+    fn test_sparse_checkout_only_materializes_requested_paths() {
+        let temp = TempDir::new().unwrap();
+        let url = fixture_repo(temp.path());
+        let manager = GitManager::new(temp.path().join("workspace"), false).unwrap();
+
+        let options = CloneOptions::shallow(1).with_sparse_paths(["/src/"]);
+        let path = manager
+            .clone_with_options(&url, Some("sparse"), &options)
+            .unwrap();
+
+        assert!(path.join("src/lib.rs").exists());
+        assert!(!path.join("docs").exists());
+        assert!(!path.join("assets").exists());
+        assert!(manager.is_shallow(&path));
+    }
+
+    #[test]
+    fn test_clone_rejects_option_like_arguments() {
+        let temp = TempDir::new().unwrap();
+        let marker = temp.path().join("pwned");
+        let target = temp.path().join("clone");
+
+        let url = format!("--upload-pack=touch {}", marker.display());
+        let err = GitManager::clone_into(&url, &target, &CloneOptions::default()).unwrap_err();
+        assert!(err.to_string().contains("must not start with '-'"));
+
+        let url = fixture_repo(temp.path());
+        let options = CloneOptions::default().with_sparse_paths(["--no-cone"]);
+        assert!(GitManager::clone_into(&url, &target, &options).is_err());
+        assert!(!marker.exists());
+        assert!(!target.exists());
+    }
+
+    #[test]
+    fn test_ensure_revision_unshallows() {
+        let temp = TempDir::new().unwrap();
+        let url = fixture_repo(temp.path());
+        let first_commit = git(
+            &temp.path().join("origin"),
+            &["rev-list", "--max-parents=0", "HEAD"],
+        );
+        let manager = GitManager::new(temp.path().join("workspace"), true).unwrap();
+
+        let path = manager.clone_repo(&url, Some("shallow")).unwrap();
+        assert!(manager.is_shallow(&path));
+        assert!(!manager.ensure_revision(&path, "HEAD").unwrap());
+
+        assert!(manager.ensure_revision(&path, &first_commit).unwrap());
+        assert!(!manager.is_shallow(&path));
+    }
+
+    #[test]
+    fn test_diff_refs_between_tags() {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path().join("repo");
+        std::fs::create_dir_all(repo.join("src")).unwrap();
+        git(&repo, &["init", "-q", "-b", "main"]);
+
+        std::fs::write(repo.join("src/a.rs"), "fn a() {}").unwrap();
+        std::fs::write(repo.join("src/b.rs"), "fn b() {}").unwrap();
+        std::fs::write(repo.join("README.md"), "readme").unwrap();
+        git(&repo, &["add", "."]);
+        git(&repo, &["commit", "-q", "-m", "v1.0"]);
+        git(&repo, &["tag", "v1.0.0"]);
+
+        std::fs::write(repo.join("src/a.rs"), "fn a() { changed() }").unwrap();
+        std::fs::write(repo.join("src/c.rs"), "fn c() {}").unwrap();
+        std::fs::remove_file(repo.join("README.md")).unwrap();
+        git(&repo, &["add", "-A"]);
+        git(&repo, &["commit", "-q", "-m", "v1.1"]);
+        git(&repo, &["tag", "v1.1.0"]);
+
+        // Uncommitted changes must not show up in a ref-to-ref diff
+        std::fs::write(repo.join("src/b.rs"), "fn b() { dirty() }").unwrap();
+
+        let manager = GitManager::new(temp.path().join("workspace"), false).unwrap();
+        let mut changes = manager.diff_refs(&repo, "v1.0.0", "v1.1.0").unwrap();
+        changes.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let summary: Vec<(&str, ChangeKind)> =
+            changes.iter().map(|c| (c.path.as_str(), c.kind)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("README.md", ChangeKind::Deleted),
+                ("src/a.rs", ChangeKind::Modified),
+                ("src/c.rs", ChangeKind::Added),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_blame_range_attributes_lines_to_authors() {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path().join("repo");
+        std::fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "-q", "-b", "main"]);
+
+        let commit_as = |name: &str, email: &str, message: &str| {
+            let output = Command::new("git")
+                .arg("-C")
+                .arg(&repo)
+                .args(["-c", &format!("user.name={}", name)])
+                .args(["-c", &format!("user.email={}", email)])
+                .args(["commit", "-q", "-a", "-m", message])
+                .output()
+                .unwrap();
+            assert!(output.status.success());
+        };
+
+        std::fs::write(repo.join("lib.rs"), "one\ntwo\nthree\n").unwrap();
+        git(&repo, &["add", "."]);
+        commit_as("Alice", "alice@example.com", "initial");
+
+        std::fs::write(repo.join("lib.rs"), "one\nTWO\nthree\nfour\n").unwrap();
+        commit_as("Bob", "bob@example.com", "edit");
+
+        let manager = GitManager::new(temp.path().join("workspace"), false).unwrap();
+        let blame = manager.blame_range(&repo, "lib.rs", 1, 4).unwrap();
+
+        let authors: Vec<(usize, &str)> =
+            blame.iter().map(|b| (b.line, b.author.as_str())).collect();
+        assert_eq!(
+            authors,
+            vec![(1, "Alice"), (2, "Bob"), (3, "Alice"), (4, "Bob")]
+        );
+        assert_eq!(blame[1].content, "TWO");
+        assert_eq!(blame[1].author_email, "bob@example.com");
+        assert_eq!(blame[1].summary, "edit");
+        assert_eq!(blame[0].commit, blame[2].commit);
+
+        // Sub-range served from the session cache
+        let cached = manager.blame_range(&repo, "lib.rs", 2, 3).unwrap();
+        assert_eq!(cached, blame[1..3].to_vec());
+    }
+
+    #[test]
+    fn test_worktree_checks_out_ref_without_touching_repo() {
+        let temp = TempDir::new().unwrap();
+        let url = fixture_repo(temp.path());
+        let origin = PathBuf::from(url.trim_start_matches("file://"));
+        git(&origin, &["tag", "old", "HEAD~2"]);
+
+        let manager = GitManager::new(temp.path().join("workspace"), false).unwrap();
+        let worktree = temp.path().join("wt");
+        manager.add_worktree(&origin, "old", &worktree).unwrap();
+
+        assert!(worktree.join("src/lib.rs").exists());
+        assert!(!worktree.join("docs").exists());
+        assert!(origin.join("docs/guide.md").exists());
+
+        manager.remove_worktree(&origin, &worktree).unwrap();
+        assert!(!worktree.exists());
+
+        // Revisions that git would read as options are refused
+        assert!(manager
+            .add_worktree(&origin, "--orphan=x", &worktree)
+            .is_err());
+        assert!(manager.ensure_revision(&origin, "--all").is_err());
+        assert!(!worktree.exists());
+    }
+
+    #[test]
+    fn test_file_history_follows_rename() {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path().join("repo");
+        std::fs::create_dir_all(repo.join("src")).unwrap();
+        git(&repo, &["init", "-q", "-b", "main"]);
+
+        std::fs::write(
+            repo.join("src/util.rs"),
+            "pub fn one() {}\npub fn two() {}\n",
+        )
+        .unwrap();
+        git(&repo, &["add", "."]);
+        git(&repo, &["commit", "-q", "-m", "Add util"]);
+        std::fs::write(
+            repo.join("src/util.rs"),
+            "pub fn one() {}\npub fn two() {}\npub fn three() {}\n",
+        )
+        .unwrap();
+        git(&repo, &["commit", "-q", "-a", "-m", "Add three"]);
+        git(&repo, &["mv", "src/util.rs", "src/helpers.rs"]);
+        git(&repo, &["commit", "-q", "-m", "Rename util to helpers"]);
+        std::fs::write(repo.join("README.md"), "unrelated\n").unwrap();
+        git(&repo, &["add", "."]);
+        git(&repo, &["commit", "-q", "-m", "Add readme"]);
+
+        let manager = GitManager::new(temp.path().join("workspace"), false).unwrap();
+        let history = manager.file_history(&repo, "src/helpers.rs", 10).unwrap();
+
+        let subjects: Vec<&str> = history.iter().map(|c| c.subject.as_str()).collect();
+        assert_eq!(
+            subjects,
+            vec!["Rename util to helpers", "Add three", "Add util"]
+        );
+        assert_eq!(history[0].path, "src/helpers.rs");
+        assert_eq!(history[2].path, "src/util.rs");
+        assert_eq!(history[0].author, "Fixture");
+        assert_eq!(history[0].hash.len(), 40);
+        assert!(history[0].timestamp > 0);
+
+        let latest = manager.file_history(&repo, "src/helpers.rs", 1).unwrap();
+        assert_eq!(latest, history[..1].to_vec());
+
+        std::fs::write(repo.join("scratch.rs"), "fn main() {}\n").unwrap();
+        assert!(manager
+            .file_history(&repo, "scratch.rs", 10)
+            .unwrap()
+            .is_empty());
+    }
 }
@@ -2,13 +2,31 @@
 //!
 //! This module provides a background job system that periodically syncs
 //! GitHub data to keep the local database up-to-date.
-
-use super::{GitHubClient, SyncEngine, SyncOptions};
+//!
+//! Incremental syncs run per repository on a [`SyncSchedule`]: each repo gets
+//! its own next-run time, staggered across the interval and jittered so they
+//! don't all hit the API on the same tick. Recently pushed repos sync more
+//! often (see [`SyncPriority`]), and all syncs pause while the last seen
+//! rate limit is below [`BackgroundSyncConfig::rate_limit_floor`].
+//!
+//! Above the floor, the quota left until the reset is shared out across
+//! the repos still due in the window: each tick syncs only its proportional
+//! share and spreads the rest up to the reset. A `RateLimitExceeded` error
+//! pauses syncing until its reset time. [`BackgroundSyncManager::status`]
+//! reports the current [`ThrottleStatus`].
+
+use super::{GitHubClient, GitHubError, RateLimitInfo, SyncEngine, SyncOptions};
+use chrono::{DateTime, Utc};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::Serialize;
 use sqlx::PgPool;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tokio::time::interval;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 
 /// Configuration for background sync jobs
 #[derive(Debug, Clone)]
@@ -24,6 +42,22 @@ pub struct BackgroundSyncConfig {
 
     /// Enable automatic sync on startup
     pub sync_on_startup: bool,
+
+    /// Smallest random delay added to each repo's next run (in seconds)
+    pub min_jitter: u64,
+
+    /// Largest random delay added to each repo's next run (in seconds)
+    pub max_jitter: u64,
+
+    /// How often the scheduler checks for due repos (in seconds)
+    pub schedule_tick: u64,
+
+    /// Pause all syncs while fewer API requests than this remain
+    pub rate_limit_floor: i32,
+
+    /// Estimated API requests one incremental repo sync costs, used to
+    /// share the quota above the floor across pending repos
+    pub requests_per_repo_sync: i32,
 }
 
 impl Default for BackgroundSyncConfig {
@@ -33,8 +67,258 @@ impl Default for BackgroundSyncConfig {
             incremental_sync_interval: 3600, // 1 hour
             max_items_per_repo: Some(100),
             sync_on_startup: true,
+            min_jitter: 30,
+            max_jitter: 300,
+            schedule_tick: 30,
+            rate_limit_floor: 100,
+            requests_per_repo_sync: 5,
+        }
+    }
+}
+
+// ============================================================================
+// Per-repo schedule
+// ============================================================================
+
+/// How often a repo syncs relative to `incremental_sync_interval`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SyncPriority {
+    /// Pushed to recently: every quarter interval
+    High,
+    #[default]
+    Normal,
+    /// Archived: every fourth interval
+    Low,
+}
+
+impl SyncPriority {
+    /// Multiplier applied to the base interval
+    pub fn interval_factor(self) -> f64 {
+        match self {
+            SyncPriority::High => 0.25,
+            SyncPriority::Normal => 1.0,
+            SyncPriority::Low => 4.0,
         }
     }
+
+    /// High if pushed within the last week, Low if archived
+    pub fn from_activity(pushed_at: Option<i64>, archived: bool, now: i64) -> Self {
+        const ACTIVE_WINDOW_SECS: i64 = 7 * 24 * 3600;
+        if archived {
+            SyncPriority::Low
+        } else if pushed_at.is_some_and(|p| now - p < ACTIVE_WINDOW_SECS) {
+            SyncPriority::High
+        } else {
+            SyncPriority::Normal
+        }
+    }
+}
+
+/// A repo's place in the [`SyncSchedule`]
+#[derive(Debug, Clone)]
+pub struct ScheduledRepo {
+    pub priority: SyncPriority,
+    pub next_run: DateTime<Utc>,
+}
+
+/// Jittered, staggered next-run times for each repo
+pub struct SyncSchedule {
+    base_interval: Duration,
+    min_jitter: Duration,
+    max_jitter: Duration,
+    rng: StdRng,
+    repos: HashMap<String, ScheduledRepo>,
+}
+
+impl SyncSchedule {
+    pub fn new(config: &BackgroundSyncConfig) -> Self {
+        Self {
+            base_interval: Duration::from_secs(config.incremental_sync_interval),
+            min_jitter: Duration::from_secs(config.min_jitter.min(config.max_jitter)),
+            max_jitter: Duration::from_secs(config.max_jitter.max(config.min_jitter)),
+            rng: StdRng::from_entropy(),
+            repos: HashMap::new(),
+        }
+    }
+
+    /// Deterministic jitter, for tests
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    /// Base interval scaled by `priority`, before jitter
+    pub fn interval_for(&self, priority: SyncPriority) -> chrono::Duration {
+        let secs = self.base_interval.as_secs_f64() * priority.interval_factor();
+        chrono::Duration::milliseconds((secs * 1000.0) as i64)
+    }
+
+    fn jitter(&mut self) -> chrono::Duration {
+        let ms = self
+            .rng
+            .gen_range(self.min_jitter.as_millis()..=self.max_jitter.as_millis());
+        chrono::Duration::milliseconds(ms as i64)
+    }
+
+    /// Replace the set of repos. New repos are spread evenly across one
+    /// interval; known repos keep their next run but pick up the new priority.
+    pub fn set_repos(&mut self, repos: Vec<(String, SyncPriority)>, now: DateTime<Utc>) {
+        let new_count = repos
+            .iter()
+            .filter(|(name, _)| !self.repos.contains_key(name))
+            .count()
+            .max(1) as i32;
+
+        let mut previous = std::mem::take(&mut self.repos);
+        let mut slot = 0;
+        for (name, priority) in repos {
+            let entry = match previous.remove(&name) {
+                Some(mut existing) => {
+                    existing.priority = priority;
+                    existing
+                }
+                None => {
+                    let offset = self.interval_for(priority) * slot / new_count;
+                    slot += 1;
+                    ScheduledRepo {
+                        priority,
+                        next_run: now + offset + self.jitter(),
+                    }
+                }
+            };
+            self.repos.insert(name, entry);
+        }
+    }
+
+    pub fn next_run(&self, name: &str) -> Option<DateTime<Utc>> {
+        self.repos.get(name).map(|r| r.next_run)
+    }
+
+    /// Repos whose next run has passed, most overdue first
+    pub fn due(&self, now: DateTime<Utc>) -> Vec<String> {
+        let mut due: Vec<_> = self
+            .repos
+            .iter()
+            .filter(|(_, r)| r.next_run <= now)
+            .collect();
+        due.sort_by_key(|(_, r)| r.next_run);
+        due.into_iter().map(|(name, _)| name.clone()).collect()
+    }
+
+    /// Schedule the repo's next run one (jittered) interval after `now`
+    pub fn reschedule(&mut self, name: &str, now: DateTime<Utc>) {
+        let Some(priority) = self.repos.get(name).map(|r| r.priority) else {
+            return;
+        };
+        let next_run = now + self.interval_for(priority) + self.jitter();
+        if let Some(repo) = self.repos.get_mut(name) {
+            repo.next_run = next_run;
+        }
+    }
+
+    /// Push every run to at least `until` (plus jitter), e.g. a rate-limit reset
+    pub fn defer_all(&mut self, until: DateTime<Utc>) {
+        let names: Vec<String> = self.repos.keys().cloned().collect();
+        for name in names {
+            let next_run = until + self.jitter();
+            if let Some(repo) = self.repos.get_mut(&name) {
+                repo.next_run = repo.next_run.max(next_run);
+            }
+        }
+    }
+
+    /// Stagger `names` evenly after `from`, the last one at `until`
+    pub fn spread(&mut self, names: &[String], from: DateTime<Utc>, until: DateTime<Utc>) {
+        let count = names.len() as i32;
+        for (i, name) in names.iter().enumerate() {
+            let at = from + (until - from) * (i as i32 + 1) / count;
+            if let Some(repo) = self.repos.get_mut(name) {
+                repo.next_run = repo.next_run.max(at);
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Rate-limit throttle
+// ============================================================================
+
+/// Rate-limit throttle state of the background sync
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ThrottleStatus {
+    /// Requests left in the current window, once a response has been seen
+    pub remaining: Option<i32>,
+    /// When the current window resets
+    pub resets_at: Option<DateTime<Utc>>,
+    /// Requests held back ([`BackgroundSyncConfig::rate_limit_floor`])
+    pub reserve: i32,
+    /// Set while syncs are paused, for the reserve or a rate-limit error
+    pub paused_until: Option<DateTime<Utc>>,
+    /// Repos due before the window resets
+    pub pending_repos: usize,
+    /// Repo syncs the quota above the reserve pays for; `None` if unknown
+    pub repo_budget: Option<usize>,
+}
+
+impl ThrottleStatus {
+    /// Throttle state from the last seen rate limit and any pause forced by
+    /// a `RateLimitExceeded` error
+    pub fn compute(
+        limit: Option<&RateLimitInfo>,
+        forced_pause: Option<DateTime<Utc>>,
+        config: &BackgroundSyncConfig,
+        pending_repos: usize,
+        now: DateTime<Utc>,
+    ) -> Self {
+        // A window that already reset says nothing about the current one
+        let limit = limit.filter(|l| l.reset > now);
+        let mut paused_until = forced_pause.filter(|until| *until > now);
+        let mut repo_budget = None;
+
+        if let Some(limit) = limit {
+            let spare = limit.remaining - config.rate_limit_floor;
+            if spare < 0 {
+                paused_until = paused_until.max(Some(limit.reset));
+            } else {
+                repo_budget = Some((spare / config.requests_per_repo_sync.max(1)) as usize);
+            }
+        }
+        if paused_until.is_some() {
+            repo_budget = Some(0);
+        }
+
+        Self {
+            remaining: limit.map(|l| l.remaining),
+            resets_at: limit.map(|l| l.reset),
+            reserve: config.rate_limit_floor,
+            paused_until,
+            pending_repos,
+            repo_budget,
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_until.is_some()
+    }
+
+    /// How many of `due` repos may sync now: all of them while the budget
+    /// covers every pending repo, otherwise their proportional share
+    pub fn allowed(&self, due: usize) -> usize {
+        match self.repo_budget {
+            None => due,
+            Some(0) => 0,
+            Some(budget) if budget >= self.pending_repos => due,
+            Some(budget) => (due * budget).div_ceil(self.pending_repos.max(1)).min(due),
+        }
+    }
+}
+
+/// Reset time of a `RateLimitExceeded` error
+fn rate_limit_reset(err: &(dyn std::error::Error + 'static)) -> Option<DateTime<Utc>> {
+    match err.downcast_ref::<GitHubError>()? {
+        GitHubError::RateLimitExceeded { reset_at } => Some(*reset_at),
+        _ => None,
+    }
 }
 
 /// Background sync job manager
@@ -42,6 +326,10 @@ pub struct BackgroundSyncManager {
     pool: PgPool,
     client: GitHubClient,
     config: BackgroundSyncConfig,
+    /// Repos due before the current rate-limit window resets
+    pending_repos: AtomicUsize,
+    /// Pause until this time, set by a `RateLimitExceeded` error
+    forced_pause: Mutex<Option<DateTime<Utc>>>,
 }
 
 impl BackgroundSyncManager {
@@ -51,6 +339,8 @@ impl BackgroundSyncManager {
             pool,
             client,
             config,
+            pending_repos: AtomicUsize::new(0),
+            forced_pause: Mutex::new(None),
         }
     }
 
@@ -59,7 +349,13 @@ impl BackgroundSyncManager {
     /// This will spawn a background task that runs indefinitely,
     /// performing periodic syncs based on the configuration.
     pub async fn start(self) -> Result<(), Box<dyn std::error::Error>> {
-        let manager = Arc::new(self);
+        Arc::new(self).start_shared().await
+    }
+
+    /// Like [`start`](Self::start), but leaves the caller a handle for
+    /// [`status`](Self::status)
+    pub async fn start_shared(self: Arc<Self>) -> Result<(), Box<dyn std::error::Error>> {
+        let manager = self;
 
         // Initial sync on startup if enabled
         if manager.config.sync_on_startup {
@@ -97,18 +393,88 @@ impl BackgroundSyncManager {
         Ok(())
     }
 
-    /// Run incremental sync loop
+    /// Current rate-limit throttle state
+    pub async fn status(&self) -> ThrottleStatus {
+        let limit = self.client.get_cached_rate_limit().await;
+        let forced_pause = *self.forced_pause.lock().unwrap();
+        ThrottleStatus::compute(
+            limit.as_ref(),
+            forced_pause,
+            &self.config,
+            self.pending_repos.load(Ordering::Relaxed),
+            Utc::now(),
+        )
+    }
+
+    /// Pause syncing if `err` is a `RateLimitExceeded`; returns its reset time
+    fn pause_on_rate_limit(
+        &self,
+        err: &(dyn std::error::Error + 'static),
+    ) -> Option<DateTime<Utc>> {
+        let reset_at = rate_limit_reset(err)?;
+        let mut forced_pause = self.forced_pause.lock().unwrap();
+        *forced_pause = (*forced_pause).max(Some(reset_at));
+        Some(reset_at)
+    }
+
+    /// Run incremental sync loop, syncing each repo when its schedule is due
     async fn run_incremental_sync_loop(&self) {
-        let mut timer = interval(Duration::from_secs(self.config.incremental_sync_interval));
+        let mut schedule = SyncSchedule::new(&self.config);
+        let mut timer = interval(Duration::from_secs(self.config.schedule_tick.max(1)));
 
         loop {
             timer.tick().await;
 
-            info!("🔄 Running incremental GitHub sync...");
-            if let Err(e) = self.run_incremental_sync().await {
-                error!("Incremental sync failed: {}", e);
-            } else {
-                info!("✅ Incremental sync completed");
+            if let Some(until) = self.rate_limit_backoff().await {
+                warn!("⏸️  GitHub rate limit low, deferring syncs until {}", until);
+                schedule.defer_all(until);
+                continue;
+            }
+
+            match self.load_repo_priorities().await {
+                Ok(repos) => schedule.set_repos(repos, Utc::now()),
+                Err(e) => {
+                    error!("Failed to load repos for sync schedule: {}", e);
+                    continue;
+                }
+            }
+
+            // Share what is left of the window across every repo due in it
+            let now = Utc::now();
+            let window_end = self
+                .client
+                .get_cached_rate_limit()
+                .await
+                .map(|l| l.reset)
+                .filter(|reset| *reset > now);
+            self.pending_repos.store(
+                schedule.due(window_end.unwrap_or(now)).len(),
+                Ordering::Relaxed,
+            );
+            let status = self.status().await;
+            let due = schedule.due(now);
+            let allowed = status.allowed(due.len());
+            if let (true, Some(reset)) = (allowed < due.len(), window_end) {
+                info!(
+                    "⏳ Quota covers {} of {} due repos, spreading the rest until {}",
+                    allowed,
+                    due.len(),
+                    reset
+                );
+                schedule.spread(&due[allowed..], now, reset);
+            }
+
+            for full_name in due.into_iter().take(allowed) {
+                if self.rate_limit_backoff().await.is_some() {
+                    break;
+                }
+                info!("🔄 Running incremental GitHub sync for {}...", full_name);
+                let engine = SyncEngine::new(Box::new(self.client.clone()), self.pool.clone());
+                if let Some(reset_at) = self.sync_repo_or_pause(&engine, &full_name).await {
+                    schedule.defer_all(reset_at);
+                    break;
+                }
+                schedule.reschedule(&full_name, Utc::now());
             }
         }
     }
@@ -120,9 +486,24 @@ impl BackgroundSyncManager {
         loop {
             timer.tick().await;
 
+            if let Some(until) = self.rate_limit_backoff().await {
+                warn!(
+                    "⏸️  GitHub rate limit low, delaying full sync until {}",
+                    until
+                );
+                let wait = (until - Utc::now()).to_std().unwrap_or_default();
+                tokio::time::sleep(wait).await;
+            }
+
             info!("🔄 Running full GitHub sync...");
             if let Err(e) = self.run_full_sync().await {
-                error!("Full sync failed: {}", e);
+                match self.pause_on_rate_limit(&*e) {
+                    Some(reset_at) => warn!(
+                        "⏸️  GitHub rate limit hit during full sync, pausing until {}",
+                        reset_at
+                    ),
+                    None => error!("Full sync failed: {}", e),
+                }
             } else {
                 info!("✅ Full sync completed");
             }
@@ -131,7 +512,7 @@ impl BackgroundSyncManager {
 
     /// Perform an incremental sync
     async fn run_incremental_sync(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let sync_engine = SyncEngine::new(self.client.clone(), self.pool.clone());
+        let sync_engine = SyncEngine::new(Box::new(self.client.clone()), self.pool.clone());
 
         // Sync only recent items
         let result = sync_engine
@@ -147,9 +528,81 @@ impl BackgroundSyncManager {
         Ok(())
     }
 
+    /// Sync a single repository (`owner/name`), pausing all syncs if it hits
+    /// the rate limit; returns the time they resume in that case
+    async fn sync_repo_or_pause(
+        &self,
+        sync_engine: &SyncEngine,
+        full_name: &str,
+    ) -> Option<DateTime<Utc>> {
+        let Err(e) = self.run_repo_sync(sync_engine, full_name).await else {
+            return None;
+        };
+        match self.pause_on_rate_limit(&*e) {
+            Some(reset_at) => {
+                warn!(
+                    "⏸️  GitHub rate limit hit, pausing syncs until {}",
+                    reset_at
+                );
+                Some(reset_at)
+            }
+            None => {
+                error!("Incremental sync of {} failed: {}", full_name, e);
+                None
+            }
+        }
+    }
+
+    /// Sync a single repository (`owner/name`)
+    async fn run_repo_sync(
+        &self,
+        sync_engine: &SyncEngine,
+        full_name: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let result = sync_engine
+            .sync_with_options(SyncOptions::default().with_repos(vec![full_name.to_string()]))
+            .await?;
+        debug!(
+            "  {}: {} issues, {} pull requests",
+            full_name, result.issues_synced, result.prs_synced
+        );
+
+        // Update last sync timestamp
+        self.update_last_sync_time().await?;
+
+        Ok(())
+    }
+
+    /// Sync-enabled repos with a priority derived from recent activity
+    async fn load_repo_priorities(
+        &self,
+    ) -> Result<Vec<(String, SyncPriority)>, Box<dyn std::error::Error>> {
+        let rows: Vec<(String, Option<i64>, i32)> = sqlx::query_as(
+            "SELECT full_name, pushed_at, archived FROM github_repositories
+             WHERE sync_enabled = 1 AND provider = 'github'",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let now = Utc::now().timestamp();
+        Ok(rows
+            .into_iter()
+            .map(|(name, pushed_at, archived)| {
+                let priority = SyncPriority::from_activity(pushed_at, archived != 0, now);
+                (name, priority)
+            })
+            .collect())
+    }
+
+    /// While syncs are paused (rate limit below the floor, or a
+    /// `RateLimitExceeded` error), the time they resume
+    async fn rate_limit_backoff(&self) -> Option<DateTime<Utc>> {
+        self.status().await.paused_until
+    }
+
     /// Perform a full sync
     async fn run_full_sync(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let sync_engine = SyncEngine::new(self.client.clone(), self.pool.clone());
+        let sync_engine = SyncEngine::new(Box::new(self.client.clone()), self.pool.clone());
 
         // Full sync without limits
         let result = sync_engine
@@ -169,8 +622,10 @@ impl BackgroundSyncManager {
     async fn update_last_sync_time(&self) -> Result<(), Box<dyn std::error::Error>> {
         sqlx::query(
             r#"
-            INSERT OR REPLACE INTO github_sync_metadata (key, value, updated_at)
-            VALUES ('last_sync', datetime('now'), datetime('now'))
+            INSERT INTO github_sync_metadata (key, value, updated_at)
+            VALUES ('last_sync', NOW()::TEXT, NOW()::TEXT)
+            ON CONFLICT (key) DO UPDATE
+            SET value = EXCLUDED.value, updated_at = EXCLUDED.updated_at
             "#,
         )
         .execute(&self.pool)
@@ -264,11 +719,281 @@ mod tests {
             incremental_sync_interval: 1800,
             max_items_per_repo: Some(50),
             sync_on_startup: false,
+            min_jitter: 10,
+            max_jitter: 60,
+            schedule_tick: 15,
+            rate_limit_floor: 50,
+            requests_per_repo_sync: 3,
         };
 
         assert_eq!(config.full_sync_interval, 7200);
         assert_eq!(config.incremental_sync_interval, 1800);
         assert_eq!(config.max_items_per_repo, Some(50));
         assert!(!config.sync_on_startup);
+        assert_eq!((config.min_jitter, config.max_jitter), (10, 60));
+    }
+
+    #[test]
+    fn test_jitter_spreads_repos_with_same_interval() {
+        let config = BackgroundSyncConfig::default();
+        let mut schedule = SyncSchedule::new(&config).with_seed(7);
+        let now = Utc::now();
+        schedule.set_repos(
+            vec![
+                ("acme/api".to_string(), SyncPriority::Normal),
+                ("acme/web".to_string(), SyncPriority::Normal),
+            ],
+            now,
+        );
+
+        schedule.reschedule("acme/api", now);
+        schedule.reschedule("acme/web", now);
+        let a = schedule.next_run("acme/api").unwrap();
+        let b = schedule.next_run("acme/web").unwrap();
+        assert_ne!(a, b);
+
+        let base = now + schedule.interval_for(SyncPriority::Normal);
+        for next in [a, b] {
+            assert!(next >= base + chrono::Duration::seconds(config.min_jitter as i64));
+            assert!(next <= base + chrono::Duration::seconds(config.max_jitter as i64));
+        }
+    }
+
+    #[test]
+    fn test_priority_and_backoff() {
+        let config = BackgroundSyncConfig::default();
+        let mut schedule = SyncSchedule::new(&config).with_seed(1);
+        assert!(
+            schedule.interval_for(SyncPriority::High) < schedule.interval_for(SyncPriority::Low)
+        );
+
+        let now = Utc::now();
+        schedule.set_repos(vec![("acme/api".to_string(), SyncPriority::High)], now);
+        let until = now + chrono::Duration::hours(2);
+        schedule.defer_all(until);
+        assert!(schedule.next_run("acme/api").unwrap() >= until);
+        assert!(schedule.due(now + chrono::Duration::hours(1)).is_empty());
+
+        let week_ago = now.timestamp() - 8 * 24 * 3600;
+        assert_eq!(
+            SyncPriority::from_activity(Some(now.timestamp()), false, now.timestamp()),
+            SyncPriority::High
+        );
+        assert_eq!(
+            SyncPriority::from_activity(Some(week_ago), false, now.timestamp()),
+            SyncPriority::Normal
+        );
+        assert_eq!(
+            SyncPriority::from_activity(None, true, now.timestamp()),
+            SyncPriority::Low
+        );
+    }
+
+    /// Serve `/rate_limit` with synthetic rate-limit headers; `remaining`
+    /// can be changed between requests
+    async fn mock_github(
+        remaining: Arc<std::sync::atomic::AtomicI32>,
+        reset: DateTime<Utc>,
+    ) -> GitHubClient {
+        use axum::http::{HeaderMap, StatusCode};
+        use axum::{routing::get, Json, Router};
+
+        let app = Router::new().route(
+            "/rate_limit",
+            get(move || {
+                let remaining = remaining.load(Ordering::Relaxed);
+                async move {
+                    let mut headers = HeaderMap::new();
+                    headers.insert("x-ratelimit-limit", "5000".parse().unwrap());
+                    headers.insert("x-ratelimit-remaining", remaining.into());
+                    headers.insert("x-ratelimit-reset", reset.timestamp().into());
+                    headers.insert("x-ratelimit-used", (5000 - remaining).into());
+                    let window = serde_json::json!({
+                        "limit": 5000,
+                        "remaining": remaining,
+                        "reset": reset.timestamp(),
+                        "used": 5000 - remaining,
+                    });
+                    let status = if remaining == 0 {
+                        StatusCode::FORBIDDEN
+                    } else {
+                        StatusCode::OK
+                    };
+                    let body = serde_json::json!({
+                        "resources": {"core": window, "search": window, "graphql": window},
+                        "rate": window,
+                    });
+                    (status, headers, Json(body))
+                }
+            }),
+        );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        GitHubClient::with_config(
+            crate::github::GitHubConfig::new("ghp_test").with_base_url(format!("http://{}", addr)),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_headers_pause_and_resume_sync() {
+        let reset = DateTime::from_timestamp(Utc::now().timestamp() + 3600, 0).unwrap();
+        let remaining = Arc::new(std::sync::atomic::AtomicI32::new(4000));
+        let client = mock_github(Arc::clone(&remaining), reset).await;
+        let pool = PgPool::connect_lazy("postgresql://localhost/unused").unwrap();
+        let manager =
+            BackgroundSyncManager::new(pool, client.clone(), BackgroundSyncConfig::default());
+
+        // Plenty of quota: nothing held back
+        client.get_rate_limit().await.unwrap();
+        let status = manager.status().await;
+        assert!(!status.is_paused());
+        assert_eq!(status.remaining, Some(4000));
+        assert_eq!(status.repo_budget, Some((4000 - 100) / 5));
+
+        // Below the reserve: paused until the window resets
+        remaining.store(50, Ordering::Relaxed);
+        client.get_rate_limit().await.unwrap();
+        let status = manager.status().await;
+        assert_eq!(status.paused_until, Some(reset));
+        assert_eq!(status.allowed(3), 0);
+        assert_eq!(manager.rate_limit_backoff().await, Some(reset));
+
+        // Just above it: two repo syncs of budget shared across ten pending
+        remaining.store(110, Ordering::Relaxed);
+        client.get_rate_limit().await.unwrap();
+        manager.pending_repos.store(10, Ordering::Relaxed);
+        let status = manager.status().await;
+        assert!(!status.is_paused());
+        assert_eq!(status.repo_budget, Some(2));
+        assert_eq!(status.allowed(4), 1);
+        assert_eq!(status.allowed(10), 2);
+
+        // Exhausted: the 403 is a rate-limit error, which pauses instead of failing
+        remaining.store(0, Ordering::Relaxed);
+        let err: Box<dyn std::error::Error> = client.get_rate_limit().await.unwrap_err().into();
+        assert_eq!(manager.pause_on_rate_limit(&*err), Some(reset));
+
+        // Quota back: the forced pause still holds until its reset time
+        remaining.store(4000, Ordering::Relaxed);
+        client.get_rate_limit().await.unwrap();
+        assert_eq!(manager.status().await.paused_until, Some(reset));
+        *manager.forced_pause.lock().unwrap() = None;
+        assert!(!manager.status().await.is_paused());
+    }
+
+    /// Provider whose every request is over the rate limit
+    struct RateLimitedProvider {
+        reset_at: DateTime<Utc>,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::github::GitProvider for RateLimitedProvider {
+        fn name(&self) -> &'static str {
+            "github"
+        }
+
+        async fn list_repos(&self) -> crate::github::Result<Vec<crate::github::Repository>> {
+            Err(GitHubError::RateLimitExceeded {
+                reset_at: self.reset_at,
+            })
+        }
+
+        async fn get_repo(
+            &self,
+            _owner: &str,
+            _repo: &str,
+        ) -> crate::github::Result<crate::github::Repository> {
+            Err(GitHubError::RateLimitExceeded {
+                reset_at: self.reset_at,
+            })
+        }
+
+        async fn get_issues(
+            &self,
+            _owner: &str,
+            _repo: &str,
+            _state: Option<&str>,
+        ) -> crate::github::Result<Vec<crate::github::Issue>> {
+            Err(GitHubError::RateLimitExceeded {
+                reset_at: self.reset_at,
+            })
+        }
+
+        async fn get_pull_requests(
+            &self,
+            _owner: &str,
+            _repo: &str,
+            _state: Option<&str>,
+        ) -> crate::github::Result<Vec<crate::github::PullRequest>> {
+            Err(GitHubError::RateLimitExceeded {
+                reset_at: self.reset_at,
+            })
+        }
+
+        async fn get_commits(
+            &self,
+            _owner: &str,
+            _repo: &str,
+            _per_page: Option<u32>,
+        ) -> crate::github::Result<Vec<crate::github::Commit>> {
+            Err(GitHubError::RateLimitExceeded {
+                reset_at: self.reset_at,
+            })
+        }
+
+        async fn rate_limit(&self) -> Option<RateLimitInfo> {
+            None
+        }
+    }
+
+    #[tokio::test]
+    async fn test_repo_sync_pauses_when_provider_is_rate_limited() {
+        let reset_at = DateTime::from_timestamp(Utc::now().timestamp() + 1800, 0).unwrap();
+        // The rate-limit error stops the sync before it reaches the database
+        let pool = PgPool::connect_lazy("postgresql://localhost/unused").unwrap();
+        let manager = BackgroundSyncManager::new(
+            pool.clone(),
+            GitHubClient::new("test-token").unwrap(),
+            BackgroundSyncConfig::default(),
+        );
+        let engine = SyncEngine::new(Box::new(RateLimitedProvider { reset_at }), pool);
+
+        assert_eq!(
+            manager.sync_repo_or_pause(&engine, "acme/widgets").await,
+            Some(reset_at)
+        );
+        assert_eq!(manager.status().await.paused_until, Some(reset_at));
+        assert_eq!(manager.rate_limit_backoff().await, Some(reset_at));
+    }
+
+    #[test]
+    fn test_spread_staggers_until_reset() {
+        let config = BackgroundSyncConfig::default();
+        let mut schedule = SyncSchedule::new(&config).with_seed(3);
+        let now = Utc::now();
+        let names: Vec<String> = (0..4).map(|i| format!("acme/repo{}", i)).collect();
+        schedule.set_repos(
+            names
+                .iter()
+                .map(|n| (n.clone(), SyncPriority::Normal))
+                .collect(),
+            now - chrono::Duration::hours(2),
+        );
+        let reset = now + chrono::Duration::minutes(40);
+        schedule.spread(&names, now, reset);
+
+        let runs: Vec<_> = names
+            .iter()
+            .map(|n| schedule.next_run(n).unwrap())
+            .collect();
+        assert!(runs.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(runs[0], now + chrono::Duration::minutes(10));
+        assert_eq!(runs[3], reset);
     }
 }
@@ -403,6 +403,20 @@ impl GitHubClient {
         response: reqwest::Response,
     ) -> GitHubError {
         match status {
+            // GitHub reports an exhausted primary rate limit as 403
+            StatusCode::FORBIDDEN
+                if response
+                    .headers()
+                    .get("x-ratelimit-remaining")
+                    .is_some_and(|v| v == "0") =>
+            {
+                match RateLimitInfo::from_headers(response.headers()) {
+                    Some(limit) => GitHubError::RateLimitExceeded {
+                        reset_at: limit.reset,
+                    },
+                    None => GitHubError::ApiError("Rate limit exceeded".to_string()),
+                }
+            }
             StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
                 GitHubError::AuthError("Invalid or expired GitHub token".to_string())
             }
@@ -596,6 +610,21 @@ impl GitHubClient {
             .await
     }
 
+    /// Submit a review with inline comments on a pull request
+    pub async fn create_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i32,
+        review: &crate::review_comments::PullRequestReview,
+    ) -> Result<Review> {
+        self.post(
+            &format!("/repos/{}/{}/pulls/{}/reviews", owner, repo, number),
+            review,
+        )
+        .await
+    }
+
     // ========================================================================
     // Commit Operations
     // ========================================================================
@@ -645,6 +674,20 @@ impl GitHubClient {
             .await
     }
 
+    /// Search code (requires authentication)
+    pub async fn search_code(
+        &self,
+        query: &str,
+        per_page: u32,
+    ) -> Result<SearchResponse<CodeSearchItem>> {
+        self.get(&format!(
+            "/search/code?q={}&per_page={}",
+            urlencoding::encode(query),
+            per_page.min(100)
+        ))
+        .await
+    }
+
     // ========================================================================
     // User Operations
     // ========================================================================
@@ -0,0 +1,1003 @@
+//! GitLab API Client
+//!
+//! REST client for GitLab (gitlab.com or self-hosted) implementing
+//! [`GitProvider`], so GitLab projects sync through the same
+//! [`SyncEngine`](crate::github::SyncEngine) as GitHub repositories.
+//!
+//! GitLab entities are mapped onto the GitHub domain models:
+//!
+//! - projects → [`Repository`] (`owner` is the namespace, `name` the project path)
+//! - issues → [`Issue`] (`number` is the project-scoped `iid`)
+//! - merge requests → [`PullRequest`] (`merged` is set for the `merged` state)
+//! - commits → [`Commit`]
+//!
+//! Lists are paginated by following the `X-Next-Page` response header.
+//! Milestones and per-MR diff stats are not part of GitLab's list
+//! responses and are left empty.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use rustassistant::github::{GitLabClient, SyncEngine};
+//! use sqlx::PgPool;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     let client = GitLabClient::new("glpat-your-token")?;
+//!     let pool = PgPool::connect(&std::env::var("DATABASE_URL")?).await?;
+//!
+//!     let sync = SyncEngine::new(Box::new(client), pool);
+//!     let result = sync.sync_all_repos().await?;
+//!     println!("Synced {} projects", result.repos_synced);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::github::client::RateLimitInfo;
+use crate::github::provider::GitProvider;
+use crate::github::{models::*, GitHubError, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::{
+    header::{HeaderMap, HeaderValue, USER_AGENT},
+    Client, StatusCode,
+};
+use serde::Deserialize;
+use std::time::Duration;
+use tracing::{debug, warn};
+
+const GITLAB_API_BASE: &str = "https://gitlab.com/api/v4";
+const DEFAULT_PER_PAGE: u32 = 100;
+const MAX_PER_PAGE: u32 = 100;
+
+// ============================================================================
+// Client Configuration
+// ============================================================================
+
+/// GitLab client configuration
+#[derive(Debug, Clone)]
+pub struct GitLabConfig {
+    /// Personal, project or group access token
+    pub token: String,
+
+    /// API base URL (default: https://gitlab.com/api/v4)
+    pub base_url: String,
+
+    /// Request timeout in seconds
+    pub timeout_secs: u64,
+
+    /// User agent string
+    pub user_agent: String,
+
+    /// Minimum remaining rate limit before warning
+    pub rate_limit_warning_threshold: i32,
+}
+
+impl Default for GitLabConfig {
+    fn default() -> Self {
+        Self {
+            token: String::new(),
+            base_url: GITLAB_API_BASE.to_string(),
+            timeout_secs: 30,
+            user_agent: format!("rustassistant/{}", env!("CARGO_PKG_VERSION")),
+            rate_limit_warning_threshold: 100,
+        }
+    }
+}
+
+impl GitLabConfig {
+    /// Create new config with token
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            token: token.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set custom base URL (for self-hosted GitLab), including `/api/v4`
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Set request timeout
+    pub fn with_timeout(mut self, secs: u64) -> Self {
+        self.timeout_secs = secs;
+        self
+    }
+}
+
+// ============================================================================
+// GitLab Client
+// ============================================================================
+
+/// GitLab REST API client
+#[derive(Clone)]
+pub struct GitLabClient {
+    config: GitLabConfig,
+    client: Client,
+    last_rate_limit: std::sync::Arc<tokio::sync::RwLock<Option<RateLimitInfo>>>,
+}
+
+impl GitLabClient {
+    /// Create new GitLab client with token
+    pub fn new(token: impl Into<String>) -> Result<Self> {
+        Self::with_config(GitLabConfig::new(token))
+    }
+
+    /// Create client with custom configuration
+    pub fn with_config(config: GitLabConfig) -> Result<Self> {
+        if config.token.is_empty() {
+            return Err(GitHubError::ConfigError(
+                "GitLab token is required".to_string(),
+            ));
+        }
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "PRIVATE-TOKEN",
+            HeaderValue::from_str(&config.token)
+                .map_err(|e| GitHubError::ConfigError(format!("Invalid token: {}", e)))?,
+        );
+        headers.insert(
+            USER_AGENT,
+            HeaderValue::from_str(&config.user_agent)
+                .map_err(|e| GitHubError::ConfigError(format!("Invalid user agent: {}", e)))?,
+        );
+
+        let client = Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .default_headers(headers)
+            .pool_max_idle_per_host(10)
+            .build()
+            .map_err(|e| GitHubError::ConfigError(format!("Failed to build HTTP client: {}", e)))?;
+
+        Ok(Self {
+            config,
+            client,
+            last_rate_limit: std::sync::Arc::new(tokio::sync::RwLock::new(None)),
+        })
+    }
+
+    /// Update rate limit from `RateLimit-*` headers
+    async fn update_rate_limit(&self, headers: &HeaderMap) {
+        if let Some(rate_limit) = rate_limit_from_headers(headers) {
+            if rate_limit.is_exhausted(self.config.rate_limit_warning_threshold) {
+                warn!(
+                    "GitLab API rate limit approaching: {}/{}",
+                    rate_limit.remaining, rate_limit.limit
+                );
+            }
+            *self.last_rate_limit.write().await = Some(rate_limit);
+        }
+    }
+
+    /// Make authenticated GET request
+    async fn get<T: for<'de> Deserialize<'de>>(&self, path: &str) -> Result<T> {
+        let url = format!("{}{}", self.config.base_url, path);
+        debug!("GET {}", url);
+
+        let response = self.client.get(&url).send().await?;
+        self.update_rate_limit(response.headers()).await;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(handle_error_response(status, response).await);
+        }
+
+        Ok(response.json().await?)
+    }
+
+    /// GET every page of a list endpoint, following `X-Next-Page`
+    ///
+    /// `query` is appended to the path and may be empty.
+    async fn get_paginated<T: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+        query: &str,
+        per_page: Option<u32>,
+    ) -> Result<Vec<T>> {
+        let per_page = per_page.unwrap_or(DEFAULT_PER_PAGE).min(MAX_PER_PAGE);
+        let separator = if query.is_empty() { "" } else { "&" };
+        let mut all_items = Vec::new();
+        let mut page = "1".to_string();
+
+        loop {
+            let url = format!(
+                "{}{}?{}{}per_page={}&page={}",
+                self.config.base_url, path, query, separator, per_page, page
+            );
+            debug!("GET {} (page {})", path, page);
+
+            let response = self.client.get(&url).send().await?;
+            self.update_rate_limit(response.headers()).await;
+
+            let status = response.status();
+            if !status.is_success() {
+                return Err(handle_error_response(status, response).await);
+            }
+
+            let next_page = response
+                .headers()
+                .get("x-next-page")
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.trim().to_string())
+                .unwrap_or_default();
+
+            let items: Vec<T> = response.json().await?;
+            all_items.extend(items);
+
+            if next_page.is_empty() {
+                break;
+            }
+            page = next_page;
+        }
+
+        Ok(all_items)
+    }
+
+    /// Project API path for `owner/repo` (namespaces may be nested)
+    fn project_path(owner: &str, repo: &str) -> String {
+        format!(
+            "/projects/{}",
+            urlencoding::encode(&format!("{}/{}", owner, repo))
+        )
+    }
+
+    // ========================================================================
+    // Project Operations
+    // ========================================================================
+
+    /// Projects the authenticated user is a member of
+    pub async fn list_projects(&self) -> Result<Vec<Repository>> {
+        let projects: Vec<GlProject> = self
+            .get_paginated("/projects", "membership=true", None)
+            .await?;
+        Ok(projects.into_iter().map(Repository::from).collect())
+    }
+
+    /// A single project by namespace and path
+    pub async fn get_project(&self, owner: &str, repo: &str) -> Result<Repository> {
+        let project: GlProject = self.get(&Self::project_path(owner, repo)).await?;
+        Ok(project.into())
+    }
+
+    // ========================================================================
+    // Issue & Merge Request Operations
+    // ========================================================================
+
+    /// Issues in a project; `state` uses GitHub's open/closed/all values
+    pub async fn list_issues(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: Option<&str>,
+    ) -> Result<Vec<Issue>> {
+        let path = format!("{}/issues", Self::project_path(owner, repo));
+        let query = format!("state={}", gitlab_state(state.unwrap_or("open")));
+        let issues: Vec<GlIssue> = self.get_paginated(&path, &query, None).await?;
+        Ok(issues.into_iter().map(Issue::from).collect())
+    }
+
+    /// Merge requests in a project; `state` uses GitHub's open/closed/all values
+    ///
+    /// "closed" includes merged requests, as it does on GitHub.
+    pub async fn list_merge_requests(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: Option<&str>,
+    ) -> Result<Vec<PullRequest>> {
+        let path = format!("{}/merge_requests", Self::project_path(owner, repo));
+        let state = state.unwrap_or("open");
+        let mrs: Vec<GlMergeRequest> = if state == "closed" {
+            let mut closed: Vec<GlMergeRequest> =
+                self.get_paginated(&path, "state=closed", None).await?;
+            closed.extend(
+                self.get_paginated::<GlMergeRequest>(&path, "state=merged", None)
+                    .await?,
+            );
+            closed
+        } else {
+            let query = format!("state={}", gitlab_state(state));
+            self.get_paginated(&path, &query, None).await?
+        };
+        Ok(mrs.into_iter().map(PullRequest::from).collect())
+    }
+
+    // ========================================================================
+    // Commit Operations
+    // ========================================================================
+
+    /// Commits on the default branch, with line stats
+    pub async fn list_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: Option<u32>,
+    ) -> Result<Vec<Commit>> {
+        let path = format!("{}/repository/commits", Self::project_path(owner, repo));
+        let commits: Vec<GlCommit> = self
+            .get_paginated(&path, "with_stats=true", per_page)
+            .await?;
+        Ok(commits.into_iter().map(Commit::from).collect())
+    }
+}
+
+#[async_trait]
+impl GitProvider for GitLabClient {
+    fn name(&self) -> &'static str {
+        "gitlab"
+    }
+
+    async fn list_repos(&self) -> Result<Vec<Repository>> {
+        self.list_projects().await
+    }
+
+    async fn get_repo(&self, owner: &str, repo: &str) -> Result<Repository> {
+        self.get_project(owner, repo).await
+    }
+
+    async fn get_issues(&self, owner: &str, repo: &str, state: Option<&str>) -> Result<Vec<Issue>> {
+        self.list_issues(owner, repo, state).await
+    }
+
+    async fn get_pull_requests(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: Option<&str>,
+    ) -> Result<Vec<PullRequest>> {
+        self.list_merge_requests(owner, repo, state).await
+    }
+
+    async fn get_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: Option<u32>,
+    ) -> Result<Vec<Commit>> {
+        self.list_commits(owner, repo, per_page).await
+    }
+
+    async fn rate_limit(&self) -> Option<RateLimitInfo> {
+        self.last_rate_limit.read().await.clone()
+    }
+}
+
+/// Map GitHub's open/closed/all filter onto GitLab's opened/closed/all
+fn gitlab_state(state: &str) -> &str {
+    match state {
+        "open" => "opened",
+        other => other,
+    }
+}
+
+/// Parse GitLab's `RateLimit-*` headers
+fn rate_limit_from_headers(headers: &HeaderMap) -> Option<RateLimitInfo> {
+    let header = |name: &str| -> Option<i64> { headers.get(name)?.to_str().ok()?.parse().ok() };
+
+    Some(RateLimitInfo {
+        limit: header("ratelimit-limit")? as i32,
+        remaining: header("ratelimit-remaining")? as i32,
+        reset: DateTime::from_timestamp(header("ratelimit-reset")?, 0)?,
+        used: header("ratelimit-observed").unwrap_or(0) as i32,
+    })
+}
+
+/// Convert an error response into a [`GitHubError`]
+async fn handle_error_response(status: StatusCode, response: reqwest::Response) -> GitHubError {
+    match status {
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+            GitHubError::AuthError("Invalid or expired GitLab token".to_string())
+        }
+        StatusCode::NOT_FOUND => GitHubError::NotFound {
+            resource_type: "resource".to_string(),
+            id: response.text().await.unwrap_or_default(),
+        },
+        StatusCode::TOO_MANY_REQUESTS => match rate_limit_from_headers(response.headers()) {
+            Some(rate_limit) => GitHubError::RateLimitExceeded {
+                reset_at: rate_limit.reset,
+            },
+            None => GitHubError::ApiError("Rate limit exceeded".to_string()),
+        },
+        _ => {
+            let body = response.text().await.unwrap_or_default();
+            GitHubError::ApiError(format!("HTTP {}: {}", status, body))
+        }
+    }
+}
+
+// ============================================================================
+// GitLab API Models
+// ============================================================================
+
+#[derive(Debug, Clone, Deserialize)]
+struct GlUser {
+    id: i64,
+    username: String,
+    name: Option<String>,
+    avatar_url: Option<String>,
+    web_url: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct GlNamespace {
+    id: i64,
+    full_path: String,
+    kind: String,
+    web_url: Option<String>,
+    avatar_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct GlProject {
+    id: i64,
+    path: String,
+    path_with_namespace: String,
+    description: Option<String>,
+    web_url: String,
+    http_url_to_repo: String,
+    ssh_url_to_repo: String,
+    namespace: GlNamespace,
+    #[serde(default)]
+    visibility: Option<String>,
+    #[serde(default)]
+    archived: bool,
+    #[serde(default)]
+    forked_from_project: Option<serde_json::Value>,
+    #[serde(default)]
+    star_count: i32,
+    #[serde(default)]
+    forks_count: i32,
+    #[serde(default)]
+    open_issues_count: Option<i32>,
+    #[serde(default)]
+    topics: Vec<String>,
+    #[serde(default)]
+    issues_enabled: Option<bool>,
+    #[serde(default)]
+    wiki_enabled: Option<bool>,
+    default_branch: Option<String>,
+    created_at: DateTime<Utc>,
+    last_activity_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct GlIssue {
+    id: i64,
+    iid: i32,
+    project_id: i64,
+    title: String,
+    description: Option<String>,
+    state: String,
+    author: GlUser,
+    #[serde(default)]
+    labels: Vec<String>,
+    #[serde(default)]
+    assignees: Vec<GlUser>,
+    #[serde(default)]
+    user_notes_count: i32,
+    #[serde(default)]
+    discussion_locked: Option<bool>,
+    web_url: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    closed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct GlMergeRequest {
+    id: i64,
+    iid: i32,
+    title: String,
+    description: Option<String>,
+    state: String,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    work_in_progress: bool,
+    author: GlUser,
+    merged_by: Option<GlUser>,
+    #[serde(default)]
+    reviewers: Vec<GlUser>,
+    #[serde(default)]
+    labels: Vec<String>,
+    source_branch: String,
+    target_branch: String,
+    sha: Option<String>,
+    #[serde(default)]
+    user_notes_count: i32,
+    web_url: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    closed_at: Option<DateTime<Utc>>,
+    merged_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct GlCommitStats {
+    additions: i32,
+    deletions: i32,
+    total: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct GlCommit {
+    id: String,
+    message: String,
+    author_name: String,
+    author_email: String,
+    authored_date: DateTime<Utc>,
+    committer_name: String,
+    committer_email: String,
+    committed_date: DateTime<Utc>,
+    #[serde(default)]
+    parent_ids: Vec<String>,
+    web_url: String,
+    stats: Option<GlCommitStats>,
+}
+
+// ============================================================================
+// Mapping onto the shared domain models
+// ============================================================================
+
+impl From<GlUser> for User {
+    fn from(user: GlUser) -> Self {
+        User {
+            id: user.id,
+            login: user.username,
+            name: user.name,
+            email: None,
+            avatar_url: user.avatar_url.unwrap_or_default(),
+            html_url: user.web_url,
+            user_type: UserType::User,
+            bio: None,
+            company: None,
+            location: None,
+            blog: None,
+            twitter_username: None,
+            public_repos: None,
+            followers: None,
+            following: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+impl From<GlNamespace> for User {
+    fn from(namespace: GlNamespace) -> Self {
+        User {
+            id: namespace.id,
+            login: namespace.full_path,
+            name: None,
+            email: None,
+            avatar_url: namespace.avatar_url.unwrap_or_default(),
+            html_url: namespace.web_url.unwrap_or_default(),
+            user_type: if namespace.kind == "group" {
+                UserType::Organization
+            } else {
+                UserType::User
+            },
+            bio: None,
+            company: None,
+            location: None,
+            blog: None,
+            twitter_username: None,
+            public_repos: None,
+            followers: None,
+            following: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+fn label(name: String) -> Label {
+    Label {
+        id: 0,
+        node_id: String::new(),
+        url: String::new(),
+        name,
+        description: None,
+        color: String::new(),
+        default: false,
+    }
+}
+
+impl From<GlProject> for Repository {
+    fn from(project: GlProject) -> Self {
+        let visibility = match project.visibility.as_deref() {
+            Some("public") => RepositoryVisibility::Public,
+            Some("internal") => RepositoryVisibility::Internal,
+            _ => RepositoryVisibility::Private,
+        };
+        let updated_at = project.last_activity_at.unwrap_or(project.created_at);
+
+        Repository {
+            id: project.id,
+            node_id: format!("gitlab:project:{}", project.id),
+            name: project.path,
+            full_name: project.path_with_namespace,
+            owner: project.namespace.into(),
+            description: project.description,
+            html_url: project.web_url,
+            clone_url: project.http_url_to_repo,
+            ssh_url: project.ssh_url_to_repo,
+            homepage: None,
+            language: None,
+            languages_url: String::new(),
+            private: visibility != RepositoryVisibility::Public,
+            visibility,
+            fork: project.forked_from_project.is_some(),
+            archived: project.archived,
+            disabled: false,
+            stargazers_count: project.star_count,
+            watchers_count: project.star_count,
+            forks_count: project.forks_count,
+            open_issues_count: project.open_issues_count.unwrap_or(0),
+            size: 0,
+            topics: project.topics,
+            has_issues: project.issues_enabled.unwrap_or(true),
+            has_projects: false,
+            has_wiki: project.wiki_enabled.unwrap_or(false),
+            has_pages: false,
+            has_downloads: false,
+            default_branch: project.default_branch.unwrap_or_else(|| "main".to_string()),
+            created_at: project.created_at,
+            updated_at,
+            pushed_at: project.last_activity_at,
+            license: None,
+        }
+    }
+}
+
+impl From<GlIssue> for Issue {
+    fn from(issue: GlIssue) -> Self {
+        Issue {
+            id: issue.id,
+            node_id: format!("gitlab:issue:{}", issue.id),
+            number: issue.iid,
+            title: issue.title,
+            body: issue.description,
+            body_text: None,
+            body_html: None,
+            user: issue.author.into(),
+            state: if issue.state == "opened" {
+                IssueState::Open
+            } else {
+                IssueState::Closed
+            },
+            state_reason: None,
+            labels: issue.labels.into_iter().map(label).collect(),
+            assignees: issue.assignees.into_iter().map(User::from).collect(),
+            milestone: None,
+            comments: issue.user_notes_count,
+            locked: issue.discussion_locked.unwrap_or(false),
+            active_lock_reason: None,
+            html_url: issue.web_url,
+            repository_url: format!("gitlab:project:{}", issue.project_id),
+            comments_url: String::new(),
+            created_at: issue.created_at,
+            updated_at: issue.updated_at,
+            closed_at: issue.closed_at,
+            pull_request: None,
+        }
+    }
+}
+
+impl From<GlMergeRequest> for PullRequest {
+    fn from(mr: GlMergeRequest) -> Self {
+        let author: User = mr.author.into();
+        let branch = |name: String, sha: String| PrBranch {
+            label: name.clone(),
+            r#ref: name,
+            sha,
+            user: author.clone(),
+            repo: None,
+        };
+        let head = branch(mr.source_branch, mr.sha.unwrap_or_default());
+        let base = branch(mr.target_branch, String::new());
+
+        PullRequest {
+            id: mr.id,
+            node_id: format!("gitlab:merge_request:{}", mr.id),
+            number: mr.iid,
+            title: mr.title,
+            body: mr.description,
+            body_text: None,
+            body_html: None,
+            state: if mr.state == "opened" {
+                PrState::Open
+            } else {
+                PrState::Closed
+            },
+            draft: mr.draft || mr.work_in_progress,
+            merged: mr.state == "merged",
+            mergeable: None,
+            mergeable_state: None,
+            merged_by: mr.merged_by.map(User::from),
+            head,
+            base,
+            requested_reviewers: mr.reviewers.into_iter().map(User::from).collect(),
+            requested_teams: Vec::new(),
+            labels: mr.labels.into_iter().map(label).collect(),
+            milestone: None,
+            commits: 0,
+            additions: 0,
+            deletions: 0,
+            changed_files: 0,
+            comments: mr.user_notes_count,
+            review_comments: 0,
+            diff_url: format!("{}.diff", mr.web_url),
+            patch_url: format!("{}.patch", mr.web_url),
+            html_url: mr.web_url,
+            issue_url: String::new(),
+            commits_url: String::new(),
+            review_comments_url: String::new(),
+            statuses_url: String::new(),
+            user: author,
+            created_at: mr.created_at,
+            updated_at: mr.updated_at,
+            closed_at: mr.closed_at.or(mr.merged_at),
+            merged_at: mr.merged_at,
+        }
+    }
+}
+
+impl From<GlCommit> for Commit {
+    fn from(commit: GlCommit) -> Self {
+        Commit {
+            node_id: format!("gitlab:commit:{}", commit.id),
+            commit: CommitDetails {
+                author: GitUser {
+                    name: commit.author_name,
+                    email: commit.author_email,
+                    date: commit.authored_date,
+                },
+                committer: GitUser {
+                    name: commit.committer_name,
+                    email: commit.committer_email,
+                    date: commit.committed_date,
+                },
+                message: commit.message,
+                comment_count: 0,
+                tree: TreeReference {
+                    sha: String::new(),
+                    url: String::new(),
+                },
+                verification: None,
+            },
+            author: None,
+            committer: None,
+            parents: commit
+                .parent_ids
+                .into_iter()
+                .map(|sha| CommitReference {
+                    sha,
+                    url: String::new(),
+                })
+                .collect(),
+            html_url: commit.web_url,
+            comments_url: String::new(),
+            stats: commit.stats.map(|s| CommitStats {
+                additions: s.additions,
+                deletions: s.deletions,
+                total: s.total,
+            }),
+            files: None,
+            sha: commit.id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::extract::{Path, Query};
+    use axum::http::HeaderMap as AxumHeaders;
+    use axum::response::IntoResponse;
+    use axum::routing::get;
+    use axum::{Json, Router};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn user(id: i64, username: &str) -> serde_json::Value {
+        json!({
+            "id": id,
+            "username": username,
+            "name": username,
+            "avatar_url": null,
+            "web_url": format!("https://gitlab.com/{}", username),
+        })
+    }
+
+    fn project() -> serde_json::Value {
+        json!({
+            "id": 42,
+            "path": "audit",
+            "path_with_namespace": "acme/tools/audit",
+            "description": "Audit tooling",
+            "web_url": "https://gitlab.com/acme/tools/audit",
+            "http_url_to_repo": "https://gitlab.com/acme/tools/audit.git",
+            "ssh_url_to_repo": "git@gitlab.com:acme/tools/audit.git",
+            "namespace": {
+                "id": 7,
+                "full_path": "acme/tools",
+                "kind": "group",
+                "web_url": "https://gitlab.com/groups/acme/tools",
+                "avatar_url": null,
+            },
+            "visibility": "internal",
+            "archived": false,
+            "star_count": 3,
+            "forks_count": 1,
+            "open_issues_count": 2,
+            "topics": ["rust"],
+            "default_branch": "main",
+            "created_at": "2024-01-01T00:00:00.000Z",
+            "last_activity_at": "2024-03-01T12:30:00.000+01:00",
+        })
+    }
+
+    fn merge_request(iid: i32, state: &str) -> serde_json::Value {
+        json!({
+            "id": 1000 + iid,
+            "iid": iid,
+            "title": format!("MR {}", iid),
+            "description": null,
+            "state": state,
+            "draft": false,
+            "work_in_progress": iid == 1,
+            "author": user(1, "alice"),
+            "merged_by": if state == "merged" { user(2, "bob") } else { serde_json::Value::Null },
+            "reviewers": [user(2, "bob")],
+            "labels": ["backend"],
+            "source_branch": format!("feature-{}", iid),
+            "target_branch": "main",
+            "sha": "abc123",
+            "user_notes_count": 4,
+            "web_url": format!("https://gitlab.com/acme/tools/audit/-/merge_requests/{}", iid),
+            "created_at": "2024-02-01T00:00:00Z",
+            "updated_at": "2024-02-02T00:00:00Z",
+            "closed_at": null,
+            "merged_at": if state == "merged" { json!("2024-02-03T00:00:00Z") } else { serde_json::Value::Null },
+        })
+    }
+
+    /// Serve one item per page, advertising the next page via `X-Next-Page`
+    fn paged(items: Vec<serde_json::Value>, page: usize) -> impl IntoResponse {
+        let mut headers = AxumHeaders::new();
+        let next = if page < items.len() {
+            (page + 1).to_string()
+        } else {
+            String::new()
+        };
+        headers.insert("x-next-page", next.parse().unwrap());
+        headers.insert("ratelimit-limit", "2000".parse().unwrap());
+        headers.insert("ratelimit-remaining", "1990".parse().unwrap());
+        headers.insert("ratelimit-reset", "1735689600".parse().unwrap());
+        headers.insert("ratelimit-observed", "10".parse().unwrap());
+        let body = items.get(page - 1).cloned().into_iter().collect::<Vec<_>>();
+        (headers, Json(body))
+    }
+
+    fn page_of(query: &HashMap<String, String>) -> usize {
+        query.get("page").and_then(|p| p.parse().ok()).unwrap_or(1)
+    }
+
+    async fn mock_gitlab() -> GitLabClient {
+        let app = Router::new()
+            .route(
+                "/projects",
+                get(|Query(q): Query<HashMap<String, String>>| async move {
+                    assert_eq!(q.get("membership").map(String::as_str), Some("true"));
+                    paged(vec![project(), project()], page_of(&q))
+                }),
+            )
+            .route(
+                "/projects/:id/merge_requests",
+                get(
+                    |Path(id): Path<String>, Query(q): Query<HashMap<String, String>>| async move {
+                        assert_eq!(id, "acme/tools/audit");
+                        let items = match q.get("state").map(String::as_str) {
+                            Some("opened") => vec![merge_request(1, "opened")],
+                            Some("closed") => vec![merge_request(2, "closed")],
+                            Some("merged") => vec![merge_request(3, "merged")],
+                            _ => vec![
+                                merge_request(1, "opened"),
+                                merge_request(2, "closed"),
+                                merge_request(3, "merged"),
+                            ],
+                        };
+                        paged(items, page_of(&q))
+                    },
+                ),
+            );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        GitLabClient::with_config(
+            GitLabConfig::new("glpat-test").with_base_url(format!("http://{}", addr)),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_client_requires_token() {
+        assert!(GitLabClient::with_config(GitLabConfig::default()).is_err());
+        assert!(GitLabClient::new("glpat-test").is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_list_repos_follows_next_page() {
+        let client = mock_gitlab().await;
+
+        let repos = client.list_repos().await.unwrap();
+        assert_eq!(repos.len(), 2);
+
+        let repo = &repos[0];
+        assert_eq!(repo.full_name, "acme/tools/audit");
+        assert_eq!(repo.name, "audit");
+        assert_eq!(repo.owner.login, "acme/tools");
+        assert_eq!(repo.owner.user_type, UserType::Organization);
+        assert_eq!(repo.visibility, RepositoryVisibility::Internal);
+        assert!(repo.private);
+        assert_eq!(
+            repo.pushed_at.unwrap().to_rfc3339(),
+            "2024-03-01T11:30:00+00:00"
+        );
+
+        let rate_limit = client.rate_limit().await.unwrap();
+        assert_eq!(rate_limit.limit, 2000);
+        assert_eq!(rate_limit.remaining, 1990);
+        assert_eq!(rate_limit.used, 10);
+    }
+
+    #[tokio::test]
+    async fn test_merge_requests_map_to_pull_requests() {
+        let client = mock_gitlab().await;
+
+        let all = client
+            .get_pull_requests("acme/tools", "audit", Some("all"))
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 3);
+
+        let draft = &all[0];
+        assert_eq!(draft.number, 1);
+        assert_eq!(draft.state, PrState::Open);
+        assert!(draft.draft);
+        assert!(!draft.merged);
+        assert_eq!(draft.head.r#ref, "feature-1");
+        assert_eq!(draft.head.sha, "abc123");
+        assert_eq!(draft.base.r#ref, "main");
+        assert_eq!(draft.labels[0].name, "backend");
+        assert_eq!(draft.requested_reviewers[0].login, "bob");
+
+        let merged = &all[2];
+        assert_eq!(merged.state, PrState::Closed);
+        assert!(merged.merged);
+        assert_eq!(merged.merged_by.as_ref().unwrap().login, "bob");
+        assert_eq!(merged.closed_at, merged.merged_at);
+
+        // GitHub's "closed" includes merged requests
+        let closed = client
+            .get_pull_requests("acme/tools", "audit", Some("closed"))
+            .await
+            .unwrap();
+        let numbers: Vec<i32> = closed.iter().map(|pr| pr.number).collect();
+        assert_eq!(numbers, vec![2, 3]);
+
+        let open = client
+            .get_pull_requests("acme/tools", "audit", None)
+            .await
+            .unwrap();
+        assert_eq!(open.len(), 1);
+    }
+}
@@ -247,6 +247,17 @@ pub struct PullRequest {
     pub merged_at: Option<DateTime<Utc>>,
 }
 
+/// A submitted pull request review
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Review {
+    pub id: i64,
+    pub body: Option<String>,
+    /// e.g. `COMMENTED`, `APPROVED`, `CHANGES_REQUESTED`
+    pub state: String,
+    pub html_url: String,
+    pub submitted_at: Option<DateTime<Utc>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum PrState {
@@ -442,6 +453,21 @@ pub struct SearchResponse<T> {
     pub items: Vec<T>,
 }
 
+/// Item returned by `GET /search/code`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeSearchItem {
+    pub name: String,
+    pub path: String,
+    pub sha: String,
+    pub html_url: String,
+    pub repository: CodeSearchRepository,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeSearchRepository {
+    pub full_name: String,
+}
+
 // ============================================================================
 // Activity Events
 // ============================================================================
@@ -0,0 +1,90 @@
+//! Git hosting provider abstraction
+//!
+//! [`GitProvider`] is the subset of a forge API that [`SyncEngine`] needs.
+//! [`GitHubClient`] and [`GitLabClient`] both implement it and return the
+//! same domain models, so the sync engine and database schema stay
+//! provider-agnostic.
+//!
+//! [`SyncEngine`]: crate::github::SyncEngine
+//! [`GitLabClient`]: crate::github::GitLabClient
+
+use crate::github::client::{GitHubClient, RateLimitInfo};
+use crate::github::{models::*, Result};
+use async_trait::async_trait;
+
+/// Operations the sync engine needs from a git hosting provider
+#[async_trait]
+pub trait GitProvider: Send + Sync {
+    /// Short provider name (e.g. "github", "gitlab"); also the `provider`
+    /// column that keys synced rows
+    fn name(&self) -> &'static str;
+
+    /// Repositories visible to the authenticated user
+    async fn list_repos(&self) -> Result<Vec<Repository>>;
+
+    /// A single repository by owner and name
+    async fn get_repo(&self, owner: &str, repo: &str) -> Result<Repository>;
+
+    /// Issues in a repository; `state` is "open", "closed" or "all"
+    async fn get_issues(&self, owner: &str, repo: &str, state: Option<&str>) -> Result<Vec<Issue>>;
+
+    /// Pull (or merge) requests; `state` is "open", "closed" or "all"
+    async fn get_pull_requests(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: Option<&str>,
+    ) -> Result<Vec<PullRequest>>;
+
+    /// Commits on the default branch, fetched `per_page` at a time
+    async fn get_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: Option<u32>,
+    ) -> Result<Vec<Commit>>;
+
+    /// Rate limit seen on the most recent response, if the provider reports one
+    async fn rate_limit(&self) -> Option<RateLimitInfo>;
+}
+
+#[async_trait]
+impl GitProvider for GitHubClient {
+    fn name(&self) -> &'static str {
+        "github"
+    }
+
+    async fn list_repos(&self) -> Result<Vec<Repository>> {
+        self.list_my_repos().await
+    }
+
+    async fn get_repo(&self, owner: &str, repo: &str) -> Result<Repository> {
+        GitHubClient::get_repo(self, owner, repo).await
+    }
+
+    async fn get_issues(&self, owner: &str, repo: &str, state: Option<&str>) -> Result<Vec<Issue>> {
+        self.list_issues(owner, repo, state).await
+    }
+
+    async fn get_pull_requests(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: Option<&str>,
+    ) -> Result<Vec<PullRequest>> {
+        self.list_pull_requests(owner, repo, state).await
+    }
+
+    async fn get_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: Option<u32>,
+    ) -> Result<Vec<Commit>> {
+        self.list_commits(owner, repo, per_page).await
+    }
+
+    async fn rate_limit(&self) -> Option<RateLimitInfo> {
+        self.get_cached_rate_limit().await
+    }
+}
@@ -44,7 +44,7 @@ use tracing::{debug, info};
 // ============================================================================
 
 /// Type of search to perform
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum SearchType {
     /// Search repositories
@@ -59,6 +59,9 @@ pub enum SearchType {
     /// Search commits
     Commits,
 
+    /// Search code (GitHub API only; the local cache holds no file contents)
+    Code,
+
     /// Search everything
     All,
 }
@@ -70,6 +73,7 @@ impl std::fmt::Display for SearchType {
             SearchType::Issues => write!(f, "issues"),
             SearchType::PullRequests => write!(f, "pull_requests"),
             SearchType::Commits => write!(f, "commits"),
+            SearchType::Code => write!(f, "code"),
             SearchType::All => write!(f, "all"),
         }
     }
@@ -318,6 +322,7 @@ impl GitHubSearcher {
             SearchType::Issues => self.search_issues(&query).await,
             SearchType::PullRequests => self.search_pull_requests(&query).await,
             SearchType::Commits => self.search_commits(&query).await,
+            SearchType::Code => Ok(Vec::new()),
             SearchType::All => self.search_all(&query).await,
         }
     }
@@ -416,7 +421,7 @@ impl GitHubSearcher {
             SELECT i.id, r.full_name, i.number, i.title, i.body, i.state,
                    i.user_login, i.labels, i.html_url, i.created_at, i.updated_at
             FROM github_issues i
-            JOIN github_repositories r ON i.repo_id = r.id
+            JOIN github_repositories r ON i.provider = r.provider AND i.repo_id = r.id
             WHERE i.is_pull_request = 0
             "#,
         );
@@ -508,7 +513,7 @@ impl GitHubSearcher {
                    p.draft, p.merged, p.user_login, p.labels, p.html_url,
                    p.created_at, p.updated_at
             FROM github_pull_requests p
-            JOIN github_repositories r ON p.repo_id = r.id
+            JOIN github_repositories r ON p.provider = r.provider AND p.repo_id = r.id
             WHERE 1=1
             "#,
         );
@@ -590,7 +595,7 @@ impl GitHubSearcher {
             SELECT c.sha, r.full_name, c.author_name, c.message,
                    c.additions, c.deletions, c.html_url, c.author_date
             FROM github_commits c
-            JOIN github_repositories r ON c.repo_id = r.id
+            JOIN github_repositories r ON c.provider = r.provider AND c.repo_id = r.id
             WHERE 1=1
             "#,
         );
@@ -29,9 +29,13 @@
 //! ```
 
 use crate::db::Database;
+use crate::error::AuditError;
+use crate::prompt_cache::PromptCache;
+use crate::prompt_guard::{self, PromptGuard};
 use crate::response_cache::ResponseCache;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use std::time::Duration;
 use tracing::{debug, error, info, warn};
 
@@ -67,6 +71,10 @@ pub struct GrokClient {
     cache: Option<ResponseCache>,
     /// Enable caching
     caching_enabled: bool,
+    /// Answers identical rendered prompts from the `prompt_cache` table
+    prompt_cache: Option<PromptCache>,
+    /// Checks every prompt before it is sent
+    guard: Arc<dyn PromptGuard>,
 }
 
 /// File scoring request
@@ -217,9 +225,17 @@ impl GrokClient {
             model,
             cache: None,
             caching_enabled: false,
+            prompt_cache: None,
+            guard: prompt_guard::default_guard(),
         }
     }
 
+    /// Replace the default pre-send guard
+    pub fn with_prompt_guard(mut self, guard: Arc<dyn PromptGuard>) -> Self {
+        self.guard = guard;
+        self
+    }
+
     /// Enable caching with the specified database path
     pub async fn with_cache(mut self, cache_db_path: &str) -> Result<Self> {
         let cache = ResponseCache::new(cache_db_path).await?;
@@ -228,6 +244,12 @@ impl GrokClient {
         Ok(self)
     }
 
+    /// Answer `ask_tracked` prompts seen before from `cache`, without an API call
+    pub fn with_prompt_cache(mut self, cache: PromptCache) -> Self {
+        self.prompt_cache = Some(cache);
+        self
+    }
+
     /// Return the model name this client is configured to use.
     pub fn model_name(&self) -> &str {
         &self.model
@@ -250,6 +272,22 @@ impl GrokClient {
         client.with_cache("data/rustassistant_cache.db").await
     }
 
+    /// Cached response for `key`, if caching is enabled and it hasn't expired
+    pub async fn cached_response(&self, key: &str, operation: &str) -> Result<Option<String>> {
+        match &self.cache {
+            Some(cache) if self.caching_enabled => cache.get(key, operation).await,
+            _ => Ok(None),
+        }
+    }
+
+    /// Store a response under `key` (no-op when caching is disabled)
+    pub async fn cache_response(&self, key: &str, operation: &str, response: &str) -> Result<()> {
+        match &self.cache {
+            Some(cache) if self.caching_enabled => cache.set(key, operation, response, None).await,
+            _ => Ok(()),
+        }
+    }
+
     /// Score a file using Grok (with caching)
     pub async fn score_file(&self, file_path: &str, content: &str) -> Result<FileScoreResult> {
         // Check cache first
@@ -387,8 +425,22 @@ Code:
             question.to_string()
         };
 
+        match &self.prompt_cache {
+            Some(cache) => {
+                cache
+                    .get_or_fetch(&self.model, &prompt, operation, || {
+                        self.call_tracked(&prompt, operation)
+                    })
+                    .await
+            }
+            None => self.call_tracked(&prompt, operation).await,
+        }
+    }
+
+    /// Send a rendered prompt and report its token usage and cost
+    async fn call_tracked(&self, prompt: &str, operation: &str) -> Result<AskResponse> {
         let response = self
-            .call_api(&prompt, operation, None)
+            .call_api(prompt, operation, None)
             .await
             .context("Failed to ask Grok (tracked)")?;
 
@@ -491,12 +543,16 @@ Code:
         operation: &str,
         repository_id: Option<i64>,
     ) -> Result<ApiResponse> {
+        let prompt = prompt_guard::guard_prompt(self.guard.as_ref(), prompt)?;
         let mut last_error = None;
+        let mut retry_after = None;
 
         for attempt in 0..MAX_RETRIES {
             if attempt > 0 {
+                // Never retry sooner than a rate limit asked for
                 let delay =
-                    Duration::from_millis(INITIAL_RETRY_DELAY_MS * 2u64.pow(attempt as u32));
+                    Duration::from_millis(INITIAL_RETRY_DELAY_MS * 2u64.pow(attempt as u32))
+                        .max(retry_after.take().unwrap_or_default());
                 info!(
                     "Retrying API call (attempt {}/{}) after {:?}",
                     attempt + 1,
@@ -506,7 +562,7 @@ Code:
                 tokio::time::sleep(delay).await;
             }
 
-            match self.call_api_once(prompt).await {
+            match self.call_api_once(&prompt).await {
                 Ok(response) => {
                     // Calculate cost
                     let cost = self.calculate_cost(&response.usage);
@@ -536,6 +592,14 @@ Code:
                 }
                 Err(e) => {
                     error!("API call failed (attempt {}): {}", attempt + 1, e);
+                    match AuditError::find(&e) {
+                        // Bad credentials or no budget: retrying cannot help
+                        Some(audit_err) if audit_err.is_terminal() => return Err(e),
+                        Some(AuditError::RateLimited {
+                            retry_after: Some(wait),
+                        }) => retry_after = Some(*wait),
+                        _ => {}
+                    }
                     last_error = Some(e);
                 }
             }
@@ -577,26 +641,17 @@ Code:
             .await
             .context("Failed to send request to Grok API")?;
 
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(anyhow::anyhow!(
-                "API returned error {}: {}",
-                status,
-                error_text
-            ));
+        if !response.status().is_success() {
+            return Err(AuditError::from_llm_response("Grok", response).await.into());
         }
 
         let api_response: ChatCompletionResponse = response
             .json()
             .await
-            .context("Failed to parse API response")?;
+            .map_err(|e| AuditError::response_parse(format!("Grok chat completion: {}", e)))?;
 
         if api_response.choices.is_empty() {
-            return Err(anyhow::anyhow!("API returned no choices"));
+            return Err(AuditError::response_parse("Grok returned no choices").into());
         }
 
         Ok(ApiResponse {
@@ -7,16 +7,31 @@
 //! - File-by-file review with scoring integration
 //! - CI/CD integration with progress tracking
 //! - Retry logic with exponential backoff
+//! - Resumable batch runs via [`BatchCheckpointStore`]
 
 use crate::cache::{AuditCache, CacheEntry};
+use crate::cost_tracker::{
+    GROK_COST_PER_MILLION_CACHED, GROK_COST_PER_MILLION_INPUT, GROK_COST_PER_MILLION_OUTPUT,
+};
 use crate::error::{AuditError, Result};
 use crate::llm_config::LimitsConfig;
+use crate::prompt_guard::{self, PromptGuard};
+use crate::redaction::{self, RedactedContent};
 use crate::scoring::FileScore;
+use crate::static_analysis::StaticAnalyzer;
 use crate::tree_state::FileCategory;
+use futures::stream::{FuturesUnordered, StreamExt};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use sha2::{Digest, Sha256};
+use sqlx::SqlitePool;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::{Mutex, Semaphore};
 use tokio::time::sleep;
 use tracing::{debug, error, info, warn};
 
@@ -51,8 +66,13 @@ pub struct RetryConfig {
     pub exponential_backoff: bool,
     /// Maximum delay cap in milliseconds
     pub max_delay_ms: u64,
+    /// Maximum number of batches analyzed at once by [`analyze_all_batches`]
+    pub max_concurrent: usize,
 }
 
+/// Default number of batches analyzed concurrently
+pub const DEFAULT_MAX_CONCURRENT_BATCHES: usize = 4;
+
 impl Default for RetryConfig {
     fn default() -> Self {
         Self {
@@ -60,6 +80,7 @@ impl Default for RetryConfig {
             initial_delay_ms: 1000,
             exponential_backoff: true,
             max_delay_ms: 30000,
+            max_concurrent: DEFAULT_MAX_CONCURRENT_BATCHES,
         }
     }
 }
@@ -72,6 +93,7 @@ impl RetryConfig {
             initial_delay_ms: limits.retry_delay_ms,
             exponential_backoff: limits.exponential_backoff,
             max_delay_ms: 30000,
+            max_concurrent: DEFAULT_MAX_CONCURRENT_BATCHES,
         }
     }
 
@@ -121,6 +143,24 @@ pub struct GrokReasoningClient {
 
     /// Retry configuration
     retry_config: RetryConfig,
+
+    /// Wire format of the endpoint at `base_url`
+    api: WireApi,
+
+    /// Pre-send check on every user prompt
+    guard: Arc<dyn PromptGuard>,
+
+    /// Masks secrets in file content when set; see [`crate::redaction`]
+    redactor: Option<StaticAnalyzer>,
+}
+
+/// Request/response shape spoken by the endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WireApi {
+    /// xAI Responses API (`/responses`)
+    Responses,
+    /// OpenAI-compatible Chat Completions (`/chat/completions`)
+    ChatCompletions,
 }
 
 /// Batch of files for analysis
@@ -319,6 +359,64 @@ pub struct TokenUsage {
     pub total_tokens: usize,
 }
 
+impl TokenUsage {
+    fn add(&mut self, other: &TokenUsage) {
+        self.prompt_tokens += other.prompt_tokens;
+        self.completion_tokens += other.completion_tokens;
+        self.reasoning_tokens += other.reasoning_tokens;
+        self.cached_tokens += other.cached_tokens;
+        self.total_tokens += other.total_tokens;
+    }
+
+    /// Estimated spend at Grok 4.1 Fast pricing
+    ///
+    /// Cached tokens are part of `prompt_tokens` but billed at the cached
+    /// rate; reasoning tokens are billed as output.
+    pub fn cost_usd(&self) -> f64 {
+        let uncached = self.prompt_tokens.saturating_sub(self.cached_tokens) as f64;
+        let output = (self.completion_tokens + self.reasoning_tokens) as f64;
+        (uncached * GROK_COST_PER_MILLION_INPUT
+            + self.cached_tokens as f64 * GROK_COST_PER_MILLION_CACHED
+            + output * GROK_COST_PER_MILLION_OUTPUT)
+            / 1_000_000.0
+    }
+}
+
+/// Running [`TokenUsage`] total shared by concurrently analyzed batches
+#[derive(Debug, Default)]
+struct TokenTally {
+    prompt_tokens: AtomicUsize,
+    completion_tokens: AtomicUsize,
+    reasoning_tokens: AtomicUsize,
+    cached_tokens: AtomicUsize,
+    total_tokens: AtomicUsize,
+}
+
+impl TokenTally {
+    fn add(&self, usage: &TokenUsage) {
+        self.prompt_tokens
+            .fetch_add(usage.prompt_tokens, Ordering::Relaxed);
+        self.completion_tokens
+            .fetch_add(usage.completion_tokens, Ordering::Relaxed);
+        self.reasoning_tokens
+            .fetch_add(usage.reasoning_tokens, Ordering::Relaxed);
+        self.cached_tokens
+            .fetch_add(usage.cached_tokens, Ordering::Relaxed);
+        self.total_tokens
+            .fetch_add(usage.total_tokens, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> TokenUsage {
+        TokenUsage {
+            prompt_tokens: self.prompt_tokens.load(Ordering::Relaxed),
+            completion_tokens: self.completion_tokens.load(Ordering::Relaxed),
+            reasoning_tokens: self.reasoning_tokens.load(Ordering::Relaxed),
+            cached_tokens: self.cached_tokens.load(Ordering::Relaxed),
+            total_tokens: self.total_tokens.load(Ordering::Relaxed),
+        }
+    }
+}
+
 /// Batch analysis result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BatchAnalysisResult {
@@ -431,6 +529,50 @@ struct UsageInfo {
     output_tokens_details: Option<OutputTokenDetails>,
 }
 
+/// Request for an OpenAI-compatible Chat Completions API
+#[derive(Debug, Serialize)]
+struct ChatRequest {
+    model: String,
+    messages: Vec<Message>,
+    max_tokens: usize,
+    temperature: f64,
+}
+
+/// Response from an OpenAI-compatible Chat Completions API
+#[derive(Debug, Deserialize)]
+struct ChatResponse {
+    #[serde(default)]
+    choices: Vec<ChatChoice>,
+    #[serde(default)]
+    usage: Option<ChatUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatChoice {
+    message: ChatMessage,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatMessage {
+    #[serde(default)]
+    content: Option<String>,
+}
+
+/// Chat Completions usage block; local servers may omit any of it
+#[derive(Debug, Deserialize)]
+struct ChatUsage {
+    #[serde(default)]
+    prompt_tokens: usize,
+    #[serde(default)]
+    completion_tokens: usize,
+    #[serde(default)]
+    total_tokens: usize,
+    #[serde(default)]
+    prompt_tokens_details: Option<TokenDetails>,
+    #[serde(default)]
+    completion_tokens_details: Option<OutputTokenDetails>,
+}
+
 /// Input token details
 #[derive(Debug, Deserialize)]
 struct TokenDetails {
@@ -465,9 +607,61 @@ impl GrokReasoningClient {
             enable_reasoning: true,
             _timeout: Duration::from_secs(300),
             retry_config: RetryConfig::default(),
+            api: WireApi::Responses,
+            guard: prompt_guard::default_guard(),
+            redactor: None,
         })
     }
 
+    /// Create a client for a local OpenAI-compatible endpoint (e.g. a
+    /// llama.cpp server at [`DEFAULT_LOCAL_BASE_URL`](crate::llm_config::DEFAULT_LOCAL_BASE_URL))
+    ///
+    /// Requests go to `{base_url}/chat/completions` without an API key, and
+    /// the xAI-only code execution tool is disabled.
+    pub fn local(base_url: impl Into<String>, model: impl Into<String>) -> Result<Self> {
+        let mut client = Self::new(String::new())?;
+        client.base_url = base_url.into().trim_end_matches('/').to_string();
+        client.model = model.into();
+        client.enable_code_execution = false;
+        client.api = WireApi::ChatCompletions;
+        Ok(client)
+    }
+
+    /// Override the API base URL
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Replace the default pre-send guard
+    pub fn with_prompt_guard(mut self, guard: Arc<dyn PromptGuard>) -> Self {
+        self.guard = guard;
+        self
+    }
+
+    /// Mask detected secrets (API keys, tokens, passwords, private keys)
+    /// before file content goes into a prompt
+    pub fn with_secret_redaction(mut self, enabled: bool) -> Self {
+        self.redactor = enabled.then(StaticAnalyzer::new);
+        self
+    }
+
+    /// Content of `file` as it will be sent: redacted when enabled
+    fn outgoing_content(&self, file: &FileForAnalysis) -> RedactedContent {
+        let outgoing = match &self.redactor {
+            Some(analyzer) => redaction::redact_with(analyzer, &file.path, &file.content),
+            None => RedactedContent::unchanged(&file.content),
+        };
+        if outgoing.is_redacted() {
+            debug!(
+                "Redacted {} secret(s) from {} before analysis",
+                outgoing.redactions().len(),
+                file.path
+            );
+        }
+        outgoing
+    }
+
     /// Create with custom configuration
     pub fn with_config(
         api_key: String,
@@ -535,74 +729,32 @@ impl GrokReasoningClient {
     }
 
     /// Create batches from files for optimal context usage
+    ///
+    /// Assembly is deterministic: files are ordered by path within each
+    /// category, and batch boundaries depend only on file sizes and
+    /// `max_batch_tokens`. The same files in any input order give the same
+    /// batches, so cache keys and checkpoints carry over between runs.
     pub fn create_batches(
         &self,
         files: Vec<FileForAnalysis>,
         max_batch_tokens: usize,
     ) -> Vec<FileBatch> {
         let mut batches = Vec::new();
-        let mut current_batch: Vec<FileForAnalysis> = Vec::new();
-        #[allow(unused_assignments)]
-        let mut current_tokens: usize = 0;
-        let mut batch_id: usize = 0;
-
-        // Sort files by priority (importance + risk score)
-        let mut sorted_files = files;
-        sorted_files.sort_by(|a, b| {
-            let score_a = a
-                .score
-                .as_ref()
-                .map(|s| s.importance + s.risk)
-                .unwrap_or(50.0);
-            let score_b = b
-                .score
-                .as_ref()
-                .map(|s| s.importance + s.risk)
-                .unwrap_or(50.0);
-            score_b
-                .partial_cmp(&score_a)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
 
-        // Group by category first, then batch by token count
-        let mut by_category: HashMap<FileCategory, Vec<FileForAnalysis>> = HashMap::new();
-        for file in sorted_files {
+        // Group by category, then batch by token count in path order
+        let mut by_category: BTreeMap<FileCategory, Vec<FileForAnalysis>> = BTreeMap::new();
+        for file in files {
             by_category.entry(file.category).or_default().push(file);
         }
 
-        for (category, category_files) in by_category {
-            current_batch.clear();
-            current_tokens = 0;
+        for (category, mut category_files) in by_category {
+            category_files.sort_by(|a, b| a.path.cmp(&b.path));
+            let mut current_batch: Vec<FileForAnalysis> = Vec::new();
+            let mut current_tokens: usize = 0;
 
             for file in category_files {
                 let file_tokens = Self::estimate_tokens(&file.content) + 500; // Buffer for prompt
 
-                // Check if adding this file would exceed limits
-                if current_tokens + file_tokens > max_batch_tokens && !current_batch.is_empty() {
-                    // Create batch from current files
-                    let priority = current_batch
-                        .iter()
-                        .map(|f| {
-                            f.score
-                                .as_ref()
-                                .map(|s| s.importance + s.risk)
-                                .unwrap_or(50.0)
-                        })
-                        .sum::<f64>()
-                        / current_batch.len() as f64;
-
-                    batches.push(FileBatch {
-                        files: std::mem::take(&mut current_batch),
-                        batch_id,
-                        estimated_tokens: current_tokens,
-                        priority,
-                        category,
-                    });
-
-                    batch_id += 1;
-                    current_tokens = 0;
-                }
-
                 // Determine batch size based on file size
                 let max_files_in_batch = if file.lines < SMALL_FILE_LOC {
                     15
@@ -614,28 +766,15 @@ impl GrokReasoningClient {
                     1
                 };
 
-                // Check file count limit
-                if current_batch.len() >= max_files_in_batch {
-                    let priority = current_batch
-                        .iter()
-                        .map(|f| {
-                            f.score
-                                .as_ref()
-                                .map(|s| s.importance + s.risk)
-                                .unwrap_or(50.0)
-                        })
-                        .sum::<f64>()
-                        / current_batch.len() as f64;
-
-                    batches.push(FileBatch {
-                        files: std::mem::take(&mut current_batch),
-                        batch_id,
-                        estimated_tokens: current_tokens,
-                        priority,
+                // Close the batch if this file would exceed the token or file count limit
+                let over_tokens = current_tokens + file_tokens > max_batch_tokens;
+                let over_count = current_batch.len() >= max_files_in_batch;
+                if !current_batch.is_empty() && (over_tokens || over_count) {
+                    batches.push(Self::finish_batch(
+                        std::mem::take(&mut current_batch),
+                        current_tokens,
                         category,
-                    });
-
-                    batch_id += 1;
+                    ));
                     current_tokens = 0;
                 }
 
@@ -645,35 +784,19 @@ impl GrokReasoningClient {
 
             // Don't forget the last batch
             if !current_batch.is_empty() {
-                let priority = current_batch
-                    .iter()
-                    .map(|f| {
-                        f.score
-                            .as_ref()
-                            .map(|s| s.importance + s.risk)
-                            .unwrap_or(50.0)
-                    })
-                    .sum::<f64>()
-                    / current_batch.len() as f64;
-
-                batches.push(FileBatch {
-                    files: std::mem::take(&mut current_batch),
-                    batch_id,
-                    estimated_tokens: current_tokens,
-                    priority,
-                    category,
-                });
-
-                batch_id += 1;
+                batches.push(Self::finish_batch(current_batch, current_tokens, category));
             }
         }
 
-        // Sort batches by priority
+        // Sort batches by priority; ties keep category and path order
         batches.sort_by(|a, b| {
             b.priority
                 .partial_cmp(&a.priority)
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
+        for (batch_id, batch) in batches.iter_mut().enumerate() {
+            batch.batch_id = batch_id;
+        }
 
         info!(
             "Created {} batches from {} files",
@@ -684,6 +807,32 @@ impl GrokReasoningClient {
         batches
     }
 
+    /// A batch of `files` whose priority is their mean importance + risk
+    fn finish_batch(
+        files: Vec<FileForAnalysis>,
+        estimated_tokens: usize,
+        category: FileCategory,
+    ) -> FileBatch {
+        let priority = files
+            .iter()
+            .map(|f| {
+                f.score
+                    .as_ref()
+                    .map(|s| s.importance + s.risk)
+                    .unwrap_or(50.0)
+            })
+            .sum::<f64>()
+            / files.len() as f64;
+
+        FileBatch {
+            files,
+            batch_id: 0,
+            estimated_tokens,
+            priority,
+            category,
+        }
+    }
+
     /// Build system prompt for code analysis
     fn build_analysis_system_prompt(&self, category: FileCategory) -> String {
         let category_context = match category {
@@ -770,7 +919,7 @@ When analyzing multiple files, return a JSON array of file results."#,
             file.category.display_name(),
             file.path,
             file.lines,
-            file.content
+            self.outgoing_content(file).content
         );
 
         let (response, token_usage) = self.call_api(&system_prompt, &user_prompt).await?;
@@ -839,7 +988,9 @@ When analyzing multiple files, return a JSON array of file results."#,
             for file in &files_to_analyze {
                 user_prompt.push_str(&format!(
                     "--- File: {} ({} lines) ---\n```\n{}\n```\n\n",
-                    file.path, file.lines, file.content
+                    file.path,
+                    file.lines,
+                    self.outgoing_content(file).content
                 ));
             }
 
@@ -971,11 +1122,18 @@ When analyzing multiple files, return a JSON array of file results."#,
         system_prompt: &str,
         user_prompt: &str,
     ) -> Result<(String, TokenUsage)> {
+        let user_prompt = prompt_guard::guard_prompt(self.guard.as_ref(), user_prompt)
+            .map_err(|e| AuditError::other(e.to_string()))?;
         let mut last_error: Option<AuditError> = None;
+        let mut retry_after = None;
 
         for attempt in 0..=self.retry_config.max_retries {
             if attempt > 0 {
-                let delay = self.retry_config.delay_for_attempt(attempt - 1);
+                // Never retry sooner than a rate limit asked for
+                let delay = self
+                    .retry_config
+                    .delay_for_attempt(attempt - 1)
+                    .max(retry_after.take().unwrap_or_default());
                 warn!(
                     "Retry attempt {}/{} after {:?} delay",
                     attempt, self.retry_config.max_retries, delay
@@ -983,7 +1141,7 @@ When analyzing multiple files, return a JSON array of file results."#,
                 sleep(delay).await;
             }
 
-            match self.call_api_once(system_prompt, user_prompt).await {
+            match self.call_api_once(system_prompt, &user_prompt).await {
                 Ok(result) => {
                     if attempt > 0 {
                         info!("API call succeeded on retry attempt {}", attempt);
@@ -993,8 +1151,18 @@ When analyzing multiple files, return a JSON array of file results."#,
                 Err(e) => {
                     let error_str = e.to_string();
                     // Check if error is retryable
-                    if Self::is_retryable_error(&error_str) {
+                    if e.is_terminal() {
+                        error!("Terminal error: {}", error_str);
+                        return Err(e);
+                    }
+                    if e.is_retryable() || Self::is_retryable_error(&error_str) {
                         warn!("Retryable error on attempt {}: {}", attempt, error_str);
+                        if let AuditError::RateLimited {
+                            retry_after: Some(wait),
+                        } = e
+                        {
+                            retry_after = Some(wait);
+                        }
                         last_error = Some(e);
                         continue;
                     } else {
@@ -1036,6 +1204,105 @@ When analyzing multiple files, return a JSON array of file results."#,
         &self,
         system_prompt: &str,
         user_prompt: &str,
+    ) -> Result<(String, TokenUsage)> {
+        match self.api {
+            WireApi::Responses => self.call_responses_once(system_prompt, user_prompt).await,
+            WireApi::ChatCompletions => self.call_chat_once(system_prompt, user_prompt).await,
+        }
+    }
+
+    /// Single Chat Completions call against an OpenAI-compatible endpoint
+    async fn call_chat_once(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+    ) -> Result<(String, TokenUsage)> {
+        let request = ChatRequest {
+            model: self.model.clone(),
+            messages: vec![
+                Message {
+                    role: "system".to_string(),
+                    content: system_prompt.to_string(),
+                },
+                Message {
+                    role: "user".to_string(),
+                    content: user_prompt.to_string(),
+                },
+            ],
+            max_tokens: self.max_tokens,
+            temperature: self.temperature,
+        };
+
+        debug!("Sending API request to {}/chat/completions", self.base_url);
+
+        let mut builder = self
+            .client
+            .post(format!("{}/chat/completions", self.base_url))
+            .header("Content-Type", "application/json");
+        if !self.api_key.is_empty() {
+            builder = builder.header("Authorization", format!("Bearer {}", self.api_key));
+        }
+        let response = builder
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| AuditError::other(format!("API request failed: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(AuditError::from_llm_response("Chat Completions", response).await);
+        }
+
+        let response_body: ChatResponse = response
+            .json()
+            .await
+            .map_err(|e| AuditError::response_parse(format!("chat completion: {}", e)))?;
+
+        let content = response_body
+            .choices
+            .into_iter()
+            .find_map(|c| c.message.content)
+            .ok_or_else(|| AuditError::response_parse("no content in chat completion"))?;
+
+        let token_usage = match response_body.usage {
+            Some(usage) => TokenUsage {
+                prompt_tokens: usage.prompt_tokens,
+                completion_tokens: usage.completion_tokens,
+                reasoning_tokens: usage
+                    .completion_tokens_details
+                    .map(|d| d.reasoning_tokens)
+                    .unwrap_or(0),
+                cached_tokens: usage
+                    .prompt_tokens_details
+                    .map(|d| d.cached_tokens)
+                    .unwrap_or(0),
+                total_tokens: if usage.total_tokens > 0 {
+                    usage.total_tokens
+                } else {
+                    usage.prompt_tokens + usage.completion_tokens
+                },
+            },
+            // Many local servers skip `usage`; estimate so budgets still move
+            None => {
+                let prompt_tokens =
+                    Self::estimate_tokens(system_prompt) + Self::estimate_tokens(user_prompt);
+                let completion_tokens = Self::estimate_tokens(&content);
+                TokenUsage {
+                    prompt_tokens,
+                    completion_tokens,
+                    total_tokens: prompt_tokens + completion_tokens,
+                    ..Default::default()
+                }
+            }
+        };
+
+        Ok((content, token_usage))
+    }
+
+    /// Single xAI Responses API call
+    async fn call_responses_once(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
     ) -> Result<(String, TokenUsage)> {
         let mut tools = Vec::new();
 
@@ -1075,24 +1342,14 @@ When analyzing multiple files, return a JSON array of file results."#,
             .await
             .map_err(|e| AuditError::other(format!("API request failed: {}", e)))?;
 
-        let status = response.status();
-        if !status.is_success() {
-            let body = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(AuditError::other(format!(
-                "API error {} (retryable={}): {}",
-                status,
-                Self::is_retryable_error(&format!("{}", status.as_u16())),
-                body
-            )));
+        if !response.status().is_success() {
+            return Err(AuditError::from_llm_response("xAI", response).await);
         }
 
         let response_body: ResponsesResponse = response
             .json()
             .await
-            .map_err(|e| AuditError::other(format!("Failed to parse response: {}", e)))?;
+            .map_err(|e| AuditError::response_parse(format!("Responses API output: {}", e)))?;
 
         // Extract text content from response
         // Debug: log raw response structure
@@ -1142,7 +1399,7 @@ When analyzing multiple files, return a JSON array of file results."#,
                 None
             })
             .next()
-            .ok_or_else(|| AuditError::other("No content in response".to_string()))?;
+            .ok_or_else(|| AuditError::response_parse("no content in Responses API output"))?;
 
         tracing::debug!("Extracted content length: {} chars", content.len());
         tracing::debug!("Content preview: {}", &content[..content.len().min(500)]);
@@ -1174,9 +1431,8 @@ When analyzing multiple files, return a JSON array of file results."#,
         // Try to extract JSON from response
         let json_str = self.extract_json(response)?;
 
-        serde_json::from_str(&json_str).map_err(|e| {
-            AuditError::other(format!("Failed to parse file analysis for {}: {}", path, e))
-        })
+        serde_json::from_str(&json_str)
+            .map_err(|e| AuditError::response_parse(format!("file analysis for {}: {}", path, e)))
     }
 
     /// Parse response for multiple files
@@ -1334,47 +1590,522 @@ When analyzing multiple files, return a JSON array of file results."#,
 /// Progress callback for batch analysis
 pub type ProgressCallback = Box<dyn Fn(usize, usize, &str) + Send + Sync>;
 
+/// Outcome of [`analyze_all_batches`]
+#[derive(Debug)]
+pub struct BatchRunSummary {
+    /// One `Result` per dispatched batch, in input order
+    pub results: Vec<Result<BatchAnalysisResult>>,
+
+    /// Token usage summed over the successful batches
+    pub tokens: TokenUsage,
+
+    /// Estimated spend of `tokens`
+    pub cost_usd: f64,
+
+    /// Whether spend reached the budget
+    pub budget_exhausted: bool,
+
+    /// IDs of batches never dispatched because the budget was exhausted
+    pub skipped_batch_ids: Vec<usize>,
+
+    /// Index of the first batch run when an earlier run's checkpoint was
+    /// resumed; batches before it were not dispatched again
+    pub resumed_from: Option<usize>,
+}
+
+// ============================================================================
+// Batch Checkpoints
+// ============================================================================
+
+/// Progress of a batch run, saved after every completed batch so a run that
+/// dies part-way can pick up where it left off
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchCheckpoint {
+    /// Fingerprint of the batches (paths and content hashes, in order); a
+    /// checkpoint only resumes a run over exactly the same batches
+    pub batch_set: String,
+
+    /// Index of the first batch not yet completed; every batch before it is
+    pub next_batch: usize,
+
+    /// Token usage of batches `[0, next_batch)`; batches completed out of
+    /// order are counted from their stored results instead
+    pub tokens: TokenUsage,
+
+    /// Content hashes of the files in every completed batch, in or out of
+    /// order; a resumed run skips a batch whose files are all listed here
+    pub completed_files: Vec<String>,
+
+    /// Unix seconds of the last save
+    pub updated_at: i64,
+}
+
+impl BatchCheckpoint {
+    /// Fingerprint `batches` for [`BatchCheckpoint::batch_set`]
+    pub fn fingerprint(batches: &[FileBatch]) -> String {
+        let mut hasher = Sha256::new();
+        for batch in batches {
+            hasher.update(batch.batch_id.to_le_bytes());
+            for file in &batch.files {
+                hasher.update(file.path.as_bytes());
+                hasher.update([0]);
+                hasher.update(file.content_hash.as_bytes());
+                hasher.update([0]);
+            }
+            hasher.update([0xff]);
+        }
+        format!("{:x}", hasher.finalize())
+    }
+}
+
+/// SQLite tables of [`BatchCheckpoint`]s, one per batch set, and of the
+/// results of the batches they record as completed
+pub struct BatchCheckpointStore {
+    pool: SqlitePool,
+}
+
+impl BatchCheckpointStore {
+    /// Open (or create) the checkpoint database at `path`
+    pub async fn new(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let database_url = format!("sqlite:{}?mode=rwc", path.display());
+        let pool = SqlitePool::connect(&database_url)
+            .await
+            .map_err(|e| AuditError::other(format!("Failed to open checkpoint db: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS batch_checkpoints (
+                batch_set TEXT PRIMARY KEY NOT NULL,
+                next_batch INTEGER NOT NULL,
+                tokens TEXT NOT NULL,
+                completed_files TEXT NOT NULL,
+                updated_at INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .map_err(|e| AuditError::other(format!("Failed to create checkpoint table: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS batch_checkpoint_results (
+                batch_set TEXT NOT NULL,
+                batch_index INTEGER NOT NULL,
+                result TEXT NOT NULL,
+                PRIMARY KEY (batch_set, batch_index)
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .map_err(|e| AuditError::other(format!("Failed to create checkpoint table: {}", e)))?;
+
+        Ok(Self { pool })
+    }
+
+    /// Open the checkpoint database in a project's `.audit-cache`
+    pub async fn for_project(project_root: &Path) -> Result<Self> {
+        Self::new(
+            &project_root
+                .join(crate::cache::CACHE_DIR)
+                .join("batch_checkpoints.db"),
+        )
+        .await
+    }
+
+    /// Checkpoint saved for `batch_set`, if any
+    pub async fn load(&self, batch_set: &str) -> Result<Option<BatchCheckpoint>> {
+        let row: Option<(i64, String, String, i64)> = sqlx::query_as(
+            "SELECT next_batch, tokens, completed_files, updated_at \
+             FROM batch_checkpoints WHERE batch_set = ?",
+        )
+        .bind(batch_set)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AuditError::other(format!("Failed to load checkpoint: {}", e)))?;
+
+        row.map(|(next_batch, tokens, completed_files, updated_at)| {
+            Ok(BatchCheckpoint {
+                batch_set: batch_set.to_string(),
+                next_batch: next_batch.max(0) as usize,
+                tokens: serde_json::from_str(&tokens)?,
+                completed_files: serde_json::from_str(&completed_files)?,
+                updated_at,
+            })
+        })
+        .transpose()
+    }
+
+    /// Insert or replace the checkpoint for its batch set
+    pub async fn save(&self, checkpoint: &BatchCheckpoint) -> Result<()> {
+        sqlx::query(
+            "INSERT OR REPLACE INTO batch_checkpoints \
+             (batch_set, next_batch, tokens, completed_files, updated_at) \
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(&checkpoint.batch_set)
+        .bind(checkpoint.next_batch as i64)
+        .bind(serde_json::to_string(&checkpoint.tokens)?)
+        .bind(serde_json::to_string(&checkpoint.completed_files)?)
+        .bind(checkpoint.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuditError::other(format!("Failed to save checkpoint: {}", e)))?;
+        Ok(())
+    }
+
+    /// Store the result of completed batch `batch_index` of `batch_set`
+    pub async fn save_result(
+        &self,
+        batch_set: &str,
+        batch_index: usize,
+        result: &BatchAnalysisResult,
+    ) -> Result<()> {
+        sqlx::query(
+            "INSERT OR REPLACE INTO batch_checkpoint_results (batch_set, batch_index, result) \
+             VALUES (?, ?, ?)",
+        )
+        .bind(batch_set)
+        .bind(batch_index as i64)
+        .bind(serde_json::to_string(result)?)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuditError::other(format!("Failed to save batch result: {}", e)))?;
+        Ok(())
+    }
+
+    /// Stored results of `batch_set`'s completed batches, by batch index
+    pub async fn load_results(
+        &self,
+        batch_set: &str,
+    ) -> Result<HashMap<usize, BatchAnalysisResult>> {
+        let rows: Vec<(i64, String)> = sqlx::query_as(
+            "SELECT batch_index, result FROM batch_checkpoint_results WHERE batch_set = ?",
+        )
+        .bind(batch_set)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AuditError::other(format!("Failed to load batch results: {}", e)))?;
+
+        rows.into_iter()
+            .map(|(index, result)| Ok((index.max(0) as usize, serde_json::from_str(&result)?)))
+            .collect()
+    }
+
+    /// Remove the checkpoint for `batch_set` and its stored results
+    pub async fn clear(&self, batch_set: &str) -> Result<()> {
+        for table in ["batch_checkpoints", "batch_checkpoint_results"] {
+            sqlx::query(&format!("DELETE FROM {} WHERE batch_set = ?", table))
+                .bind(batch_set)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| AuditError::other(format!("Failed to clear checkpoint: {}", e)))?;
+        }
+        Ok(())
+    }
+}
+
+/// Checkpoint bookkeeping of one run: which batches have completed
+struct CheckpointProgress<'a> {
+    store: &'a BatchCheckpointStore,
+    checkpoint: BatchCheckpoint,
+    /// Token usage per completed batch index; `None` until it completes
+    completed: Vec<Option<TokenUsage>>,
+}
+
+impl CheckpointProgress<'_> {
+    fn is_complete(&self) -> bool {
+        self.completed.iter().all(Option::is_some)
+    }
+
+    /// Record batch `index` as completed, store its result and save the
+    /// checkpoint. Only the contiguous completed prefix adds to the
+    /// checkpoint's tokens.
+    async fn complete(
+        &mut self,
+        index: usize,
+        file_hashes: Vec<String>,
+        result: &BatchAnalysisResult,
+    ) {
+        if let Err(e) = self
+            .store
+            .save_result(&self.checkpoint.batch_set, index, result)
+            .await
+        {
+            // Without its stored result the batch can't be skipped on resume
+            warn!("Failed to save batch result: {}", e);
+            return;
+        }
+
+        self.completed[index] = Some(result.total_tokens.clone());
+        while let Some(Some(tokens)) = self.completed.get(self.checkpoint.next_batch) {
+            self.checkpoint.tokens.add(tokens);
+            self.checkpoint.next_batch += 1;
+        }
+        self.checkpoint.completed_files.extend(file_hashes);
+        self.checkpoint.updated_at = chrono::Utc::now().timestamp();
+
+        if let Err(e) = self.store.save(&self.checkpoint).await {
+            warn!("Failed to save batch checkpoint: {}", e);
+        }
+    }
+}
+
 /// Analyze multiple batches with progress reporting
+///
+/// Up to `retry_config().max_concurrent` batches run at once. A failing
+/// batch does not cancel the others: the summary holds one `Result` per
+/// dispatched batch, in input order, alongside the token usage summed over
+/// the successful ones.
+///
+/// With a `budget_usd`, no new batch is dispatched once the estimated spend
+/// of completed batches reaches the budget. Batches already in flight still
+/// finish; the rest are listed in `skipped_batch_ids`.
+///
+/// With a `checkpoint` store, progress and each completed batch's result
+/// are saved as batches complete. A later run over the same batches skips
+/// every batch the earlier run completed, in or out of order, and reports
+/// its stored result and token usage alongside the new ones. The checkpoint
+/// is cleared once every batch has completed.
 pub async fn analyze_all_batches(
     client: &GrokReasoningClient,
     batches: Vec<FileBatch>,
     cache: Option<&AuditCache>,
+    budget_usd: Option<f64>,
+    checkpoint: Option<&BatchCheckpointStore>,
     progress: Option<ProgressCallback>,
-) -> Result<Vec<BatchAnalysisResult>> {
-    let total_batches = batches.len();
-    let mut results = Vec::new();
-
-    for (i, batch) in batches.into_iter().enumerate() {
-        if let Some(ref cb) = progress {
-            cb(
-                i + 1,
-                total_batches,
-                &format!(
-                    "Analyzing batch {} ({} files)",
-                    batch.batch_id,
-                    batch.files.len()
-                ),
-            );
+) -> BatchRunSummary {
+    run_batches(
+        batches,
+        client.retry_config().max_concurrent,
+        budget_usd,
+        checkpoint,
+        progress.as_ref(),
+        |batch| async move { client.analyze_batch(&batch, cache).await },
+    )
+    .await
+}
+
+/// Drive `analyze` over `batches` with at most `max_concurrent` in flight
+async fn run_batches<F, Fut>(
+    batches: Vec<FileBatch>,
+    max_concurrent: usize,
+    budget_usd: Option<f64>,
+    checkpoint_store: Option<&BatchCheckpointStore>,
+    progress: Option<&ProgressCallback>,
+    analyze: F,
+) -> BatchRunSummary
+where
+    F: Fn(FileBatch) -> Fut,
+    Fut: Future<Output = Result<BatchAnalysisResult>>,
+{
+    let semaphore = Semaphore::new(max_concurrent.max(1));
+    let tally = TokenTally::default();
+    let completed = AtomicUsize::new(0);
+
+    // Resume from a checkpoint over the same batches
+    let batch_set = BatchCheckpoint::fingerprint(&batches);
+    let mut resumed_from = None;
+    // Results of batches an earlier run completed, served instead of rerun
+    let mut reused: HashMap<usize, BatchAnalysisResult> = HashMap::new();
+    let checkpoint = match checkpoint_store {
+        Some(store) => {
+            let saved = match store.load(&batch_set).await {
+                Ok(saved) => saved.filter(|c| c.next_batch < batches.len()),
+                Err(e) => {
+                    warn!("Ignoring unreadable batch checkpoint: {}", e);
+                    None
+                }
+            };
+            let mut completed = vec![None; batches.len()];
+            let saved = match saved {
+                Some(saved) => match store.load_results(&batch_set).await {
+                    Ok(mut stored) => {
+                        let done: std::collections::HashSet<&str> =
+                            saved.completed_files.iter().map(String::as_str).collect();
+                        for (index, batch) in batches.iter().enumerate() {
+                            let files_done = batch
+                                .files
+                                .iter()
+                                .all(|f| done.contains(f.content_hash.as_str()));
+                            if let Some(result) = stored.remove(&index).filter(|_| files_done) {
+                                reused.insert(index, result);
+                            }
+                        }
+                        if (0..saved.next_batch).all(|index| reused.contains_key(&index)) {
+                            Some(saved)
+                        } else {
+                            warn!("Ignoring batch checkpoint with missing batch results");
+                            reused.clear();
+                            None
+                        }
+                    }
+                    Err(e) => {
+                        warn!("Ignoring batch checkpoint: {}", e);
+                        None
+                    }
+                },
+                None => None,
+            };
+            let checkpoint = match saved {
+                Some(saved) => {
+                    info!(
+                        "Resuming batch run: {} of {} batches already complete",
+                        reused.len(),
+                        batches.len()
+                    );
+                    // The prefix is in the saved tokens; later batches are not
+                    tally.add(&saved.tokens);
+                    for (&index, result) in &reused {
+                        if index >= saved.next_batch {
+                            tally.add(&result.total_tokens);
+                        }
+                        completed[index] = Some(result.total_tokens.clone());
+                    }
+                    resumed_from = Some(saved.next_batch);
+                    saved
+                }
+                None => BatchCheckpoint {
+                    batch_set: batch_set.clone(),
+                    next_batch: 0,
+                    tokens: TokenUsage::default(),
+                    completed_files: Vec::new(),
+                    updated_at: chrono::Utc::now().timestamp(),
+                },
+            };
+            Some(Mutex::new(CheckpointProgress {
+                store,
+                checkpoint,
+                completed,
+            }))
         }
+        None => None,
+    };
+    let checkpoint = checkpoint.as_ref();
+
+    let batch_count = batches.len();
+    let batches: Vec<(usize, FileBatch)> = batches
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| !reused.contains_key(index))
+        .collect();
+    let total_batches = batches.len();
 
-        match client.analyze_batch(&batch, cache).await {
-            Ok(result) => {
-                info!(
-                    "Batch {} complete: {} files in {}ms",
-                    result.batch_id,
-                    result.file_results.len(),
-                    result.processing_time_ms
-                );
-                results.push(result);
+    let mut pending: FuturesUnordered<_> = batches
+        .into_iter()
+        .map(|(batch_index, batch)| {
+            let (semaphore, tally, completed, analyze) = (&semaphore, &tally, &completed, &analyze);
+            async move {
+                let _permit = semaphore
+                    .acquire()
+                    .await
+                    .expect("batch semaphore is never closed");
+                let batch_id = batch.batch_id;
+                let file_count = batch.files.len();
+                let file_hashes: Vec<String> = checkpoint
+                    .map(|_| batch.files.iter().map(|f| f.content_hash.clone()).collect())
+                    .unwrap_or_default();
+
+                if let Some(budget) = budget_usd {
+                    let spent = tally.snapshot().cost_usd();
+                    if spent >= budget {
+                        debug!(
+                            "Skipping batch {}: ${:.4} spent of ${:.4} budget",
+                            batch_id, spent, budget
+                        );
+                        return (batch_index, Err(batch_id));
+                    }
+                }
+
+                let result = analyze(batch).await;
+                match &result {
+                    Ok(r) => {
+                        tally.add(&r.total_tokens);
+                        if let Some(checkpoint) = checkpoint {
+                            // Held across the save so checkpoints land in order
+                            checkpoint
+                                .lock()
+                                .await
+                                .complete(batch_index, file_hashes, r)
+                                .await;
+                        }
+                        info!(
+                            "Batch {} complete: {} files in {}ms",
+                            r.batch_id,
+                            r.file_results.len(),
+                            r.processing_time_ms
+                        );
+                    }
+                    Err(e) => warn!("Batch {} failed: {}", batch_id, e),
+                }
+
+                let done = completed.fetch_add(1, Ordering::Relaxed) + 1;
+                if let Some(cb) = progress {
+                    cb(
+                        done,
+                        total_batches,
+                        &format!("Analyzed batch {} ({} files)", batch_id, file_count),
+                    );
+                }
+                (batch_index, Ok(result))
             }
-            Err(e) => {
-                warn!("Batch {} failed: {}", batch.batch_id, e);
-                // Continue with other batches
+        })
+        .collect();
+
+    // Each slot is a batch's result, or the ID of a skipped one
+    let mut slots: Vec<Option<std::result::Result<Result<BatchAnalysisResult>, usize>>> =
+        (0..batch_count).map(|_| None).collect();
+    while let Some((index, outcome)) = pending.next().await {
+        slots[index] = Some(outcome);
+    }
+    drop(pending);
+    for (index, result) in reused {
+        slots[index] = Some(Ok(Ok(result)));
+    }
+
+    let mut results = Vec::with_capacity(batch_count);
+    let mut skipped_batch_ids = Vec::new();
+    for slot in slots {
+        match slot.expect("every batch yields an outcome") {
+            Ok(result) => results.push(result),
+            Err(batch_id) => skipped_batch_ids.push(batch_id),
+        }
+    }
+
+    // A clean run leaves nothing to resume
+    if let Some(checkpoint) = checkpoint {
+        let progress = checkpoint.lock().await;
+        if progress.is_complete() {
+            if let Err(e) = progress.store.clear(&batch_set).await {
+                warn!("Failed to clear batch checkpoint: {}", e);
             }
         }
     }
 
-    Ok(results)
+    let tokens = tally.snapshot();
+    let cost_usd = tokens.cost_usd();
+    let budget_exhausted = budget_usd.is_some_and(|budget| cost_usd >= budget);
+    if !skipped_batch_ids.is_empty() {
+        warn!(
+            "Budget exhausted (${:.4}): skipped {} of {} batches",
+            cost_usd,
+            skipped_batch_ids.len(),
+            total_batches
+        );
+    }
+
+    BatchRunSummary {
+        results,
+        tokens,
+        cost_usd,
+        budget_exhausted,
+        skipped_batch_ids,
+        resumed_from,
+    }
 }
 
 #[cfg(test)]
@@ -1394,6 +2125,7 @@ mod tests {
             initial_delay_ms: 1000,
             exponential_backoff: true,
             max_delay_ms: 10000,
+            max_concurrent: 1,
         };
 
         assert_eq!(config.delay_for_attempt(0), Duration::from_millis(1000));
@@ -1436,6 +2168,9 @@ mod tests {
             enable_reasoning: true,
             _timeout: Duration::from_secs(300),
             retry_config: RetryConfig::default(),
+            api: WireApi::Responses,
+            guard: prompt_guard::default_guard(),
+            redactor: None,
         };
 
         let files: Vec<FileForAnalysis> = (0..20)
@@ -1458,6 +2193,66 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_create_batches_is_independent_of_input_order() {
+        let client = GrokReasoningClient {
+            client: Client::new(),
+            api_key: "test".to_string(),
+            model: GROK_REASONING_MODEL.to_string(),
+            base_url: "https://api.x.ai/v1".to_string(),
+            max_tokens: 32000,
+            temperature: 0.3,
+            max_turns: 5,
+            enable_code_execution: true,
+            enable_reasoning: true,
+            _timeout: Duration::from_secs(300),
+            retry_config: RetryConfig::default(),
+            api: WireApi::Responses,
+            guard: prompt_guard::default_guard(),
+            redactor: None,
+        };
+
+        let files: Vec<FileForAnalysis> = (0..30)
+            .map(|i| FileForAnalysis {
+                path: format!("src/m{:02}.rs", i),
+                content: "x".repeat(400 * (i % 7 + 1)),
+                lines: 20 * (i % 7 + 1),
+                score: None,
+                category: if i % 3 == 0 {
+                    FileCategory::Tests
+                } else {
+                    FileCategory::Audit
+                },
+                content_hash: format!("hash{}", i),
+            })
+            .collect();
+        let mut shuffled = files.clone();
+        shuffled.reverse();
+        shuffled.rotate_left(11);
+
+        let composition = |batches: &[FileBatch]| {
+            batches
+                .iter()
+                .map(|b| {
+                    let paths: Vec<String> = b.files.iter().map(|f| f.path.clone()).collect();
+                    (b.batch_id, b.category, b.estimated_tokens, paths)
+                })
+                .collect::<Vec<_>>()
+        };
+        let first = client.create_batches(files, 3000);
+        let second = client.create_batches(shuffled, 3000);
+
+        assert!(first.len() > 2);
+        assert_eq!(composition(&first), composition(&second));
+        assert_eq!(
+            BatchCheckpoint::fingerprint(&first),
+            BatchCheckpoint::fingerprint(&second)
+        );
+        for batch in &first {
+            assert!(batch.files.windows(2).all(|w| w[0].path < w[1].path));
+        }
+    }
+
     #[test]
     fn test_extract_json_direct() {
         let client = GrokReasoningClient {
@@ -1472,6 +2267,9 @@ mod tests {
             enable_reasoning: true,
             _timeout: Duration::from_secs(300),
             retry_config: RetryConfig::default(),
+            api: WireApi::Responses,
+            guard: prompt_guard::default_guard(),
+            redactor: None,
         };
 
         let response = r#"{"score": 85}"#;
@@ -1493,6 +2291,9 @@ mod tests {
             enable_reasoning: true,
             _timeout: Duration::from_secs(300),
             retry_config: RetryConfig::default(),
+            api: WireApi::Responses,
+            guard: prompt_guard::default_guard(),
+            redactor: None,
         };
 
         let response = r#"Here's the analysis:
@@ -1504,10 +2305,387 @@ mod tests {
         assert_eq!(json, r#"{"score": 85}"#);
     }
 
+    fn empty_batch(batch_id: usize) -> FileBatch {
+        FileBatch {
+            files: Vec::new(),
+            batch_id,
+            estimated_tokens: 0,
+            priority: 0.0,
+            category: FileCategory::Audit,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_batches_bounded_ordered_and_isolated() {
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let batches: Vec<FileBatch> = (0..6).map(empty_batch).collect();
+
+        let summary = run_batches(batches, 2, None, None, None, |batch| {
+            let (in_flight, peak) = (&in_flight, &peak);
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                // Later batches finish first, so completion order differs from input order
+                sleep(Duration::from_millis(5 * (6 - batch.batch_id) as u64)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                if batch.batch_id == 2 {
+                    return Err(AuditError::other("boom"));
+                }
+                Ok(BatchAnalysisResult {
+                    batch_id: batch.batch_id,
+                    file_results: Vec::new(),
+                    batch_insights: None,
+                    total_tokens: TokenUsage {
+                        prompt_tokens: 7,
+                        total_tokens: 10,
+                        ..TokenUsage::default()
+                    },
+                    processing_time_ms: 0,
+                    tool_calls_count: 0,
+                })
+            }
+        })
+        .await;
+        let (results, tokens) = (summary.results, summary.tokens);
+
+        assert!(peak.load(Ordering::SeqCst) <= 2);
+        assert_eq!(results.len(), 6);
+        for (i, result) in results.iter().enumerate() {
+            match result {
+                Ok(r) => assert_eq!(r.batch_id, i),
+                Err(_) => assert_eq!(i, 2),
+            }
+        }
+        assert!(results[2].is_err());
+        assert_eq!(tokens.prompt_tokens, 35);
+        assert_eq!(tokens.total_tokens, 50);
+    }
+
+    #[tokio::test]
+    async fn test_run_batches_stops_dispatching_when_budget_exhausted() {
+        let batches: Vec<FileBatch> = (0..3).map(empty_batch).collect();
+
+        let summary = run_batches(batches, 1, Some(0.0001), None, None, |batch| async move {
+            Ok(BatchAnalysisResult {
+                batch_id: batch.batch_id,
+                file_results: Vec::new(),
+                batch_insights: None,
+                total_tokens: TokenUsage {
+                    prompt_tokens: 1_000,
+                    completion_tokens: 1_000,
+                    total_tokens: 2_000,
+                    ..TokenUsage::default()
+                },
+                processing_time_ms: 0,
+                tool_calls_count: 0,
+            })
+        })
+        .await;
+
+        assert_eq!(summary.results.len(), 1);
+        assert_eq!(summary.results[0].as_ref().unwrap().batch_id, 0);
+        assert_eq!(summary.skipped_batch_ids, vec![1, 2]);
+        assert!(summary.budget_exhausted);
+        assert!((summary.cost_usd - 0.0007).abs() < 1e-12);
+    }
+
+    #[tokio::test]
+    async fn test_run_batches_resumes_from_checkpoint() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let store = BatchCheckpointStore::for_project(temp.path())
+            .await
+            .unwrap();
+        let batches: Vec<FileBatch> = (0..5)
+            .map(|id| {
+                let mut batch = empty_batch(id);
+                batch.files.push(FileForAnalysis {
+                    path: format!("src/file_{}.rs", id),
+                    content: String::new(),
+                    lines: 1,
+                    score: None,
+                    category: FileCategory::Audit,
+                    content_hash: format!("hash-{}", id),
+                });
+                batch
+            })
+            .collect();
+        let batch_set = BatchCheckpoint::fingerprint(&batches);
+        let ok = |batch_id: usize| BatchAnalysisResult {
+            batch_id,
+            file_results: Vec::new(),
+            batch_insights: None,
+            total_tokens: TokenUsage {
+                prompt_tokens: 100,
+                total_tokens: 100,
+                ..TokenUsage::default()
+            },
+            processing_time_ms: 0,
+            tool_calls_count: 0,
+        };
+
+        // First run fails batch 2: the prefix stops there, 3 and 4 are stored
+        let first = run_batches(
+            batches.clone(),
+            1,
+            None,
+            Some(&store),
+            None,
+            |batch| async move {
+                if batch.batch_id == 2 {
+                    return Err(AuditError::other("process killed"));
+                }
+                Ok(ok(batch.batch_id))
+            },
+        )
+        .await;
+        assert_eq!(first.resumed_from, None);
+        let saved = store.load(&batch_set).await.unwrap().unwrap();
+        assert_eq!(saved.next_batch, 2);
+        assert_eq!(saved.tokens.prompt_tokens, 200);
+        let mut completed_files = saved.completed_files.clone();
+        completed_files.sort();
+        assert_eq!(
+            completed_files,
+            vec!["hash-0", "hash-1", "hash-3", "hash-4"]
+        );
+
+        // Second run only redoes batch 2 and re-serves the stored results
+        let dispatched = std::sync::Mutex::new(Vec::new());
+        let second = run_batches(batches.clone(), 2, None, Some(&store), None, |batch| {
+            dispatched.lock().unwrap().push(batch.batch_id);
+            async move { Ok(ok(batch.batch_id)) }
+        })
+        .await;
+        assert_eq!(dispatched.into_inner().unwrap(), vec![2]);
+        assert_eq!(second.resumed_from, Some(2));
+        let ids: Vec<usize> = second
+            .results
+            .iter()
+            .map(|r| r.as_ref().unwrap().batch_id)
+            .collect();
+        assert_eq!(ids, vec![0, 1, 2, 3, 4]);
+        // Each batch's usage is counted exactly once
+        assert_eq!(second.tokens.prompt_tokens, 500);
+
+        // Completed cleanly, so the checkpoint is gone
+        assert!(store.load(&batch_set).await.unwrap().is_none());
+
+        // A different set of batches never resumes another run's checkpoint
+        store.save(&saved).await.unwrap();
+        let other = run_batches(
+            batches[..3].to_vec(),
+            1,
+            None,
+            Some(&store),
+            None,
+            |batch| async move { Ok(ok(batch.batch_id)) },
+        )
+        .await;
+        assert_eq!(other.resumed_from, None);
+        assert_eq!(other.results.len(), 3);
+    }
+
+    #[test]
+    fn test_outgoing_content_redacts_secrets_only_when_enabled() {
+        let file = FileForAnalysis {
+            path: "src/client.rs".to_string(),
+            content: "fn client() {\n    let api_key = \"sk_live_0123456789abcdefXYZ\";\n}\n"
+                .to_string(),
+            lines: 3,
+            score: None,
+            category: FileCategory::Audit,
+            content_hash: "abc".to_string(),
+        };
+        let client = GrokReasoningClient::new("test".to_string()).unwrap();
+        assert_eq!(client.outgoing_content(&file).content, file.content);
+
+        let client = client.with_secret_redaction(true);
+        let outgoing = client.outgoing_content(&file);
+        assert!(outgoing.is_redacted());
+        assert!(!outgoing.content.contains("sk_live_0123456789abcdefXYZ"));
+        assert_eq!(outgoing.restore(), file.content);
+    }
+
+    #[test]
+    fn test_token_usage_cost_bills_cached_and_reasoning_tokens() {
+        let usage = TokenUsage {
+            prompt_tokens: 1_000_000,
+            completion_tokens: 500_000,
+            reasoning_tokens: 500_000,
+            cached_tokens: 400_000,
+            total_tokens: 2_000_000,
+        };
+        // 0.6M input, 0.4M cached, 1M output
+        assert!((usage.cost_usd() - (0.12 + 0.02 + 0.50)).abs() < 1e-9);
+    }
+
     #[test]
     fn test_file_category_debug() {
         assert_eq!(format!("{:?}", FileCategory::Audit), "Audit");
         assert_eq!(format!("{:?}", FileCategory::Janus), "Janus");
         assert_eq!(format!("{:?}", FileCategory::Clients), "Clients");
     }
+
+    /// Serve an OpenAI-style completion at `/v1/chat/completions`, with or
+    /// without a `usage` block
+    async fn mock_local_server(with_usage: bool) -> String {
+        use axum::{routing::post, Json, Router};
+
+        let app = Router::new().route(
+            "/v1/chat/completions",
+            post(move |Json(req): Json<serde_json::Value>| async move {
+                assert_eq!(req["model"], "qwen2.5-coder");
+                assert_eq!(req["messages"][0]["role"], "system");
+                let mut body = serde_json::json!({
+                    "id": "chatcmpl-1",
+                    "object": "chat.completion",
+                    "choices": [{
+                        "index": 0,
+                        "message": {
+                            "role": "assistant",
+                            "content": "{\"path\": \"src/lib.rs\", \"overall_score\": 82.0, \"summary\": \"Tidy\"}"
+                        },
+                        "finish_reason": "stop"
+                    }]
+                });
+                if with_usage {
+                    body["usage"] = serde_json::json!({
+                        "prompt_tokens": 120,
+                        "completion_tokens": 30,
+                        "total_tokens": 150
+                    });
+                }
+                Json(body)
+            }),
+        );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        format!("http://{}/v1", addr)
+    }
+
+    #[tokio::test]
+    async fn test_local_provider_chat_completions() {
+        let file = FileForAnalysis {
+            path: "src/lib.rs".to_string(),
+            content: "pub fn add(a: i32, b: i32) -> i32 { a + b }".to_string(),
+            lines: 1,
+            score: None,
+            category: FileCategory::Audit,
+            content_hash: "abc".to_string(),
+        };
+
+        let client =
+            GrokReasoningClient::local(mock_local_server(true).await, "qwen2.5-coder").unwrap();
+        let result = client.analyze_file(&file).await.unwrap();
+        assert_eq!(result.overall_score, 82.0);
+        assert_eq!(result.summary, "Tidy");
+        assert_eq!(result.tokens_used.prompt_tokens, 120);
+        assert_eq!(result.tokens_used.completion_tokens, 30);
+        assert_eq!(result.tokens_used.total_tokens, 150);
+
+        // No `usage` block: fall back to estimated tokens
+        let client =
+            GrokReasoningClient::local(mock_local_server(false).await, "qwen2.5-coder").unwrap();
+        let result = client.analyze_file(&file).await.unwrap();
+        let usage = &result.tokens_used;
+        assert!(usage.prompt_tokens > 0);
+        assert!(usage.completion_tokens > 0);
+        assert_eq!(
+            usage.total_tokens,
+            usage.prompt_tokens + usage.completion_tokens
+        );
+    }
+
+    /// Serve `/{scenario}/chat/completions` with a canned failure, counting
+    /// requests
+    async fn mock_failing_server(hits: std::sync::Arc<AtomicUsize>) -> String {
+        use axum::extract::Path as UrlPath;
+        use axum::http::{HeaderMap, StatusCode};
+        use axum::{routing::post, Router};
+
+        let app = Router::new().route(
+            "/:scenario/chat/completions",
+            post(move |UrlPath(scenario): UrlPath<String>| {
+                hits.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    let mut headers = HeaderMap::new();
+                    match scenario.as_str() {
+                        "limited" => {
+                            headers.insert("retry-after", "12".parse().unwrap());
+                            (StatusCode::TOO_MANY_REQUESTS, headers, "slow down")
+                        }
+                        "auth" => (StatusCode::UNAUTHORIZED, headers, "invalid api key"),
+                        _ => (StatusCode::OK, headers, "{\"choices\": [tru"),
+                    }
+                }
+            }),
+        );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_api_failures_map_to_error_variants() {
+        let hits = std::sync::Arc::new(AtomicUsize::new(0));
+        let base_url = mock_failing_server(std::sync::Arc::clone(&hits)).await;
+        let file = FileForAnalysis {
+            path: "src/lib.rs".to_string(),
+            content: "pub fn add(a: i32, b: i32) -> i32 { a + b }".to_string(),
+            lines: 1,
+            score: None,
+            category: FileCategory::Audit,
+            content_hash: "abc".to_string(),
+        };
+        let analyze = |scenario: &str, max_retries: usize| {
+            let mut client =
+                GrokReasoningClient::local(format!("{}/{}", base_url, scenario), "qwen2.5-coder")
+                    .unwrap();
+            client.set_retry_config(RetryConfig {
+                max_retries,
+                ..Default::default()
+            });
+            let file = file.clone();
+            async move { client.analyze_file(&file).await.unwrap_err() }
+        };
+        let take_hits = || hits.swap(0, Ordering::SeqCst);
+
+        let err = analyze("limited", 0).await;
+        assert!(
+            matches!(err, AuditError::RateLimited { retry_after: Some(d) } if d.as_secs() == 12),
+            "{:?}",
+            err
+        );
+        assert!(err.is_retryable());
+        take_hits();
+
+        // Terminal: not retried even with retries left
+        let err = analyze("auth", 3).await;
+        assert!(matches!(err, AuditError::AuthFailed), "{:?}", err);
+        assert!(err.is_terminal());
+        assert_eq!(take_hits(), 1);
+
+        let err = analyze("garbled", 0).await;
+        assert!(matches!(err, AuditError::ResponseParse { .. }), "{:?}", err);
+        take_hits();
+
+        // A blocked prompt never reaches the endpoint
+        let client = GrokReasoningClient::local(format!("{}/garbled", base_url), "qwen2.5-coder")
+            .unwrap()
+            .with_prompt_guard(Arc::new(
+                prompt_guard::DefaultPromptGuard::default().with_max_chars(10),
+            ));
+        assert!(client.analyze_file(&file).await.is_err());
+        assert_eq!(take_hits(), 0);
+    }
 }
@@ -34,7 +34,8 @@
 
 use crate::chunking::{chunk_document, ChunkConfig};
 use crate::db::{create_chunks, get_document, mark_document_indexed, store_embedding};
-use crate::embeddings::{EmbeddingConfig, EmbeddingGenerator};
+use crate::embeddings::{BatchedEmbedder, Embedder, EmbeddingConfig, EmbeddingGenerator};
+use crate::progress::{self, ProgressReporter};
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
@@ -181,19 +182,35 @@ impl std::fmt::Display for IndexingStage {
 /// Main document indexer that orchestrates the indexing pipeline
 pub struct DocumentIndexer {
     config: IndexingConfig,
-    embedding_generator: Arc<EmbeddingGenerator>,
+    embedder: Arc<dyn Embedder>,
+    progress: Arc<dyn ProgressReporter>,
 }
 
 impl DocumentIndexer {
-    /// Create a new document indexer
+    /// Create a new document indexer using the local fastembed model from
+    /// `config.embedding_config`
     pub async fn new(config: IndexingConfig) -> Result<Self> {
         let embedding_generator = EmbeddingGenerator::new(config.embedding_config.clone())
             .context("Failed to create embedding generator")?;
 
-        Ok(Self {
+        Ok(Self::with_embedder(config, Arc::new(embedding_generator)))
+    }
+
+    /// Create a document indexer backed by any [`Embedder`]. Requests are
+    /// split into `config.max_batch_size` batches, retrying failed batches.
+    pub fn with_embedder(config: IndexingConfig, embedder: Arc<dyn Embedder>) -> Self {
+        let embedder = BatchedEmbedder::new(embedder).with_batch_size(config.max_batch_size);
+        Self {
             config,
-            embedding_generator: Arc::new(embedding_generator),
-        })
+            embedder: Arc::new(embedder),
+            progress: progress::noop(),
+        }
+    }
+
+    /// Report [`DocumentIndexer::index_documents`] progress to `reporter`
+    pub fn with_progress(mut self, reporter: Arc<dyn ProgressReporter>) -> Self {
+        self.progress = reporter;
+        self
     }
 
     /// Index a document by ID
@@ -237,26 +254,12 @@ impl DocumentIndexer {
 
         // Stage 3: Generate embeddings in batches
         tracing::debug!("Generating embeddings for {} chunks", chunks.len());
-        let mut all_embeddings = Vec::new();
-
-        for (batch_idx, chunk_batch) in chunks.chunks(self.config.max_batch_size).enumerate() {
-            let batch_texts: Vec<&str> = chunk_batch.iter().map(|c| c.content.as_str()).collect();
-
-            tracing::debug!(
-                "Processing batch {}/{} ({} chunks)",
-                batch_idx + 1,
-                chunks.len().div_ceil(self.config.max_batch_size),
-                batch_texts.len()
-            );
-
-            let batch_embeddings = self
-                .embedding_generator
-                .embed_batch(&batch_texts)
-                .await
-                .context("Failed to generate embeddings")?;
-
-            all_embeddings.extend(batch_embeddings);
-        }
+        let texts: Vec<String> = chunks.iter().map(|c| c.content.clone()).collect();
+        let all_embeddings = self
+            .embedder
+            .embed(&texts)
+            .await
+            .context("Failed to generate embeddings")?;
 
         tracing::info!("Generated {} embeddings", all_embeddings.len());
 
@@ -281,13 +284,13 @@ impl DocumentIndexer {
             .context("Failed to store chunks")?;
 
         // Store embeddings
-        let model_name = self.embedding_generator.model_name();
+        let model_name = self.embedder.model_id();
 
         for (idx, (chunk, embedding)) in db_chunks.iter().zip(all_embeddings.iter()).enumerate() {
             store_embedding(
                 pool,
                 chunk.id.clone(),
-                embedding.vector.clone(),
+                embedding.clone(),
                 model_name.to_string(),
             )
             .await
@@ -308,7 +311,7 @@ impl DocumentIndexer {
             all_embeddings.len(),
             total_words,
             model_name.to_string(),
-            self.embedding_generator.dimension(),
+            all_embeddings.first().map_or(0, Vec::len),
             was_reindexed,
         );
 
@@ -328,6 +331,8 @@ impl DocumentIndexer {
         document_ids: &[&str],
     ) -> Result<Vec<IndexingResult>> {
         let mut results = Vec::new();
+        self.progress
+            .start("Indexing documents", document_ids.len() as u64);
 
         for (idx, document_id) in document_ids.iter().enumerate() {
             tracing::info!(
@@ -344,8 +349,14 @@ impl DocumentIndexer {
                     // Continue with other documents
                 }
             }
+            self.progress.advance(document_id, (idx + 1) as u64);
         }
 
+        self.progress.finish(&format!(
+            "Indexed {}/{} documents",
+            results.len(),
+            document_ids.len()
+        ));
         Ok(results)
     }
 
@@ -354,9 +365,9 @@ impl DocumentIndexer {
         &self.config
     }
 
-    /// Get the embedding generator
-    pub fn embedding_generator(&self) -> &EmbeddingGenerator {
-        &self.embedding_generator
+    /// Get the embedding backend
+    pub fn embedder(&self) -> &Arc<dyn Embedder> {
+        &self.embedder
     }
 }
 
@@ -368,6 +379,7 @@ impl DocumentIndexer {
 pub struct BatchIndexer {
     indexer: Arc<DocumentIndexer>,
     concurrency: usize,
+    progress: Arc<dyn ProgressReporter>,
 }
 
 impl BatchIndexer {
@@ -377,13 +389,34 @@ impl BatchIndexer {
         Ok(Self {
             indexer: Arc::new(indexer),
             concurrency: concurrency.max(1),
+            progress: progress::noop(),
         })
     }
 
+    /// Create a batch indexer backed by any [`Embedder`]
+    pub fn with_embedder(
+        config: IndexingConfig,
+        embedder: Arc<dyn Embedder>,
+        concurrency: usize,
+    ) -> Self {
+        Self {
+            indexer: Arc::new(DocumentIndexer::with_embedder(config, embedder)),
+            concurrency: concurrency.max(1),
+            progress: progress::noop(),
+        }
+    }
+
+    /// Report [`BatchIndexer::index_batch`] progress to `reporter`, one
+    /// advance per finished document in completion order
+    pub fn with_progress(mut self, reporter: Arc<dyn ProgressReporter>) -> Self {
+        self.progress = reporter;
+        self
+    }
+
     /// Index `document_ids` concurrently, gated by a semaphore of size `self.concurrency`.
     ///
     /// Individual document failures are logged and skipped — the method always
-    /// returns the results for documents that succeeded.
+    /// returns the results for documents that succeeded, ordered by document id.
     pub async fn index_batch(
         &self,
         pool: &PgPool,
@@ -428,13 +461,21 @@ impl BatchIndexer {
         }
 
         let mut results = Vec::with_capacity(document_ids.len());
+        let mut done = 0u64;
+        self.progress
+            .start("Indexing documents", document_ids.len() as u64);
 
         while let Some(outcome) = join_set.join_next().await {
+            done += 1;
             match outcome {
-                Ok(Some(result)) => results.push(result),
-                Ok(None) => {} // individual failure already logged
+                Ok(Some(result)) => {
+                    self.progress.advance(&result.document_id, done);
+                    results.push(result);
+                }
+                Ok(None) => self.progress.advance("failed", done), // failure already logged
                 Err(join_err) => {
                     tracing::error!(error = %join_err, "Batch index task panicked");
+                    self.progress.advance("panicked", done);
                 }
             }
         }
@@ -445,6 +486,12 @@ impl BatchIndexer {
             failed = document_ids.len() - results.len(),
             "Batch indexing complete"
         );
+        results.sort_by(|a, b| a.document_id.cmp(&b.document_id));
+        self.progress.finish(&format!(
+            "Indexed {}/{} documents",
+            results.len(),
+            document_ids.len()
+        ));
 
         Ok(results)
     }
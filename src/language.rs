@@ -0,0 +1,168 @@
+//! Source language detection
+//!
+//! [`FileLanguage`] is the single answer to "what language is this file and
+//! is it code?". The auto-scanner's skip logic, the code chunker, the file
+//! scorer and static analysis all go through it, so a language is either
+//! supported everywhere or nowhere.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Detected file language
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FileLanguage {
+    Rust,
+    Kotlin,
+    Python,
+    TypeScript,
+    JavaScript,
+    Go,
+    Java,
+    Shell,
+    Swift,
+    Cpp,
+    C,
+    Ruby,
+    Unknown,
+}
+
+/// Every extension [`FileLanguage::from_extension`] recognises as code
+pub const CODE_EXTENSIONS: &[&str] = &[
+    "rs", "kt", "kts", "py", "ts", "tsx", "js", "jsx", "go", "java", "sh", "bash", "zsh", "swift",
+    "cpp", "cxx", "cc", "hpp", "c", "h", "rb",
+];
+
+impl FileLanguage {
+    /// Detect language from the extension of `path` (case-insensitive)
+    pub fn from_extension(path: &str) -> Self {
+        let file_name = path.rsplit(['/', '\\']).next().unwrap_or(path);
+        let ext = match file_name.rsplit_once('.') {
+            Some((_, ext)) => ext.to_ascii_lowercase(),
+            None => return Self::Unknown,
+        };
+        match ext.as_str() {
+            "rs" => Self::Rust,
+            "kt" | "kts" => Self::Kotlin,
+            "py" => Self::Python,
+            "ts" | "tsx" => Self::TypeScript,
+            "js" | "jsx" => Self::JavaScript,
+            "go" => Self::Go,
+            "java" => Self::Java,
+            "sh" | "bash" | "zsh" => Self::Shell,
+            "swift" => Self::Swift,
+            "cpp" | "cxx" | "cc" | "hpp" => Self::Cpp,
+            "c" | "h" => Self::C,
+            "rb" => Self::Ruby,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// [`from_extension`](Self::from_extension) for a [`Path`]
+    pub fn from_path(path: &Path) -> Self {
+        Self::from_extension(&path.to_string_lossy())
+    }
+
+    /// Whether files in this language are source code worth scanning
+    pub fn is_code(&self) -> bool {
+        !matches!(self, Self::Unknown)
+    }
+
+    /// Get single-line comment prefix for this language
+    pub fn comment_prefix(&self) -> &'static str {
+        match self {
+            Self::Rust
+            | Self::Kotlin
+            | Self::TypeScript
+            | Self::JavaScript
+            | Self::Go
+            | Self::Java
+            | Self::Swift
+            | Self::Cpp
+            | Self::C => "//",
+            Self::Python | Self::Shell | Self::Ruby => "#",
+            Self::Unknown => "//",
+        }
+    }
+}
+
+impl std::fmt::Display for FileLanguage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Rust => write!(f, "rust"),
+            Self::Kotlin => write!(f, "kotlin"),
+            Self::Python => write!(f, "python"),
+            Self::TypeScript => write!(f, "typescript"),
+            Self::JavaScript => write!(f, "javascript"),
+            Self::Go => write!(f, "go"),
+            Self::Java => write!(f, "java"),
+            Self::Shell => write!(f, "shell"),
+            Self::Swift => write!(f, "swift"),
+            Self::Cpp => write!(f, "cpp"),
+            Self::C => write!(f, "c"),
+            Self::Ruby => write!(f, "ruby"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auto_scanner::AutoScannerConfig;
+    use crate::code_chunker::CodeChunker;
+    use crate::static_analysis::StaticAnalyzer;
+
+    #[test]
+    fn test_call_sites_agree_on_languages() {
+        let scanner = AutoScannerConfig::default();
+        let chunker = CodeChunker::new();
+        let analyzer = StaticAnalyzer::new();
+        let content = "fn main() {\n    println!(\"hi\");\n}\n";
+
+        for path in [
+            "src/main.rs",
+            "app/App.kt",
+            "build.gradle.kts",
+            "tool.py",
+            "web/index.tsx",
+            "web/Button.jsx",
+            "lib/util.js",
+            "cmd/main.go",
+            "Main.java",
+            "deploy.sh",
+            "View.swift",
+            "core.cpp",
+            "core.h",
+            "task.rb",
+            "README.md",
+            "Cargo.toml",
+            "Makefile",
+            "dir.d/config",
+            "LOUD.RS",
+        ] {
+            let language = FileLanguage::from_extension(path);
+            assert_eq!(
+                scanner.is_analyzable_file(path),
+                language.is_code(),
+                "scanner disagrees on {}",
+                path
+            );
+            assert_eq!(
+                analyzer.analyze(path, content).language,
+                language,
+                "{}",
+                path
+            );
+            for chunk in chunker.chunk_file(path, content, "repo") {
+                assert_eq!(chunk.language, language, "chunker disagrees on {}", path);
+            }
+        }
+
+        assert_eq!(FileLanguage::from_extension("LOUD.RS"), FileLanguage::Rust);
+        assert!(!FileLanguage::from_extension("dir.d/config").is_code());
+        assert!(FileLanguage::from_extension("web/Button.jsx").is_code());
+        for ext in CODE_EXTENSIONS {
+            assert!(FileLanguage::from_extension(&format!("f.{}", ext)).is_code());
+        }
+    }
+}
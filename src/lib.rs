@@ -25,11 +25,13 @@
 pub mod api;
 pub mod audit;
 pub mod auto_scanner;
+pub mod auto_tag;
 pub mod backup;
 pub mod cache;
 pub mod cache_layer;
 pub mod cache_migrate;
 pub mod chunking;
+pub mod ci_gate;
 pub mod cli;
 pub mod code_chunker;
 pub mod code_review;
@@ -40,30 +42,41 @@ pub mod cost_tracker;
 pub mod db;
 pub mod directory_tree;
 pub mod doc_generator;
+pub mod dto;
 pub mod embeddings;
 pub mod enhanced_scanner;
 pub mod error;
+pub mod findings_diff;
 pub mod formatter;
 pub mod git;
 pub mod github;
 pub mod grok_client;
 pub mod grok_reasoning;
+pub mod health;
+pub mod ideas;
 pub mod indexing;
+pub mod language;
 pub mod llm;
 pub mod llm_audit;
 pub mod llm_config;
 pub mod metrics;
+pub mod minification;
 pub mod model_router;
 pub mod multi_tenant;
 pub mod ollama_client;
 pub mod parser;
+pub mod progress;
+pub mod prompt_cache;
+pub mod prompt_guard;
 pub mod prompt_hashes;
 pub mod prompt_router;
 pub mod query_analytics;
 pub mod query_router;
 pub mod query_templates;
 pub mod queue;
+pub mod redaction;
 pub mod refactor_assistant;
+pub mod refactor_patch;
 pub mod repo_analysis;
 pub mod repo_cache;
 pub mod repo_cache_sql;
@@ -71,7 +84,12 @@ pub mod repo_manager;
 pub mod repo_sync;
 pub mod research;
 pub mod response_cache;
+pub mod review_comments;
+pub mod sarif;
+pub mod scan_progress;
+pub mod scan_report;
 pub mod scanner;
+pub mod schema;
 pub mod scoring;
 pub mod search;
 pub mod server;
@@ -99,6 +117,7 @@ pub use api::{
     RateLimiter, SearchRequest, SearchResponse, SearchType, UploadDocumentRequest,
     UploadDocumentResponse,
 };
+pub use auto_tag::{AutoTagger, CapturedNote, SuggestionSource, TagSuggestion};
 pub use cache::{AuditCache, CacheEntry, CacheStats};
 pub use cache_layer::{
     CacheConfig as CacheLayerConfig, CacheKey, CacheLayer, CacheStats as CacheLayerStats,
@@ -110,18 +129,19 @@ pub use cli::{
     QueueCommands, ReportCommands, ScanCommands, TaskCommands,
 };
 pub use code_chunker::{
-    compute_chunking_stats, compute_content_hash, ChunkerConfig, ChunkingStats, CodeChunk,
-    CodeChunker, DedupEntry, DedupIndex, EntityType,
+    call_adjacency, compute_chunking_stats, compute_content_hash, normalized_content_hash,
+    strip_comments, CallEdge, ChunkDelta, ChunkerConfig, ChunkingStats, CodeChunk, CodeChunker,
+    DedupEntry, DedupIndex, DedupReport, DuplicateSummary, EntityType, VectorSpace,
 };
 pub use code_review::{
     CodeReview, CodeReviewer, FileReview, IssueSeverity, ReviewIssue, ReviewStats,
 };
-pub use config::Config;
-pub use context::{ContextBuilder as OldContextBuilder, GlobalContextBundle};
+pub use config::{ApiAuthConfig, Config, PROFILE_ENV_VAR};
+pub use context::{ContextBuilder as OldContextBuilder, GlobalContextBundle, TruncationStrategy};
 pub use context_builder::{Context, ContextBuilder, ContextFile, QueryBuilder};
 pub use cost_tracker::{
-    BudgetStatus, CostStats, CostTracker, OperationCost, SavingsReport, StaticDecisionRecord,
-    TokenUsage,
+    BudgetStatus, CostStats, CostTracker, MonthlySpend, OperationCost, SavingsReport,
+    StaticDecisionRecord, TokenUsage,
 };
 pub use db::{
     add_repository, create_note, create_task, delete_note, get_next_task, get_note, get_repository,
@@ -132,17 +152,26 @@ pub use db::{
 pub use directory_tree::{DirectoryTreeBuilder, Hotspot, TreeSummary};
 pub use doc_generator::{DocGenerator, FunctionDoc, ModuleDoc, ParameterDoc, ReadmeContent};
 pub use embeddings::{
-    Embedding, EmbeddingConfig, EmbeddingGenerator, EmbeddingModelType, EmbeddingStats,
+    BatchedEmbedder, Embedder, Embedding, EmbeddingConfig, EmbeddingGenerator, EmbeddingModelType,
+    EmbeddingStats, HttpEmbedder,
+};
+pub use enhanced_scanner::{
+    EnhancedScanner, EnhancedScannerConfig, LanguageLoc, LanguageStats as ScannerLanguageStats,
 };
-pub use enhanced_scanner::EnhancedScanner;
 pub use error::{AuditError, Result};
 pub use formatter::{BatchFormatResult, CodeFormatter, FormatMode, FormatResult, Formatter};
-pub use git::GitManager;
+pub use git::{
+    BlameLine, ChangeKind, ChangedFile, CloneOptions, FileCommit, GitManager, RetryPolicy,
+    SubmoduleInfo,
+};
 pub use grok_client::{FileScoreResult, GrokClient, QuickAnalysisResult};
 pub use grok_reasoning::{
-    analyze_all_batches, BatchAnalysisResult, FileAnalysisResult as GrokFileAnalysisResult,
-    FileBatch, FileForAnalysis, GrokReasoningClient, IdentifiedIssue, Improvement, RetryConfig,
+    analyze_all_batches, BatchAnalysisResult, BatchCheckpoint, BatchCheckpointStore,
+    BatchRunSummary, FileAnalysisResult as GrokFileAnalysisResult, FileBatch, FileForAnalysis,
+    GrokReasoningClient, IdentifiedIssue, Improvement, RetryConfig,
 };
+pub use health::{health_router, shutdown_signal, HealthState, Shutdown, WorkerHealth};
+pub use ideas::{IdeaPromoter, IdeaPromotion};
 pub use indexing::{
     BatchIndexer, DocumentIndexer, IndexingConfig, IndexingProgress, IndexingResult, IndexingStage,
 };
@@ -157,15 +186,16 @@ pub use llm_audit::{
 };
 pub use llm_config::{
     claude_models, CacheConfig, FileSelectionConfig, LimitsConfig, LlmConfig, ProviderConfig,
-    LLM_CONFIG_FILE,
+    DEFAULT_LOCAL_BASE_URL, LLM_CONFIG_FILE,
 };
 pub use query_router::{Action, QueryIntent, QueryRouter, RoutingStats, UserContext};
 pub use query_templates::{QueryTemplate, TemplateCategory, TemplateRegistry};
 pub use queue::{
-    advance_stage, capture_note, capture_thought, capture_todo, enqueue, get_pending_items,
-    get_queue_item, get_queue_stats, get_retriable_items, mark_failed, update_analysis,
-    AnalysisResult, FileAnalysisResult as QueueFileAnalysisResult, LlmAnalyzer, ProcessorConfig,
-    QueueProcessor, QueueStats,
+    advance_stage, capture_note, capture_thought, capture_todo, enqueue, get_dead_letter_items,
+    get_pending_items, get_queue_item, get_queue_stats, get_retriable_items, mark_failed,
+    record_failure, requeue_dead_letter, update_analysis, AnalysisResult,
+    FileAnalysisResult as QueueFileAnalysisResult, LlmAnalyzer, ProcessorConfig, QueueProcessor,
+    QueueStats,
 };
 pub use refactor_assistant::{
     CodeLocation, CodeSmell, CodeSmellType, EffortEstimate, PlanStep, RefactorAssistant,
@@ -180,8 +210,8 @@ pub use repo_cache::{
     RepoCacheEntry,
 };
 pub use repo_cache_sql::{
-    CacheEntry as RepoCacheEntrySql, CacheStats as RepoCacheStatsSql, CacheTypeStats,
-    EvictionPolicy, ModelStats, RepoCacheSql,
+    CacheCompaction, CacheDbStats, CacheEntry as RepoCacheEntrySql,
+    CacheStats as RepoCacheStatsSql, CacheTypeStats, EvictionPolicy, ModelStats, RepoCacheSql,
 };
 
 pub use metrics::{
@@ -190,21 +220,28 @@ pub use metrics::{
     RequestTimer,
 };
 pub use multi_tenant::{QuotaType, Tenant, TenantManager, TenantQuota, TenantUsage, UsageMetric};
+pub use prompt_cache::{PromptCache, PromptCacheStats};
 pub use prompt_router::{
-    PromptRouter, PromptRouterConfig, PromptRoutingStats, PromptTier, TierKind,
+    FileAnalysisTemplate, PromptOverride, PromptRouter, PromptRouterConfig, PromptRoutingStats,
+    PromptTier, TierKind, FILE_ANALYSIS_TEMPLATE_PATH,
 };
 pub use query_analytics::{
     AnalyticsConfig, AnalyticsStats, QueryAnalytics, QueryPattern, SearchAnalytics,
 };
 pub use response_cache::{CacheStats as ResponseCacheStats, CachedResponse, ResponseCache};
+pub use review_comments::{PullRequestReview, ReviewComment};
+pub use sarif::{SarifLevel, SarifLog, SarifResult};
+pub use scan_progress::{ScanPhase, ScanProgressHub, ScanUpdate};
+#[allow(deprecated)]
 pub use scanner::{
     build_dir_tree, fetch_user_repos, get_dir_tree, get_unanalyzed_files, save_dir_tree,
     save_file_analysis, scan_directory_for_todos, scan_repo_for_todos, sync_repos_to_db,
     DetectedTodo, GitHubRepo, ScanResult, Scanner, TreeNode as ScannerTreeNode,
 };
 pub use scoring::{
-    CodebaseScore, ComplexityIndicators, FileScore, FileScorer, ScoreBreakdown, ScoringWeights,
-    TodoBreakdown,
+    detect_regressions, CodebaseScore, ComplexityIndicators, DiffVerdict, ExternalCodeMode,
+    FileScore, FileScorer, OwnershipRules, ScoreBreakdown, ScoreDiff, ScoreRegression,
+    ScoringWeights, TodoBreakdown, SCORE_REGRESSION_THRESHOLD,
 };
 pub use search::{
     SearchConfig, SearchFilters, SearchQuery, SearchResult, SearchResultMetadata, SearchStats,
@@ -217,8 +254,8 @@ pub use static_analysis::{
     SecurityFinding, SkipReason, StaticAnalysisResult, StaticAnalyzer, StaticAnalyzerConfig,
 };
 pub use tag_schema::{
-    CodeAge, CodeStatus, Complexity, DirectoryNode, IssuesSummary, NodeStats, NodeType, Priority,
-    SimpleIssueDetector, TagCategory, TagSchema, TagValidation,
+    CodeAge, CodeStatus, Complexity, DirectoryNode, FrozenViolation, IssuesSummary, NodeStats,
+    NodeType, Priority, SimpleIssueDetector, TagCategory, TagSchema, TagValidation,
 };
 pub use tags::TagScanner;
 pub use tasks::TaskGenerator;
@@ -227,7 +264,7 @@ pub use test_generator::{
     Fixture, GeneratedTests, TestCase, TestFramework, TestGapAnalysis, TestGenerator, TestType,
     UntestFunction,
 };
-pub use tests_runner::{TestResults, TestRunner};
+pub use tests_runner::{RustTestRunner, TestResults, TestRunner};
 pub use todo_scanner::{TodoItem, TodoPriority, TodoScanner, TodoSummary};
 pub use token_budget::{BudgetConfig, ModelTokenStats, MonthlyTracker, TokenPricing, TokenStats};
 pub use tree_state::{
@@ -270,15 +307,17 @@ pub mod prelude {
     };
     pub use crate::directory_tree::{DirectoryTreeBuilder, Hotspot, TreeSummary};
     pub use crate::embeddings::{
-        Embedding, EmbeddingConfig, EmbeddingGenerator, EmbeddingModelType, EmbeddingStats,
+        BatchedEmbedder, Embedder, Embedding, EmbeddingConfig, EmbeddingGenerator,
+        EmbeddingModelType, EmbeddingStats, HttpEmbedder,
     };
     pub use crate::enhanced_scanner::EnhancedScanner;
     pub use crate::error::{AuditError, Result};
     pub use crate::git::GitManager;
     pub use crate::grok_client::{FileScoreResult, GrokClient, QuickAnalysisResult};
     pub use crate::grok_reasoning::{
-        analyze_all_batches, BatchAnalysisResult, FileAnalysisResult as GrokFileAnalysisResult,
-        FileBatch, FileForAnalysis, GrokReasoningClient, IdentifiedIssue, Improvement, RetryConfig,
+        analyze_all_batches, BatchAnalysisResult, BatchRunSummary,
+        FileAnalysisResult as GrokFileAnalysisResult, FileBatch, FileForAnalysis,
+        GrokReasoningClient, IdentifiedIssue, Improvement, RetryConfig,
     };
     pub use crate::indexing::{
         BatchIndexer, DocumentIndexer, IndexingConfig, IndexingProgress, IndexingResult,
@@ -291,10 +330,11 @@ pub mod prelude {
     pub use crate::query_router::{Action, QueryIntent, QueryRouter, RoutingStats, UserContext};
     pub use crate::query_templates::{QueryTemplate, TemplateCategory, TemplateRegistry};
     pub use crate::queue::{
-        advance_stage, capture_note, capture_thought, capture_todo, enqueue, get_pending_items,
-        get_queue_item, get_queue_stats, get_retriable_items, mark_failed, update_analysis,
-        AnalysisResult, FileAnalysisResult as QueueFileAnalysisResult, LlmAnalyzer,
-        ProcessorConfig, QueueProcessor, QueueStats,
+        advance_stage, capture_note, capture_thought, capture_todo, enqueue, get_dead_letter_items,
+        get_pending_items, get_queue_item, get_queue_stats, get_retriable_items, mark_failed,
+        record_failure, requeue_dead_letter, update_analysis, AnalysisResult,
+        FileAnalysisResult as QueueFileAnalysisResult, LlmAnalyzer, ProcessorConfig,
+        QueueProcessor, QueueStats,
     };
     pub use crate::repo_analysis::{
         FileMetadata, LanguageStats, RepoAnalyzer, RepoNodeType, RepoTree, TreeNode,
@@ -305,6 +345,7 @@ pub mod prelude {
     pub use crate::response_cache::{
         CacheStats as ResponseCacheStats, CachedResponse, ResponseCache,
     };
+    #[allow(deprecated)]
     pub use crate::scanner::{
         build_dir_tree, fetch_user_repos, get_dir_tree, get_unanalyzed_files, save_dir_tree,
         save_file_analysis, scan_directory_for_todos, scan_repo_for_todos, sync_repos_to_db,
@@ -325,7 +366,7 @@ pub mod prelude {
     };
     pub use crate::tags::TagScanner;
     pub use crate::tasks::TaskGenerator;
-    pub use crate::tests_runner::{TestResults, TestRunner};
+    pub use crate::tests_runner::{RustTestRunner, TestResults, TestRunner};
     pub use crate::todo_scanner::{TodoItem, TodoPriority, TodoScanner, TodoSummary};
     pub use crate::tree_state::{
         CategoryChangeSummary, ChangeType, DiffSummary, FileCategory, FileChange, FileState,
@@ -19,7 +19,7 @@ pub struct LlmClient {
     api_key: String,
     /// Model name
     model: String,
-    /// LLM provider (xai, google)
+    /// LLM provider (xai, google, anthropic, local)
     provider: String,
     /// Base URL
     base_url: String,
@@ -69,6 +69,7 @@ impl LlmClient {
             "google" | "gemini" => "https://generativelanguage.googleapis.com/v1beta".to_string(),
             "xai" | "grok" => "https://api.x.ai/v1".to_string(),
             "anthropic" | "claude" => "https://api.anthropic.com/v1".to_string(),
+            "local" => crate::llm_config::DEFAULT_LOCAL_BASE_URL.to_string(),
             _ => {
                 warn!("Unknown provider '{}', defaulting to XAI", provider);
                 "https://api.x.ai/v1".to_string()
@@ -91,6 +92,12 @@ impl LlmClient {
         })
     }
 
+    /// Override the API base URL (e.g. where a `local` server listens)
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
     /// Analyze a file with LLM
     pub async fn analyze_file(
         &self,
@@ -134,7 +141,8 @@ impl LlmClient {
     /// Call the LLM API
     async fn call_llm(&self, system: &str, user: &str) -> Result<LlmAnalysisResult> {
         match self.provider.as_str() {
-            "xai" | "grok" => self.call_xai(system, user).await,
+            // A local OpenAI-compatible server takes the same request shape as xAI
+            "xai" | "grok" | "local" => self.call_xai(system, user).await,
             "google" | "gemini" => self.call_google(system, user).await,
             "anthropic" | "claude" => self.call_anthropic(system, user).await,
             _ => Err(AuditError::other(format!(
@@ -201,29 +209,27 @@ impl LlmClient {
             max_tokens: self.max_tokens,
         };
 
-        let response = self
+        let mut builder = self
             .client
             .post(format!("{}/chat/completions", self.base_url))
-            .header("Authorization", format!("Bearer {}", self.api_key))
-            .header("Content-Type", "application/json")
+            .header("Content-Type", "application/json");
+        if !self.api_key.is_empty() {
+            builder = builder.header("Authorization", format!("Bearer {}", self.api_key));
+        }
+        let response = builder
             .json(&request)
             .send()
             .await
             .map_err(|e| AuditError::other(format!("XAI API request failed: {}", e)))?;
 
         if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            return Err(AuditError::other(format!(
-                "XAI API error {}: {}",
-                status, body
-            )));
+            return Err(AuditError::from_llm_response("XAI", response).await);
         }
 
         let data: XaiResponse = response
             .json()
             .await
-            .map_err(|e| AuditError::other(format!("Failed to parse XAI response: {}", e)))?;
+            .map_err(|e| AuditError::response_parse(format!("XAI response: {}", e)))?;
 
         let content = data
             .choices
@@ -231,7 +237,12 @@ impl LlmClient {
             .map(|c| c.message.content.clone())
             .unwrap_or_default();
 
-        let tokens_used = data.usage.and_then(|u| u.total_tokens);
+        let mut tokens_used = data.usage.and_then(|u| u.total_tokens);
+        if tokens_used.is_none() && self.provider == "local" {
+            // Local servers may omit `usage`; estimate instead
+            let chars = system.len() + user.len() + content.len();
+            tokens_used = Some((chars as f64 * crate::grok_reasoning::TOKENS_PER_CHAR) as usize);
+        }
 
         Ok(LlmAnalysisResult {
             summary: content.lines().take(3).collect::<Vec<_>>().join(" "),
@@ -323,18 +334,13 @@ impl LlmClient {
             .map_err(|e| AuditError::other(format!("Gemini API request failed: {}", e)))?;
 
         if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            return Err(AuditError::other(format!(
-                "Gemini API error {}: {}",
-                status, body
-            )));
+            return Err(AuditError::from_llm_response("Gemini", response).await);
         }
 
         let data: GeminiResponse = response
             .json()
             .await
-            .map_err(|e| AuditError::other(format!("Failed to parse Gemini response: {}", e)))?;
+            .map_err(|e| AuditError::response_parse(format!("Gemini response: {}", e)))?;
 
         let content = data
             .candidates
@@ -415,18 +421,13 @@ impl LlmClient {
             .map_err(|e| AuditError::other(format!("Claude API request failed: {}", e)))?;
 
         if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            return Err(AuditError::other(format!(
-                "Claude API error {}: {}",
-                status, body
-            )));
+            return Err(AuditError::from_llm_response("Claude", response).await);
         }
 
         let data: ClaudeResponse = response
             .json()
             .await
-            .map_err(|e| AuditError::other(format!("Failed to parse Claude response: {}", e)))?;
+            .map_err(|e| AuditError::response_parse(format!("Claude response: {}", e)))?;
 
         let content = data
             .content
@@ -2,12 +2,17 @@
 //!
 //! Uses xAI's Grok API to analyze content and files for the processing queue.
 
-use crate::queue::processor::{AnalysisResult, FileAnalysisResult, LlmAnalyzer};
+use crate::prompt_guard::{self, PromptGuard};
+use crate::queue::processor::{
+    AnalysisResult, BreakdownTask, FileAnalysisResult, IdeaBreakdown, LlmAnalyzer, SuggestedTag,
+    TagSuggestions,
+};
 use anyhow::Result;
 use async_trait::async_trait;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::sync::Arc;
 use std::time::Duration;
 use tracing::{debug, error};
 
@@ -28,6 +33,8 @@ pub struct GrokAnalyzer {
     api_key: String,
     /// Track token usage for cost management
     tokens_used: std::sync::atomic::AtomicU64,
+    /// Checks every prompt before it is sent
+    guard: Arc<dyn PromptGuard>,
 }
 
 impl GrokAnalyzer {
@@ -41,9 +48,16 @@ impl GrokAnalyzer {
             client,
             api_key,
             tokens_used: std::sync::atomic::AtomicU64::new(0),
+            guard: prompt_guard::default_guard(),
         }
     }
 
+    /// Replace the default pre-send guard
+    pub fn with_prompt_guard(mut self, guard: Arc<dyn PromptGuard>) -> Self {
+        self.guard = guard;
+        self
+    }
+
     pub fn tokens_used(&self) -> u64 {
         self.tokens_used.load(std::sync::atomic::Ordering::Relaxed)
     }
@@ -55,11 +69,26 @@ impl GrokAnalyzer {
         user_prompt: &str,
         json_mode: bool,
     ) -> Result<(String, Option<usize>)> {
+        let (content, usage) = self
+            .call_grok_with_usage(model, system_prompt, user_prompt, json_mode)
+            .await?;
+        Ok((content, usage.map(|u| u.total_tokens as usize)))
+    }
+
+    /// Like `call_grok`, but returns the full prompt/completion token split
+    async fn call_grok_with_usage(
+        &self,
+        model: &str,
+        system_prompt: &str,
+        user_prompt: &str,
+        json_mode: bool,
+    ) -> Result<(String, Option<GrokUsage>)> {
+        let user_prompt = prompt_guard::guard_prompt(self.guard.as_ref(), user_prompt)?;
         let mut payload = json!({
             "model": model,
             "messages": [
                 {"role": "system", "content": system_prompt},
-                {"role": "user", "content": user_prompt}
+                {"role": "user", "content": user_prompt.as_ref()}
             ],
             "temperature": 0.3,
             "max_tokens": 2048
@@ -88,8 +117,7 @@ impl GrokAnalyzer {
         let result: GrokResponse = response.json().await?;
 
         // Track usage
-        let tokens = result.usage.as_ref().map(|u| u.total_tokens as usize);
-        if let Some(usage) = result.usage {
+        if let Some(ref usage) = result.usage {
             self.tokens_used.fetch_add(
                 usage.total_tokens as u64,
                 std::sync::atomic::Ordering::Relaxed,
@@ -107,7 +135,7 @@ impl GrokAnalyzer {
             .map(|c| c.message.content.clone())
             .unwrap_or_default();
 
-        Ok((content, tokens))
+        Ok((content, result.usage))
     }
 }
 
@@ -129,6 +157,10 @@ struct GrokMessage {
 
 #[derive(Debug, Deserialize)]
 struct GrokUsage {
+    #[serde(default)]
+    prompt_tokens: u32,
+    #[serde(default)]
+    completion_tokens: u32,
     total_tokens: u32,
 }
 
@@ -301,6 +333,99 @@ Guidelines:
             tokens_used: tokens,
         })
     }
+
+    async fn breakdown_idea(&self, idea: &str) -> Result<IdeaBreakdown> {
+        let system_prompt = r#"You are a pragmatic technical project planner for a solo developer.
+Break the provided idea down into concrete, actionable tasks that together deliver it.
+
+You must respond with valid JSON in this exact format:
+{
+    "tasks": [
+        {
+            "title": "Short imperative task title",
+            "description": "What needs to be done and how to tell it is finished",
+            "priority": 1-4
+        }
+    ]
+}
+
+Guidelines:
+- Each task should be completable in a single focused session (under a day)
+- Titles start with a verb (Add, Fix, Write, Measure, ...)
+- Order tasks in the sequence they should be done
+- priority: 1 critical, 2 high, 3 medium, 4 low
+- Return between 1 and 10 tasks; do not include vague or research-only filler"#;
+
+        let user_prompt = format!("Idea to break down:\n{}", idea);
+
+        let (response, usage) = self
+            .call_grok_with_usage(DEFAULT_MODEL, system_prompt, &user_prompt, true)
+            .await?;
+
+        #[derive(Deserialize)]
+        struct BreakdownResponse {
+            #[serde(default)]
+            tasks: Vec<BreakdownTask>,
+        }
+
+        let parsed: BreakdownResponse = serde_json::from_str(&response)
+            .map_err(|e| anyhow::anyhow!("Failed to parse Grok response: {} - {}", e, response))?;
+
+        Ok(IdeaBreakdown {
+            tasks: parsed.tasks,
+            model: DEFAULT_MODEL.to_string(),
+            input_tokens: usage.as_ref().map(|u| u.prompt_tokens as u64).unwrap_or(0),
+            output_tokens: usage
+                .as_ref()
+                .map(|u| u.completion_tokens as u64)
+                .unwrap_or(0),
+        })
+    }
+
+    async fn suggest_tags(&self, content: &str, vocabulary: &[String]) -> Result<TagSuggestions> {
+        let system_prompt = format!(
+            r#"You classify short developer notes using a fixed tag vocabulary.
+Only use tags from this list: {}
+
+You must respond with valid JSON in this exact format:
+{{
+    "tags": [
+        {{ "tag": "one of the allowed tags", "confidence": 0.0-1.0 }}
+    ]
+}}
+
+Guidelines:
+- Suggest at most 3 tags, most relevant first
+- Omit tags you are not reasonably sure about
+- Return an empty list if nothing fits"#,
+            vocabulary.join(", ")
+        );
+
+        let user_prompt = format!("Note to tag:\n{}", content);
+
+        let (response, usage) = self
+            .call_grok_with_usage(DEFAULT_MODEL, &system_prompt, &user_prompt, true)
+            .await?;
+
+        #[derive(Deserialize)]
+        struct TagResponse {
+            #[serde(default)]
+            tags: Vec<SuggestedTag>,
+        }
+
+        let parsed: TagResponse = serde_json::from_str(&response)
+            .map_err(|e| anyhow::anyhow!("Failed to parse Grok response: {} - {}", e, response))?;
+
+        Ok(TagSuggestions {
+            tags: parsed.tags,
+            model: DEFAULT_MODEL.to_string(),
+            input_tokens: usage.as_ref().map(|u| u.prompt_tokens as u64).unwrap_or(0),
+            output_tokens: usage
+                .as_ref()
+                .map(|u| u.completion_tokens as u64)
+                .unwrap_or(0),
+        })
+    }
 }
 
 // ============================================================================
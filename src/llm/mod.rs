@@ -2,6 +2,9 @@
 //!
 //! Provides LLM integration for code analysis and content processing.
 
+#[deprecated(
+    note = "Use grok_client::GrokClient (cached, cost-tracked) or model_router::ModelRouter"
+)]
 pub mod compat;
 pub mod grok;
 pub mod simple_client;
@@ -12,7 +15,8 @@ pub use grok::{
     TodoAnalysis,
 };
 
-// Re-export compatibility types
+// Re-export compatibility types (deprecated with the compat module)
+#[allow(deprecated)]
 pub use compat::{FileAuditResult, LlmAnalysisResult, LlmClient};
 
 // Re-export simple client for research system
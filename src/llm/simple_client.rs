@@ -3,7 +3,9 @@
 //! Provides a simplified interface for the research and backup systems
 //! to use the Grok LLM API.
 
+use crate::prompt_guard::{self, PromptGuard};
 use anyhow::Result;
+use std::sync::Arc;
 
 /// Simple Grok client for research system
 #[derive(Clone)]
@@ -11,6 +13,7 @@ pub struct GrokClient {
     api_key: String,
     model: String,
     base_url: String,
+    guard: Arc<dyn PromptGuard>,
 }
 
 impl GrokClient {
@@ -20,6 +23,7 @@ impl GrokClient {
             api_key,
             model: "grok-4.1".to_string(),
             base_url: "https://api.x.ai/v1".to_string(),
+            guard: prompt_guard::default_guard(),
         }
     }
 
@@ -34,18 +38,20 @@ impl GrokClient {
             api_key,
             model,
             base_url: "https://api.x.ai/v1".to_string(),
+            guard: prompt_guard::default_guard(),
         })
     }
 
     /// Generate a completion from Grok
     pub async fn generate(&self, prompt: &str, max_tokens: usize) -> Result<String> {
+        let prompt = prompt_guard::guard_prompt(self.guard.as_ref(), prompt)?;
         let client = reqwest::Client::new();
 
         let body = serde_json::json!({
             "messages": [
                 {
                     "role": "user",
-                    "content": prompt
+                    "content": prompt.as_ref()
                 }
             ],
             "model": self.model,
@@ -82,6 +88,12 @@ impl GrokClient {
         self.model = model.into();
         self
     }
+
+    /// Replace the default pre-send guard
+    pub fn with_prompt_guard(mut self, guard: Arc<dyn PromptGuard>) -> Self {
+        self.guard = guard;
+        self
+    }
 }
 
 #[cfg(test)]
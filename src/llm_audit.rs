@@ -4,12 +4,16 @@
 //! - **Regular Audit**: Holistic codebase analysis, entire codebase in context
 //! - **Full Audit**: File-by-file deep dive with scoring and master review
 
+// Still built on the deprecated llm::compat client
+#![allow(deprecated)]
+
 use crate::cache::AuditCache;
 use crate::error::Result;
 use crate::llm::LlmClient;
 use crate::llm_config::LlmConfig;
 use crate::scoring::{CodebaseScore, FileScore, TodoBreakdown};
 use crate::types::Category;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -106,7 +110,7 @@ pub struct FileAnalysis {
 }
 
 /// LLM analysis of a single file
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FileLlmAnalysis {
     /// Purpose of the file
     pub purpose: String,
@@ -147,7 +151,7 @@ pub struct FileRelationships {
 }
 
 /// Master review synthesizing full audit findings
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MasterReview {
     /// Executive summary
     pub executive_summary: String,
@@ -229,7 +233,7 @@ pub struct TechDebtArea {
 }
 
 /// Recommendation from audit
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Recommendation {
     /// Priority (Critical, High, Medium, Low)
     pub priority: String,
@@ -292,6 +296,12 @@ impl LlmAuditor {
                 "anthropic".to_string(),
                 32000, // Max output tokens for Claude
             ),
+            // Local OpenAI-compatible server (llama.cpp etc.) serving the configured model
+            "local" => (
+                config.provider.default_model.clone(),
+                "local".to_string(),
+                config.provider.max_tokens,
+            ),
             // Claude Sonnet 4 - balanced performance for routine audits
             "sonnet" => (
                 "claude-sonnet-4-20250514".to_string(),
@@ -305,13 +315,17 @@ impl LlmAuditor {
             ),
         };
 
-        let llm_client = LlmClient::new_with_provider(
+        let is_local = actual_provider == "local";
+        let mut llm_client = LlmClient::new_with_provider(
             api_key,
             actual_provider,
             model,
             max_tokens,
             config.provider.temperature,
         )?;
+        if is_local {
+            llm_client = llm_client.with_base_url(config.provider.local_base_url());
+        }
 
         // Initialize cache if enabled
         let cache = if config.cache.enabled {
@@ -642,6 +656,7 @@ impl LlmAuditor {
             },
             total_tech_debt: tech_debt,
             overall_health,
+            external: None,
         })
     }
 
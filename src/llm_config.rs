@@ -16,6 +16,10 @@ use tracing::{info, warn};
 /// LLM audit configuration file name
 pub const LLM_CONFIG_FILE: &str = ".llm-audit.toml";
 
+/// Base URL used by the `local` provider when `base_url` is not set
+/// (llama.cpp `server` default)
+pub const DEFAULT_LOCAL_BASE_URL: &str = "http://localhost:8080/v1";
+
 /// Configuration for LLM audits
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct LlmConfig {
@@ -33,6 +37,15 @@ pub struct LlmConfig {
 
     /// Cache settings
     pub cache: CacheConfig,
+
+    /// CI gate thresholds for `rustassistant check`
+    #[serde(default)]
+    pub gates: crate::ci_gate::GateConfig,
+
+    /// Path globs pinned to a prompt tier, first match wins (see
+    /// [`PromptRouter::with_override_config`](crate::prompt_router::PromptRouter::with_override_config))
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub prompt_overrides: Vec<crate::prompt_router::PromptOverride>,
 }
 
 /// File selection configuration
@@ -66,7 +79,9 @@ pub struct FileSelectionConfig {
 /// Provider configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProviderConfig {
-    /// Default provider (xai, google, anthropic)
+    /// Default provider (xai, google, anthropic, local)
+    ///
+    /// `local` talks to any OpenAI-compatible server at `base_url`.
     pub default_provider: String,
 
     /// Default model name
@@ -80,6 +95,17 @@ pub struct ProviderConfig {
 
     /// Temperature for LLM responses
     pub temperature: f64,
+
+    /// Base URL of the OpenAI-compatible endpoint for the `local` provider
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+}
+
+impl ProviderConfig {
+    /// Endpoint for the `local` provider
+    pub fn local_base_url(&self) -> &str {
+        self.base_url.as_deref().unwrap_or(DEFAULT_LOCAL_BASE_URL)
+    }
 }
 
 /// Cost and quota limits
@@ -170,6 +196,7 @@ impl Default for ProviderConfig {
             api_key: None,
             max_tokens: 16000,
             temperature: 0.2,
+            base_url: None,
         }
     }
 }
@@ -280,6 +307,14 @@ impl LlmConfig {
 
     /// Get API key for a specific provider
     pub fn get_api_key_for_provider(&self, provider: &str) -> Result<String> {
+        // Local servers usually run without auth, so a missing key is fine
+        if provider.eq_ignore_ascii_case("local") {
+            let key = std::env::var("LOCAL_LLM_API_KEY")
+                .ok()
+                .or_else(|| self.provider.api_key.clone());
+            return Ok(key.unwrap_or_default());
+        }
+
         // Determine which env var to check based on provider
         let env_var = match provider.to_lowercase().as_str() {
             "anthropic" | "claude" => "ANTHROPIC_API_KEY",
@@ -585,6 +620,20 @@ mod tests {
         assert!(config.enabled);
     }
 
+    #[test]
+    fn test_prompt_overrides_load_from_toml() {
+        let mut content = toml::to_string(&LlmConfig::default()).unwrap();
+        content.push_str("\n[[prompt_overrides]]\nglob = \"src/auth/**\"\ntier = \"DeepDive\"\n");
+
+        let config: LlmConfig = toml::from_str(&content).unwrap();
+        assert_eq!(config.prompt_overrides.len(), 1);
+        assert_eq!(config.prompt_overrides[0].glob, "src/auth/**");
+        assert_eq!(
+            config.prompt_overrides[0].tier,
+            crate::static_analysis::AnalysisRecommendation::DeepDive
+        );
+    }
+
     #[test]
     fn test_glob_match() {
         assert!(glob_match("**/*.rs", "src/main.rs"));
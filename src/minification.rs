@@ -0,0 +1,258 @@
+//! Minified / bundled file detection
+//!
+//! Minified output is expensive to send to the LLM and yields nothing useful,
+//! but "long lines" alone is a poor signal: Rust and Go sources with long
+//! string literals or tables have long lines too, while minified CSS can be a
+//! single line of any length. [`detect_minified`] combines several signals
+//! into a confidence instead of a yes/no answer:
+//!
+//! - **Line length** measured outside string literals, so long literals in
+//!   ordinary code don't count
+//! - **Whitespace ratio** — minifiers strip indentation and spacing
+//! - **Markers** left behind by bundlers (`sourceMappingURL`, webpack runtime)
+//! - **Language** — web languages are routinely minified, compiled languages
+//!   almost never, so the same signals weigh less for them
+//!
+//! The scanner only trusts the confidence for files whose lines are long and
+//! few enough (`AutoScannerConfig::minified_avg_line_threshold` and
+//! `minified_max_lines`), and skips any file with a line over
+//! [`MINIFIED_SINGLE_LINE_LEN`] outright.
+
+use crate::language::FileLanguage;
+use serde::{Deserialize, Serialize};
+
+/// Confidence at or above which a file is treated as minified
+pub const DEFAULT_MINIFIED_THRESHOLD: f64 = 0.6;
+
+/// Average line length a file must exceed before its confidence counts
+pub const DEFAULT_MINIFIED_AVG_LINE_LEN: usize = 500;
+
+/// Line count a file must stay under before its confidence counts
+pub const DEFAULT_MINIFIED_MAX_LINES: usize = 50;
+
+/// A single line longer than this (string literals included) marks a file as
+/// minified however many other lines it has, e.g. inlined CSS-in-JS
+pub const MINIFIED_SINGLE_LINE_LEN: usize = 5_000;
+
+/// Strings bundlers and minifiers leave in their output
+const MINIFIED_MARKERS: &[&str] = &[
+    "sourceMappingURL=",
+    "webpackBootstrap",
+    "__webpack_require__",
+    "!function(e,t){",
+    "!function(t,e){",
+    "(function(e,t){",
+];
+
+/// Measurements behind a [`MinificationReport::confidence`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MinificationReport {
+    /// 0.0 (ordinary source) to 1.0 (certainly minified)
+    pub confidence: f64,
+    /// Non-blank lines
+    pub lines: usize,
+    /// Average length of non-blank lines, string literals excluded
+    pub avg_line_len: usize,
+    /// Longest line, string literals excluded
+    pub max_line_len: usize,
+    /// Longest line, string literals included
+    pub longest_raw_line: usize,
+    /// Share of whitespace among characters outside string literals
+    pub whitespace_ratio: f64,
+    /// First bundler marker found, if any
+    pub marker: Option<String>,
+}
+
+impl MinificationReport {
+    /// Whether the confidence reaches `threshold`
+    pub fn is_minified(&self, threshold: f64) -> bool {
+        self.confidence >= threshold
+    }
+
+    /// Whether some line exceeds [`MINIFIED_SINGLE_LINE_LEN`]
+    pub fn has_overlong_line(&self) -> bool {
+        self.longest_raw_line > MINIFIED_SINGLE_LINE_LEN
+    }
+}
+
+/// Estimate how likely `content` (of the file at `path`) is minified
+pub fn detect_minified(path: &str, content: &str) -> MinificationReport {
+    let ext = path
+        .rsplit(['/', '\\'])
+        .next()
+        .and_then(|name| name.rsplit_once('.'))
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+        .unwrap_or_default();
+    let language = FileLanguage::from_extension(path);
+    // Single quotes delimit lifetimes and chars in Rust, not strings
+    let single_quote_strings = language != FileLanguage::Rust;
+
+    let mut lines = 0usize;
+    let mut total_len = 0usize;
+    let mut max_line_len = 0usize;
+    let mut longest_raw_line = 0usize;
+    let mut code_chars = 0usize;
+    let mut whitespace = 0usize;
+
+    for line in content.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let len = code_len(line, single_quote_strings, &mut whitespace);
+        lines += 1;
+        total_len += len;
+        code_chars += len;
+        max_line_len = max_line_len.max(len);
+        longest_raw_line = longest_raw_line.max(line.chars().count());
+    }
+
+    let avg_line_len = if lines > 0 { total_len / lines } else { 0 };
+    let whitespace_ratio = if code_chars > 0 {
+        whitespace as f64 / code_chars as f64
+    } else {
+        0.0
+    };
+    let marker = MINIFIED_MARKERS
+        .iter()
+        .find(|m| content.contains(*m))
+        .map(|m| m.to_string());
+
+    // Without long lines nothing else matters: minified output is dense
+    let line_score = ((avg_line_len as f64 - 200.0) / 800.0)
+        .max((max_line_len as f64 - 1_000.0) / 4_000.0)
+        .clamp(0.0, 1.0);
+    let whitespace_score = ((0.12 - whitespace_ratio) / 0.10).clamp(0.0, 1.0);
+    let marker_score = if marker.is_some() { 1.0 } else { 0.0 };
+
+    let language_weight = match ext.as_str() {
+        "js" | "mjs" | "cjs" | "jsx" | "ts" | "tsx" | "css" | "scss" | "less" | "html" | "htm"
+        | "json" | "svg" => 1.0,
+        _ if language.is_code() => 0.7,
+        _ => 0.85,
+    };
+
+    let confidence = (line_score
+        * (0.5 + 0.35 * whitespace_score + 0.15 * marker_score)
+        * language_weight
+        // Long lines plus a bundler marker is conclusive on its own
+        + if line_score > 0.0 { 0.25 * marker_score } else { 0.0 })
+    .clamp(0.0, 1.0);
+
+    MinificationReport {
+        confidence,
+        lines,
+        avg_line_len,
+        max_line_len,
+        longest_raw_line,
+        whitespace_ratio,
+        marker,
+    }
+}
+
+/// Length of `line` without the contents of string literals, adding its
+/// whitespace outside literals to `whitespace`
+fn code_len(line: &str, single_quote_strings: bool, whitespace: &mut usize) -> usize {
+    let mut len = 0;
+    let mut in_string: Option<char> = None;
+    let mut escaped = false;
+
+    for c in line.chars() {
+        match in_string {
+            Some(quote) => {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' {
+                    escaped = true;
+                } else if c == quote {
+                    in_string = None;
+                    len += 1;
+                }
+            }
+            None => {
+                len += 1;
+                if c.is_whitespace() {
+                    *whitespace += 1;
+                } else if c == '"' || c == '`' || (c == '\'' && single_quote_strings) {
+                    in_string = Some(c);
+                }
+            }
+        }
+    }
+
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rust_with_long_string_literals_is_not_minified() {
+        let literal = "lorem ipsum dolor sit amet ".repeat(40);
+        let mut content = String::from("//! Fixtures\n\npub const GREETINGS: &[&str] = &[\n");
+        for _ in 0..30 {
+            content.push_str(&format!("    \"{}\",\n", literal));
+        }
+        content.push_str("];\n\npub fn first() -> &'static str {\n    GREETINGS[0]\n}\n");
+        assert!(content.lines().count() <= 40);
+
+        let report = detect_minified("src/fixtures.rs", &content);
+        assert!(
+            !report.is_minified(DEFAULT_MINIFIED_THRESHOLD),
+            "{:?}",
+            report
+        );
+        assert_eq!(report.confidence, 0.0);
+    }
+
+    #[test]
+    fn test_minified_js_is_detected() {
+        let body = "var n=function(e){return e&&e.__esModule?e:{default:e}},r=n(require(\"react\"));function o(e,t){if(!(e instanceof t))throw new TypeError(\"Cannot call a class as a function\")}".repeat(30);
+        let content = format!(
+            "!function(e,t){{{}}}(this);\n//# sourceMappingURL=app.js.map\n",
+            body
+        );
+
+        let report = detect_minified("static/app.js", &content);
+        assert!(
+            report.is_minified(DEFAULT_MINIFIED_THRESHOLD),
+            "{:?}",
+            report
+        );
+        assert!(report.marker.is_some());
+        assert!(report.whitespace_ratio < 0.1);
+    }
+
+    #[test]
+    fn test_minified_css_without_markers_is_detected() {
+        let content =
+            ".btn{color:#fff;background:#000;padding:0 4px}.nav>li{display:inline-block;margin:0}"
+                .repeat(30);
+        let report = detect_minified("dist/site.css", &content);
+        assert!(
+            report.is_minified(DEFAULT_MINIFIED_THRESHOLD),
+            "{:?}",
+            report
+        );
+    }
+
+    #[test]
+    fn test_overlong_line_counts_string_literals() {
+        let css = "display:flex;align-items:center;gap:4px;".repeat(150);
+        let mut content = "import styled from 'styled-components';\n\n".to_string();
+        content.push_str(&format!("export const Row = styled.div`{}`;\n", css));
+        content.push_str(&"export const gap = (n: number) => n * 4;\n".repeat(60));
+
+        let report = detect_minified("src/theme.ts", &content);
+        assert!(report.max_line_len < MINIFIED_SINGLE_LINE_LEN);
+        assert!(report.longest_raw_line > MINIFIED_SINGLE_LINE_LEN);
+        assert!(report.has_overlong_line());
+    }
+
+    #[test]
+    fn test_formatted_js_is_not_minified() {
+        let content = "function add(a, b) {\n    return a + b;\n}\n".repeat(20);
+        let report = detect_minified("src/math.js", &content);
+        assert_eq!(report.confidence, 0.0);
+    }
+}
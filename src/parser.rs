@@ -3,10 +3,16 @@
 //! This module provides Rust-aware parsing for static analysis,
 //! using regex patterns to extract function signatures, types,
 //! imports, and calculate complexity metrics.
+//!
+//! [`extract_signatures`] covers every [`FileLanguage`] and returns only
+//! function signatures, for callers that need a symbol index but not bodies.
 
 use crate::error::Result;
+use crate::language::FileLanguage;
 use crate::types::Category;
+use once_cell::sync::Lazy;
 use regex::Regex;
+use std::collections::HashMap;
 use std::path::Path;
 
 /// Code parser for Rust source files
@@ -501,6 +507,590 @@ pub struct ComplexityMetrics {
     pub lloc: usize,
 }
 
+// ============================================================================
+// Signature extraction
+// ============================================================================
+
+/// Visibility of an extracted [`Signature`], mapped onto each language's rules
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visibility {
+    /// Exported: `pub`, `public`, `export`, capitalised Go names, ...
+    Public,
+    /// Visible past its file but not exported: `pub(crate)`, `internal`,
+    /// `protected`, Java package-private, Swift's default `internal`
+    Restricted,
+    /// Private to its module, class or file
+    Private,
+}
+
+/// A function or method signature, without its body
+#[derive(Debug, Clone, PartialEq)]
+pub struct Signature {
+    /// Function name (Go methods and C++ out-of-line definitions keep only
+    /// the name; the receiver/qualifier is in `text`)
+    pub name: String,
+    /// Parameters as written, one entry each, whitespace collapsed
+    pub params: Vec<String>,
+    /// Declared return type, if any
+    pub return_type: Option<String>,
+    /// Visibility under the language's rules
+    pub visibility: Visibility,
+    /// 1-based line the declaration starts on
+    pub line: usize,
+    /// Generic parameters including brackets, e.g. `<T: Clone>`
+    pub generics: Option<String>,
+    /// Rust `where` predicates, without the keyword
+    pub where_clause: Option<String>,
+    /// The whole signature as a single logical line
+    pub text: String,
+}
+
+/// Function declaration heads per language. Each match ends at the function
+/// name (`name`, or `alt` for the second shell form); `mods` holds the
+/// modifiers that decide visibility.
+static SIGNATURE_HEADS: Lazy<HashMap<FileLanguage, Regex>> = Lazy::new(|| {
+    let heads = [
+        (
+            FileLanguage::Rust,
+            r#"(?m)^[ \t]*(?P<mods>(?:pub(?:\s*\([^)]*\))?\s+)?(?:(?:const|async|unsafe|default|extern(?:\s*"[^"]*")?)\s+)*)fn\s+(?P<name>\w+)"#,
+        ),
+        (
+            FileLanguage::Python,
+            r"(?m)^[ \t]*(?P<mods>(?:async\s+)?)def\s+(?P<name>\w+)",
+        ),
+        (
+            FileLanguage::Go,
+            r"(?m)^func\s+(?:\([^)]*\)\s*)?(?P<name>\w+)",
+        ),
+        (
+            FileLanguage::TypeScript,
+            r"(?m)^[ \t]*(?P<mods>(?:(?:export|default|declare|async)\s+)*)function\s*\*?\s*(?P<name>[\w$]+)",
+        ),
+        (
+            FileLanguage::JavaScript,
+            r"(?m)^[ \t]*(?P<mods>(?:(?:export|default|async)\s+)*)function\s*\*?\s*(?P<name>[\w$]+)",
+        ),
+        (
+            FileLanguage::Kotlin,
+            r"(?m)^[ \t]*(?P<mods>(?:(?:public|private|internal|protected|override|open|abstract|final|suspend|inline|operator|infix|tailrec|external)\s+)*)fun\s+(?P<generics><[^>]*>\s*)?(?:[\w.<>?]+\.)?(?P<name>\w+)",
+        ),
+        (
+            FileLanguage::Swift,
+            r"(?m)^[ \t]*(?P<mods>(?:(?:public|private|fileprivate|internal|open|static|class|final|override|mutating|nonmutating|@\w+)\s+)*)func\s+(?P<name>\w+)",
+        ),
+        (
+            FileLanguage::Java,
+            r"(?m)^[ \t]*(?P<mods>(?:(?:public|private|protected|static|final|abstract|synchronized|native|default|strictfp)\s+)*)(?P<generics><[^>]*>\s+)?(?P<ret>[\w$.]+(?:<[^()]*?>)?(?:\[\])*)[ \t]+(?P<name>\w+)\s*\(",
+        ),
+        (
+            FileLanguage::Cpp,
+            r"(?m)^[ \t]*(?P<mods>(?:(?:static|inline|extern|virtual|constexpr|explicit|friend)\s+)*)(?P<ret>(?:(?:const|unsigned|signed|struct|long)\s+)*[\w:]+(?:<[^()]*?>)?[ \t*&]+)(?P<name>[\w:~]+)\s*\(",
+        ),
+        (
+            FileLanguage::C,
+            r"(?m)^[ \t]*(?P<mods>(?:(?:static|inline|extern)\s+)*)(?P<ret>(?:(?:const|unsigned|signed|struct|enum|long)\s+)*\w+[ \t*]+)(?P<name>\w+)\s*\(",
+        ),
+        (
+            FileLanguage::Ruby,
+            r"(?m)^[ \t]*def\s+(?P<mods>self\.)?(?P<name>\w+[?!=]?)",
+        ),
+        (
+            FileLanguage::Shell,
+            r"(?m)^[ \t]*(?:(?P<mods>function)\s+(?P<name>[\w:-]+)|(?P<alt>[A-Za-z_][\w:-]*)\s*\(\s*\))",
+        ),
+    ];
+    heads
+        .into_iter()
+        .map(|(language, pattern)| {
+            (
+                language,
+                Regex::new(pattern).expect("Invalid signature regex"),
+            )
+        })
+        .collect()
+});
+
+/// Words the C-family head patterns can mistake for a return type or name
+const NOT_A_SIGNATURE: &[&str] = &[
+    "return",
+    "else",
+    "if",
+    "while",
+    "for",
+    "switch",
+    "case",
+    "do",
+    "new",
+    "delete",
+    "throw",
+    "goto",
+    "sizeof",
+    "typedef",
+    "using",
+    "namespace",
+    "co_return",
+];
+
+/// Extract function and method signatures without their bodies
+///
+/// Lighter than [`crate::code_chunker::CodeChunker`]: bodies are skipped by
+/// bracket matching (or indentation for Python and Ruby) rather than
+/// extracted, and functions nested inside bodies are not reported. Methods
+/// are reported for impl/class/trait blocks; JavaScript and TypeScript only
+/// cover `function` declarations. Multi-line Rust generics and `where`
+/// clauses are folded into [`Signature::text`].
+pub fn extract_signatures(content: &str, language: FileLanguage) -> Vec<Signature> {
+    let Some(head) = SIGNATURE_HEADS.get(&language) else {
+        return Vec::new();
+    };
+    let bytes = content.as_bytes();
+    let mut signatures = Vec::new();
+    let mut pos = 0;
+
+    while let Some(caps) = head.captures_at(content, pos) {
+        let whole = caps.get(0).expect("match");
+        let name = caps
+            .name("name")
+            .or_else(|| caps.name("alt"))
+            .expect("name");
+        let header_start = if caps.name("ret").is_some() {
+            // C-family heads consume the opening paren
+            whole.end() - 1
+        } else {
+            name.end()
+        };
+        let (mut header_end, mut terminator) = scan_header(bytes, header_start, language);
+
+        // Brace on the line after the signature
+        if terminator == Some(b'\n')
+            && !matches!(language, FileLanguage::Python | FileLanguage::Ruby)
+        {
+            let next = header_end
+                + bytes[header_end..]
+                    .iter()
+                    .take_while(|b| b.is_ascii_whitespace())
+                    .count();
+            if bytes.get(next) == Some(&b'{') {
+                (header_end, terminator) = (next, Some(b'{'));
+            }
+        }
+
+        pos = match (language, terminator) {
+            (FileLanguage::Python | FileLanguage::Ruby, _) => {
+                skip_indented(content, whole.start(), header_end)
+            }
+            (_, Some(b'{')) => skip_block(bytes, header_end, language),
+            _ => header_end.max(whole.end()),
+        };
+
+        let line = content[..whole.start()].matches('\n').count() + 1;
+        if let Some(signature) = build_signature(
+            language,
+            &caps,
+            name.as_str(),
+            &content[whole.start()..header_start],
+            &content[header_start..header_end],
+            terminator,
+            line,
+        ) {
+            signatures.push(signature);
+        }
+    }
+
+    signatures
+}
+
+fn build_signature(
+    language: FileLanguage,
+    caps: &regex::Captures,
+    name: &str,
+    lead: &str,
+    header: &str,
+    terminator: Option<u8>,
+    line: usize,
+) -> Option<Signature> {
+    let mut mods = caps.name("mods").map_or("", |m| m.as_str()).to_string();
+    let mut ret = caps.name("ret").map(|m| collapse(m.as_str()));
+
+    if matches!(
+        language,
+        FileLanguage::Java | FileLanguage::Cpp | FileLanguage::C
+    ) {
+        let ret_word = ret.as_deref().unwrap_or_default();
+        if NOT_A_SIGNATURE.contains(&ret_word) || NOT_A_SIGNATURE.contains(&name) {
+            return None;
+        }
+        // `public Foo(...)`: a constructor, the modifier was taken as its type
+        if ["public", "private", "protected"].contains(&ret_word) {
+            mods = format!("{} {}", mods, ret_word);
+            ret = None;
+        }
+    }
+
+    let mut rest = header.trim_start();
+    let mut generics = caps.name("generics").map(|m| m.as_str().trim().to_string());
+    let generic_open = match language {
+        FileLanguage::Go => Some(b'['),
+        FileLanguage::Rust | FileLanguage::TypeScript | FileLanguage::Swift | FileLanguage::Cpp => {
+            Some(b'<')
+        }
+        _ => None,
+    };
+    if let Some(open) = generic_open {
+        if rest.as_bytes().first() == Some(&open) {
+            let (inner, after) = take_balanced(rest)?;
+            let close = if open == b'[' { ']' } else { '>' };
+            generics = Some(format!(
+                "{}{}{}",
+                open as char,
+                split_top_level(inner).join(", "),
+                close
+            ));
+            rest = after.trim_start();
+        }
+    }
+    if let Some(g) = generics.as_mut() {
+        if g.starts_with('<') && g.ends_with('>') {
+            *g = format!("<{}>", split_top_level(&g[1..g.len() - 1]).join(", "));
+        }
+    }
+
+    let params = if rest.starts_with('(') {
+        let (inner, after) = take_balanced(rest)?;
+        rest = after;
+        split_top_level(inner)
+    } else if language == FileLanguage::Ruby {
+        let params = split_top_level(rest);
+        rest = "";
+        params
+    } else {
+        Vec::new()
+    };
+
+    // A C-family "declaration" whose arguments aren't typed is a call or a
+    // constructed object, e.g. `Widget w(parent);`
+    if terminator == Some(b';')
+        && matches!(
+            language,
+            FileLanguage::Java | FileLanguage::Cpp | FileLanguage::C
+        )
+        && !params
+            .iter()
+            .all(|p| p == "void" || p == "..." || p.split_whitespace().count() > 1)
+    {
+        return None;
+    }
+
+    let mut tail = collapse(rest);
+    let mut where_clause = None;
+    if language == FileLanguage::Rust {
+        if let Some(at) = find_word(&tail, "where") {
+            let predicates = split_top_level(&tail[at + "where".len()..]).join(", ");
+            tail = format!("{} where {}", tail[..at].trim(), predicates)
+                .trim()
+                .to_string();
+            where_clause = Some(predicates).filter(|p| !p.is_empty());
+        }
+    }
+    let returns = match language {
+        FileLanguage::Rust => {
+            let returns = find_word(&tail, "where").map_or(tail.as_str(), |at| &tail[..at]);
+            returns.trim().strip_prefix("->").map(str::trim)
+        }
+        FileLanguage::Python | FileLanguage::Swift => {
+            tail.split_once("->").map(|(_, ret)| ret.trim())
+        }
+        FileLanguage::Kotlin | FileLanguage::TypeScript | FileLanguage::JavaScript => {
+            tail.strip_prefix(':').map(str::trim)
+        }
+        FileLanguage::Go => Some(tail.as_str()),
+        _ => None,
+    };
+    if let Some(returns) = returns.filter(|r| !r.is_empty()) {
+        ret = Some(returns.to_string());
+    }
+
+    let visibility = visibility(language, &mods, name);
+
+    let mut text = format!(
+        "{}{}({})",
+        collapse(lead),
+        generics
+            .as_deref()
+            .filter(|_| generic_open.is_some())
+            .unwrap_or_default(),
+        params.join(", ")
+    );
+    if !tail.is_empty() {
+        if !tail.starts_with(':') {
+            text.push(' ');
+        }
+        text.push_str(&tail);
+    }
+
+    Some(Signature {
+        name: name.rsplit("::").next().unwrap_or(name).to_string(),
+        params,
+        return_type: ret,
+        visibility,
+        line,
+        generics,
+        where_clause,
+        text,
+    })
+}
+
+fn visibility(language: FileLanguage, mods: &str, name: &str) -> Visibility {
+    let has = |word: &str| mods.split_whitespace().any(|m| m == word);
+    match language {
+        FileLanguage::Rust if mods.contains("pub(") || mods.contains("pub (") => {
+            Visibility::Restricted
+        }
+        FileLanguage::Rust if has("pub") => Visibility::Public,
+        FileLanguage::Python if name.starts_with("__") && name.ends_with("__") => {
+            Visibility::Public
+        }
+        FileLanguage::Python if name.starts_with('_') => Visibility::Private,
+        FileLanguage::Go if name.starts_with(|c: char| c.is_ascii_uppercase()) => {
+            Visibility::Public
+        }
+        FileLanguage::TypeScript | FileLanguage::JavaScript if has("export") => Visibility::Public,
+        FileLanguage::Kotlin if has("private") => Visibility::Private,
+        FileLanguage::Kotlin if has("internal") || has("protected") => Visibility::Restricted,
+        FileLanguage::Swift if has("private") || has("fileprivate") => Visibility::Private,
+        FileLanguage::Swift if has("public") || has("open") => Visibility::Public,
+        FileLanguage::Swift => Visibility::Restricted,
+        FileLanguage::Java if has("public") => Visibility::Public,
+        FileLanguage::Java if has("private") => Visibility::Private,
+        FileLanguage::Java => Visibility::Restricted,
+        FileLanguage::C | FileLanguage::Cpp if has("static") => Visibility::Private,
+        FileLanguage::Rust
+        | FileLanguage::Go
+        | FileLanguage::TypeScript
+        | FileLanguage::JavaScript => Visibility::Private,
+        _ => Visibility::Public,
+    }
+}
+
+/// Scan a signature from `start` to where its body (or the declaration)
+/// begins, returning that offset and the byte found there
+fn scan_header(bytes: &[u8], start: usize, language: FileLanguage) -> (usize, Option<u8>) {
+    let angle = !matches!(
+        language,
+        FileLanguage::Python | FileLanguage::Go | FileLanguage::Ruby | FileLanguage::Shell
+    );
+    let mut depth = 0usize;
+    let mut seen_params = false;
+    let mut i = start;
+
+    while i < bytes.len() {
+        let b = bytes[i];
+        match b {
+            b'-' | b'=' if bytes.get(i + 1) == Some(&b'>') => {
+                i += 2;
+                continue;
+            }
+            b'"' | b'`' => {
+                i = skip_string(bytes, i);
+                continue;
+            }
+            b'\'' if language != FileLanguage::Rust => {
+                i = skip_string(bytes, i);
+                continue;
+            }
+            b'(' | b'[' => depth += 1,
+            b'<' if angle => depth += 1,
+            b')' | b']' => {
+                depth = depth.saturating_sub(1);
+                seen_params |= depth == 0;
+            }
+            b'>' if angle => depth = depth.saturating_sub(1),
+            _ if depth > 0 => {}
+            b'{' | b';' => return (i, Some(b)),
+            b':' if language == FileLanguage::Python => return (i, Some(b)),
+            b'=' if language == FileLanguage::Kotlin => return (i, Some(b)),
+            b'\n' if language == FileLanguage::Ruby => return (i, Some(b)),
+            b'\n' if seen_params && language != FileLanguage::Rust => return (i, Some(b)),
+            _ => {}
+        }
+        i += 1;
+    }
+    (bytes.len(), None)
+}
+
+/// Offset just past the `}` matching the `{` at `open`
+fn skip_block(bytes: &[u8], open: usize, language: FileLanguage) -> usize {
+    let mut depth = 0usize;
+    let mut i = open;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'{' => depth += 1,
+            b'}' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return i + 1;
+                }
+            }
+            b'"' | b'`' => {
+                i = skip_string(bytes, i);
+                continue;
+            }
+            b'\'' if language == FileLanguage::Rust => {
+                // Char literals; anything else is a lifetime
+                if bytes.get(i + 2) == Some(&b'\'') {
+                    i += 3;
+                    continue;
+                }
+                if bytes.get(i + 1) == Some(&b'\\') {
+                    i += 3;
+                    while i < bytes.len() && bytes[i] != b'\'' {
+                        i += 1;
+                    }
+                }
+            }
+            b'\'' => {
+                i = skip_string(bytes, i);
+                continue;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i + 1 < bytes.len() && !(bytes[i] == b'*' && bytes[i + 1] == b'/') {
+                    i += 1;
+                }
+                i += 1;
+            }
+            b'#' if language == FileLanguage::Shell
+                && (i == 0 || bytes[i - 1].is_ascii_whitespace()) =>
+            {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+/// Offset of the first line after `from` indented no deeper than the
+/// definition starting at `def_start`
+fn skip_indented(content: &str, def_start: usize, from: usize) -> usize {
+    let indent = |line: &str| line.len() - line.trim_start().len();
+    let def_indent = indent(&content[def_start..]);
+    let mut offset = content[from..]
+        .find('\n')
+        .map_or(content.len(), |n| from + n + 1);
+
+    for line in content[offset..].split_inclusive('\n') {
+        let trimmed = line.trim();
+        if !trimmed.is_empty() && !trimmed.starts_with('#') && indent(line) <= def_indent {
+            return offset;
+        }
+        offset += line.len();
+    }
+    content.len()
+}
+
+/// Offset just past the closing quote of the string opening at `start`
+fn skip_string(bytes: &[u8], start: usize) -> usize {
+    let quote = bytes[start];
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b if b == quote => return i + 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+/// Split `s` (which starts with an opening bracket) into the bracket's
+/// contents and what follows the matching close
+fn take_balanced(s: &str) -> Option<(&str, &str)> {
+    let bytes = s.as_bytes();
+    let (open, close) = match bytes.first()? {
+        b'(' => (b'(', b')'),
+        b'[' => (b'[', b']'),
+        b'<' => (b'<', b'>'),
+        _ => return None,
+    };
+    let mut depth = 0usize;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'-' | b'=' if open == b'<' && bytes.get(i + 1) == Some(&b'>') => i += 1,
+            b'"' if open != b'<' => {
+                i = skip_string(bytes, i);
+                continue;
+            }
+            b if b == open => depth += 1,
+            b if b == close => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some((&s[1..i], &s[i + 1..]));
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Split on commas outside any brackets, collapsing whitespace and dropping
+/// empty entries (trailing commas)
+fn split_top_level(s: &str) -> Vec<String> {
+    let bytes = s.as_bytes();
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'-' | b'=' if bytes.get(i + 1) == Some(&b'>') => i += 1,
+            b'"' => {
+                i = skip_string(bytes, i);
+                continue;
+            }
+            b'(' | b'[' | b'{' | b'<' => depth += 1,
+            b')' | b']' | b'}' | b'>' => depth = depth.saturating_sub(1),
+            b',' if depth == 0 => {
+                parts.push(collapse(&s[start..i]));
+                start = i + 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    parts.push(collapse(&s[start..]));
+    parts.retain(|p| !p.is_empty());
+    parts
+}
+
+/// Collapse runs of whitespace (including newlines) to single spaces
+fn collapse(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Byte offset of `word` as a whole word in `s`
+fn find_word(s: &str, word: &str) -> Option<usize> {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    s.match_indices(word)
+        .map(|(at, _)| at)
+        .find(|&at| !s[..at].ends_with(is_ident) && !s[at + word.len()..].starts_with(is_ident))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -684,4 +1274,142 @@ impl MyStruct {
         assert_eq!(functions[0].param_count, 2); // &self + x
         assert_eq!(functions[1].param_count, 1); // &mut self only
     }
+
+    #[test]
+    fn test_extract_signatures_multiline_rust() {
+        let content = r#"
+/// Merge two maps
+pub fn merge<K, V>(
+    left: HashMap<K, V>,
+    right: &'a HashMap<K, V>,
+) -> Result<HashMap<K, V>, MergeError>
+where
+    K: Eq + Hash,
+    V: Fn(u8) -> u8,
+{
+    fn helper() {}
+    let brace = '{';
+    left
+}
+
+impl Store {
+    pub(crate) async fn load(&self, id: &str) -> Option<Record> {
+        None
+    }
+
+    fn flush(&mut self) {}
+}
+
+trait Sink {
+    fn write(&mut self, bytes: &[u8]) -> usize;
+}
+"#;
+
+        let signatures = extract_signatures(content, FileLanguage::Rust);
+        let names: Vec<_> = signatures.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["merge", "load", "flush", "write"]);
+
+        let merge = &signatures[0];
+        assert_eq!(merge.line, 3);
+        assert_eq!(merge.visibility, Visibility::Public);
+        assert_eq!(merge.generics.as_deref(), Some("<K, V>"));
+        assert_eq!(
+            merge.params,
+            vec!["left: HashMap<K, V>", "right: &'a HashMap<K, V>"]
+        );
+        assert_eq!(
+            merge.return_type.as_deref(),
+            Some("Result<HashMap<K, V>, MergeError>")
+        );
+        assert_eq!(
+            merge.where_clause.as_deref(),
+            Some("K: Eq + Hash, V: Fn(u8) -> u8")
+        );
+        assert_eq!(
+            merge.text,
+            "pub fn merge<K, V>(left: HashMap<K, V>, right: &'a HashMap<K, V>) \
+             -> Result<HashMap<K, V>, MergeError> where K: Eq + Hash, V: Fn(u8) -> u8"
+        );
+
+        assert_eq!(signatures[1].visibility, Visibility::Restricted);
+        assert_eq!(signatures[1].return_type.as_deref(), Some("Option<Record>"));
+        assert_eq!(signatures[2].visibility, Visibility::Private);
+        assert_eq!(signatures[2].return_type, None);
+        assert_eq!(signatures[3].params, vec!["&mut self", "bytes: &[u8]"]);
+    }
+
+    #[test]
+    fn test_extract_signatures_python_annotations() {
+        let content = r#"
+async def fetch(
+    url: str,
+    headers: Dict[str, str] = {},
+    *,
+    timeout: float = 3.0,
+) -> Optional[bytes]:
+    def retry(n: int) -> None:
+        pass
+    return None
+
+
+class Client:
+    def __init__(self, base: str):
+        self.base = base
+
+    def _session(self) -> "Session":
+        return Session()
+"#;
+
+        let signatures = extract_signatures(content, FileLanguage::Python);
+        let names: Vec<_> = signatures.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["fetch", "__init__", "_session"]);
+
+        let fetch = &signatures[0];
+        assert_eq!(fetch.line, 2);
+        assert_eq!(
+            fetch.params,
+            vec![
+                "url: str",
+                "headers: Dict[str, str] = {}",
+                "*",
+                "timeout: float = 3.0"
+            ]
+        );
+        assert_eq!(fetch.return_type.as_deref(), Some("Optional[bytes]"));
+        assert_eq!(fetch.visibility, Visibility::Public);
+        assert!(fetch.text.starts_with("async def fetch(url: str,"));
+
+        assert_eq!(signatures[1].return_type, None);
+        assert_eq!(signatures[1].visibility, Visibility::Public);
+        assert_eq!(signatures[2].return_type.as_deref(), Some("\"Session\""));
+        assert_eq!(signatures[2].visibility, Visibility::Private);
+    }
+
+    #[test]
+    fn test_extract_signatures_other_languages() {
+        let go = "func (s *Server) Handle[T any](w Writer, r *Request) (int, error) {\n\treturn 0, nil\n}\n";
+        let sig = &extract_signatures(go, FileLanguage::Go)[0];
+        assert_eq!(sig.name, "Handle");
+        assert_eq!(sig.generics.as_deref(), Some("[T any]"));
+        assert_eq!(sig.return_type.as_deref(), Some("(int, error)"));
+        assert_eq!(sig.visibility, Visibility::Public);
+
+        let java = "public class A {\n    private static <T> List<T> wrap(T item, int n) throws IOException {\n        return List.of(item);\n    }\n    public A(int x) {}\n}\n";
+        let sigs = extract_signatures(java, FileLanguage::Java);
+        assert_eq!(sigs.len(), 2);
+        assert_eq!(sigs[0].name, "wrap");
+        assert_eq!(sigs[0].return_type.as_deref(), Some("List<T>"));
+        assert_eq!(sigs[0].visibility, Visibility::Private);
+        assert_eq!(sigs[1].name, "A");
+        assert_eq!(sigs[1].return_type, None);
+        assert_eq!(sigs[1].visibility, Visibility::Public);
+
+        let ts = "export async function load<T>(id: string, cb: (e: Error) => void): Promise<T> {\n  return get(id);\n}\n";
+        let sig = &extract_signatures(ts, FileLanguage::TypeScript)[0];
+        assert_eq!(sig.params, vec!["id: string", "cb: (e: Error) => void"]);
+        assert_eq!(sig.return_type.as_deref(), Some("Promise<T>"));
+        assert_eq!(sig.visibility, Visibility::Public);
+
+        assert!(extract_signatures("fn main() {}", FileLanguage::Unknown).is_empty());
+    }
 }
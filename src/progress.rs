@@ -0,0 +1,65 @@
+//! Progress reporting for long-running operations
+//!
+//! Directory chunking, document indexing, research runs and repository scans
+//! all report through [`ProgressReporter`], so a caller can attach a CLI
+//! progress bar or forward to SSE without each module growing its own hook.
+//! Every operation defaults to [`NoopProgress`].
+//!
+//! ```rust,no_run
+//! use rustassistant::code_chunker::CodeChunker;
+//! use rustassistant::progress::ProgressReporter;
+//! use std::sync::Arc;
+//!
+//! struct Stderr;
+//!
+//! impl ProgressReporter for Stderr {
+//!     fn start(&self, message: &str, total: u64) {
+//!         eprintln!("{} (0/{})", message, total);
+//!     }
+//!     fn advance(&self, message: &str, done: u64) {
+//!         eprintln!("  [{}] {}", done, message);
+//!     }
+//!     fn finish(&self, message: &str) {
+//!         eprintln!("{}", message);
+//!     }
+//! }
+//!
+//! let chunker = CodeChunker::new().with_progress(Arc::new(Stderr));
+//! let chunks = chunker.chunk_directory("src".as_ref(), "my-repo").unwrap();
+//! ```
+
+use std::sync::Arc;
+
+/// Observer of a long-running operation
+///
+/// An operation calls [`start`](Self::start) once with the number of units
+/// it will process, [`advance`](Self::advance) after each unit with the
+/// running count, and [`finish`](Self::finish) once at the end, including
+/// when it stops early.
+pub trait ProgressReporter: Send + Sync {
+    /// The operation began; `total` is the number of units it will process
+    fn start(&self, message: &str, total: u64);
+
+    /// `done` units are complete; `message` names the latest one
+    fn advance(&self, message: &str, done: u64);
+
+    /// The operation ended
+    fn finish(&self, message: &str);
+}
+
+/// Reporter that ignores every call
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopProgress;
+
+impl ProgressReporter for NoopProgress {
+    fn start(&self, _message: &str, _total: u64) {}
+
+    fn advance(&self, _message: &str, _done: u64) {}
+
+    fn finish(&self, _message: &str) {}
+}
+
+/// Shared no-op reporter, the default for every operation
+pub fn noop() -> Arc<dyn ProgressReporter> {
+    Arc::new(NoopProgress)
+}
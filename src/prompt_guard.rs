@@ -0,0 +1,288 @@
+//! Pre-send guardrails for LLM prompts
+//!
+//! Every LLM client runs its outgoing prompt through a [`PromptGuard`]
+//! before the request is made. A guard can let the prompt through, rewrite
+//! it (e.g. mask PII) or block it with a [`PromptBlocked`] error naming the
+//! rule that fired. Warnings are logged and the prompt is sent unchanged.
+//!
+//! [`DefaultPromptGuard`] enforces a size limit and a forbidden-path list
+//! (`.env`, private keys, ...); PII rules are opt-in.
+
+use regex::Regex;
+use std::borrow::Cow;
+use std::sync::Arc;
+use thiserror::Error;
+use tracing::warn;
+
+/// Default size limit in characters (~250k tokens)
+pub const DEFAULT_MAX_PROMPT_CHARS: usize = 1_000_000;
+
+/// File names that must never reach an LLM; `*` matches any run of characters
+pub const DEFAULT_FORBIDDEN_PATHS: &[&str] = &[
+    ".env",
+    ".env.*",
+    "*.pem",
+    "*.p12",
+    "*.pfx",
+    "id_rsa",
+    "id_dsa",
+    "id_ecdsa",
+    "id_ed25519",
+    ".netrc",
+    ".pgpass",
+];
+
+/// A prompt a guard refused to send
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("prompt blocked by rule `{rule}`: {detail}")]
+pub struct PromptBlocked {
+    /// Rule that fired, e.g. `max_size` or `forbidden_path`
+    pub rule: String,
+    pub detail: String,
+}
+
+impl PromptBlocked {
+    pub fn new(rule: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            rule: rule.into(),
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Inspects a prompt before it is sent
+pub trait PromptGuard: Send + Sync {
+    /// `Ok(None)` sends the prompt as is, `Ok(Some(_))` sends the rewritten
+    /// prompt instead, `Err(_)` blocks the request
+    fn check(&self, prompt: &str) -> Result<Option<String>, PromptBlocked>;
+}
+
+/// Run `prompt` through `guard`, giving back what should be sent
+pub fn guard_prompt<'a>(
+    guard: &dyn PromptGuard,
+    prompt: &'a str,
+) -> Result<Cow<'a, str>, PromptBlocked> {
+    match guard.check(prompt) {
+        Ok(None) => Ok(Cow::Borrowed(prompt)),
+        Ok(Some(rewritten)) => Ok(Cow::Owned(rewritten)),
+        Err(blocked) => {
+            warn!("{}", blocked);
+            Err(blocked)
+        }
+    }
+}
+
+/// The guard clients use unless given another
+pub fn default_guard() -> Arc<dyn PromptGuard> {
+    Arc::new(DefaultPromptGuard::default())
+}
+
+/// What a PII rule does when it matches
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PiiAction {
+    /// Log and send unchanged
+    Warn,
+    /// Replace each match with `[REDACTED]`
+    Redact,
+    /// Refuse to send
+    Block,
+}
+
+/// A named PII pattern
+#[derive(Debug, Clone)]
+pub struct PiiRule {
+    pub name: String,
+    pub pattern: Regex,
+    pub action: PiiAction,
+}
+
+/// Size limit, forbidden paths and optional PII rules
+#[derive(Debug, Clone)]
+pub struct DefaultPromptGuard {
+    pub max_chars: usize,
+    /// File name patterns; see [`DEFAULT_FORBIDDEN_PATHS`]
+    pub forbidden_paths: Vec<String>,
+    pub pii_rules: Vec<PiiRule>,
+}
+
+impl Default for DefaultPromptGuard {
+    fn default() -> Self {
+        Self {
+            max_chars: DEFAULT_MAX_PROMPT_CHARS,
+            forbidden_paths: DEFAULT_FORBIDDEN_PATHS
+                .iter()
+                .map(|p| p.to_string())
+                .collect(),
+            pii_rules: Vec::new(),
+        }
+    }
+}
+
+impl DefaultPromptGuard {
+    pub fn with_max_chars(mut self, max_chars: usize) -> Self {
+        self.max_chars = max_chars;
+        self
+    }
+
+    pub fn with_forbidden_path(mut self, pattern: impl Into<String>) -> Self {
+        self.forbidden_paths.push(pattern.into());
+        self
+    }
+
+    pub fn with_pii_rule(mut self, name: &str, pattern: Regex, action: PiiAction) -> Self {
+        self.pii_rules.push(PiiRule {
+            name: name.to_string(),
+            pattern,
+            action,
+        });
+        self
+    }
+
+    /// Email addresses and US social security numbers
+    pub fn with_default_pii(self, action: PiiAction) -> Self {
+        self.with_pii_rule(
+            "email",
+            Regex::new(r"\b[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}\b").unwrap(),
+            action,
+        )
+        .with_pii_rule("ssn", Regex::new(r"\b\d{3}-\d{2}-\d{4}\b").unwrap(), action)
+    }
+
+    /// First path-like token whose file name is forbidden
+    ///
+    /// Only tokens with a directory separator count as paths, so source code
+    /// that merely mentions `".env"` isn't blocked.
+    fn find_forbidden_path<'a>(&self, prompt: &'a str) -> Option<(&'a str, &str)> {
+        let tokens = prompt.split(|c: char| {
+            c.is_whitespace() || matches!(c, '"' | '\'' | '`' | '(' | ')' | '[' | ']' | '<' | '>')
+        });
+        for token in tokens {
+            let token = token.trim_end_matches([':', ',', ';']);
+            if !token.contains(['/', '\\']) {
+                continue;
+            }
+            let name = token.rsplit(['/', '\\']).next().unwrap_or(token);
+            if let Some(pattern) = self
+                .forbidden_paths
+                .iter()
+                .find(|p| wildcard_match(p, name))
+            {
+                return Some((token, pattern.as_str()));
+            }
+        }
+        None
+    }
+}
+
+impl PromptGuard for DefaultPromptGuard {
+    fn check(&self, prompt: &str) -> Result<Option<String>, PromptBlocked> {
+        let len = prompt.chars().count();
+        if len > self.max_chars {
+            return Err(PromptBlocked::new(
+                "max_size",
+                format!("{} chars exceeds the {} limit", len, self.max_chars),
+            ));
+        }
+
+        if let Some((path, pattern)) = self.find_forbidden_path(prompt) {
+            return Err(PromptBlocked::new(
+                "forbidden_path",
+                format!("{} matches `{}`", path, pattern),
+            ));
+        }
+
+        let mut rewritten: Option<String> = None;
+        for rule in &self.pii_rules {
+            let current = rewritten.as_deref().unwrap_or(prompt);
+            if !rule.pattern.is_match(current) {
+                continue;
+            }
+            let rule_name = format!("pii:{}", rule.name);
+            match rule.action {
+                PiiAction::Warn => warn!("Prompt matches {} rule; sending anyway", rule_name),
+                PiiAction::Redact => {
+                    rewritten = Some(
+                        rule.pattern
+                            .replace_all(current, crate::redaction::REDACTED)
+                            .into_owned(),
+                    );
+                }
+                PiiAction::Block => {
+                    return Err(PromptBlocked::new(rule_name, "prompt contains PII"));
+                }
+            }
+        }
+        Ok(rewritten)
+    }
+}
+
+/// Glob match supporting only `*`
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == name,
+        Some((prefix, rest)) => {
+            let Some(mut remaining) = name.strip_prefix(prefix) else {
+                return false;
+            };
+            if rest.is_empty() {
+                return true;
+            }
+            loop {
+                if wildcard_match(rest, remaining) {
+                    return true;
+                }
+                let mut chars = remaining.chars();
+                if chars.next().is_none() {
+                    return false;
+                }
+                remaining = chars.as_str();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_guard_blocks_env_file_path() {
+        let guard = DefaultPromptGuard::default();
+        let prompt = "Review this configuration.\n\nFile: deploy/.env\nDATABASE_URL=postgres://...";
+
+        let blocked = guard_prompt(&guard, prompt).unwrap_err();
+        assert_eq!(blocked.rule, "forbidden_path");
+        assert!(blocked.detail.contains("deploy/.env"));
+
+        let variant = guard.check("see ./config/.env.production").unwrap_err();
+        assert_eq!(variant.rule, "forbidden_path");
+
+        // Code that only names the file is fine
+        let code = "fn main() {\n    dotenvy::from_filename(\".env\").ok();\n}";
+        assert!(matches!(guard_prompt(&guard, code), Ok(Cow::Borrowed(_))));
+    }
+
+    #[test]
+    fn test_size_limit_and_pii_rules() {
+        let guard = DefaultPromptGuard::default()
+            .with_max_chars(64)
+            .with_default_pii(PiiAction::Redact);
+
+        let too_long = "x".repeat(65);
+        assert_eq!(guard.check(&too_long).unwrap_err().rule, "max_size");
+
+        let sent = guard_prompt(&guard, "Contact jane.doe@example.com").unwrap();
+        assert_eq!(sent, "Contact [REDACTED]");
+
+        let strict = DefaultPromptGuard::default().with_default_pii(PiiAction::Block);
+        assert_eq!(strict.check("SSN 123-45-6789").unwrap_err().rule, "pii:ssn");
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match(".env.*", ".env.local"));
+        assert!(!wildcard_match(".env.*", ".env"));
+        assert!(wildcard_match("*.pem", "server.pem"));
+        assert!(!wildcard_match("*.pem", "server.pem.txt"));
+    }
+}
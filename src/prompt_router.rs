@@ -18,12 +18,38 @@
 //! - Minimal prompts reduce input tokens by ~60% and output tokens by ~50%
 //! - DeepDive prompts add ~30% input tokens but surface 2-3x more actionable issues
 //! - Net savings of 30-50% on LLM spend when combined with static pre-filter Skip
+//!
+//! ## Path Overrides
+//!
+//! Globs can pin files to a tier regardless of what static analysis says,
+//! e.g. to never skip security-sensitive code. They are read from
+//! `.llm-audit.toml`; the first matching glob wins:
+//!
+//! ```toml
+//! [[prompt_overrides]]
+//! glob = "src/auth/**"
+//! tier = "DeepDive"
+//!
+//! [[prompt_overrides]]
+//! glob = "src/generated/**"
+//! tier = "Skip"
+//! ```
+//!
+//! ## Repo Prompt Templates
+//!
+//! A repo can replace the built-in file analysis prompt by shipping
+//! `.audit/prompts/file_analysis.md`. The template must contain `{path}` and
+//! `{content}`; `{static_signals}` is optional. The response is still parsed
+//! as the built-in JSON analysis, so the template should ask for that shape.
 
+use crate::error::{AuditError, Result};
 use crate::static_analysis::{
-    AnalysisRecommendation, FileLanguage, QualitySignals, StaticAnalysisResult,
+    AnalysisRecommendation, FileLanguage, QualitySignals, SkipReason, StaticAnalysisResult,
 };
+use globset::{Glob, GlobBuilder, GlobMatcher};
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::path::Path;
 
 // ---------------------------------------------------------------------------
 // Prompt tier configuration
@@ -76,6 +102,16 @@ impl Default for PromptRouterConfig {
     }
 }
 
+/// A path override as written in `.llm-audit.toml`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PromptOverride {
+    /// Glob matched against the repo-relative path (`*` stops at `/`, `**` doesn't)
+    pub glob: String,
+
+    /// Recommendation forced on matching files (`Skip`, `Minimal`, `Standard` or `DeepDive`)
+    pub tier: AnalysisRecommendation,
+}
+
 // ---------------------------------------------------------------------------
 // Prompt tier
 // ---------------------------------------------------------------------------
@@ -141,36 +177,101 @@ impl From<&AnalysisRecommendation> for TierKind {
 /// Routes files to the appropriate prompt tier based on static analysis results
 pub struct PromptRouter {
     config: PromptRouterConfig,
+    /// Path overrides in priority order
+    overrides: Vec<(GlobMatcher, AnalysisRecommendation)>,
 }
 
 impl PromptRouter {
     /// Create a new prompt router with default configuration
     pub fn new() -> Self {
-        Self {
-            config: PromptRouterConfig::default(),
-        }
+        Self::with_config(PromptRouterConfig::default())
     }
 
     /// Create a new prompt router with custom configuration
     pub fn with_config(config: PromptRouterConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Force files matching a glob to a recommendation, ahead of static
+    /// analysis and the tier heuristics; the first matching glob wins
+    pub fn with_overrides(mut self, rules: Vec<(Glob, AnalysisRecommendation)>) -> Self {
+        self.overrides.extend(
+            rules
+                .into_iter()
+                .map(|(glob, tier)| (glob.compile_matcher(), tier)),
+        );
+        self
+    }
+
+    /// [`with_overrides`](Self::with_overrides) for rules loaded from config
+    pub fn with_override_config(self, rules: &[PromptOverride]) -> Result<Self> {
+        let rules = rules
+            .iter()
+            .map(|rule| {
+                GlobBuilder::new(&rule.glob)
+                    .literal_separator(true)
+                    .build()
+                    .map(|glob| (glob, rule.tier))
+                    .map_err(|e| {
+                        AuditError::config(format!(
+                            "Invalid prompt override '{}': {}",
+                            rule.glob, e
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(self.with_overrides(rules))
+    }
+
+    /// The overridden recommendation for `file_path`, if any glob matches
+    pub fn override_for(&self, file_path: &str) -> Option<AnalysisRecommendation> {
+        self.overrides
+            .iter()
+            .find(|(matcher, _)| matcher.is_match(file_path))
+            .map(|(_, tier)| *tier)
+    }
+
+    /// Replace the static recommendation with the override for `file_path`
+    ///
+    /// Callers that skip files on [`AnalysisRecommendation::Skip`] must apply
+    /// this first, so an override can rescue a file static analysis skipped.
+    /// Returns whether an override applied.
+    pub fn apply_overrides(
+        &self,
+        file_path: &str,
+        static_result: &mut StaticAnalysisResult,
+    ) -> bool {
+        let Some(tier) = self.override_for(file_path) else {
+            return false;
+        };
+        static_result.recommendation = tier;
+        static_result.skip_reason = match tier {
+            AnalysisRecommendation::Skip => Some(SkipReason::PathOverride),
+            _ => None,
+        };
+        true
     }
 
     /// Route a file to the appropriate prompt tier
     ///
     /// Takes the static analysis result and the file content, and returns
-    /// a fully rendered `PromptTier` ready to send to the LLM.
+    /// a fully rendered `PromptTier` ready to send to the LLM. A path
+    /// override takes precedence, even when tiered routing is disabled.
     pub fn route(
         &self,
         file_path: &str,
         content: &str,
         static_result: &StaticAnalysisResult,
     ) -> PromptTier {
-        if !self.config.enabled {
+        let overridden = self.override_for(file_path);
+        if !self.config.enabled && overridden.is_none() {
             return self.build_standard(file_path, content, static_result);
         }
 
-        let tier_kind = TierKind::from(&static_result.recommendation);
+        let tier_kind = TierKind::from(&overridden.unwrap_or(static_result.recommendation));
 
         match tier_kind {
             TierKind::Minimal => self.build_minimal(file_path, content, static_result),
@@ -675,6 +776,89 @@ fn summarize_red_flags(signals: &QualitySignals) -> String {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Repo prompt templates
+// ---------------------------------------------------------------------------
+
+/// Repo-relative path of a repo's file analysis prompt override
+pub const FILE_ANALYSIS_TEMPLATE_PATH: &str = ".audit/prompts/file_analysis.md";
+
+/// A repo's replacement for the built-in file analysis prompt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileAnalysisTemplate {
+    template: String,
+}
+
+impl FileAnalysisTemplate {
+    /// Placeholders every template must contain
+    pub const REQUIRED_PLACEHOLDERS: [&'static str; 2] = ["{path}", "{content}"];
+
+    /// Parse a template, failing if a required placeholder is missing
+    pub fn parse(template: impl Into<String>) -> Result<Self> {
+        let template = template.into();
+        let missing: Vec<&str> = Self::REQUIRED_PLACEHOLDERS
+            .into_iter()
+            .filter(|placeholder| !template.contains(placeholder))
+            .collect();
+        if !missing.is_empty() {
+            return Err(AuditError::config(format!(
+                "Prompt template is missing required placeholder(s): {}",
+                missing.join(", ")
+            )));
+        }
+        Ok(Self { template })
+    }
+
+    /// The repo's template from [`FILE_ANALYSIS_TEMPLATE_PATH`], or `None`
+    /// when it doesn't ship one
+    pub fn load_for_repo(repo_path: &Path) -> Result<Option<Self>> {
+        let path = repo_path.join(FILE_ANALYSIS_TEMPLATE_PATH);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let template = std::fs::read_to_string(&path)?;
+        Self::parse(template)
+            .map(Some)
+            .map_err(|e| e.context(format!("Invalid prompt template {}", path.display())))
+    }
+
+    /// Fill in the placeholders; text substituted in is never re-expanded
+    pub fn render(&self, path: &str, content: &str, signals: &QualitySignals) -> String {
+        let mut rendered = String::with_capacity(self.template.len() + content.len());
+        let mut rest = self.template.as_str();
+        while let Some(start) = rest.find('{') {
+            rendered.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let value = if rest.starts_with("{path}") {
+                Some(("{path}", path.to_string()))
+            } else if rest.starts_with("{content}") {
+                Some(("{content}", content.to_string()))
+            } else if rest.starts_with("{static_signals}") {
+                Some(("{static_signals}", format_static_context(signals)))
+            } else {
+                None
+            };
+            match value {
+                Some((placeholder, value)) => {
+                    rendered.push_str(&value);
+                    rest = &rest[placeholder.len()..];
+                }
+                None => {
+                    rendered.push('{');
+                    rest = &rest[1..];
+                }
+            }
+        }
+        rendered.push_str(rest);
+        rendered
+    }
+
+    /// Hash of the raw template, for cache keys
+    pub fn hash(&self) -> String {
+        crate::static_analysis::content_hash(&self.template)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Token estimation
 // ---------------------------------------------------------------------------
@@ -910,6 +1094,94 @@ mod tests {
         assert_eq!(tier.tier, TierKind::Standard);
     }
 
+    fn overrides(rules: &[(&str, AnalysisRecommendation)]) -> PromptRouter {
+        let rules: Vec<PromptOverride> = rules
+            .iter()
+            .map(|(glob, tier)| PromptOverride {
+                glob: glob.to_string(),
+                tier: *tier,
+            })
+            .collect();
+        PromptRouter::new().with_override_config(&rules).unwrap()
+    }
+
+    #[test]
+    fn test_override_deep_dive_wins_over_static_skip() {
+        let router = overrides(&[("src/auth/**", AnalysisRecommendation::DeepDive)]);
+        let mut result =
+            make_static_result(AnalysisRecommendation::Skip, QualitySignals::default());
+        result.skip_reason = Some(SkipReason::TrivialFile);
+
+        assert!(router.apply_overrides("src/auth/token/jwt.rs", &mut result));
+        assert_eq!(result.recommendation, AnalysisRecommendation::DeepDive);
+        assert_eq!(result.skip_reason, None);
+        assert_eq!(
+            router
+                .route("src/auth/token/jwt.rs", "fn f() {}", &result)
+                .tier,
+            TierKind::DeepDive
+        );
+
+        // Unmatched paths keep the static decision
+        let mut other = make_static_result(AnalysisRecommendation::Skip, QualitySignals::default());
+        assert!(!router.apply_overrides("src/authz.rs", &mut other));
+        assert_eq!(other.recommendation, AnalysisRecommendation::Skip);
+    }
+
+    #[test]
+    fn test_override_first_match_wins() {
+        let router = overrides(&[
+            ("src/auth/legacy/*.rs", AnalysisRecommendation::Skip),
+            ("src/auth/**", AnalysisRecommendation::DeepDive),
+        ]);
+        assert_eq!(
+            router.override_for("src/auth/legacy/old.rs"),
+            Some(AnalysisRecommendation::Skip)
+        );
+        // `*` does not cross directories, so the broader rule applies
+        assert_eq!(
+            router.override_for("src/auth/legacy/v1/old.rs"),
+            Some(AnalysisRecommendation::DeepDive)
+        );
+
+        let mut result =
+            make_static_result(AnalysisRecommendation::DeepDive, QualitySignals::default());
+        router.apply_overrides("src/auth/legacy/old.rs", &mut result);
+        assert_eq!(result.recommendation, AnalysisRecommendation::Skip);
+        assert_eq!(result.skip_reason, Some(SkipReason::PathOverride));
+    }
+
+    #[test]
+    fn test_override_applies_when_routing_disabled() {
+        let router = PromptRouter::with_config(PromptRouterConfig {
+            enabled: false,
+            ..Default::default()
+        })
+        .with_overrides(vec![(
+            Glob::new("src/auth/**").unwrap(),
+            AnalysisRecommendation::DeepDive,
+        )]);
+        let result = make_static_result(AnalysisRecommendation::Minimal, QualitySignals::default());
+
+        assert_eq!(
+            router.route("src/auth/mod.rs", "", &result).tier,
+            TierKind::DeepDive
+        );
+        assert_eq!(
+            router.route("src/lib.rs", "", &result).tier,
+            TierKind::Standard
+        );
+    }
+
+    #[test]
+    fn test_override_config_rejects_invalid_glob() {
+        let rules = [PromptOverride {
+            glob: "src/[auth".to_string(),
+            tier: AnalysisRecommendation::DeepDive,
+        }];
+        assert!(PromptRouter::new().with_override_config(&rules).is_err());
+    }
+
     #[test]
     fn test_tier_kind_display() {
         assert_eq!(format!("{}", TierKind::Minimal), "MINIMAL");
@@ -1067,4 +1339,33 @@ mod tests {
         assert!(ctx.contains("8"));
         assert!(ctx.contains("yes"));
     }
+
+    #[test]
+    fn test_file_analysis_template_requires_placeholders() {
+        let err = FileAnalysisTemplate::parse("Review {path} please").unwrap_err();
+        assert!(err.to_string().contains("{content}"));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(FILE_ANALYSIS_TEMPLATE_PATH);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "Review this code").unwrap();
+        let err = FileAnalysisTemplate::load_for_repo(dir.path()).unwrap_err();
+        assert!(err.to_string().contains("file_analysis.md"));
+        assert!(err.to_string().contains("{path}, {content}"));
+    }
+
+    #[test]
+    fn test_file_analysis_template_render() {
+        let template =
+            FileAnalysisTemplate::parse("{path} {\"json\": 1}\n{static_signals}\n{content}")
+                .unwrap();
+        let signals = QualitySignals {
+            total_lines: 3,
+            ..Default::default()
+        };
+        let rendered = template.render("src/a.rs", "let s = \"{path}\";", &signals);
+        assert!(rendered.starts_with("src/a.rs {\"json\": 1}\nLines: 3 total"));
+        // Substituted content is left alone
+        assert!(rendered.ends_with("let s = \"{path}\";"));
+    }
 }
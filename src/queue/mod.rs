@@ -6,7 +6,9 @@ pub mod processor;
 
 // Re-export main types
 pub use processor::{
-    advance_stage, capture_note, capture_thought, capture_todo, enqueue, get_pending_items,
-    get_queue_item, get_queue_stats, get_retriable_items, mark_failed, update_analysis,
-    AnalysisResult, FileAnalysisResult, LlmAnalyzer, ProcessorConfig, QueueProcessor, QueueStats,
+    advance_stage, capture_note, capture_thought, capture_todo, enqueue, get_dead_letter_items,
+    get_pending_items, get_queue_item, get_queue_stats, get_retriable_items, mark_failed,
+    record_failure, requeue_dead_letter, update_analysis, AnalysisResult, BreakdownTask,
+    FileAnalysisResult, IdeaBreakdown, LlmAnalyzer, ProcessorConfig, QueueProcessor, QueueStats,
+    SuggestedTag, TagSuggestions,
 };
@@ -29,15 +29,73 @@
 //! }
 //! ```
 
+use crate::context::{ContextBuilder, GlobalContextBundle};
 use crate::db::Database;
-use crate::grok_client::GrokClient;
+use crate::grok_client::{AskResponse, GrokClient};
+use crate::prompt_router::FileAnalysisTemplate;
+use crate::redaction::{self, RedactedContent};
+use crate::refactor_patch::Patch;
+use crate::static_analysis::{self, StaticAnalyzer};
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// LLM calls the assistant makes; implemented by [`GrokClient`]
+#[async_trait::async_trait]
+pub trait RefactorLlm: Send + Sync {
+    async fn ask(&self, question: &str, context: Option<&str>) -> Result<String>;
+
+    async fn ask_tracked(
+        &self,
+        question: &str,
+        context: Option<&str>,
+        operation: &str,
+    ) -> Result<AskResponse>;
+
+    /// Cached response for `key`, if any
+    async fn cached_response(&self, _key: &str, _operation: &str) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    async fn cache_response(&self, _key: &str, _operation: &str, _response: &str) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl RefactorLlm for GrokClient {
+    async fn ask(&self, question: &str, context: Option<&str>) -> Result<String> {
+        GrokClient::ask(self, question, context).await
+    }
+
+    async fn ask_tracked(
+        &self,
+        question: &str,
+        context: Option<&str>,
+        operation: &str,
+    ) -> Result<AskResponse> {
+        GrokClient::ask_tracked(self, question, context, operation).await
+    }
+
+    async fn cached_response(&self, key: &str, operation: &str) -> Result<Option<String>> {
+        GrokClient::cached_response(self, key, operation).await
+    }
+
+    async fn cache_response(&self, key: &str, operation: &str, response: &str) -> Result<()> {
+        GrokClient::cache_response(self, key, operation, response).await
+    }
+}
 
 /// Refactoring assistant with AI-powered analysis
 pub struct RefactorAssistant {
-    grok_client: GrokClient,
+    llm: Box<dyn RefactorLlm>,
+    /// Set when secrets are masked before content is sent
+    redactor: Option<StaticAnalyzer>,
+    /// Total tokens [`RefactorAssistant::analyze_files`] may spend
+    token_budget: Option<usize>,
+    /// Repo template used instead of [`RefactorAssistant::analysis_prompt`]
+    prompt_template: Option<FileAnalysisTemplate>,
 }
 
 /// Complete refactoring analysis for a file or directory
@@ -62,6 +120,21 @@ pub struct RefactoringAnalysis {
     pub tokens_used: Option<usize>,
 }
 
+/// Result of [`RefactorAssistant::analyze_files`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BatchRefactoringAnalysis {
+    /// Per-file analyses, in input order
+    pub analyses: Vec<RefactoringAnalysis>,
+    /// Files that could not be read or analyzed, with the error
+    pub failed: Vec<(PathBuf, String)>,
+    /// Files not analyzed because the token budget ran out
+    pub skipped: Vec<PathBuf>,
+    /// Tokens used across all files (cache hits cost none)
+    pub total_tokens: usize,
+    /// Files answered from the response cache
+    pub cache_hits: usize,
+}
+
 /// Detected code smell
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CodeSmell {
@@ -284,7 +357,56 @@ impl RefactorAssistant {
     /// Create a new refactoring assistant
     pub async fn new(db: Database) -> Result<Self> {
         let grok_client = GrokClient::from_env(db).await?;
-        Ok(Self { grok_client })
+        Ok(Self::with_llm(grok_client))
+    }
+
+    /// Create an assistant backed by any [`RefactorLlm`]
+    pub fn with_llm(llm: impl RefactorLlm + 'static) -> Self {
+        Self {
+            llm: Box::new(llm),
+            redactor: None,
+            token_budget: None,
+            prompt_template: None,
+        }
+    }
+
+    /// Stop a batch once this many tokens have been used
+    pub fn with_token_budget(mut self, max_tokens: usize) -> Self {
+        self.token_budget = Some(max_tokens);
+        self
+    }
+
+    /// Mask detected secrets (API keys, tokens, passwords, private keys)
+    /// before file content goes into a prompt
+    pub fn with_secret_redaction(mut self, enabled: bool) -> Self {
+        self.redactor = enabled.then(StaticAnalyzer::new);
+        self
+    }
+
+    /// Build file analysis prompts from a repo's template instead of the
+    /// built-in one
+    pub fn with_prompt_template(mut self, template: Option<FileAnalysisTemplate>) -> Self {
+        self.prompt_template = template;
+        self
+    }
+
+    /// Prompt for analyzing `content` (already redacted) of `file_path`
+    fn file_prompt(&self, file_path: &str, content: &str) -> String {
+        match &self.prompt_template {
+            Some(template) => {
+                let signals = StaticAnalyzer::new().analyze(file_path, content).signals;
+                template.render(file_path, content, &signals)
+            }
+            None => Self::analysis_prompt(content),
+        }
+    }
+
+    /// Content as it will be sent: redacted when enabled, otherwise as is
+    pub fn outgoing_content(&self, file_path: &str, content: &str) -> RedactedContent {
+        match &self.redactor {
+            Some(analyzer) => redaction::redact_with(analyzer, file_path, content),
+            None => RedactedContent::unchanged(content),
+        }
     }
 
     /// Analyze a file for refactoring opportunities
@@ -297,6 +419,121 @@ impl RefactorAssistant {
             .await
     }
 
+    /// Unified-diff patch for the suggestions in `analysis` that come with a
+    /// before/after example; the file itself is never modified
+    pub fn suggest_patch(
+        &self,
+        file_path: impl AsRef<Path>,
+        analysis: &RefactoringAnalysis,
+    ) -> Result<Patch> {
+        let file_path = file_path.as_ref();
+        let content = std::fs::read_to_string(file_path)
+            .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
+
+        Patch::from_suggestions(
+            &file_path.to_string_lossy(),
+            &content,
+            &analysis.suggestions,
+        )
+    }
+
+    /// Analyze several files, sending the same shared context with each
+    ///
+    /// Responses are cached per file (keyed by path, content and context), so
+    /// unchanged files cost nothing on a re-run. Once the token budget is
+    /// spent the remaining files are skipped.
+    pub async fn analyze_files(
+        &self,
+        paths: &[PathBuf],
+        shared_context: Option<&GlobalContextBundle>,
+    ) -> Result<BatchRefactoringAnalysis> {
+        let shared_context = shared_context.map(ContextBuilder::format_for_llm);
+        let context_hash = shared_context
+            .as_deref()
+            .map(static_analysis::content_hash)
+            .unwrap_or_default();
+        let mut batch = BatchRefactoringAnalysis::default();
+
+        for path in paths {
+            if self
+                .token_budget
+                .is_some_and(|budget| batch.total_tokens >= budget)
+            {
+                batch.skipped.push(path.clone());
+                continue;
+            }
+
+            let content = match std::fs::read_to_string(path) {
+                Ok(content) => content,
+                Err(e) => {
+                    batch.failed.push((path.clone(), e.to_string()));
+                    continue;
+                }
+            };
+            let file_path = path.to_string_lossy().to_string();
+            let outgoing = self.outgoing_content(&file_path, &content);
+            let prompt = self.file_prompt(&file_path, &outgoing.content);
+            let mut cache_key = format!(
+                "{}:{}:{}",
+                file_path,
+                static_analysis::content_hash(&outgoing.content),
+                context_hash
+            );
+            if let Some(template) = &self.prompt_template {
+                cache_key = format!("{}:{}", cache_key, template.hash());
+            }
+
+            let cached = self
+                .llm
+                .cached_response(&cache_key, "refactor_analysis")
+                .await
+                .unwrap_or_else(|e| {
+                    warn!("Refactor cache lookup failed for {}: {}", file_path, e);
+                    None
+                });
+            let (response, tokens) = match cached {
+                Some(response) => {
+                    batch.cache_hits += 1;
+                    (response, 0)
+                }
+                None => {
+                    // The prompt already carries the file content
+                    match self
+                        .llm
+                        .ask_tracked(&prompt, shared_context.as_deref(), "refactor_analysis")
+                        .await
+                    {
+                        Ok(tracked) => {
+                            if let Err(e) = self
+                                .llm
+                                .cache_response(&cache_key, "refactor_analysis", &tracked.content)
+                                .await
+                            {
+                                warn!("Failed to cache refactor analysis: {}", e);
+                            }
+                            (tracked.content, tracked.total_tokens as usize)
+                        }
+                        Err(e) => {
+                            batch.failed.push((path.clone(), format!("{:#}", e)));
+                            continue;
+                        }
+                    }
+                }
+            };
+
+            batch.total_tokens += tokens;
+            match self.parse_refactoring_response(&response, file_path) {
+                Ok(mut analysis) => {
+                    analysis.tokens_used = Some(tokens);
+                    batch.analyses.push(analysis);
+                }
+                Err(e) => batch.failed.push((path.clone(), e.to_string())),
+            }
+        }
+
+        Ok(batch)
+    }
+
     /// Analyze directory for refactoring opportunities
     pub async fn analyze_directory(
         &self,
@@ -328,7 +565,30 @@ impl RefactorAssistant {
         file_path: String,
         content: &str,
     ) -> Result<RefactoringAnalysis> {
-        let prompt = format!(
+        let outgoing = self.outgoing_content(&file_path, content);
+        if outgoing.is_redacted() {
+            tracing::debug!(
+                "Redacted {} secret(s) from {} before analysis",
+                outgoing.redactions().len(),
+                file_path
+            );
+        }
+        let prompt = self.file_prompt(&file_path, &outgoing.content);
+
+        let tracked = self
+            .llm
+            .ask_tracked(&prompt, Some(&outgoing.content), "refactor_analysis")
+            .await
+            .context("Failed to analyze code for refactoring")?;
+
+        let mut analysis = self.parse_refactoring_response(&tracked.content, file_path)?;
+        analysis.tokens_used = Some(tracked.total_tokens as usize);
+        Ok(analysis)
+    }
+
+    /// Prompt asking for a JSON refactoring analysis of `content`
+    pub fn analysis_prompt(content: &str) -> String {
+        format!(
             r#"Analyze this code for refactoring opportunities. Return ONLY valid JSON with this structure:
 {{
   "code_smells": [
@@ -391,17 +651,7 @@ For each smell, provide:
 - Effort estimate
 - Priority level"#,
             content
-        );
-
-        let tracked = self
-            .grok_client
-            .ask_tracked(&prompt, Some(content), "refactor_analysis")
-            .await
-            .context("Failed to analyze code for refactoring")?;
-
-        let mut analysis = self.parse_refactoring_response(&tracked.content, file_path)?;
-        analysis.tokens_used = Some(tracked.total_tokens as usize);
-        Ok(analysis)
+        )
     }
 
     /// Generate a refactoring plan for multiple files
@@ -474,7 +724,7 @@ Make the plan:
         );
 
         let response = self
-            .grok_client
+            .llm
             .ask(&prompt, None)
             .await
             .context("Failed to generate refactoring plan")?;
@@ -528,7 +778,7 @@ Suggest:
         );
 
         let response = self
-            .grok_client
+            .llm
             .ask(&prompt, Some(&content))
             .await
             .context("Failed to suggest extract function")?;
@@ -1144,3 +1394,172 @@ impl std::fmt::Display for RefactoringType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::SystemMap;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    /// (question, context) of each request
+    type Requests = Arc<Mutex<Vec<(String, Option<String>)>>>;
+
+    /// Records every request and answers with a fixed analysis
+    #[derive(Default)]
+    struct MockLlm {
+        requests: Requests,
+        cache: Mutex<HashMap<String, String>>,
+    }
+
+    #[async_trait::async_trait]
+    impl RefactorLlm for MockLlm {
+        async fn ask(&self, _question: &str, _context: Option<&str>) -> Result<String> {
+            anyhow::bail!("not used")
+        }
+
+        async fn ask_tracked(
+            &self,
+            question: &str,
+            context: Option<&str>,
+            _operation: &str,
+        ) -> Result<AskResponse> {
+            self.requests
+                .lock()
+                .unwrap()
+                .push((question.to_string(), context.map(String::from)));
+            Ok(AskResponse {
+                content: r#"{"code_smells": [], "suggestions": [], "complexity_score": 20, "maintainability_score": 80, "priorities": [], "estimated_effort": "small"}"#.to_string(),
+                total_tokens: 100,
+                prompt_tokens: 80,
+                completion_tokens: 20,
+                cost_usd: 0.0,
+            })
+        }
+
+        async fn cached_response(&self, key: &str, _operation: &str) -> Result<Option<String>> {
+            Ok(self.cache.lock().unwrap().get(key).cloned())
+        }
+
+        async fn cache_response(&self, key: &str, _operation: &str, response: &str) -> Result<()> {
+            self.cache
+                .lock()
+                .unwrap()
+                .insert(key.to_string(), response.to_string());
+            Ok(())
+        }
+    }
+
+    fn write_files(dir: &Path) -> Vec<PathBuf> {
+        let a = dir.join("a.rs");
+        let b = dir.join("b.rs");
+        std::fs::write(&a, "pub fn parse_a() -> u32 { 1 }\n").unwrap();
+        std::fs::write(&b, "pub fn parse_b() -> u32 { 2 }\n").unwrap();
+        vec![a, b]
+    }
+
+    #[tokio::test]
+    async fn test_analyze_files_sends_shared_context_with_each_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = write_files(dir.path());
+        let bundle = ContextBuilder::new(dir.path())
+            .build(SystemMap {
+                total_files: 2,
+                files_by_category: HashMap::new(),
+                lines_by_category: HashMap::new(),
+                dependencies: vec![],
+                mermaid_diagram: None,
+            })
+            .unwrap();
+
+        let llm = MockLlm::default();
+        let requests = llm.requests.clone();
+        let assistant = RefactorAssistant::with_llm(llm);
+
+        let batch = assistant
+            .analyze_files(&paths, Some(&bundle))
+            .await
+            .unwrap();
+        assert_eq!(batch.analyses.len(), 2);
+        assert_eq!(batch.total_tokens, 200);
+
+        {
+            let requests = requests.lock().unwrap();
+            assert_eq!(requests.len(), 2);
+            for ((question, context), code) in requests.iter().zip(["parse_a", "parse_b"]) {
+                assert!(question.contains(code));
+                assert!(context
+                    .as_deref()
+                    .unwrap()
+                    .contains("GLOBAL CONTEXT BUNDLE"));
+            }
+        }
+
+        // Unchanged files come from the cache on a re-run
+        let rerun = assistant
+            .analyze_files(&paths, Some(&bundle))
+            .await
+            .unwrap();
+        assert_eq!(rerun.cache_hits, 2);
+        assert_eq!(rerun.total_tokens, 0);
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_analyze_files_stops_at_token_budget() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = write_files(dir.path());
+        let llm = MockLlm::default();
+        let requests = llm.requests.clone();
+        let assistant = RefactorAssistant::with_llm(llm).with_token_budget(100);
+
+        let batch = assistant.analyze_files(&paths, None).await.unwrap();
+        assert_eq!(batch.analyses.len(), 1);
+        assert_eq!(batch.skipped, vec![paths[1].clone()]);
+        assert_eq!(batch.total_tokens, 100);
+
+        // Without a bundle the file content goes once, in the prompt
+        let requests = requests.lock().unwrap();
+        let (question, context) = &requests[0];
+        assert!(question.contains("parse_a"));
+        assert_eq!(context, &None);
+    }
+
+    #[tokio::test]
+    async fn test_repo_prompt_template_replaces_builtin_prompt() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = write_files(dir.path());
+        let template_path = dir
+            .path()
+            .join(crate::prompt_router::FILE_ANALYSIS_TEMPLATE_PATH);
+        std::fs::create_dir_all(template_path.parent().unwrap()).unwrap();
+        std::fs::write(
+            &template_path,
+            "House rules review of {path}\n{static_signals}\n```\n{content}\n```\n",
+        )
+        .unwrap();
+
+        let template = FileAnalysisTemplate::load_for_repo(dir.path()).unwrap();
+        assert!(template.is_some());
+        let llm = MockLlm::default();
+        let requests = llm.requests.clone();
+        let assistant = RefactorAssistant::with_llm(llm).with_prompt_template(template);
+
+        let analysis = assistant.analyze_file(&paths[0]).await.unwrap();
+        assert_eq!(analysis.maintainability_score, 80.0);
+
+        let requests = requests.lock().unwrap();
+        let (question, _) = &requests[0];
+        assert!(question.starts_with("House rules review of "));
+        assert!(question.contains("a.rs"));
+        assert!(question.contains("pub fn parse_a()"));
+        assert!(question.contains("Functions: 1"));
+        assert!(!question.contains("Focus on detecting"));
+
+        // Repos without a template keep the built-in prompt
+        let other = tempfile::tempdir().unwrap();
+        assert!(FileAnalysisTemplate::load_for_repo(other.path())
+            .unwrap()
+            .is_none());
+    }
+}
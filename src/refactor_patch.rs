@@ -0,0 +1,448 @@
+//! Unified-diff patches from refactoring suggestions
+//!
+//! Turns the before/after examples in [`RefactoringSuggestion`]s into a
+//! [`Patch`] that can be reviewed and applied with `git apply`. Nothing here
+//! writes files. Each suggestion's `before` snippet must match exactly one
+//! place in the file (ignoring indentation); suggestions that don't match,
+//! match ambiguously or overlap an earlier hunk are dropped into
+//! [`Patch::rejected`] instead.
+
+use crate::refactor_assistant::RefactoringSuggestion;
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+/// Unchanged lines shown around each change
+const CONTEXT_LINES: usize = 3;
+
+/// Confidence for a snippet that matched only after ignoring indentation
+const REINDENTED_CONFIDENCE: f64 = 0.8;
+
+/// One `@@` hunk
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatchHunk {
+    /// 1-based first line in the original file
+    pub old_start: usize,
+    pub old_lines: usize,
+    /// 1-based first line in the patched file
+    pub new_start: usize,
+    pub new_lines: usize,
+    /// Body lines, each prefixed with ' ', '-' or '+'
+    pub lines: Vec<String>,
+    /// Titles of the suggestions this hunk applies
+    pub suggestions: Vec<String>,
+    /// 1.0 for an exact snippet match, lower when indentation was adjusted
+    pub confidence: f64,
+}
+
+/// A suggestion that could not be turned into a hunk
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RejectedHunk {
+    pub suggestion: String,
+    pub reason: String,
+}
+
+/// Patch for a single file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Patch {
+    /// Path as it appears in the `---`/`+++` headers
+    pub file: String,
+    pub hunks: Vec<PatchHunk>,
+    pub rejected: Vec<RejectedHunk>,
+    /// Mean hunk confidence (0.0 for an empty patch)
+    pub confidence: f64,
+    /// Whether the original file ends without a newline
+    #[serde(default)]
+    pub no_newline_at_eof: bool,
+}
+
+/// A replacement of `old_start..old_end` (0-based, exclusive) in the file
+struct Change {
+    old_start: usize,
+    old_end: usize,
+    new_lines: Vec<String>,
+    suggestion: String,
+    confidence: f64,
+}
+
+impl Patch {
+    /// Build a patch for `content` from the suggestions' before/after examples
+    ///
+    /// Suggestions without an example, or whose example doesn't change
+    /// anything, are ignored rather than rejected.
+    pub fn from_suggestions(
+        file: &str,
+        content: &str,
+        suggestions: &[RefactoringSuggestion],
+    ) -> Result<Self> {
+        let lines: Vec<&str> = content.lines().collect();
+        let mut changes: Vec<Change> = Vec::new();
+        let mut rejected = Vec::new();
+
+        for suggestion in suggestions {
+            let Some(example) = &suggestion.example else {
+                continue;
+            };
+            let before = trim_blank_edges(&example.before);
+            let after = trim_blank_edges(&example.after);
+            if before.is_empty() || before == after {
+                continue;
+            }
+            let reject = |reason: &str| RejectedHunk {
+                suggestion: suggestion.title.clone(),
+                reason: reason.to_string(),
+            };
+
+            let matches = find_snippet(&lines, &before);
+            let start = match matches.as_slice() {
+                [] => {
+                    rejected.push(reject("`before` snippet not found in file"));
+                    continue;
+                }
+                [start] => *start,
+                _ => {
+                    rejected.push(reject("`before` snippet matches more than once"));
+                    continue;
+                }
+            };
+            let end = start + before.len();
+            if changes
+                .iter()
+                .any(|c| start < c.old_end && c.old_start < end)
+            {
+                rejected.push(reject("overlaps an earlier hunk"));
+                continue;
+            }
+
+            let exact = lines[start..end] == before[..];
+            let indent = leading_whitespace(lines[start]);
+            changes.push(Change {
+                old_start: start,
+                old_end: end,
+                new_lines: reindent(&after, indent),
+                suggestion: suggestion.title.clone(),
+                confidence: if exact { 1.0 } else { REINDENTED_CONFIDENCE },
+            });
+        }
+
+        changes.sort_by_key(|c| c.old_start);
+        let no_newline_at_eof = !content.is_empty() && !content.ends_with('\n');
+        let hunks = build_hunks(&lines, &changes, no_newline_at_eof);
+        let confidence = if hunks.is_empty() {
+            0.0
+        } else {
+            hunks.iter().map(|h| h.confidence).sum::<f64>() / hunks.len() as f64
+        };
+        let patch = Self {
+            file: file.to_string(),
+            hunks,
+            rejected,
+            confidence,
+            no_newline_at_eof,
+        };
+
+        // Never hand back a patch that doesn't apply to the file it was made for
+        patch.apply(content)?;
+        Ok(patch)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hunks.is_empty()
+    }
+
+    /// The patch in unified diff format, ready for `git apply`
+    pub fn to_unified_diff(&self) -> String {
+        if self.hunks.is_empty() {
+            return String::new();
+        }
+        let mut out = format!("diff --git a/{0} b/{0}\n--- a/{0}\n+++ b/{0}\n", self.file);
+        for hunk in &self.hunks {
+            out.push_str(&format!(
+                "@@ -{},{} +{},{} @@\n",
+                hunk.old_start, hunk.old_lines, hunk.new_start, hunk.new_lines
+            ));
+            for line in &hunk.lines {
+                out.push_str(line);
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    /// Apply the patch to `content`, checking every context and removed line
+    pub fn apply(&self, content: &str) -> Result<String> {
+        let lines: Vec<&str> = content.lines().collect();
+        let mut out: Vec<&str> = Vec::with_capacity(lines.len());
+        let mut pos = 0;
+
+        for hunk in &self.hunks {
+            let start = hunk.old_start.saturating_sub(1);
+            if start < pos || start > lines.len() {
+                bail!(
+                    "Hunk at line {} is out of order or past EOF",
+                    hunk.old_start
+                );
+            }
+            out.extend_from_slice(&lines[pos..start]);
+            pos = start;
+
+            for line in &hunk.lines {
+                if line.starts_with('\\') {
+                    continue;
+                }
+                let (marker, text) = line.split_at(1);
+                match marker {
+                    " " | "-" => {
+                        if lines.get(pos) != Some(&text) {
+                            bail!(
+                                "Hunk at line {} does not apply: line {} differs",
+                                hunk.old_start,
+                                pos + 1
+                            );
+                        }
+                        if marker == " " {
+                            out.push(text);
+                        }
+                        pos += 1;
+                    }
+                    "+" => out.push(text),
+                    _ => bail!("Malformed hunk line: {:?}", line),
+                }
+            }
+        }
+        out.extend_from_slice(&lines[pos..]);
+
+        let mut patched = out.join("\n");
+        if !self.no_newline_at_eof && !patched.is_empty() {
+            patched.push('\n');
+        }
+        Ok(patched)
+    }
+}
+
+/// Group nearby changes into hunks with surrounding context
+fn build_hunks(lines: &[&str], changes: &[Change], no_newline_at_eof: bool) -> Vec<PatchHunk> {
+    let mut hunks = Vec::new();
+    let mut offset: isize = 0;
+    let mut i = 0;
+
+    while i < changes.len() {
+        // Changes whose contexts would touch share a hunk
+        let mut j = i + 1;
+        while j < changes.len()
+            && changes[j].old_start <= changes[j - 1].old_end + 2 * CONTEXT_LINES
+        {
+            j += 1;
+        }
+        let group = &changes[i..j];
+
+        let ctx_start = group[0].old_start.saturating_sub(CONTEXT_LINES);
+        let ctx_end = (group[group.len() - 1].old_end + CONTEXT_LINES).min(lines.len());
+
+        let mut body = Vec::new();
+        let mut pos = ctx_start;
+        let mut added = 0usize;
+        let mut removed = 0usize;
+        for change in group {
+            body.extend(
+                lines[pos..change.old_start]
+                    .iter()
+                    .map(|l| format!(" {}", l)),
+            );
+            body.extend(
+                lines[change.old_start..change.old_end]
+                    .iter()
+                    .map(|l| format!("-{}", l)),
+            );
+            body.extend(change.new_lines.iter().map(|l| format!("+{}", l)));
+            removed += change.old_end - change.old_start;
+            added += change.new_lines.len();
+            pos = change.old_end;
+        }
+        body.extend(lines[pos..ctx_end].iter().map(|l| format!(" {}", l)));
+        if no_newline_at_eof && ctx_end == lines.len() {
+            mark_missing_newline(&mut body);
+        }
+
+        let old_lines = ctx_end - ctx_start;
+        let new_lines = old_lines - removed + added;
+        let new_start = (ctx_start as isize + offset) as usize;
+        hunks.push(PatchHunk {
+            old_start: ctx_start + 1,
+            old_lines,
+            new_start: new_start + 1,
+            new_lines,
+            lines: body,
+            suggestions: group.iter().map(|c| c.suggestion.clone()).collect(),
+            confidence: group.iter().map(|c| c.confidence).fold(1.0, f64::min),
+        });
+
+        offset += added as isize - removed as isize;
+        i = j;
+    }
+    hunks
+}
+
+/// Add git's `\ No newline at end of file` after the last line of each side
+fn mark_missing_newline(body: &mut Vec<String>) {
+    const MARKER: &str = "\\ No newline at end of file";
+    let last_old = body.iter().rposition(|l| !l.starts_with('+'));
+    let last_new = body.iter().rposition(|l| !l.starts_with('-'));
+    match (last_old, last_new) {
+        (Some(old), Some(new)) if old == new => body.insert(old + 1, MARKER.to_string()),
+        (old, new) => {
+            // Later position first so the earlier index stays valid
+            let mut at: Vec<usize> = old.into_iter().chain(new).collect();
+            at.sort_unstable_by(|a, b| b.cmp(a));
+            for i in at {
+                body.insert(i + 1, MARKER.to_string());
+            }
+        }
+    }
+}
+
+/// Start indices where `snippet` matches `lines`, ignoring leading and
+/// trailing whitespace on each line
+fn find_snippet(lines: &[&str], snippet: &[&str]) -> Vec<usize> {
+    if snippet.len() > lines.len() {
+        return Vec::new();
+    }
+    (0..=lines.len() - snippet.len())
+        .filter(|&start| {
+            snippet
+                .iter()
+                .zip(&lines[start..])
+                .all(|(s, l)| s.trim() == l.trim())
+        })
+        .collect()
+}
+
+/// Lines of `text` without leading/trailing blank lines
+fn trim_blank_edges(text: &str) -> Vec<&str> {
+    let lines: Vec<&str> = text.lines().collect();
+    let start = lines
+        .iter()
+        .position(|l| !l.trim().is_empty())
+        .unwrap_or(lines.len());
+    let end = lines
+        .iter()
+        .rposition(|l| !l.trim().is_empty())
+        .map_or(start, |i| i + 1);
+    lines[start..end].to_vec()
+}
+
+fn leading_whitespace(line: &str) -> &str {
+    &line[..line.len() - line.trim_start().len()]
+}
+
+/// Shift `lines` so their least-indented line starts at `indent`
+fn reindent(lines: &[&str], indent: &str) -> Vec<String> {
+    let common = lines
+        .iter()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| leading_whitespace(l).len())
+        .min()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .map(|l| {
+            if l.trim().is_empty() {
+                String::new()
+            } else {
+                format!("{}{}", indent, &l[common..])
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::refactor_assistant::{
+        EffortEstimate, RefactoringExample, RefactoringPriority, RefactoringType,
+    };
+
+    fn suggestion(title: &str, before: &str, after: &str) -> RefactoringSuggestion {
+        RefactoringSuggestion {
+            refactoring_type: RefactoringType::ImproveErrorHandling,
+            title: title.to_string(),
+            description: String::new(),
+            benefits: vec![],
+            steps: vec![],
+            example: Some(RefactoringExample {
+                before: before.to_string(),
+                after: after.to_string(),
+                explanation: String::new(),
+            }),
+            effort: EffortEstimate::Small,
+            priority: RefactoringPriority::Medium,
+        }
+    }
+
+    const FILE: &str = "use std::fs;\n\nfn load(path: &str) -> String {\n    let data = fs::read_to_string(path).unwrap();\n    data.trim().to_string()\n}\n";
+
+    #[test]
+    fn test_patch_from_suggestion_applies_cleanly() {
+        // The LLM's snippet is unindented; the hunk keeps the file's indentation
+        let fix = suggestion(
+            "Propagate read errors",
+            "let data = fs::read_to_string(path).unwrap();",
+            "let data = fs::read_to_string(path)\n    .unwrap_or_default();",
+        );
+        let patch = Patch::from_suggestions("src/load.rs", FILE, &[fix]).unwrap();
+
+        assert_eq!(patch.hunks.len(), 1);
+        assert!(patch.rejected.is_empty());
+        assert_eq!(patch.confidence, REINDENTED_CONFIDENCE);
+
+        let diff = patch.to_unified_diff();
+        assert!(diff.starts_with("diff --git a/src/load.rs b/src/load.rs\n--- a/src/load.rs\n+++ b/src/load.rs\n@@ -1,6 +1,7 @@\n"));
+        assert!(diff.contains("\n-    let data = fs::read_to_string(path).unwrap();\n"));
+        assert!(diff.contains(
+            "\n+    let data = fs::read_to_string(path)\n+        .unwrap_or_default();\n"
+        ));
+        git2::Diff::from_buffer(diff.as_bytes()).expect("git-parsable diff");
+
+        let patched = patch.apply(FILE).unwrap();
+        assert!(patched.contains("        .unwrap_or_default();\n    data.trim()"));
+        assert_eq!(patched.lines().count(), FILE.lines().count() + 1);
+    }
+
+    #[test]
+    fn test_suggestion_that_does_not_apply_is_rejected() {
+        let stale = suggestion(
+            "Use a BufReader",
+            "let file = File::open(path)?;",
+            "let file = BufReader::new(File::open(path)?);",
+        );
+        let fix = suggestion(
+            "Return trimmed slice",
+            "    data.trim().to_string()",
+            "    data.trim().to_owned()",
+        );
+        let patch = Patch::from_suggestions("src/load.rs", FILE, &[stale, fix]).unwrap();
+
+        assert_eq!(patch.hunks.len(), 1);
+        assert_eq!(patch.hunks[0].suggestions, vec!["Return trimmed slice"]);
+        assert_eq!(patch.confidence, 1.0);
+        assert_eq!(patch.rejected.len(), 1);
+        assert_eq!(patch.rejected[0].suggestion, "Use a BufReader");
+
+        // A patch is checked against the file it is applied to
+        let edited = FILE.replace("data.trim().to_string()", "data.to_string()");
+        assert!(patch.apply(&edited).is_err());
+    }
+
+    #[test]
+    fn test_missing_newline_at_eof_is_marked() {
+        let content = "fn a() {}\nfn b() { 1 }";
+        let fix = suggestion("Return unit", "fn b() { 1 }", "fn b() {}");
+        let patch = Patch::from_suggestions("src/b.rs", content, &[fix]).unwrap();
+
+        let diff = patch.to_unified_diff();
+        assert!(diff.ends_with(
+            "-fn b() { 1 }\n\\ No newline at end of file\n+fn b() {}\n\\ No newline at end of file\n"
+        ));
+        git2::Diff::from_buffer(diff.as_bytes()).expect("git-parsable diff");
+        assert_eq!(patch.apply(content).unwrap(), "fn a() {}\nfn b() {}");
+    }
+}
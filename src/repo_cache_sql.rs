@@ -10,6 +10,7 @@
 //! - Token usage tracking and cost estimation
 //! - Advanced queries (by repo, model, prompt, date range)
 //! - Cache eviction policies (LRU, size-based, cost-aware)
+//! - Compaction by entry count and age, with on-disk size reporting
 //! - Migration from JSON file-based cache
 //!
 //! ## Usage
@@ -140,6 +141,27 @@ pub struct ModelStats {
     pub cost: f64,
 }
 
+/// On-disk size of a cache database
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheDbStats {
+    pub entries: i64,
+    /// Database file size (page count × page size)
+    pub total_bytes: i64,
+    /// Creation time of the oldest entry
+    pub oldest_entry: Option<DateTime<Utc>>,
+}
+
+/// What [`RepoCacheSql::compact`] removed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheCompaction {
+    /// Entries older than the age limit
+    pub expired: u64,
+    /// Least recently used entries beyond the entry limit
+    pub evicted: u64,
+    pub bytes_before: i64,
+    pub bytes_after: i64,
+}
+
 /// Eviction policy for cache cleanup
 #[derive(Debug, Clone, Copy)]
 pub enum EvictionPolicy {
@@ -303,6 +325,23 @@ impl RepoCacheSql {
         .execute(&self.pool)
         .await?;
 
+        // Static (zero-cost) scan results, invalidated by content hash
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS static_scan_results (
+                repo_path TEXT NOT NULL,
+                file_path TEXT NOT NULL,
+                file_hash TEXT NOT NULL,
+                schema_version INTEGER NOT NULL,
+                result_json TEXT NOT NULL,
+                updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+                PRIMARY KEY (repo_path, file_path)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
         // Initialize stats row if not exists
         sqlx::query(
             r#"
@@ -717,6 +756,75 @@ impl RepoCacheSql {
         Ok(deleted)
     }
 
+    /// Entry count, database size and oldest entry
+    pub async fn db_stats(&self) -> Result<CacheDbStats> {
+        let (entries, oldest) = sqlx::query_as::<_, (i64, Option<i64>)>(
+            r#"
+            SELECT COUNT(*), CAST(strftime('%s', MIN(created_at)) AS INTEGER)
+            FROM cache_entries
+            "#,
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        let (total_bytes,) = sqlx::query_as::<_, (i64,)>(
+            "SELECT page_count * page_size FROM pragma_page_count(), pragma_page_size()",
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(CacheDbStats {
+            entries,
+            total_bytes,
+            oldest_entry: oldest.and_then(|secs| DateTime::from_timestamp(secs, 0)),
+        })
+    }
+
+    /// Prune entries beyond the limits, then `VACUUM` to hand the space back
+    /// to the filesystem. Entries created more than `max_age_days` ago go
+    /// first; past `max_entries`, the least recently used follow. An entry
+    /// within both limits is never removed.
+    pub async fn compact(&self, max_entries: usize, max_age_days: u32) -> Result<CacheCompaction> {
+        let bytes_before = self.db_stats().await?.total_bytes;
+
+        let expired =
+            sqlx::query("DELETE FROM cache_entries WHERE created_at < datetime('now', $1)")
+                .bind(format!("-{} days", max_age_days))
+                .execute(&self.pool)
+                .await?
+                .rows_affected();
+
+        // Rewrites get a new id, so it breaks last_accessed ties by recency
+        let evicted = sqlx::query(
+            r#"
+            DELETE FROM cache_entries
+            WHERE id NOT IN (
+                SELECT id FROM cache_entries
+                ORDER BY last_accessed DESC, id DESC
+                LIMIT $1
+            )
+            "#,
+        )
+        .bind(max_entries as i64)
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+
+        sqlx::query("VACUUM").execute(&self.pool).await?;
+        let bytes_after = self.db_stats().await?.total_bytes;
+
+        info!(
+            "Compacted cache: {} expired, {} evicted, {} → {} bytes",
+            expired, evicted, bytes_before, bytes_after
+        );
+        Ok(CacheCompaction {
+            expired,
+            evicted,
+            bytes_before,
+            bytes_after,
+        })
+    }
+
     /// Get entries for a specific repository
     pub async fn entries_for_repo(&self, repo_path: &str) -> Result<Vec<CacheEntry>> {
         let rows = sqlx::query_as::<
@@ -851,6 +959,74 @@ impl RepoCacheSql {
 
         Ok(entries)
     }
+
+    /// Static scan results for a repository as `file_path -> (file_hash, result)`.
+    /// Rows written under another `schema_version` are ignored.
+    pub async fn static_results(
+        &self,
+        repo_path: &str,
+        schema_version: i32,
+    ) -> Result<std::collections::HashMap<String, (String, serde_json::Value)>> {
+        let rows: Vec<(String, String, String)> = sqlx::query_as(
+            r#"
+            SELECT file_path, file_hash, result_json
+            FROM static_scan_results
+            WHERE repo_path = $1 AND schema_version = $2
+            "#,
+        )
+        .bind(repo_path)
+        .bind(schema_version)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(file_path, file_hash, json)| {
+                let value = serde_json::from_str(&json).ok()?;
+                Some((file_path, (file_hash, value)))
+            })
+            .collect())
+    }
+
+    /// Store static scan results as `(file_path, file_hash, result)`, replacing
+    /// any previous result for the same file
+    pub async fn store_static_results(
+        &self,
+        repo_path: &str,
+        schema_version: i32,
+        results: &[(String, String, serde_json::Value)],
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        for (file_path, file_hash, result) in results {
+            sqlx::query(
+                r#"
+                INSERT INTO static_scan_results
+                    (repo_path, file_path, file_hash, schema_version, result_json, updated_at)
+                VALUES ($1, $2, $3, $4, $5, datetime('now'))
+                ON CONFLICT (repo_path, file_path) DO UPDATE SET
+                    file_hash = excluded.file_hash,
+                    schema_version = excluded.schema_version,
+                    result_json = excluded.result_json,
+                    updated_at = excluded.updated_at
+                "#,
+            )
+            .bind(repo_path)
+            .bind(file_path)
+            .bind(file_hash)
+            .bind(schema_version)
+            .bind(serde_json::to_string(result)?)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        debug!(
+            "Stored {} static scan results for {}",
+            results.len(),
+            repo_path
+        );
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -999,6 +1175,74 @@ mod tests {
         assert_eq!(stats.total_entries, 0);
     }
 
+    #[tokio::test]
+    async fn test_compact_keeps_most_recently_used_entries() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let cache = RepoCacheSql::new(temp.path().join("cache.db"))
+            .await
+            .unwrap();
+
+        for i in 0..6 {
+            cache
+                .set(CacheSetParams {
+                    cache_type: crate::repo_cache::CacheType::Refactor,
+                    repo_path: "/test/repo",
+                    file_path: &format!("src/file{}.rs", i),
+                    content: &format!("fn file{}() {{}}", i),
+                    provider: "xai",
+                    model: "grok-beta",
+                    result: serde_json::json!({"score": i}),
+                    tokens_used: Some(100),
+                    prompt_hash: None,
+                    schema_version: None,
+                })
+                .await
+                .unwrap();
+        }
+        // file0 was used last, file5 first; file4 is past the age limit
+        for i in 0..6 {
+            sqlx::query(
+                "UPDATE cache_entries SET last_accessed = datetime('now', $1) WHERE file_path = $2",
+            )
+            .bind(format!("-{} minutes", i))
+            .bind(format!("src/file{}.rs", i))
+            .execute(&cache.pool)
+            .await
+            .unwrap();
+        }
+        sqlx::query("UPDATE cache_entries SET created_at = datetime('now', '-40 days') WHERE file_path = 'src/file4.rs'")
+            .execute(&cache.pool)
+            .await
+            .unwrap();
+
+        let before = cache.db_stats().await.unwrap();
+        assert_eq!(before.entries, 6);
+        assert!(before.total_bytes > 0);
+        let oldest = before.oldest_entry.unwrap();
+        assert!(Utc::now() - oldest > chrono::Duration::days(39));
+
+        // Within both limits: nothing is touched
+        let noop = cache.compact(10, 60).await.unwrap();
+        assert_eq!((noop.expired, noop.evicted), (0, 0));
+        assert_eq!(cache.db_stats().await.unwrap().entries, 6);
+
+        let report = cache.compact(3, 30).await.unwrap();
+        assert_eq!((report.expired, report.evicted), (1, 2));
+        let mut kept: Vec<String> = cache
+            .get_all_entries()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| e.file_path)
+            .collect();
+        kept.sort();
+        assert_eq!(kept, vec!["src/file0.rs", "src/file1.rs", "src/file2.rs"]);
+
+        let after = cache.db_stats().await.unwrap();
+        assert_eq!(after.entries, 3);
+        assert!(after.oldest_entry.unwrap() > oldest);
+    }
+
     #[tokio::test]
     #[ignore = "RepoCacheSql uses SQLite internally; not available in postgres-only build"]
     async fn test_eviction() {
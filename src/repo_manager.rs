@@ -4,6 +4,7 @@
 //! Eliminates the need for bind-mounted host directories by cloning repos into
 //! container-managed storage.
 
+use crate::git::{CloneOptions, GitManager, RetryPolicy};
 use anyhow::{anyhow, Context, Result};
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -17,6 +18,10 @@ pub struct RepoManager {
     github_token: Option<String>,
     /// Default branch name
     default_branch: String,
+    /// How fresh clones are made (shallow depth, sparse paths)
+    clone_options: CloneOptions,
+    /// Retry behavior for transient network failures during clone/pull
+    retry_policy: RetryPolicy,
 }
 
 impl RepoManager {
@@ -39,11 +44,29 @@ impl RepoManager {
             repos_dir,
             github_token,
             default_branch: "main".to_string(),
+            // Shallow clone to save space
+            clone_options: CloneOptions::shallow(1),
+            retry_policy: RetryPolicy::default(),
         })
     }
 
+    /// Set how clone and pull retry transient network failures
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Set the options used for fresh clones (depth, sparse-checkout paths)
+    pub fn with_clone_options(mut self, options: CloneOptions) -> Self {
+        self.clone_options = options;
+        self
+    }
+
     /// Clone a repository or update if it already exists
     ///
+    /// Network failures (unresolvable host, timeouts, connection resets) are
+    /// retried per the [`RetryPolicy`]; auth failures are returned at once.
+    ///
     /// # Arguments
     /// * `git_url` - Git clone URL (HTTPS)
     /// * `repo_name` - Local directory name for the repo
@@ -53,11 +76,29 @@ impl RepoManager {
     pub fn clone_or_update(&self, git_url: &str, repo_name: &str) -> Result<PathBuf> {
         let repo_path = self.repos_dir.join(repo_name);
 
-        if repo_path.exists() {
-            self.update_repo(&repo_path, git_url)
-        } else {
-            self.clone_repo(git_url, repo_name)
-        }
+        self.retry_policy.run(|| {
+            if repo_path.exists() {
+                self.update_repo(&repo_path, git_url)
+            } else {
+                self.clone_repo(git_url, repo_name)
+            }
+        })
+    }
+
+    /// [`RepoManager::clone_or_update`] for async callers; retries wait
+    /// without blocking the runtime thread
+    pub async fn clone_or_update_async(&self, git_url: &str, repo_name: &str) -> Result<PathBuf> {
+        let repo_path = self.repos_dir.join(repo_name);
+
+        self.retry_policy
+            .run_async(|| async {
+                if repo_path.exists() {
+                    self.update_repo(&repo_path, git_url)
+                } else {
+                    self.clone_repo(git_url, repo_name)
+                }
+            })
+            .await
     }
 
     /// Clone a fresh repository
@@ -69,24 +110,10 @@ impl RepoManager {
         // Build authenticated URL if token is available
         let clone_url = self.build_authenticated_url(git_url)?;
 
-        let mut cmd = Command::new("git");
-        cmd.arg("clone")
-            .arg("--depth=1") // Shallow clone to save space
-            .arg(&clone_url)
-            .arg(&repo_path);
-
-        // Set environment to avoid credential prompts
-        cmd.env("GIT_TERMINAL_PROMPT", "0");
-
-        let output = cmd
-            .output()
-            .context("Failed to execute git clone command")?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            error!("Git clone failed: {}", stderr);
-            return Err(anyhow!("Git clone failed: {}", stderr));
-        }
+        GitManager::clone_into(&clone_url, &repo_path, &self.clone_options).map_err(|e| {
+            error!("Git clone failed: {}", e);
+            anyhow!("Git clone failed: {}", e)
+        })?;
 
         info!("Successfully cloned {} to {:?}", repo_name, repo_path);
         Ok(repo_path)
@@ -277,7 +304,7 @@ impl RepoManager {
         Ok(())
     }
 
-    /// List all cloned repositories
+    /// List all cloned repositories, sorted by name
     pub fn list_repos(&self) -> Result<Vec<String>> {
         let mut repos = Vec::new();
 
@@ -296,6 +323,7 @@ impl RepoManager {
             }
         }
 
+        repos.sort();
         Ok(repos)
     }
 
@@ -346,8 +346,11 @@ impl RepoSyncService {
         self.repos.get(id)
     }
 
+    /// Active repos ordered by local path, then name
     pub fn list_repos(&self) -> Vec<&RegisteredRepo> {
-        self.repos.values().filter(|r| r.active).collect()
+        let mut repos: Vec<&RegisteredRepo> = self.repos.values().filter(|r| r.active).collect();
+        repos.sort_by(|a, b| (&a.local_path, &a.name).cmp(&(&b.local_path, &b.name)));
+        repos
     }
 
     /// Remove a repo from the in-memory map (synchronous, no DB write).
@@ -1,11 +1,49 @@
 //! Research Aggregator
 //!
 //! Synthesizes findings from multiple workers into a coherent report.
+//!
+//! By default every successful worker becomes a report section. In
+//! [`AggregationMode::ConfidenceWeighted`] workers weigh in proportion to their
+//! confidence, workers below the confidence floor are moved to an appendix,
+//! and contradicting claims between the remaining workers are listed as
+//! disagreements instead of being silently merged.
 
 use super::{ResearchRequest, WorkerResult};
 use crate::llm::GrokClient;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use tracing::warn;
+
+/// Confidence (1-10) below which a worker's findings go to the appendix
+pub const DEFAULT_CONFIDENCE_FLOOR: i32 = 6;
+
+/// Words that flip the meaning of a claim
+const NEGATIONS: &[&str] = &[
+    "not", "no", "never", "cannot", "without", "lacks", "neither", "nor",
+];
+
+/// Words ignored when comparing two claims
+const STOPWORDS: &[&str] = &[
+    "a", "an", "the", "of", "to", "in", "on", "for", "and", "or", "it", "its", "this", "that",
+    "with", "be", "is", "are", "was", "were", "does", "do", "did", "can", "will", "should", "has",
+    "have", "by", "as", "at", "from",
+];
+
+/// Share of content words two claims must have in common to be about the
+/// same thing
+const SAME_SUBJECT_OVERLAP: f64 = 0.6;
+
+/// How worker findings are combined into a report
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum AggregationMode {
+    /// Every successful worker's findings become a section
+    #[default]
+    Concatenate,
+    /// Findings weigh in proportion to worker confidence; workers below
+    /// `confidence_floor` go to the appendix
+    ConfidenceWeighted { confidence_floor: i32 },
+}
 
 // ============================================================================
 // Aggregated Report
@@ -23,6 +61,12 @@ pub struct ResearchReport {
     pub total_tokens: i64,
     pub worker_count: i32,
     pub successful_workers: i32,
+    /// Contradicting claims between workers above the confidence floor
+    #[serde(default)]
+    pub disagreements: Vec<Disagreement>,
+    /// Sections from workers below the confidence floor
+    #[serde(default)]
+    pub appendix: Vec<ReportSection>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,6 +75,33 @@ pub struct ReportSection {
     pub content: String,
     pub sources: Vec<String>,
     pub confidence: i32,
+    /// Share (0.0-1.0) of the main report this section carries; 0.0 when
+    /// findings are not weighted
+    #[serde(default)]
+    pub weight: f64,
+}
+
+/// Two workers asserting opposite things about the same subject
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Disagreement {
+    pub subtopic: String,
+    pub claim: String,
+    pub confidence: i32,
+    pub other_subtopic: String,
+    pub other_claim: String,
+    pub other_confidence: i32,
+}
+
+/// Worker results split for a confidence-weighted report
+#[derive(Debug, Clone)]
+pub struct WeightedFindings {
+    /// Sections at or above the floor, highest weight first
+    pub sections: Vec<ReportSection>,
+    /// Sections below the floor
+    pub appendix: Vec<ReportSection>,
+    pub disagreements: Vec<Disagreement>,
+    /// Confidence-weighted mean confidence of `sections`
+    pub confidence_score: i32,
 }
 
 // ============================================================================
@@ -40,6 +111,7 @@ pub struct ReportSection {
 pub struct Aggregator {
     llm: GrokClient,
     max_tokens: usize,
+    mode: AggregationMode,
 }
 
 impl Aggregator {
@@ -47,9 +119,15 @@ impl Aggregator {
         Self {
             llm,
             max_tokens: 8192, // Larger for synthesis
+            mode: AggregationMode::default(),
         }
     }
 
+    pub fn with_mode(mut self, mode: AggregationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
     /// Aggregate worker results into a final report
     pub async fn aggregate(
         &self,
@@ -62,39 +140,39 @@ impl Aggregator {
             return Err(anyhow::anyhow!("No successful worker results to aggregate"));
         }
 
-        // Build sections from worker results
-        let sections: Vec<ReportSection> = successful
-            .iter()
-            .map(|r| ReportSection {
-                title: r.subtopic.clone(),
-                content: r.findings.clone(),
-                sources: r
-                    .sources
-                    .as_ref()
-                    .and_then(|s| serde_json::from_str(s).ok())
-                    .unwrap_or_default(),
-                confidence: r.confidence,
-            })
-            .collect();
+        let weighted = match self.mode {
+            AggregationMode::Concatenate => WeightedFindings {
+                sections: successful.iter().map(|r| section(r, 0.0)).collect(),
+                appendix: Vec::new(),
+                disagreements: Vec::new(),
+                confidence_score: successful.iter().map(|r| r.confidence).sum::<i32>()
+                    / successful.len() as i32,
+            },
+            AggregationMode::ConfidenceWeighted { confidence_floor } => {
+                weigh_findings(&successful, confidence_floor)
+            }
+        };
 
         // Use LLM to synthesize
-        let (summary, key_findings, recommendations) = self.synthesize(request, &sections).await?;
+        let (summary, key_findings, recommendations) = self
+            .synthesize(request, &weighted.sections, &weighted.disagreements)
+            .await?;
 
         let total_tokens: i64 = results.iter().map(|r| r.tokens_used).sum();
-        let avg_confidence =
-            successful.iter().map(|r| r.confidence).sum::<i32>() / successful.len() as i32;
 
         Ok(ResearchReport {
             research_id: request.id.clone(),
             topic: request.topic.clone(),
             summary,
-            sections,
+            sections: weighted.sections,
             key_findings,
             recommendations,
-            confidence_score: avg_confidence,
+            confidence_score: weighted.confidence_score,
             total_tokens,
             worker_count: results.len() as i32,
             successful_workers: successful.len() as i32,
+            disagreements: weighted.disagreements,
+            appendix: weighted.appendix,
         })
     }
 
@@ -103,12 +181,42 @@ impl Aggregator {
         &self,
         request: &ResearchRequest,
         sections: &[ReportSection],
+        disagreements: &[Disagreement],
     ) -> Result<(String, Vec<String>, Vec<String>)> {
-        let sections_text: String = sections
+        let weighted = sections.iter().any(|s| s.weight > 0.0);
+        let mut sections_text: String = sections
             .iter()
-            .map(|s| format!("## {}\n\n{}", s.title, s.content))
+            .map(|s| {
+                if weighted {
+                    format!(
+                        "## {} (confidence {}/10, weight {:.0}%)\n\n{}",
+                        s.title,
+                        s.confidence,
+                        s.weight * 100.0,
+                        s.content
+                    )
+                } else {
+                    format!("## {}\n\n{}", s.title, s.content)
+                }
+            })
             .collect::<Vec<_>>()
             .join("\n\n---\n\n");
+        if weighted {
+            sections_text.push_str(
+                "\n\nGive each section influence over the synthesis in proportion to its weight.",
+            );
+        }
+        if !disagreements.is_empty() {
+            sections_text.push_str(
+                "\n\nWORKERS DISAGREE ON (present these as open questions, not conclusions):\n",
+            );
+            for d in disagreements {
+                sections_text.push_str(&format!(
+                    "- \"{}\" ({}) vs \"{}\" ({})\n",
+                    d.claim, d.subtopic, d.other_claim, d.other_subtopic
+                ));
+            }
+        }
 
         let prompt = format!(
             r#"Synthesize these research findings into a coherent report.
@@ -167,6 +275,147 @@ Recommendations should be practical next steps based on the research."#,
     }
 }
 
+// ============================================================================
+// Confidence Weighting
+// ============================================================================
+
+fn section(result: &WorkerResult, weight: f64) -> ReportSection {
+    ReportSection {
+        title: result.subtopic.clone(),
+        content: result.findings.clone(),
+        sources: result
+            .sources
+            .as_ref()
+            .and_then(|s| serde_json::from_str(s).ok())
+            .unwrap_or_default(),
+        confidence: result.confidence,
+        weight,
+    }
+}
+
+/// Split worker results on `confidence_floor`, weight the rest by confidence
+/// and find where they contradict each other
+///
+/// If no worker reaches the floor all of them stay in the main report, since
+/// an empty report with everything in the appendix helps nobody.
+pub fn weigh_findings(results: &[&WorkerResult], confidence_floor: i32) -> WeightedFindings {
+    let (mut main, mut low): (Vec<&WorkerResult>, Vec<&WorkerResult>) = results
+        .iter()
+        .partition(|r| r.confidence >= confidence_floor);
+    if main.is_empty() {
+        warn!(
+            "No research worker reached confidence {}; keeping all findings",
+            confidence_floor
+        );
+        main = std::mem::take(&mut low);
+    }
+    main.sort_by(|a, b| {
+        b.confidence
+            .cmp(&a.confidence)
+            .then(a.worker_index.cmp(&b.worker_index))
+    });
+
+    let total = main.iter().map(|r| r.confidence.max(0) as f64).sum::<f64>();
+    let weight = |r: &WorkerResult| {
+        if total > 0.0 {
+            r.confidence.max(0) as f64 / total
+        } else {
+            1.0 / main.len() as f64
+        }
+    };
+    let confidence_score = main
+        .iter()
+        .map(|r| weight(r) * r.confidence as f64)
+        .sum::<f64>()
+        .round() as i32;
+
+    let mut disagreements = Vec::new();
+    for (i, a) in main.iter().enumerate() {
+        for b in &main[i + 1..] {
+            for claim in claims(&a.findings) {
+                for other in claims(&b.findings) {
+                    if contradicts(&claim, &other) {
+                        disagreements.push(Disagreement {
+                            subtopic: a.subtopic.clone(),
+                            claim: claim.clone(),
+                            confidence: a.confidence,
+                            other_subtopic: b.subtopic.clone(),
+                            other_claim: other,
+                            other_confidence: b.confidence,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    WeightedFindings {
+        sections: main.iter().map(|r| section(r, weight(r))).collect(),
+        appendix: low.iter().map(|r| section(r, 0.0)).collect(),
+        disagreements,
+        confidence_score,
+    }
+}
+
+/// Individual statements in a worker's findings: bullet points and
+/// sentences, without headings
+fn claims(findings: &str) -> Vec<String> {
+    findings
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(strip_list_marker)
+        .flat_map(|line| line.split(". "))
+        .map(|sentence| sentence.trim().trim_end_matches('.').to_string())
+        .filter(|sentence| sentence.split_whitespace().count() >= 3)
+        .collect()
+}
+
+/// `line` without a leading `-`, `*`, `•` or `1.` / `1)` list marker
+fn strip_list_marker(line: &str) -> &str {
+    let line = line.trim_start_matches(['-', '*', '•']).trim_start();
+    let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    match line[digits..].strip_prefix(['.', ')']) {
+        Some(rest) if digits > 0 => rest.trim_start(),
+        _ => line,
+    }
+}
+
+/// Whether two claims are about the same subject but exactly one is negated
+fn contradicts(a: &str, b: &str) -> bool {
+    let (negated_a, words_a) = content_words(a);
+    let (negated_b, words_b) = content_words(b);
+    if negated_a == negated_b || words_a.is_empty() || words_b.is_empty() {
+        return false;
+    }
+    let shared = words_a.intersection(&words_b).count() as f64;
+    let union = words_a.union(&words_b).count() as f64;
+    shared / union >= SAME_SUBJECT_OVERLAP
+}
+
+/// Lowercased content words of a claim, and whether it is negated
+fn content_words(claim: &str) -> (bool, HashSet<String>) {
+    let mut negated = false;
+    let mut words = HashSet::new();
+    for word in claim
+        .split(|c: char| !c.is_alphanumeric() && c != '\'' && c != '_')
+        .filter(|w| !w.is_empty())
+    {
+        let word = word.to_lowercase();
+        if NEGATIONS.contains(&word.as_str()) || word.ends_with("n't") {
+            negated = !negated;
+        } else if !STOPWORDS.contains(&word.as_str()) {
+            // "supports" and "support" make the same claim
+            let stem = match word.strip_suffix('s') {
+                Some(stem) if stem.len() > 2 && !stem.ends_with('s') => stem.to_string(),
+                _ => word,
+            };
+            words.insert(stem);
+        }
+    }
+    (negated, words)
+}
+
 // ============================================================================
 // Report Formatting
 // ============================================================================
@@ -200,14 +449,48 @@ impl ResearchReport {
         }
         md.push('\n');
 
+        if !self.disagreements.is_empty() {
+            md.push_str("## Disagreements\n\n");
+            for d in &self.disagreements {
+                md.push_str(&format!(
+                    "- **{}** ({}/10): {}\n  **{}** ({}/10): {}\n",
+                    d.subtopic,
+                    d.confidence,
+                    d.claim,
+                    d.other_subtopic,
+                    d.other_confidence,
+                    d.other_claim
+                ));
+            }
+            md.push('\n');
+        }
+
         md.push_str("## Detailed Sections\n\n");
         for section in &self.sections {
             md.push_str(&format!("### {}\n\n", section.title));
-            md.push_str(&format!("*Confidence: {}/10*\n\n", section.confidence));
+            if section.weight > 0.0 {
+                md.push_str(&format!(
+                    "*Confidence: {}/10 | Weight: {:.0}%*\n\n",
+                    section.confidence,
+                    section.weight * 100.0
+                ));
+            } else {
+                md.push_str(&format!("*Confidence: {}/10*\n\n", section.confidence));
+            }
             md.push_str(&section.content);
             md.push_str("\n\n");
         }
 
+        if !self.appendix.is_empty() {
+            md.push_str("## Appendix: Low-Confidence Findings\n\n");
+            for section in &self.appendix {
+                md.push_str(&format!("### {}\n\n", section.title));
+                md.push_str(&format!("*Confidence: {}/10*\n\n", section.confidence));
+                md.push_str(&section.content);
+                md.push_str("\n\n");
+            }
+        }
+
         md
     }
 
@@ -244,3 +527,90 @@ impl ResearchReport {
         output
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn worker(index: i32, subtopic: &str, confidence: i32, findings: &str) -> WorkerResult {
+        let mut result = WorkerResult::new("research-1", index, subtopic);
+        result.findings = findings.to_string();
+        result.confidence = confidence;
+        result.status = "completed".to_string();
+        result
+    }
+
+    #[test]
+    fn test_confidence_weighted_merge_appendixes_low_confidence_worker() {
+        let results = [
+            worker(
+                0,
+                "Runtime internals",
+                9,
+                "## Runtime\n- Tokio supports io_uring on Linux.\n- The scheduler is work-stealing.",
+            ),
+            worker(
+                1,
+                "Ecosystem",
+                7,
+                "Tokio does not support io_uring on Linux. Most HTTP crates build on hyper.",
+            ),
+            worker(
+                2,
+                "Forum anecdotes",
+                3,
+                "The scheduler is not work-stealing. Someone said async is slow.",
+            ),
+        ];
+        let refs: Vec<&WorkerResult> = results.iter().collect();
+
+        let weighted = weigh_findings(&refs, 5);
+        let titles: Vec<&str> = weighted.sections.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, vec!["Runtime internals", "Ecosystem"]);
+        assert!((weighted.sections[0].weight - 9.0 / 16.0).abs() < 1e-9);
+        assert!((weighted.sections[1].weight - 7.0 / 16.0).abs() < 1e-9);
+        assert_eq!(weighted.confidence_score, 8);
+
+        assert_eq!(weighted.appendix.len(), 1);
+        assert_eq!(weighted.appendix[0].title, "Forum anecdotes");
+
+        // Only the two trusted workers' conflict is surfaced; the appendixed
+        // worker contradicting the scheduler claim is not
+        assert_eq!(weighted.disagreements.len(), 1);
+        let d = &weighted.disagreements[0];
+        assert_eq!(d.claim, "Tokio supports io_uring on Linux");
+        assert_eq!(d.other_claim, "Tokio does not support io_uring on Linux");
+
+        let report = ResearchReport {
+            research_id: "research-1".to_string(),
+            topic: "Tokio".to_string(),
+            summary: String::new(),
+            sections: weighted.sections,
+            key_findings: Vec::new(),
+            recommendations: Vec::new(),
+            confidence_score: weighted.confidence_score,
+            total_tokens: 0,
+            worker_count: 3,
+            successful_workers: 3,
+            disagreements: weighted.disagreements,
+            appendix: weighted.appendix,
+        };
+        let md = report.to_markdown();
+        let appendix = md.find("## Appendix: Low-Confidence Findings").unwrap();
+        assert!(md.find("### Forum anecdotes").unwrap() > appendix);
+        assert!(md.find("### Ecosystem").unwrap() < appendix);
+        assert!(md.contains("## Disagreements"));
+    }
+
+    #[test]
+    fn test_all_workers_below_floor_stay_in_report() {
+        let results = [
+            worker(0, "A", 4, "Short note."),
+            worker(1, "B", 2, "Another."),
+        ];
+        let refs: Vec<&WorkerResult> = results.iter().collect();
+        let weighted = weigh_findings(&refs, DEFAULT_CONFIDENCE_FLOOR);
+        assert_eq!(weighted.sections.len(), 2);
+        assert!(weighted.appendix.is_empty());
+    }
+}
@@ -4,12 +4,15 @@
 //! aggregate findings, and produce comprehensive reports.
 
 pub mod aggregator;
+pub mod source_cache;
 pub mod worker;
 
 use serde::{Deserialize, Serialize};
 use sqlx::{FromRow, PgPool};
 use uuid::Uuid;
 
+use crate::db::{ListFilter, Page, PageRequest};
+
 // ============================================================================
 // Research Request Model
 // ============================================================================
@@ -305,13 +308,69 @@ pub async fn get_research_with_results(
     Ok((request, results))
 }
 
-pub async fn list_research(pool: &PgPool, limit: i32) -> anyhow::Result<Vec<ResearchRequest>> {
-    let requests = sqlx::query_as::<_, ResearchRequest>(
-        "SELECT * FROM research_requests ORDER BY created_at DESC LIMIT ?",
-    )
-    .bind(limit)
-    .fetch_all(pool)
-    .await?;
+/// List research requests a page at a time, newest first. Applies `status`,
+/// `repo_id` (matched against `repo_context`) and the `created_at` range.
+pub async fn list_research(
+    pool: &PgPool,
+    filter: &ListFilter,
+    page: PageRequest,
+) -> anyhow::Result<Page<ResearchRequest>> {
+    let mut conditions: Vec<String> = Vec::new();
+    let mut param_idx = 1usize;
+
+    if filter.status.is_some() {
+        conditions.push(format!("status = ${}", param_idx));
+        param_idx += 1;
+    }
+    if filter.repo_id.is_some() {
+        conditions.push(format!("repo_context = ${}", param_idx));
+        param_idx += 1;
+    }
+    if filter.created_after.is_some() {
+        conditions.push(format!("created_at >= ${}", param_idx));
+        param_idx += 1;
+    }
+    if filter.created_before.is_some() {
+        conditions.push(format!("created_at < ${}", param_idx));
+        param_idx += 1;
+    }
+
+    let where_clause = if conditions.is_empty() {
+        String::new()
+    } else {
+        format!("WHERE {}", conditions.join(" AND "))
+    };
+
+    let count_sql = format!("SELECT COUNT(*) FROM research_requests {}", where_clause);
+    let sql = format!(
+        "SELECT * FROM research_requests {} ORDER BY created_at DESC, id ASC LIMIT ${} OFFSET ${}",
+        where_clause,
+        param_idx,
+        param_idx + 1
+    );
+
+    let mut count_q = sqlx::query_scalar::<_, i64>(&count_sql);
+    let mut q = sqlx::query_as::<_, ResearchRequest>(&sql);
+
+    if let Some(ref status) = filter.status {
+        count_q = count_q.bind(status);
+        q = q.bind(status);
+    }
+    if let Some(ref repo_id) = filter.repo_id {
+        count_q = count_q.bind(repo_id);
+        q = q.bind(repo_id);
+    }
+    if let Some(after) = filter.created_after {
+        count_q = count_q.bind(after);
+        q = q.bind(after);
+    }
+    if let Some(before) = filter.created_before {
+        count_q = count_q.bind(before);
+        q = q.bind(before);
+    }
+    q = q.bind(page.limit).bind(page.offset);
 
-    Ok(requests)
+    let total = count_q.fetch_one(pool).await?;
+    let items = q.fetch_all(pool).await?;
+    Ok(Page::new(items, total, page))
 }
@@ -0,0 +1,155 @@
+//! Shared Source Cache
+//!
+//! Workers of the same research request often retrieve overlapping RAG
+//! sources. The first worker to retrieve a source summarizes it and stores
+//! the summary here; siblings reuse it instead of paying for another
+//! summary. Entries are partitioned by `research_id`.
+
+use super::worker::RagResult;
+use anyhow::Result;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::sync::{Mutex, OnceCell};
+
+/// A summarized source shared between workers
+#[derive(Debug, Clone)]
+pub struct CachedSource {
+    /// Source label (URL or document title)
+    pub source: String,
+    pub summary: String,
+    /// Tokens spent producing `summary`
+    pub tokens: usize,
+}
+
+/// Sources gathered by one worker
+#[derive(Debug, Clone, Default)]
+pub struct SourceBundle {
+    /// Source labels, in retrieval order
+    pub sources: Vec<String>,
+    pub summaries: Vec<String>,
+    /// Tokens this worker actually spent (reused sources cost nothing)
+    pub tokens_used: usize,
+    /// How many sources were served from the cache
+    pub reused: usize,
+}
+
+impl SourceBundle {
+    /// Summaries formatted for inclusion in a worker prompt
+    pub fn context(&self) -> String {
+        self.sources
+            .iter()
+            .zip(&self.summaries)
+            .enumerate()
+            .map(|(i, (source, summary))| format!("[{}] {}\n{}", i + 1, source, summary))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+type Slot = Arc<OnceCell<CachedSource>>;
+
+/// Per-research cache of summarized sources
+#[derive(Default)]
+pub struct SourceCache {
+    entries: Mutex<HashMap<String, HashMap<String, Slot>>>,
+}
+
+impl SourceCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cache key: the source URL when there is one, otherwise a content hash
+    pub fn key(source: &str, content: &str) -> String {
+        if source.contains("://") {
+            format!("url:{}", source)
+        } else {
+            format!("sha256:{:x}", Sha256::digest(content.as_bytes()))
+        }
+    }
+
+    /// Return the cached source for `key`, running `fetch` only if no
+    /// sibling has retrieved it yet
+    ///
+    /// Concurrent callers for the same key wait for a single fetch. The
+    /// returned flag is `true` when the entry came from the cache.
+    pub async fn get_or_fetch<F, Fut>(
+        &self,
+        research_id: &str,
+        key: &str,
+        fetch: F,
+    ) -> Result<(CachedSource, bool)>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<CachedSource>>,
+    {
+        let slot = {
+            let mut entries = self.entries.lock().await;
+            entries
+                .entry(research_id.to_string())
+                .or_default()
+                .entry(key.to_string())
+                .or_default()
+                .clone()
+        };
+
+        let mut fetched = false;
+        let source = slot
+            .get_or_try_init(|| {
+                fetched = true;
+                fetch()
+            })
+            .await?
+            .clone();
+
+        Ok((source, !fetched))
+    }
+
+    /// Resolve RAG hits through the cache, summarizing only new sources
+    pub async fn collect<F, Fut>(
+        &self,
+        research_id: &str,
+        hits: &[RagResult],
+        summarize: F,
+    ) -> Result<SourceBundle>
+    where
+        F: Fn(&RagResult) -> Fut,
+        Fut: Future<Output = Result<CachedSource>>,
+    {
+        let mut bundle = SourceBundle::default();
+
+        for hit in hits {
+            let key = Self::key(&hit.source, &hit.content);
+            let (cached, reused) = self
+                .get_or_fetch(research_id, &key, || summarize(hit))
+                .await?;
+
+            if reused {
+                bundle.reused += 1;
+            } else {
+                bundle.tokens_used += cached.tokens;
+            }
+            bundle.sources.push(cached.source);
+            bundle.summaries.push(cached.summary);
+        }
+
+        Ok(bundle)
+    }
+
+    /// Number of distinct sources cached for a research request
+    pub async fn len(&self, research_id: &str) -> usize {
+        self.entries
+            .lock()
+            .await
+            .get(research_id)
+            .map(|e| e.len())
+            .unwrap_or(0)
+    }
+
+    /// Drop all entries for a finished research request
+    pub async fn clear(&self, research_id: &str) {
+        self.entries.lock().await.remove(research_id);
+    }
+}
@@ -3,15 +3,18 @@
 //! Handles parallel research execution. Each worker investigates
 //! a subtopic and reports findings back for aggregation.
 
+use super::source_cache::{CachedSource, SourceCache};
 use super::{save_worker_result, ResearchRequest, WorkerResult};
 use crate::db::get_all_embeddings;
 use crate::embeddings::{EmbeddingConfig, EmbeddingGenerator};
 use crate::llm::GrokClient;
+use crate::progress::{self, ProgressReporter};
 use crate::vector_index::{IndexConfig, VectorIndex};
 use anyhow::Result;
 use futures::future::join_all;
 use once_cell::sync::OnceCell;
 use sqlx::PgPool;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::sync::{Mutex, Semaphore};
 use tracing::{error, info, warn};
@@ -30,6 +33,8 @@ pub struct WorkerConfig {
     pub max_tokens: usize,
     /// Retry failed workers
     pub retry_failed: bool,
+    /// RAG sources retrieved per worker
+    pub max_sources: usize,
 }
 
 impl Default for WorkerConfig {
@@ -39,6 +44,7 @@ impl Default for WorkerConfig {
             timeout_secs: 120,
             max_tokens: 4096,
             retry_failed: true,
+            max_sources: 5,
         }
     }
 }
@@ -52,6 +58,8 @@ pub struct ResearchOrchestrator {
     llm: Arc<GrokClient>,
     config: WorkerConfig,
     semaphore: Arc<Semaphore>,
+    progress: Arc<dyn ProgressReporter>,
+    sources: Arc<SourceCache>,
 }
 
 impl ResearchOrchestrator {
@@ -62,10 +70,20 @@ impl ResearchOrchestrator {
             llm: Arc::new(llm),
             config,
             semaphore,
+            progress: progress::noop(),
+            sources: Arc::new(SourceCache::new()),
         }
     }
 
+    /// Report worker completions to `reporter`
+    pub fn with_progress(mut self, reporter: Arc<dyn ProgressReporter>) -> Self {
+        self.progress = reporter;
+        self
+    }
+
     /// Execute a research request with parallel workers
+    ///
+    /// Results are ordered by `worker_index`.
     pub async fn execute(&self, request: &ResearchRequest) -> Result<Vec<WorkerResult>> {
         info!(
             "Starting research: {} with {} workers",
@@ -75,9 +93,14 @@ impl ResearchOrchestrator {
         // Step 1: Generate subtopics using LLM
         let subtopics = self.generate_subtopics(request).await?;
         info!("Generated {} subtopics", subtopics.len());
+        self.progress.start(
+            &format!("Researching {}", request.topic),
+            subtopics.len() as u64,
+        );
 
         // Step 2: Spawn workers for each subtopic
         let mut handles = Vec::new();
+        let finished = Arc::new(AtomicU64::new(0));
 
         for (index, subtopic) in subtopics.into_iter().enumerate() {
             let pool = self.pool.clone();
@@ -87,6 +110,9 @@ impl ResearchOrchestrator {
             let topic = request.topic.clone();
             let context = request.repo_context.clone();
             let config = self.config.clone();
+            let progress = self.progress.clone();
+            let finished = finished.clone();
+            let sources = self.sources.clone();
 
             let handle = tokio::spawn(async move {
                 // Acquire semaphore to limit concurrency
@@ -94,7 +120,17 @@ impl ResearchOrchestrator {
 
                 let mut result = WorkerResult::new(&research_id, index as i32, &subtopic);
 
-                match Self::run_worker(&llm, &topic, &subtopic, context.as_deref(), &config).await {
+                let worker = Self::run_worker(
+                    &pool,
+                    &llm,
+                    &sources,
+                    &research_id,
+                    &topic,
+                    &subtopic,
+                    context.as_deref(),
+                    &config,
+                );
+                match worker.await {
                     Ok((findings, sources, tokens)) => {
                         result.findings = findings;
                         result.sources = Some(serde_json::to_string(&sources).unwrap_or_default());
@@ -115,6 +151,8 @@ impl ResearchOrchestrator {
                     error!("Failed to save worker result: {}", e);
                 }
 
+                let done = finished.fetch_add(1, Ordering::SeqCst) + 1;
+                progress.advance(&result.subtopic, done);
                 result
             });
 
@@ -122,17 +160,25 @@ impl ResearchOrchestrator {
         }
 
         // Step 3: Collect all results
-        let results: Vec<WorkerResult> = join_all(handles)
+        let mut results: Vec<WorkerResult> = join_all(handles)
             .await
             .into_iter()
             .filter_map(|r| r.ok())
             .collect();
+        results.sort_by_key(|r| r.worker_index);
+        self.sources.clear(&request.id).await;
 
+        let succeeded = results.iter().filter(|r| r.status == "completed").count();
         info!(
             "Research complete: {}/{} workers succeeded",
-            results.iter().filter(|r| r.status == "completed").count(),
+            succeeded,
             results.len()
         );
+        self.progress.finish(&format!(
+            "Research complete: {}/{} workers succeeded",
+            succeeded,
+            results.len()
+        ));
 
         Ok(results)
     }
@@ -193,19 +239,47 @@ Subtopics should be:
     }
 
     /// Run a single worker to research a subtopic
+    ///
+    /// RAG sources are resolved through the shared [`SourceCache`], so a
+    /// source already summarized by a sibling worker costs no tokens here.
+    #[allow(clippy::too_many_arguments)]
     async fn run_worker(
+        pool: &PgPool,
         llm: &GrokClient,
+        cache: &SourceCache,
+        research_id: &str,
         main_topic: &str,
         subtopic: &str,
         context: Option<&str>,
         config: &WorkerConfig,
     ) -> Result<(String, Vec<String>, usize)> {
+        let hits = search_rag_context(pool, subtopic, config.max_sources)
+            .await
+            .unwrap_or_else(|e| {
+                warn!(error = %e, "RAG lookup failed — researching without sources");
+                vec![]
+            });
+        let bundle = cache
+            .collect(research_id, &hits, |hit| {
+                Self::summarize_source(llm, main_topic, hit.source.clone(), hit.content.clone())
+            })
+            .await?;
+        if bundle.reused > 0 {
+            info!(
+                "Worker reused {}/{} cached sources for '{}'",
+                bundle.reused,
+                bundle.sources.len(),
+                subtopic
+            );
+        }
+
         let prompt = format!(
             r#"Research the following subtopic in depth.
 
 Main Topic: {main_topic}
 Subtopic to Research: {subtopic}
 {context}
+{sources}
 
 Provide:
 1. A comprehensive analysis of this subtopic
@@ -219,15 +293,38 @@ Be thorough but focused on this specific subtopic."#,
             context = context
                 .map(|c| format!("Context:\n{}", c))
                 .unwrap_or_default(),
+            sources = if bundle.sources.is_empty() {
+                String::new()
+            } else {
+                format!("Sources:\n{}", bundle.context())
+            },
         );
 
         let response = llm.generate(&prompt, config.max_tokens).await?;
-        let tokens = response.len() / 4; // Rough estimate
+        let tokens = response.len() / 4 + bundle.tokens_used; // Rough estimate
 
-        // For now, sources are empty (would come from RAG)
-        let sources: Vec<String> = vec![];
+        Ok((response, bundle.sources, tokens))
+    }
 
-        Ok((response, sources, tokens))
+    /// Summarize a retrieved source for the research topic
+    async fn summarize_source(
+        llm: &GrokClient,
+        main_topic: &str,
+        source: String,
+        content: String,
+    ) -> Result<CachedSource> {
+        let prompt = format!(
+            "Summarize the key facts in this source that are relevant to \"{}\". \
+             Be concise.\n\nSource: {}\n\n{}",
+            main_topic, source, content
+        );
+        let summary = llm.generate(&prompt, 512).await?;
+
+        Ok(CachedSource {
+            source,
+            tokens: summary.len() / 4,
+            summary,
+        })
     }
 
     /// Calculate confidence score based on result quality
@@ -0,0 +1,250 @@
+//! GitHub pull request review comments
+//!
+//! Converts LLM findings ([`FileAnalysisResult`] issues and
+//! [`SecurityConcern`]s) into a review for the GitHub
+//! `POST /repos/{owner}/{repo}/pulls/{number}/reviews` endpoint. Findings on
+//! the same line share one inline comment; findings without a line go into
+//! the review body.
+//!
+//! ```rust,ignore
+//! let review = PullRequestReview::from_findings(&file_results, &report.security_concerns);
+//! github.create_review("owner", "repo", 42, &review).await?;
+//! ```
+
+use crate::error::Result;
+use crate::grok_reasoning::{FileAnalysisResult, IdentifiedIssue};
+use crate::llm_audit::SecurityConcern;
+use crate::sarif::normalize_path;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Review event that leaves comments without approving or blocking
+const COMMENT_EVENT: &str = "COMMENT";
+
+/// Diff side comments attach to (the PR's version of the file)
+const RIGHT_SIDE: &str = "RIGHT";
+
+/// Separator between findings grouped into one comment
+const FINDING_SEPARATOR: &str = "\n\n---\n\n";
+
+/// One inline comment, in the GitHub review-comments API shape
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewComment {
+    /// Repo-relative file path
+    pub path: String,
+    /// 1-based line in the PR's version of the file
+    pub line: usize,
+    /// Diff side; always `RIGHT`
+    pub side: String,
+    /// Markdown body
+    pub body: String,
+}
+
+/// A review to submit on a pull request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestReview {
+    /// Summary, including findings that have no line to anchor to
+    pub body: String,
+    /// Review event; always `COMMENT`
+    pub event: String,
+    /// Inline comments, one per (path, line)
+    pub comments: Vec<ReviewComment>,
+}
+
+impl PullRequestReview {
+    /// Build a review from per-file issues and security concerns
+    pub fn from_findings(files: &[FileAnalysisResult], concerns: &[SecurityConcern]) -> Self {
+        let mut inline: BTreeMap<(String, usize), Vec<String>> = BTreeMap::new();
+        let mut unanchored: Vec<String> = Vec::new();
+
+        for file in files {
+            let path = normalize_path(&file.path);
+            for issue in &file.issues {
+                match issue.line.filter(|&line| line > 0) {
+                    Some(line) => inline
+                        .entry((path.clone(), line))
+                        .or_default()
+                        .push(issue_body(issue)),
+                    None => unanchored.push(format!("`{}`: {}", path, issue_body(issue))),
+                }
+            }
+        }
+
+        for concern in concerns {
+            let body = concern_body(concern);
+            let anchors: Vec<(String, usize)> = concern
+                .affected_areas
+                .iter()
+                .filter_map(|area| parse_area(area))
+                .collect();
+            if anchors.is_empty() {
+                let areas = if concern.affected_areas.is_empty() {
+                    String::new()
+                } else {
+                    format!(" ({})", concern.affected_areas.join(", "))
+                };
+                unanchored.push(format!("{}{}", body, areas));
+            }
+            for anchor in anchors {
+                inline.entry(anchor).or_default().push(body.clone());
+            }
+        }
+
+        let finding_count: usize = inline.values().map(Vec::len).sum::<usize>() + unanchored.len();
+        let mut body = format!(
+            "Audit found {} finding(s): {} inline comment(s).",
+            finding_count,
+            inline.len()
+        );
+        if !unanchored.is_empty() {
+            body.push_str("\n\nFindings without a line:\n");
+            for finding in &unanchored {
+                body.push_str("\n- ");
+                body.push_str(&finding.replace("\n\n", "\n  "));
+            }
+        }
+
+        Self {
+            body,
+            event: COMMENT_EVENT.to_string(),
+            comments: inline
+                .into_iter()
+                .map(|((path, line), bodies)| ReviewComment {
+                    path,
+                    line,
+                    side: RIGHT_SIDE.to_string(),
+                    body: bodies.join(FINDING_SEPARATOR),
+                })
+                .collect(),
+        }
+    }
+
+    /// Serialize as the JSON request body
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+/// Markdown for one issue
+fn issue_body(issue: &IdentifiedIssue) -> String {
+    let mut text = format!(
+        "**{}**{}: {}",
+        label(&issue.severity),
+        match issue.category.trim() {
+            "" => String::new(),
+            category => format!(" ({})", category),
+        },
+        issue.description
+    );
+    if let Some(ref fix) = issue.suggested_fix {
+        text.push_str("\n\nSuggested fix: ");
+        text.push_str(fix);
+    }
+    text
+}
+
+/// Markdown for one security concern
+fn concern_body(concern: &SecurityConcern) -> String {
+    let mut text = format!(
+        "**{}** (security): {}",
+        label(&concern.severity),
+        concern.description
+    );
+    if !concern.recommendation.is_empty() {
+        text.push_str("\n\nRecommendation: ");
+        text.push_str(&concern.recommendation);
+    }
+    text
+}
+
+/// Severity as shown in a comment, e.g. `High`
+fn label(severity: &str) -> String {
+    let severity = severity.trim();
+    let mut chars = severity.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => "Finding".to_string(),
+    }
+}
+
+/// Parse an affected area written as `path:line`; a bare path has no anchor
+fn parse_area(area: &str) -> Option<(String, usize)> {
+    let (path, line) = area.rsplit_once(':')?;
+    let line: usize = line.trim().parse().ok()?;
+    (!path.is_empty() && line > 0).then(|| (normalize_path(path), line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sarif::tests::file;
+
+    fn issue(severity: &str, line: Option<usize>, description: &str) -> IdentifiedIssue {
+        IdentifiedIssue {
+            severity: severity.to_string(),
+            category: "quality".to_string(),
+            line,
+            description: description.to_string(),
+            suggested_fix: None,
+        }
+    }
+
+    #[test]
+    fn test_groups_same_line_and_falls_back_to_summary() {
+        let files = vec![file(
+            "./src/lib.rs",
+            vec![
+                issue("high", Some(10), "Unchecked unwrap"),
+                issue("low", Some(10), "Magic number"),
+                issue("medium", Some(20), "Long function"),
+                issue("medium", None, "Module is too large"),
+            ],
+        )];
+        let concerns = vec![
+            SecurityConcern {
+                severity: "critical".to_string(),
+                description: "Token logged".to_string(),
+                affected_areas: vec!["src/lib.rs:10".to_string()],
+                recommendation: "Redact it".to_string(),
+            },
+            SecurityConcern {
+                severity: "High".to_string(),
+                description: "No rate limiting".to_string(),
+                affected_areas: vec!["src/api".to_string()],
+                recommendation: String::new(),
+            },
+        ];
+
+        let review = PullRequestReview::from_findings(&files, &concerns);
+        assert_eq!(review.event, "COMMENT");
+        assert_eq!(review.comments.len(), 2);
+
+        let line_10 = &review.comments[0];
+        assert_eq!((line_10.path.as_str(), line_10.line), ("src/lib.rs", 10));
+        assert_eq!(line_10.body.matches(FINDING_SEPARATOR).count(), 2);
+        assert!(line_10
+            .body
+            .starts_with("**High** (quality): Unchecked unwrap"));
+        assert!(line_10.body.contains("**Low** (quality): Magic number"));
+        assert!(line_10
+            .body
+            .contains("**Critical** (security): Token logged"));
+        assert_eq!(review.comments[1].line, 20);
+
+        assert!(review
+            .body
+            .starts_with("Audit found 6 finding(s): 2 inline comment(s)."));
+        assert!(review
+            .body
+            .contains("- `src/lib.rs`: **Medium** (quality): Module is too large"));
+        assert!(review.body.contains("No rate limiting (src/api)"));
+        assert!(!review.body.contains("Unchecked unwrap"));
+
+        let value: serde_json::Value = serde_json::from_str(&review.to_json().unwrap()).unwrap();
+        assert_eq!(value["comments"][0]["side"], "RIGHT");
+        assert_eq!(value["comments"][1]["path"], "src/lib.rs");
+    }
+}
@@ -0,0 +1,349 @@
+//! SARIF 2.1.0 output for GitHub code scanning
+//!
+//! Converts LLM findings ([`FileAnalysisResult`] issues and
+//! [`SecurityConcern`]s) into a SARIF log that can be uploaded with
+//! `github/codeql-action/upload-sarif`.
+//!
+//! ```rust,ignore
+//! let log = SarifLog::from_findings(&file_results, &report.security_concerns);
+//! std::fs::write("audit.sarif", log.to_json()?)?;
+//! ```
+
+use crate::error::Result;
+use crate::grok_reasoning::{FileAnalysisResult, IdentifiedIssue};
+use crate::llm_audit::SecurityConcern;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// SARIF specification version emitted
+pub const SARIF_VERSION: &str = "2.1.0";
+
+/// JSON schema URI for [`SARIF_VERSION`]
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Rule ID for findings that carry no category
+const GENERAL_RULE: &str = "general";
+
+/// Rule ID for [`SecurityConcern`]s
+const SECURITY_RULE: &str = "security";
+
+/// Top-level SARIF document
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    pub schema: String,
+    pub version: String,
+    pub runs: Vec<SarifRun>,
+}
+
+/// One analysis run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SarifRun {
+    pub tool: SarifTool,
+    pub results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SarifTool {
+    pub driver: SarifDriver,
+}
+
+/// The analysis tool: this crate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifDriver {
+    pub name: String,
+    pub version: String,
+    pub information_uri: String,
+    pub rules: Vec<SarifRule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRule {
+    pub id: String,
+    pub short_description: SarifMessage,
+}
+
+/// A single finding
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    pub rule_id: String,
+    pub level: SarifLevel,
+    pub message: SarifMessage,
+    pub locations: Vec<SarifLocation>,
+}
+
+/// SARIF result level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SarifLevel {
+    Error,
+    Warning,
+    Note,
+}
+
+impl SarifLevel {
+    /// Map a free-form severity (critical/high/medium/low) to a level
+    pub fn from_severity(severity: &str) -> Self {
+        match severity.trim().to_lowercase().as_str() {
+            "critical" | "high" => SarifLevel::Error,
+            "medium" => SarifLevel::Warning,
+            _ => SarifLevel::Note,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SarifMessage {
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifLocation {
+    pub physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifPhysicalLocation {
+    pub artifact_location: SarifArtifactLocation,
+    pub region: SarifRegion,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SarifArtifactLocation {
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRegion {
+    /// 1-based; findings without a line are anchored at line 1
+    pub start_line: usize,
+}
+
+impl SarifLocation {
+    fn new(path: &str, line: Option<usize>) -> Self {
+        Self {
+            physical_location: SarifPhysicalLocation {
+                artifact_location: SarifArtifactLocation {
+                    uri: normalize_path(path),
+                },
+                region: SarifRegion {
+                    start_line: line.unwrap_or(1).max(1),
+                },
+            },
+        }
+    }
+
+    /// Parse an affected area written as `path` or `path:line`
+    fn from_area(area: &str) -> Self {
+        match area.rsplit_once(':') {
+            Some((path, line)) if !path.is_empty() => match line.trim().parse() {
+                Ok(line) => Self::new(path, Some(line)),
+                Err(_) => Self::new(area, None),
+            },
+            _ => Self::new(area, None),
+        }
+    }
+}
+
+impl SarifResult {
+    /// Result for an issue found in `path`
+    pub fn from_issue(path: &str, issue: &IdentifiedIssue) -> Self {
+        let mut text = issue.description.clone();
+        if let Some(ref fix) = issue.suggested_fix {
+            text.push_str("\n\nSuggested fix: ");
+            text.push_str(fix);
+        }
+
+        Self {
+            rule_id: rule_id(&issue.category),
+            level: SarifLevel::from_severity(&issue.severity),
+            message: SarifMessage { text },
+            locations: vec![SarifLocation::new(path, issue.line)],
+        }
+    }
+
+    /// Result for a security concern, located at each affected area
+    pub fn from_security_concern(concern: &SecurityConcern) -> Self {
+        let mut text = concern.description.clone();
+        if !concern.recommendation.is_empty() {
+            text.push_str("\n\nRecommendation: ");
+            text.push_str(&concern.recommendation);
+        }
+
+        Self {
+            rule_id: SECURITY_RULE.to_string(),
+            level: SarifLevel::from_severity(&concern.severity),
+            message: SarifMessage { text },
+            locations: concern
+                .affected_areas
+                .iter()
+                .map(|area| SarifLocation::from_area(area))
+                .collect(),
+        }
+    }
+}
+
+impl SarifLog {
+    /// Build a single-run log from per-file issues and security concerns
+    pub fn from_findings(files: &[FileAnalysisResult], concerns: &[SecurityConcern]) -> Self {
+        let mut results: Vec<SarifResult> = files
+            .iter()
+            .flat_map(|file| {
+                file.issues
+                    .iter()
+                    .map(|issue| SarifResult::from_issue(&file.path, issue))
+            })
+            .collect();
+        results.extend(concerns.iter().map(SarifResult::from_security_concern));
+
+        let rules = results
+            .iter()
+            .map(|r| r.rule_id.as_str())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(|id| SarifRule {
+                id: id.to_string(),
+                short_description: SarifMessage {
+                    text: format!("{} finding", id),
+                },
+            })
+            .collect();
+
+        Self {
+            schema: SARIF_SCHEMA.to_string(),
+            version: SARIF_VERSION.to_string(),
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: env!("CARGO_PKG_NAME").to_string(),
+                        version: env!("CARGO_PKG_VERSION").to_string(),
+                        information_uri: "https://github.com/nuniesmith/audit".to_string(),
+                        rules,
+                    },
+                },
+                results,
+            }],
+        }
+    }
+
+    /// Serialize as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+/// Normalize an issue category into a rule ID
+fn rule_id(category: &str) -> String {
+    let id = category.trim().to_lowercase().replace(' ', "-");
+    if id.is_empty() {
+        GENERAL_RULE.to_string()
+    } else {
+        id
+    }
+}
+
+/// A finding's path as a repo-relative, forward-slash path: code scanning
+/// and review comments both match it against the files in the repository
+pub(crate) fn normalize_path(path: &str) -> String {
+    path.trim_start_matches("./").replace('\\', "/")
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    fn issue(severity: &str, category: &str, line: Option<usize>) -> IdentifiedIssue {
+        IdentifiedIssue {
+            severity: severity.to_string(),
+            category: category.to_string(),
+            line,
+            description: format!("{} issue", severity),
+            suggested_fix: None,
+        }
+    }
+
+    /// An analysis of `path` with only `issues` set; shared with the review
+    /// comment tests
+    pub(crate) fn file(path: &str, issues: Vec<IdentifiedIssue>) -> FileAnalysisResult {
+        let mut result: FileAnalysisResult = serde_json::from_value(serde_json::json!({
+            "path": path,
+            "overall_score": 0.0,
+            "security_score": 0.0,
+            "quality_score": 0.0,
+            "complexity_score": 0.0,
+            "maintainability_score": 0.0,
+            "summary": "",
+        }))
+        .unwrap();
+        result.issues = issues;
+        result
+    }
+
+    #[test]
+    fn test_sarif_log_structure() {
+        let files = vec![file(
+            "./src/lib.rs",
+            vec![
+                issue("critical", "Security", Some(42)),
+                issue("medium", "quality", None),
+                issue("low", "", Some(0)),
+            ],
+        )];
+        let concerns = vec![SecurityConcern {
+            severity: "High".to_string(),
+            description: "Token logged".to_string(),
+            affected_areas: vec!["src/auth.rs:7".to_string(), "src/db.rs".to_string()],
+            recommendation: "Redact it".to_string(),
+        }];
+
+        let json = SarifLog::from_findings(&files, &concerns)
+            .to_json()
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["version"], "2.1.0");
+        assert_eq!(value["$schema"], SARIF_SCHEMA);
+        let driver = &value["runs"][0]["tool"]["driver"];
+        assert_eq!(driver["name"], "rustassistant");
+        assert_eq!(driver["version"], env!("CARGO_PKG_VERSION"));
+
+        let results = value["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 4);
+        let levels: Vec<&str> = results
+            .iter()
+            .map(|r| r["level"].as_str().unwrap())
+            .collect();
+        assert_eq!(levels, vec!["error", "warning", "note", "error"]);
+
+        let location = |r: usize, l: usize| &results[r]["locations"][l]["physicalLocation"];
+        assert_eq!(location(0, 0)["artifactLocation"]["uri"], "src/lib.rs");
+        assert_eq!(location(0, 0)["region"]["startLine"], 42);
+        // Missing or zero lines are anchored at line 1
+        assert_eq!(location(1, 0)["region"]["startLine"], 1);
+        assert_eq!(location(2, 0)["region"]["startLine"], 1);
+        assert_eq!(location(3, 0)["artifactLocation"]["uri"], "src/auth.rs");
+        assert_eq!(location(3, 0)["region"]["startLine"], 7);
+        assert_eq!(location(3, 1)["region"]["startLine"], 1);
+
+        assert_eq!(results[2]["ruleId"], "general");
+        for result in results {
+            let rule_id = result["ruleId"].as_str().unwrap();
+            assert!(driver["rules"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|rule| rule["id"] == rule_id));
+            assert!(!result["message"]["text"].as_str().unwrap().is_empty());
+        }
+
+        // Round-trips through the typed model
+        let parsed: SarifLog = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.runs[0].results.len(), 4);
+    }
+}
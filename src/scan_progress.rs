@@ -0,0 +1,524 @@
+//! Live scan progress
+//!
+//! [`ScanProgressHub`] fans out progress from the auto-scanner to any number
+//! of subscribers, alongside the `repositories` progress columns the web UI
+//! polls. [`scan_events_router`] exposes it as Server-Sent Events:
+//!
+//! ```text
+//! GET /repos/:id/events
+//!
+//! event: snapshot
+//! data: {"repo_id":"…","phase":"progress","files_processed":12,…}
+//!
+//! event: progress
+//! data: {"repo_id":"…","phase":"progress","files_processed":13,…}
+//! ```
+//!
+//! The first event is always a `snapshot` of the current state (from the hub
+//! if a scan ran since startup, otherwise from the database), so a client
+//! that reconnects is immediately up to date. Later events are named after
+//! their phase: `start`, `progress`, `complete` or `error`.
+//!
+//! `GET /scans/:id/events` streams the same updates as lifecycle events for
+//! dashboards: `scan_started`, `file_analyzed`, `cost_milestone` (each time
+//! the cumulative cost passes another $0.10), `scan_complete` and
+//! `scan_failed`. It sends no snapshot, only what is published after the
+//! client connects.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
+    routing::get,
+    Json, Router,
+};
+use futures::stream::{self, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::BroadcastStream;
+
+/// Buffered updates per repo before a slow subscriber starts skipping
+const CHANNEL_CAPACITY: usize = 256;
+
+/// Spacing of `cost_milestone` events on `/scans/:id/events`
+const COST_MILESTONE_USD: f64 = 0.10;
+
+// ============================================================================
+// Updates
+// ============================================================================
+
+/// Where a scan is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ScanPhase {
+    /// No scan running
+    Idle,
+    Start,
+    Progress,
+    Complete,
+    Error,
+}
+
+impl ScanPhase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScanPhase::Idle => "idle",
+            ScanPhase::Start => "start",
+            ScanPhase::Progress => "progress",
+            ScanPhase::Complete => "complete",
+            ScanPhase::Error => "error",
+        }
+    }
+}
+
+/// One progress update for a repository scan
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct ScanUpdate {
+    pub repo_id: String,
+    pub phase: ScanPhase,
+    pub files_processed: i64,
+    pub total_files: i64,
+    pub current_file: Option<String>,
+    pub cost_accumulated: f64,
+    pub cache_hits: i64,
+    /// Set on `complete`
+    pub issues_found: Option<i64>,
+    /// Set on `error`
+    pub error: Option<String>,
+    pub timestamp: i64,
+}
+
+impl ScanUpdate {
+    pub fn new(repo_id: impl Into<String>, phase: ScanPhase) -> Self {
+        Self {
+            repo_id: repo_id.into(),
+            phase,
+            files_processed: 0,
+            total_files: 0,
+            current_file: None,
+            cost_accumulated: 0.0,
+            cache_hits: 0,
+            issues_found: None,
+            error: None,
+            timestamp: chrono::Utc::now().timestamp(),
+        }
+    }
+
+    pub fn with_files(mut self, processed: i64, total: i64) -> Self {
+        self.files_processed = processed;
+        self.total_files = total;
+        self
+    }
+
+    pub fn with_current_file(mut self, file: impl Into<String>) -> Self {
+        self.current_file = Some(file.into());
+        self
+    }
+
+    pub fn with_cost(mut self, cost_accumulated: f64, cache_hits: i64) -> Self {
+        self.cost_accumulated = cost_accumulated;
+        self.cache_hits = cache_hits;
+        self
+    }
+
+    pub fn with_issues(mut self, issues_found: i64) -> Self {
+        self.issues_found = Some(issues_found);
+        self
+    }
+
+    pub fn with_error(mut self, error: impl Into<String>) -> Self {
+        self.error = Some(error.into());
+        self
+    }
+}
+
+// ============================================================================
+// Hub
+// ============================================================================
+
+struct RepoChannel {
+    tx: broadcast::Sender<ScanUpdate>,
+    latest: Option<ScanUpdate>,
+}
+
+/// Per-repo broadcast of [`ScanUpdate`]s plus the latest update for late joiners
+#[derive(Clone, Default)]
+pub struct ScanProgressHub {
+    repos: Arc<Mutex<HashMap<String, RepoChannel>>>,
+}
+
+impl ScanProgressHub {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `update` as the repo's current state and send it to subscribers
+    pub fn publish(&self, update: ScanUpdate) {
+        let mut repos = self.repos.lock().unwrap();
+        let channel = repos
+            .entry(update.repo_id.clone())
+            .or_insert_with(|| RepoChannel {
+                tx: broadcast::channel(CHANNEL_CAPACITY).0,
+                latest: None,
+            });
+        channel.latest = Some(update.clone());
+        // No subscribers is fine — the update is kept as `latest`
+        let _ = channel.tx.send(update);
+    }
+
+    /// Current state and a receiver for later updates, taken atomically so
+    /// nothing published in between is lost
+    pub fn subscribe(
+        &self,
+        repo_id: &str,
+    ) -> (Option<ScanUpdate>, broadcast::Receiver<ScanUpdate>) {
+        let mut repos = self.repos.lock().unwrap();
+        let channel = repos
+            .entry(repo_id.to_string())
+            .or_insert_with(|| RepoChannel {
+                tx: broadcast::channel(CHANNEL_CAPACITY).0,
+                latest: None,
+            });
+        (channel.latest.clone(), channel.tx.subscribe())
+    }
+
+    /// Drop a repo's channel if nothing was published and nobody listens,
+    /// so lookups of unknown ids don't accumulate
+    fn forget_if_unused(&self, repo_id: &str) {
+        let mut repos = self.repos.lock().unwrap();
+        if repos
+            .get(repo_id)
+            .is_some_and(|c| c.latest.is_none() && c.tx.receiver_count() == 0)
+        {
+            repos.remove(repo_id);
+        }
+    }
+
+    pub fn latest(&self, repo_id: &str) -> Option<ScanUpdate> {
+        self.repos
+            .lock()
+            .unwrap()
+            .get(repo_id)
+            .and_then(|c| c.latest.clone())
+    }
+}
+
+// ============================================================================
+// SSE endpoint
+// ============================================================================
+
+/// State for [`scan_events_router`]
+#[derive(Clone)]
+pub struct ScanEventsState {
+    pub hub: ScanProgressHub,
+    pub pool: PgPool,
+}
+
+/// `GET /repos/:id/events` and `GET /scans/:id/events`
+pub fn scan_events_router(state: ScanEventsState) -> Router {
+    Router::new()
+        .route("/repos/:id/events", get(stream_scan_events))
+        .route("/scans/:id/events", get(stream_scan_lifecycle))
+        .with_state(state)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/repos/{id}/events",
+    tag = "scans",
+    params(("id" = String, Path, description = "Repository id")),
+    responses(
+        (status = 200, description = "Server-Sent Events; each `data` is a ScanUpdate", body = ScanUpdate, content_type = "text/event-stream"),
+        (status = 404, body = crate::api::openapi::StatusError)
+    )
+)]
+async fn stream_scan_events(
+    State(state): State<ScanEventsState>,
+    Path(repo_id): Path<String>,
+) -> Response {
+    let (latest, rx) = state.hub.subscribe(&repo_id);
+
+    let snapshot = match latest {
+        Some(update) => update,
+        None => match snapshot_from_db(&state.pool, &repo_id).await {
+            Ok(Some(update)) => update,
+            Ok(None) => {
+                drop(rx);
+                state.hub.forget_if_unused(&repo_id);
+                return not_found(&repo_id);
+            }
+            Err(e) => return internal_error(e),
+        },
+    };
+
+    Sse::new(event_stream(snapshot, rx))
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/scans/{id}/events",
+    tag = "scans",
+    params(("id" = String, Path, description = "Repository id")),
+    responses(
+        (status = 200, description = "Server-Sent Events named scan_started, file_analyzed, cost_milestone, scan_complete or scan_failed; each `data` is a ScanUpdate", body = ScanUpdate, content_type = "text/event-stream"),
+        (status = 404, body = crate::api::openapi::StatusError)
+    )
+)]
+async fn stream_scan_lifecycle(
+    State(state): State<ScanEventsState>,
+    Path(repo_id): Path<String>,
+) -> Response {
+    let (latest, rx) = state.hub.subscribe(&repo_id);
+
+    // Only the repo's existence matters; its current state is not replayed
+    if latest.is_none() {
+        match snapshot_from_db(&state.pool, &repo_id).await {
+            Ok(Some(_)) => {}
+            Ok(None) => {
+                drop(rx);
+                state.hub.forget_if_unused(&repo_id);
+                return not_found(&repo_id);
+            }
+            Err(e) => return internal_error(e),
+        }
+    }
+
+    let events = lifecycle_stream(rx).map(|(name, update)| to_event(name, &update));
+    Sse::new(events)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+fn not_found(repo_id: &str) -> Response {
+    (
+        StatusCode::NOT_FOUND,
+        Json(serde_json::json!({
+            "error": format!("Repository not found: {}", repo_id),
+            "status": 404
+        })),
+    )
+        .into_response()
+}
+
+fn internal_error(e: sqlx::Error) -> Response {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(serde_json::json!({ "error": e.to_string(), "status": 500 })),
+    )
+        .into_response()
+}
+
+/// Snapshot followed by live updates. The stream owns the receiver, so a
+/// client disconnect drops it and nothing is left running.
+fn event_stream(
+    snapshot: ScanUpdate,
+    rx: broadcast::Receiver<ScanUpdate>,
+) -> impl Stream<Item = Result<Event, Infallible>> {
+    let first = stream::once(async move { to_event("snapshot", &snapshot) });
+    // Lagged receivers skip what they missed; the next update carries totals
+    let live = BroadcastStream::new(rx)
+        .filter_map(|update| async move { update.ok() })
+        .map(|update| to_event(update.phase.as_str(), &update));
+    first.chain(live)
+}
+
+/// Updates published after subscribing, as named lifecycle events
+fn lifecycle_stream(
+    rx: broadcast::Receiver<ScanUpdate>,
+) -> impl Stream<Item = (&'static str, ScanUpdate)> {
+    let mut tracker = LifecycleTracker::default();
+    BroadcastStream::new(rx)
+        .filter_map(|update| async move { update.ok() })
+        .flat_map(move |update| stream::iter(tracker.events(update)))
+}
+
+/// Names hub updates for `/scans/:id/events` and spots cost milestones
+#[derive(Default)]
+struct LifecycleTracker {
+    /// Milestones passed as of the last update seen; `None` until the
+    /// first update, so joining mid-scan doesn't report old milestones
+    milestones_passed: Option<u64>,
+}
+
+impl LifecycleTracker {
+    fn events(&mut self, update: ScanUpdate) -> Vec<(&'static str, ScanUpdate)> {
+        // Nudge up so 0.30 isn't floored to 2.999… milestones
+        let passed = ((update.cost_accumulated + 1e-9) / COST_MILESTONE_USD).floor() as u64;
+        match update.phase {
+            ScanPhase::Start => {
+                self.milestones_passed = Some(passed);
+                vec![("scan_started", update)]
+            }
+            ScanPhase::Progress => {
+                let crossed = self.milestones_passed.is_some_and(|before| passed > before);
+                self.milestones_passed = Some(passed);
+                if crossed {
+                    vec![
+                        ("file_analyzed", update.clone()),
+                        ("cost_milestone", update),
+                    ]
+                } else {
+                    vec![("file_analyzed", update)]
+                }
+            }
+            ScanPhase::Complete => {
+                self.milestones_passed = None;
+                vec![("scan_complete", update)]
+            }
+            ScanPhase::Error => {
+                self.milestones_passed = None;
+                vec![("scan_failed", update)]
+            }
+            ScanPhase::Idle => Vec::new(),
+        }
+    }
+}
+
+fn to_event(name: &str, update: &ScanUpdate) -> Result<Event, Infallible> {
+    Ok(Event::default()
+        .event(name)
+        .data(serde_json::to_string(update).unwrap_or_default()))
+}
+
+/// Current progress from the `repositories` columns the scanner maintains
+async fn snapshot_from_db(pool: &PgPool, repo_id: &str) -> sqlx::Result<Option<ScanUpdate>> {
+    #[allow(clippy::type_complexity)]
+    let row: Option<(
+        Option<String>,
+        Option<i32>,
+        Option<i32>,
+        Option<String>,
+        Option<f64>,
+        Option<i32>,
+        Option<String>,
+    )> = sqlx::query_as(
+        r#"
+        SELECT scan_status, scan_files_processed, scan_files_total, scan_current_file,
+               scan_cost_accumulated, scan_cache_hits, last_error
+        FROM repositories
+        WHERE id = $1
+        "#,
+    )
+    .bind(repo_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.map(
+        |(status, processed, total, current_file, cost, cache_hits, last_error)| {
+            let phase = match status.as_deref() {
+                Some("scanning") => ScanPhase::Progress,
+                Some("error") => ScanPhase::Error,
+                _ => ScanPhase::Idle,
+            };
+            let mut update = ScanUpdate::new(repo_id, phase)
+                .with_files(processed.unwrap_or(0) as i64, total.unwrap_or(0) as i64)
+                .with_cost(cost.unwrap_or(0.0), cache_hits.unwrap_or(0) as i64);
+            update.current_file = current_file;
+            if phase == ScanPhase::Error {
+                update.error = last_error;
+            }
+            update
+        },
+    ))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_subscribe_returns_latest_then_live_updates() {
+        let hub = ScanProgressHub::new();
+        hub.publish(ScanUpdate::new("r1", ScanPhase::Start).with_files(0, 3));
+
+        let (latest, mut rx) = hub.subscribe("r1");
+        assert_eq!(latest.unwrap().phase, ScanPhase::Start);
+
+        hub.publish(ScanUpdate::new("r1", ScanPhase::Progress).with_files(1, 3));
+        hub.publish(ScanUpdate::new("r2", ScanPhase::Start));
+        let next = rx.recv().await.unwrap();
+        assert_eq!(next.phase, ScanPhase::Progress);
+        assert_eq!(next.files_processed, 1);
+        assert!(rx.try_recv().is_err(), "other repos must not leak in");
+        assert_eq!(hub.latest("r1").unwrap().phase, ScanPhase::Progress);
+    }
+
+    #[tokio::test]
+    async fn test_lifecycle_stream_orders_events_without_backlog() {
+        let hub = ScanProgressHub::new();
+        // Published before connecting: must not be replayed
+        hub.publish(ScanUpdate::new("r1", ScanPhase::Start).with_files(0, 9));
+
+        let (_, rx) = hub.subscribe("r1");
+        let events = lifecycle_stream(rx);
+
+        let progress = |done, cost, file| {
+            ScanUpdate::new("r1", ScanPhase::Progress)
+                .with_files(done, 3)
+                .with_current_file(file)
+                .with_cost(cost, 0)
+        };
+        hub.publish(ScanUpdate::new("r1", ScanPhase::Start).with_files(0, 3));
+        hub.publish(progress(1, 0.04, "a.rs"));
+        hub.publish(progress(2, 0.12, "b.rs"));
+        hub.publish(progress(3, 0.15, "c.rs"));
+        hub.publish(
+            ScanUpdate::new("r1", ScanPhase::Complete)
+                .with_files(3, 3)
+                .with_cost(0.15, 0),
+        );
+
+        let received: Vec<(&str, ScanUpdate)> = events.take(6).collect().await;
+        let names: Vec<&str> = received.iter().map(|(name, _)| *name).collect();
+        assert_eq!(
+            names,
+            vec![
+                "scan_started",
+                "file_analyzed",
+                "file_analyzed",
+                "cost_milestone",
+                "file_analyzed",
+                "scan_complete",
+            ]
+        );
+        assert_eq!(received[0].1.total_files, 3);
+        let milestone = &received[3].1;
+        assert_eq!(milestone.files_processed, 2);
+        assert_eq!(milestone.current_file.as_deref(), Some("b.rs"));
+        assert_eq!(milestone.cost_accumulated, 0.12);
+    }
+
+    #[test]
+    fn test_lifecycle_tracker_ignores_milestones_before_joining() {
+        let mut tracker = LifecycleTracker::default();
+        let progress = |cost| ScanUpdate::new("r1", ScanPhase::Progress).with_cost(cost, 0);
+
+        // Joined mid-scan at $0.35: no milestone for the ones already passed
+        assert_eq!(tracker.events(progress(0.35)).len(), 1);
+        assert_eq!(tracker.events(progress(0.39)).len(), 1);
+        assert_eq!(tracker.events(progress(0.40))[1].0, "cost_milestone");
+    }
+
+    #[tokio::test]
+    async fn test_dropped_stream_releases_subscriber() {
+        let hub = ScanProgressHub::new();
+        let (_, rx) = hub.subscribe("r1");
+        let stream = event_stream(ScanUpdate::new("r1", ScanPhase::Idle), rx);
+        assert_eq!(hub.repos.lock().unwrap()["r1"].tx.receiver_count(), 1);
+        drop(stream);
+        assert_eq!(hub.repos.lock().unwrap()["r1"].tx.receiver_count(), 0);
+    }
+}
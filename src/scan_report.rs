@@ -0,0 +1,133 @@
+//! Consolidated scan report
+//!
+//! One JSON document per completed scan (`scan_report.json`) for CI to
+//! archive and diff. Unlike scan events it is written once, at the end,
+//! and covers the whole scan: what was analyzed or skipped and why, the
+//! prompt tier mix, issues, tasks and cost.
+//!
+//! Field order is fixed by the struct definitions, maps are `BTreeMap`s and
+//! file lists are sorted by path, so two reports of the same scan differ
+//! only in their timestamps.
+
+use crate::auto_scanner::PlanSkipReason;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Bumped when fields are renamed or removed
+pub const SCAN_REPORT_VERSION: u32 = 1;
+
+/// Default file name
+pub const SCAN_REPORT_FILE: &str = "scan_report.json";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScanReport {
+    pub version: u32,
+    pub repo: ReportRepo,
+    /// Commit scanned (`None` outside a git checkout)
+    pub commit: Option<String>,
+    /// Commit changes were detected against (`None` on a first scan)
+    pub base_commit: Option<String>,
+    pub completed_at: i64,
+    pub duration_ms: i64,
+    /// Stopped early (cost budget, pause or shutdown); the next scan resumes
+    pub budget_halted: bool,
+    pub files: ReportFiles,
+    /// Files sent to the LLM (or its cache) per prompt tier
+    pub tiers: BTreeMap<String, usize>,
+    pub issues_found: i64,
+    pub tasks_created: i64,
+    pub cost: ReportCost,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReportRepo {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReportFiles {
+    /// Changed files the scan started with
+    pub changed: usize,
+    /// Analyzed by the LLM or served from its cache
+    pub analyzed: usize,
+    pub cache_hits: usize,
+    /// Left for a later scan by a budget halt, pause or shutdown
+    pub not_reached: usize,
+    pub skipped: Vec<SkippedFile>,
+    pub failed: Vec<FailedFile>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkippedFile {
+    pub path: String,
+    pub reason: PlanSkipReason,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FailedFile {
+    pub path: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReportCost {
+    /// Actual LLM spend, in dollars
+    pub total_usd: f64,
+    /// What analyzing every routed file at full price would have cost
+    pub estimated_usd: f64,
+    /// Saved by static skips, cache hits and smaller prompt tiers
+    pub saved_usd: f64,
+    /// Budget the scan ran under (0.0 = unlimited)
+    pub budget_usd: f64,
+}
+
+impl ScanReport {
+    /// Sort file lists so the output is stable
+    pub fn normalize(&mut self) {
+        self.files.skipped.sort_by(|a, b| a.path.cmp(&b.path));
+        self.files.failed.sort_by(|a, b| a.path.cmp(&b.path));
+    }
+
+    /// Write as pretty-printed JSON, creating parent directories
+    pub fn write_to(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let mut json = serde_json::to_string_pretty(self)?;
+        json.push('\n');
+        std::fs::write(path, json)
+            .with_context(|| format!("Failed to write scan report {}", path.display()))
+    }
+}
+
+/// Where a repo's report goes: `{repo}` in `template` becomes the repo name,
+/// and a directory (trailing `/`) gets [`SCAN_REPORT_FILE`] appended
+pub fn report_path(template: &str, repo_name: &str) -> PathBuf {
+    let path = template.replace("{repo}", repo_name);
+    if path.ends_with('/') || path.ends_with('\\') {
+        PathBuf::from(path).join(SCAN_REPORT_FILE)
+    } else {
+        PathBuf::from(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_path_template() {
+        assert_eq!(
+            report_path("reports/{repo}/", "api"),
+            PathBuf::from("reports/api/scan_report.json")
+        );
+        assert_eq!(
+            report_path("/tmp/{repo}-scan.json", "web"),
+            PathBuf::from("/tmp/web-scan.json")
+        );
+    }
+}
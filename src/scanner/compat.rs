@@ -2,7 +2,33 @@
 //!
 //! This module provides backward compatibility with the old Scanner interface
 //! that was used by enhanced_scanner and server modules.
-
+//!
+//! # Migrating
+//!
+//! [`Scanner`] is deprecated and will be removed in the next release. Its
+//! behavior is available unchanged through [`EnhancedScanner::scan`]:
+//!
+//! ```rust,no_run
+//! use rustassistant::enhanced_scanner::EnhancedScanner;
+//! use rustassistant::scanner::compat::{migrate_scanner_config, LegacyScannerConfig};
+//! use std::path::PathBuf;
+//!
+//! # fn main() -> rustassistant::Result<()> {
+//! // Before: Scanner::new(PathBuf::from("."), 1_000_000, false)?
+//! let config = migrate_scanner_config(LegacyScannerConfig {
+//!     root: PathBuf::from("."),
+//!     max_file_size: 1_000_000,
+//!     include_tests: false,
+//! });
+//! let scanner = EnhancedScanner::from_config(config, None)?;
+//! # Ok(())
+//! # }
+//! ```
+
+// The deprecated Scanner is still implemented (and re-exported) here
+#![allow(deprecated)]
+
+use crate::enhanced_scanner::EnhancedScannerConfig;
 use crate::error::Result;
 use crate::tags::TagScanner;
 use crate::types::{
@@ -10,12 +36,62 @@ use crate::types::{
     IssueCategory, IssueSeverity, SystemMap,
 };
 use ignore::WalkBuilder;
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use tracing::{debug, info};
 
+/// Arguments of the deprecated [`Scanner::new`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegacyScannerConfig {
+    pub root: PathBuf,
+    pub max_file_size: usize,
+    pub include_tests: bool,
+}
+
+/// Map a legacy [`Scanner`] configuration onto [`EnhancedScannerConfig`].
+///
+/// Test running and deep analysis stay off, so [`EnhancedScanner::scan`]
+/// produces the same report the legacy scanner did.
+///
+/// [`EnhancedScanner::scan`]: crate::enhanced_scanner::EnhancedScanner::scan
+pub fn migrate_scanner_config(old: LegacyScannerConfig) -> EnhancedScannerConfig {
+    EnhancedScannerConfig {
+        root: old.root,
+        max_file_size: old.max_file_size,
+        include_tests: old.include_tests,
+        run_tests: false,
+        deep_analysis: false,
+    }
+}
+
+/// Previous per-file results keyed by relative path, with the content hash
+/// each was computed from
+pub(crate) type StaticResultCache = HashMap<String, (String, FileAnalysis)>;
+
+/// A freshly analyzed file: relative path, content hash, result
+pub(crate) type FreshResult = (String, String, FileAnalysis);
+
+/// Outcome of scanning one file
+enum FileScan {
+    Cached(FileAnalysis),
+    Fresh(String, FileAnalysis),
+}
+
+/// Output of [`Scanner::scan_cached`]
+pub(crate) struct CachedScan {
+    pub report: AuditReport,
+    /// Files that were (re)analyzed
+    pub fresh: Vec<FreshResult>,
+    /// Files served from the cache
+    pub hits: usize,
+}
+
 /// Scanner for analyzing codebases (compatibility layer)
+#[deprecated(
+    note = "Use EnhancedScanner::scan; see scanner::compat::migrate_scanner_config to convert the configuration"
+)]
 pub struct Scanner {
     /// Root directory to scan
     root: PathBuf,
@@ -41,14 +117,23 @@ impl Scanner {
     }
 
     /// Scan the codebase and generate a report
-    pub fn scan(&self, _request: &AuditRequest) -> Result<AuditReport> {
+    pub fn scan(&self, request: &AuditRequest) -> Result<AuditReport> {
+        Ok(self.scan_cached(request, &StaticResultCache::new())?.report)
+    }
+
+    /// Scan, reusing results from `cache` for files whose content hash matches
+    pub(crate) fn scan_cached(
+        &self,
+        _request: &AuditRequest,
+        cache: &StaticResultCache,
+    ) -> Result<CachedScan> {
         info!("Starting codebase scan at {}", self.root.display());
 
         // Build system map
         let system_map = self.build_system_map()?;
 
         // Scan all files
-        let files = self.scan_files()?;
+        let (files, fresh, hits) = self.scan_files(cache)?;
 
         // Calculate summary
         let summary = self.calculate_summary(&files);
@@ -64,7 +149,7 @@ impl Scanner {
             }
         }
 
-        Ok(AuditReport {
+        let report = AuditReport {
             id: uuid::Uuid::new_v4().to_string(),
             repository: self.root.to_string_lossy().to_string(),
             branch: "main".to_string(),
@@ -76,6 +161,12 @@ impl Scanner {
             summary,
             test_results: None,
             context_bundle: None,
+        };
+
+        Ok(CachedScan {
+            report,
+            fresh,
+            hits,
         })
     }
 
@@ -94,10 +185,45 @@ impl Scanner {
     }
 
     /// Scan all files in the codebase
-    fn scan_files(&self) -> Result<Vec<FileAnalysis>> {
+    fn scan_files(
+        &self,
+        cache: &StaticResultCache,
+    ) -> Result<(Vec<FileAnalysis>, Vec<FreshResult>, usize)> {
         let mut analyses = Vec::new();
+        let mut fresh = Vec::new();
+        let mut hits = 0;
 
-        let walk = WalkBuilder::new(&self.root)
+        self.for_each_source_file(&self.root, |path, content| {
+            match self.scan_file(path, content, cache)? {
+                FileScan::Cached(analysis) => {
+                    hits += 1;
+                    analyses.push(analysis);
+                }
+                FileScan::Fresh(hash, analysis) => {
+                    fresh.push((
+                        analysis.path.to_string_lossy().to_string(),
+                        hash,
+                        analysis.clone(),
+                    ));
+                    analyses.push(analysis);
+                }
+            }
+            Ok(())
+        })?;
+
+        info!("Scanned {} files ({} from cache)", analyses.len(), hits);
+        Ok((analyses, fresh, hits))
+    }
+
+    /// Call `f` with the path and content of every file under `root` the
+    /// scan would analyze: not gitignored, within the size limit, UTF-8, and
+    /// not a test file unless tests are included
+    pub(crate) fn for_each_source_file(
+        &self,
+        root: &Path,
+        mut f: impl FnMut(&Path, String) -> Result<()>,
+    ) -> Result<()> {
+        let walk = WalkBuilder::new(root)
             .hidden(false)
             .git_ignore(true)
             .build();
@@ -105,39 +231,58 @@ impl Scanner {
         for entry in walk.flatten() {
             let path = entry.path();
             if path.is_file() {
-                if let Some(analysis) = self.scan_file(path)? {
-                    analyses.push(analysis);
+                if let Some(content) = self.read_source(path) {
+                    f(path, content)?;
                 }
             }
         }
-
-        info!("Scanned {} files", analyses.len());
-        Ok(analyses)
+        Ok(())
     }
 
-    /// Scan a single file
-    fn scan_file(&self, path: &Path) -> Result<Option<FileAnalysis>> {
+    /// Content of `path`, unless the scan skips it
+    fn read_source(&self, path: &Path) -> Option<String> {
         // Skip files that are too large
         if let Ok(metadata) = fs::metadata(path) {
             if metadata.len() > self.max_file_size as u64 {
                 debug!("Skipping large file: {}", path.display());
-                return Ok(None);
+                return None;
             }
         }
 
         // Skip test files if not included
         if !self.include_tests && is_test_file(path) {
-            return Ok(None);
+            return None;
         }
 
-        // Read file content
-        let content = match fs::read_to_string(path) {
-            Ok(c) => c,
+        match fs::read_to_string(path) {
+            Ok(content) => Some(content),
             Err(_) => {
                 debug!("Skipping non-UTF8 file: {}", path.display());
-                return Ok(None);
+                None
             }
-        };
+        }
+    }
+
+    /// Scan a single file
+    fn scan_file(
+        &self,
+        path: &Path,
+        content: String,
+        cache: &StaticResultCache,
+    ) -> Result<FileScan> {
+        let rel_path = path
+            .strip_prefix(&self.root)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .to_string();
+
+        // Unchanged since the cached result was computed
+        let hash = format!("{:x}", Sha256::digest(content.as_bytes()));
+        if let Some((cached_hash, analysis)) = cache.get(&rel_path) {
+            if *cached_hash == hash {
+                return Ok(FileScan::Cached(analysis.clone()));
+            }
+        }
 
         // Determine category
         let category = categorize_file(path);
@@ -149,23 +294,20 @@ impl Scanner {
         // Calculate priority
         let priority = calculate_priority(&issues, &category);
 
-        let rel_path = path
-            .strip_prefix(&self.root)
-            .unwrap_or(path)
-            .to_string_lossy()
-            .to_string();
-
-        Ok(Some(FileAnalysis {
-            path: PathBuf::from(rel_path),
-            category,
-            priority,
-            lines: content.lines().count(),
-            doc_blocks: 0,
-            security_rating: None,
-            issues,
-            llm_analysis: None,
-            tags,
-        }))
+        Ok(FileScan::Fresh(
+            hash,
+            FileAnalysis {
+                path: PathBuf::from(rel_path),
+                category,
+                priority,
+                lines: content.lines().count(),
+                doc_blocks: 0,
+                security_rating: None,
+                issues,
+                llm_analysis: None,
+                tags,
+            },
+        ))
     }
 
     /// Calculate summary statistics
@@ -196,6 +338,17 @@ impl Scanner {
             total_tests: None,
             test_pass_rate: None,
             code_coverage: None,
+            cache_hits: None,
+            cache_hit_rate: None,
+        }
+    }
+
+    /// This scanner's configuration, for [`migrate_scanner_config`]
+    pub fn legacy_config(&self) -> LegacyScannerConfig {
+        LegacyScannerConfig {
+            root: self.root.clone(),
+            max_file_size: self.max_file_size,
+            include_tests: self.include_tests,
         }
     }
 }
@@ -322,3 +475,98 @@ fn calculate_priority(issues: &[Issue], category: &Category) -> FilePriority {
         FilePriority::Low
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enhanced_scanner::EnhancedScanner;
+
+    #[test]
+    fn test_migrated_config_scans_identically() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("lib.rs"),
+            "// TODO: tidy\nfn main() { x.unwrap(); }\n",
+        )
+        .unwrap();
+        fs::create_dir(dir.path().join("tests")).unwrap();
+        fs::write(dir.path().join("tests/it.rs"), "// FIXME\n").unwrap();
+
+        let legacy = Scanner::new(dir.path().to_path_buf(), 1_000_000, false).unwrap();
+        let config = migrate_scanner_config(legacy.legacy_config());
+        assert_eq!(config.root, dir.path());
+        assert_eq!(config.max_file_size, 1_000_000);
+        assert!(!config.include_tests);
+        assert!(!config.run_tests && !config.deep_analysis);
+
+        let request = AuditRequest {
+            repository: dir.path().to_string_lossy().to_string(),
+            branch: None,
+            enable_llm: false,
+            focus: vec![],
+            include_tests: false,
+        };
+        let old = legacy.scan(&request).unwrap();
+        let new = EnhancedScanner::from_config(config, None)
+            .unwrap()
+            .scan(&request)
+            .unwrap();
+
+        assert_eq!(new.summary.total_files, old.summary.total_files);
+        assert_eq!(new.summary.total_lines, old.summary.total_lines);
+        assert_eq!(new.summary.total_issues, old.summary.total_issues);
+        assert_eq!(new.issues_by_severity, old.issues_by_severity);
+        assert!(new.test_results.is_none() && new.context_bundle.is_none());
+    }
+
+    #[test]
+    fn test_scan_cached_rescans_only_changed_files() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("a.rs"), "// TODO: a\nfn a() {}\n").unwrap();
+        fs::write(dir.path().join("b.rs"), "fn b() { x.unwrap(); }\n").unwrap();
+        let scanner = Scanner::new(dir.path().to_path_buf(), 1_000_000, false).unwrap();
+        let request = AuditRequest {
+            repository: dir.path().to_string_lossy().to_string(),
+            branch: None,
+            enable_llm: false,
+            focus: vec![],
+            include_tests: false,
+        };
+
+        let first = scanner
+            .scan_cached(&request, &StaticResultCache::new())
+            .unwrap();
+        assert_eq!(first.hits, 0);
+        assert_eq!(first.fresh.len(), first.report.summary.total_files);
+        let mut cache: StaticResultCache = first
+            .fresh
+            .into_iter()
+            .map(|(path, hash, analysis)| (path, (hash, analysis)))
+            .collect();
+
+        // Nothing changed: served entirely from cache
+        let second = scanner.scan_cached(&request, &cache).unwrap();
+        assert!(second.fresh.is_empty());
+        assert_eq!(second.hits, second.report.summary.total_files);
+        assert_eq!(
+            second.report.summary.total_issues,
+            first.report.summary.total_issues
+        );
+
+        // One edited file: only that file is re-analyzed
+        fs::write(dir.path().join("b.rs"), "fn b() {}\n").unwrap();
+        let third = scanner.scan_cached(&request, &cache).unwrap();
+        assert_eq!(third.fresh.len(), 1);
+        assert_eq!(third.fresh[0].0, "b.rs");
+        assert_eq!(third.hits, third.report.summary.total_files - 1);
+
+        for (path, hash, analysis) in third.fresh {
+            cache.insert(path, (hash, analysis));
+        }
+        assert!(scanner
+            .scan_cached(&request, &cache)
+            .unwrap()
+            .fresh
+            .is_empty());
+    }
+}
@@ -12,5 +12,6 @@ pub use github::{
     DetectedTodo, GitHubRepo, ScanResult, TreeNode,
 };
 
-// Re-export compatibility scanner
+// Re-export compatibility scanner (deprecated; see `compat` for migrating)
+#[allow(deprecated)]
 pub use compat::Scanner;
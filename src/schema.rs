@@ -0,0 +1,307 @@
+//! JSON Schemas for LLM response types
+//!
+//! Every type parsed out of an LLM response derives [`schemars::JsonSchema`]
+//! and is registered here by name. The schemas can be passed to a provider as
+//! a structured-output constraint, and [`validate_response`] checks a parsed
+//! response against one, reporting the first mismatch with its JSON path
+//! (e.g. `$.top_priorities[2]: expected string, got number`) instead of a
+//! bare serde error.
+
+use crate::doc_generator::{ModuleDoc, ReadmeContent};
+use crate::error::{AuditError, Result};
+use crate::llm_audit::{FileLlmAnalysis, MasterReview, Recommendation};
+use once_cell::sync::Lazy;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::HashMap;
+
+static SCHEMAS: Lazy<HashMap<&'static str, Value>> = Lazy::new(|| {
+    fn schema<T: schemars::JsonSchema>() -> Value {
+        schemars::schema_for!(T).to_value()
+    }
+
+    HashMap::from([
+        ("FileLlmAnalysis", schema::<FileLlmAnalysis>()),
+        ("MasterReview", schema::<MasterReview>()),
+        ("Recommendation", schema::<Recommendation>()),
+        ("ModuleDoc", schema::<ModuleDoc>()),
+        ("ReadmeContent", schema::<ReadmeContent>()),
+    ])
+});
+
+/// JSON Schema of every LLM response type, keyed by type name
+pub fn response_schemas() -> HashMap<&'static str, Value> {
+    SCHEMAS.clone()
+}
+
+/// JSON Schema of one LLM response type
+pub fn response_schema(schema_name: &str) -> Option<&'static Value> {
+    SCHEMAS.get(schema_name)
+}
+
+/// Check `value` against the schema registered as `schema_name`
+///
+/// Returns [`AuditError::SchemaViolation`] naming the path of the first
+/// mismatch.
+pub fn validate_response(schema_name: &str, value: &Value) -> Result<()> {
+    let schema = response_schema(schema_name)
+        .ok_or_else(|| AuditError::other(format!("Unknown response schema: {}", schema_name)))?;
+    check(schema, schema, value, "$")
+}
+
+/// Parse an LLM response body into `T`, validating it against the schema
+/// registered as `schema_name` first so a mismatch names the offending path
+pub fn parse_response<T: DeserializeOwned>(schema_name: &str, response: &str) -> Result<T> {
+    let value: Value = serde_json::from_str(response)?;
+    validate_response(schema_name, &value)?;
+    Ok(serde_json::from_value(value)?)
+}
+
+fn violation(path: &str, message: impl Into<String>) -> AuditError {
+    AuditError::SchemaViolation {
+        path: path.to_string(),
+        message: message.into(),
+    }
+}
+
+fn kind_of(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn has_type(value: &Value, ty: &str) -> bool {
+    match ty {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "string" => value.is_string(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|f| f.fract() == 0.0)
+        }
+        _ => true,
+    }
+}
+
+/// Validate `value` at `path` against `schema`, resolving `$ref`s in `root`
+///
+/// Covers the keywords schemars emits for plain data types: `type`, `enum`,
+/// `const`, `minimum`/`maximum`, `properties`/`required`/
+/// `additionalProperties`, `items` and the `anyOf`/`oneOf`/`allOf`
+/// combinators.
+fn check(root: &Value, schema: &Value, value: &Value, path: &str) -> Result<()> {
+    let schema = match schema {
+        Value::Bool(true) => return Ok(()),
+        Value::Bool(false) => return Err(violation(path, "no value is allowed here")),
+        Value::Object(schema) => schema,
+        _ => return Ok(()),
+    };
+
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        let target = reference
+            .strip_prefix('#')
+            .and_then(|pointer| root.pointer(pointer))
+            .ok_or_else(|| {
+                violation(path, format!("unresolvable schema reference {}", reference))
+            })?;
+        check(root, target, value, path)?;
+    }
+
+    if let Some(ty) = schema.get("type") {
+        let allowed: Vec<&str> = match ty {
+            Value::String(t) => vec![t.as_str()],
+            Value::Array(ts) => ts.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !allowed.is_empty() && !allowed.iter().any(|t| has_type(value, t)) {
+            return Err(violation(
+                path,
+                format!("expected {}, got {}", allowed.join(" or "), kind_of(value)),
+            ));
+        }
+    }
+
+    if let Some(options) = schema.get("enum").and_then(Value::as_array) {
+        if !options.contains(value) {
+            let options: Vec<String> = options.iter().map(Value::to_string).collect();
+            return Err(violation(
+                path,
+                format!("{} is not one of {}", value, options.join(", ")),
+            ));
+        }
+    }
+    if let Some(expected) = schema.get("const") {
+        if expected != value {
+            return Err(violation(
+                path,
+                format!("expected {}, got {}", expected, value),
+            ));
+        }
+    }
+
+    if let Some(n) = value.as_f64() {
+        if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+            if n < min {
+                return Err(violation(
+                    path,
+                    format!("{} is below the minimum {}", n, min),
+                ));
+            }
+        }
+        if let Some(max) = schema.get("maximum").and_then(Value::as_f64) {
+            if n > max {
+                return Err(violation(
+                    path,
+                    format!("{} is above the maximum {}", n, max),
+                ));
+            }
+        }
+    }
+
+    if let Some(branches) = schema.get("allOf").and_then(Value::as_array) {
+        for branch in branches {
+            check(root, branch, value, path)?;
+        }
+    }
+    for combinator in ["anyOf", "oneOf"] {
+        if let Some(branches) = schema.get(combinator).and_then(Value::as_array) {
+            if !branches
+                .iter()
+                .any(|branch| check(root, branch, value, path).is_ok())
+            {
+                return Err(violation(path, "does not match any allowed variant"));
+            }
+        }
+    }
+
+    if let Value::Object(fields) = value {
+        if let Some(required) = schema.get("required").and_then(Value::as_array) {
+            for key in required.iter().filter_map(Value::as_str) {
+                if !fields.contains_key(key) {
+                    return Err(violation(
+                        &format!("{}.{}", path, key),
+                        "missing required field",
+                    ));
+                }
+            }
+        }
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for (key, field) in fields {
+            let field_path = format!("{}.{}", path, key);
+            match properties.and_then(|p| p.get(key)) {
+                Some(field_schema) => check(root, field_schema, field, &field_path)?,
+                None => {
+                    if let Some(extra) = schema.get("additionalProperties") {
+                        check(root, extra, field, &field_path)
+                            .map_err(|_| violation(&field_path, "unknown field"))?;
+                    }
+                }
+            }
+        }
+    }
+
+    if let (Value::Array(items), Some(item_schema)) = (value, schema.get("items")) {
+        for (i, item) in items.iter().enumerate() {
+            check(root, item_schema, item, &format!("{}[{}]", path, i))?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn master_review() -> Value {
+        json!({
+            "executive_summary": "Solid core, thin tests",
+            "top_priorities": ["Add integration tests"],
+            "strengths": ["Clear module boundaries"],
+            "weaknesses": [],
+            "architecture_quality": 78.0,
+            "code_consistency": 82.5,
+            "test_coverage_assessment": "Sparse",
+            "sustainability": 70,
+            "strategic_recommendations": []
+        })
+    }
+
+    #[test]
+    fn test_every_response_type_has_an_object_schema() {
+        let schemas = response_schemas();
+        for name in [
+            "FileLlmAnalysis",
+            "MasterReview",
+            "Recommendation",
+            "ModuleDoc",
+            "ReadmeContent",
+        ] {
+            assert_eq!(schemas[name]["type"], "object", "{}", name);
+        }
+        assert!(schemas["MasterReview"]["required"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("executive_summary")));
+    }
+
+    #[test]
+    fn test_valid_response_parses() {
+        let review: MasterReview =
+            parse_response("MasterReview", &master_review().to_string()).unwrap();
+        assert_eq!(review.top_priorities, vec!["Add integration tests"]);
+        assert_eq!(review.sustainability, 70.0);
+    }
+
+    #[test]
+    fn test_violations_name_the_offending_path() {
+        let mut drifted = master_review();
+        drifted["top_priorities"] = json!(["Add tests", 3]);
+        let err = validate_response("MasterReview", &drifted).unwrap_err();
+        assert!(
+            matches!(&err, AuditError::SchemaViolation { path, .. } if path == "$.top_priorities[1]"),
+            "{}",
+            err
+        );
+        assert!(err.to_string().contains("expected string, got integer"));
+
+        let mut missing = master_review();
+        missing.as_object_mut().unwrap().remove("strengths");
+        let err = validate_response("MasterReview", &missing).unwrap_err();
+        assert!(matches!(&err, AuditError::SchemaViolation { path, .. } if path == "$.strengths"));
+
+        // Nested definitions are resolved through `$ref`
+        let doc = json!({
+            "module_name": "db",
+            "summary": "Database access",
+            "functions": [{
+                "name": "connect",
+                "signature": "fn connect()",
+                "description": "Open a pool",
+                "parameters": [{ "name": "url", "param_type": 5, "description": "" }],
+                "returns": "Pool",
+                "examples": []
+            }],
+            "examples": []
+        });
+        let err = validate_response("ModuleDoc", &doc).unwrap_err();
+        assert!(matches!(
+            &err,
+            AuditError::SchemaViolation { path, .. } if path == "$.functions[0].parameters[0].param_type"
+        ));
+    }
+
+    #[test]
+    fn test_unknown_schema_is_an_error() {
+        assert!(validate_response("NoSuchType", &json!({})).is_err());
+    }
+}
@@ -267,6 +267,12 @@ impl SemanticSearcher {
         tracing::debug!("Retrieving candidate embeddings from database");
         let candidates = self.get_candidate_embeddings(pool, &query.filters).await?;
 
+        // Vectors from another model are not comparable, even at the same dimension
+        let candidates: Vec<CandidateEmbedding> = candidates
+            .into_iter()
+            .filter(|c| c.model == query_embedding.model)
+            .collect();
+
         if candidates.is_empty() {
             tracing::warn!("No candidate embeddings found");
             return Ok(Vec::new());
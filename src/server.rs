@@ -1,14 +1,19 @@
 //! Axum API server for the audit service + RustAssistant dashboard
 
+use crate::api::auth::api_auth_middleware;
 use crate::api::proxy::{proxy_router, ProxyState};
 use crate::api::repos::{repo_router, RepoAppState};
 use crate::audit::endpoint::{audit_router, AuditState};
 use crate::config::Config;
 use crate::db::Database;
 use crate::db::{self, init_db, Repository};
+use crate::dto::v1::{severity_counts, AuditTagDto, IssueSeverityDto};
+use crate::enhanced_scanner::EnhancedScanner;
 use crate::error::{AuditError, Result};
 use crate::git::GitManager;
-use crate::github::webhook::{WebhookHandler, WebhookPayload};
+use crate::github::webhook::{WebhookEvent, WebhookHandler, WebhookPayload, WebhookProcessor};
+use crate::health::{health_router, shutdown_signal, HealthState, Shutdown, WorkerHealth};
+#[allow(deprecated)]
 use crate::llm::LlmClient;
 use crate::model_router::{ModelRouter, ModelRouterConfig};
 use crate::queue::{get_queue_stats, QueueStats};
@@ -18,9 +23,9 @@ use crate::scanner::github::sync_repos_to_db;
 use crate::sync_scheduler::{SyncScheduler, SyncSchedulerConfig};
 // WebUI removed — RustAssistant is API-only (batch-015)
 
-use crate::scanner::Scanner;
+use crate::scanner::compat::{migrate_scanner_config, LegacyScannerConfig};
 use crate::tags::TagScanner;
-use crate::types::{AuditRequest, AuditTag};
+use crate::types::AuditRequest;
 use axum::{
     extract::{Json, Query, State},
     http::{header, HeaderMap, Method, StatusCode},
@@ -30,7 +35,7 @@ use axum::{
 };
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
@@ -43,13 +48,14 @@ use tracing::{info, warn};
 pub struct AppState {
     config: Arc<Config>,
     pub(crate) git_manager: Arc<GitManager>,
-    #[allow(dead_code)]
+    #[allow(dead_code, deprecated)]
     llm_client: Option<Arc<LlmClient>>,
     pub(crate) db_pool: PgPool,
 }
 
 impl AppState {
     /// Create new application state
+    #[allow(deprecated)]
     pub async fn new(config: Config) -> Result<Self> {
         let git_manager = Arc::new(GitManager::new(
             config.git.workspace_dir.clone(),
@@ -95,8 +101,18 @@ impl AppState {
 /// the `RepoSyncService` so it can trigger a repo sync on push events).
 #[derive(Clone)]
 struct WebhookState {
-    sync_service: Arc<tokio::sync::RwLock<RepoSyncService>>,
-    webhook_secret: String,
+    processor: Arc<PushSyncProcessor>,
+    /// Accepted signing secrets: the current one, then the previous one while
+    /// a rotation is in progress
+    webhook_secrets: Vec<String>,
+    /// Deliveries are persisted here before processing (at-least-once)
+    pool: PgPool,
+}
+
+impl WebhookState {
+    fn handler(&self) -> WebhookHandler {
+        WebhookHandler::with_secrets(self.webhook_secrets.clone()).with_store(self.pool.clone())
+    }
 }
 
 pub async fn run_server(config: Config) -> Result<()> {
@@ -105,6 +121,8 @@ pub async fn run_server(config: Config) -> Result<()> {
         .parse()
         .map_err(|e| AuditError::config(format!("Invalid server address: {}", e)))?;
 
+    config.auth.validate()?;
+
     info!("Starting RustAssistant server on {}", socket_addr);
 
     // Initialize tracing
@@ -194,13 +212,17 @@ pub async fn run_server(config: Config) -> Result<()> {
         .ok()
         .and_then(|v| v.parse::<u64>().ok())
         .unwrap_or(300);
-    SyncScheduler::new(
+    let shutdown = Shutdown::new();
+    let sync_health = WorkerHealth::new();
+    let sync_handle = SyncScheduler::new(
         SyncSchedulerConfig {
             interval: std::time::Duration::from_secs(sync_interval_secs),
             ..SyncSchedulerConfig::default()
         },
         Arc::clone(&sync_service),
     )
+    .with_shutdown(shutdown.clone())
+    .with_health(sync_health.clone())
     .start();
     info!(interval_secs = sync_interval_secs, "SyncScheduler started");
 
@@ -230,13 +252,47 @@ pub async fn run_server(config: Config) -> Result<()> {
     // Build WebhookState for the GitHub push-event → sync trigger
     // ------------------------------------------------------------------
     let webhook_state = WebhookState {
-        sync_service: Arc::clone(&sync_service),
-        webhook_secret: std::env::var("GITHUB_WEBHOOK_SECRET").unwrap_or_default(),
+        processor: Arc::new(PushSyncProcessor {
+            sync_service: Arc::clone(&sync_service),
+        }),
+        webhook_secrets: ["GITHUB_WEBHOOK_SECRET", "GITHUB_WEBHOOK_SECRET_PREVIOUS"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .filter(|secret| !secret.is_empty())
+            .collect(),
+        pool: state.db_pool.clone(),
     };
 
+    // Retry deliveries that failed (or were interrupted) before the last shutdown
+    {
+        let wh = webhook_state.clone();
+        tokio::spawn(async move {
+            if let Err(e) = wh.handler().replay_unprocessed(wh.processor.as_ref()).await {
+                warn!(error = %e, "Webhook replay failed");
+            }
+        });
+    }
+
+    let health_state =
+        HealthState::new(state.db_pool.clone(), shutdown.clone()).with_sync(sync_health);
+
     // SECURITY: Configure restrictive CORS policy
     let cors = build_cors_layer();
 
+    // SECURITY: API key / basic auth on everything but the public paths
+    if config.auth.enabled {
+        info!(
+            keys = config.auth.api_keys.len(),
+            basic = config.auth.basic_auth.is_some(),
+            anonymous_read = config.auth.allow_anonymous_read,
+            "API auth enabled"
+        );
+    } else {
+        warn!("API auth disabled (API_AUTH_ENABLED=false) — only bind to localhost");
+    }
+    let auth =
+        axum::middleware::from_fn_with_state(Arc::new(config.auth.clone()), api_auth_middleware);
+
     // ------------------------------------------------------------------
     // Compose routers
     // ------------------------------------------------------------------
@@ -267,9 +323,11 @@ pub async fn run_server(config: Config) -> Result<()> {
         .nest("/api/v1", repo_router(repo_app_state.clone()))
         // OpenAI-compatible proxy at /v1  (for external apps e.g. futures trading bot)
         .nest("/v1", proxy_router(ProxyState::new(repo_app_state)))
-        // Health check (aliased for OpenClaw / external probes)
-        .route("/healthz", get(health_check))
-        // Middleware (applied last, wraps everything)
+        // Liveness / readiness probes (OpenClaw, container orchestrators)
+        .merge(health_router(health_state))
+        // Middleware (applied last, wraps everything; CORS outermost so
+        // preflight requests are answered before auth)
+        .layer(auth)
         .layer(cors)
         .layer(TraceLayer::new_for_http());
 
@@ -279,6 +337,7 @@ pub async fn run_server(config: Config) -> Result<()> {
         socket_addr
     );
     info!("Health check            http://{}/healthz", socket_addr);
+    info!("Readiness check         http://{}/readyz", socket_addr);
 
     // Start server
     let listener = tokio::net::TcpListener::bind(&socket_addr)
@@ -286,9 +345,18 @@ pub async fn run_server(config: Config) -> Result<()> {
         .map_err(|e| AuditError::other(format!("Failed to bind to {}: {}", socket_addr, e)))?;
 
     axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal(shutdown))
         .await
         .map_err(|e| AuditError::other(format!("Server error: {}", e)))?;
 
+    // Let a running sync pass finish before exiting
+    if tokio::time::timeout(Duration::from_secs(30), sync_handle)
+        .await
+        .is_err()
+    {
+        warn!("Timed out waiting for SyncScheduler to stop");
+    }
+
     Ok(())
 }
 
@@ -342,15 +410,16 @@ fn build_cors_layer() -> CorsLayer {
 /// `RepoSyncService::sync` for any registered repo whose `remote_url`
 /// matches the repository in the event payload.
 ///
-/// The endpoint always returns **200 OK** quickly — the sync itself runs in a
-/// background `tokio::spawn` so GitHub doesn't time out waiting for us.
+/// Verified deliveries are stored in `github_webhook_events` before the endpoint
+/// returns **200 OK**; the sync itself runs in a background `tokio::spawn` so
+/// GitHub doesn't time out waiting for us. Failed syncs stay unprocessed and
+/// are replayed on the next startup. A redelivery of an already-processed
+/// delivery id is acked without action.
 async fn handle_github_webhook(
     State(wh_state): State<WebhookState>,
     headers: HeaderMap,
     body: String,
 ) -> impl IntoResponse {
-    use crate::github::webhook::WebhookEvent;
-
     let event_type = headers
         .get("x-github-event")
         .and_then(|v| v.to_str().ok())
@@ -371,8 +440,8 @@ async fn handle_github_webhook(
     let payload = WebhookPayload::new(&event_type, &delivery_id, signature, &body);
 
     // Verify signature when a secret is configured.
-    if !wh_state.webhook_secret.is_empty() {
-        let handler = WebhookHandler::new(&wh_state.webhook_secret);
+    if !wh_state.webhook_secrets.is_empty() {
+        let handler = wh_state.handler();
         match handler.verify_signature(&payload) {
             Ok(true) => {}
             Ok(false) => {
@@ -394,92 +463,132 @@ async fn handle_github_webhook(
         }
     }
 
-    // Parse the event — unsupported types are silently acked.
-    let event = match payload.parse_event() {
-        Ok(e) => e,
-        Err(e) => {
-            info!(
-                delivery = %delivery_id,
-                event_type = %event_type,
-                "Unrecognised webhook event type — acking without action: {}",
-                e
-            );
+    // Parse the event — unsupported types are silently acked and not stored.
+    if let Err(e) = payload.parse_event() {
+        info!(
+            delivery = %delivery_id,
+            event_type = %event_type,
+            "Unrecognised webhook event type — acking without action: {}",
+            e
+        );
+        return (
+            StatusCode::OK,
+            Json(serde_json::json!({ "status": "ignored" })),
+        )
+            .into_response();
+    }
+
+    let handler = wh_state.handler();
+    match handler.persist(&payload).await {
+        Ok(true) => {}
+        Ok(false) => {
             return (
                 StatusCode::OK,
-                Json(serde_json::json!({ "status": "ignored" })),
+                Json(serde_json::json!({ "status": "duplicate" })),
             )
                 .into_response();
         }
-    };
+        Err(e) => {
+            warn!(delivery = %delivery_id, error = %e, "Failed to persist webhook delivery");
+            return (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(serde_json::json!({ "error": "Webhook store unavailable" })),
+            )
+                .into_response();
+        }
+    }
+
+    tokio::spawn(async move {
+        // Failures are recorded on the stored row for replay
+        let _ = handler.process(&payload, wh_state.processor.as_ref()).await;
+    });
 
-    // Only push events trigger a repo sync.
-    if let WebhookEvent::Push(ref push) = event {
-        let repo_full_name = push.repository.full_name.clone();
-        let branch = push.branch_name().unwrap_or("unknown").to_string();
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({ "status": "accepted" })),
+    )
+        .into_response()
+}
+
+/// Syncs every registered repo matching a push event's repository
+struct PushSyncProcessor {
+    sync_service: Arc<tokio::sync::RwLock<RepoSyncService>>,
+}
+
+#[async_trait::async_trait]
+impl WebhookProcessor for PushSyncProcessor {
+    async fn process(&self, event: &WebhookEvent) -> crate::github::Result<()> {
+        // Only push events trigger a repo sync.
+        let WebhookEvent::Push(push) = event else {
+            return Ok(());
+        };
+        let repo_full_name = &push.repository.full_name;
+        let branch = push.branch_name().unwrap_or("unknown");
 
         info!(
-            delivery = %delivery_id,
             repo = %repo_full_name,
             branch = %branch,
             "Push webhook received — checking for matching registered repo"
         );
 
-        // Clone the sync_service Arc so we can move it into the background task.
-        let sync_service = Arc::clone(&wh_state.sync_service);
-
-        tokio::spawn(async move {
-            let svc = sync_service.read().await;
-
-            // Find any registered repo whose remote_url ends with the GitHub
-            // full name (handles both HTTPS and SSH remote URL formats).
-            let matching_ids: Vec<String> = svc
-                .list_repos()
-                .iter()
-                .filter(|r| {
-                    r.remote_url
-                        .as_deref()
-                        .map(|u| u.contains(&repo_full_name))
-                        .unwrap_or(false)
-                })
-                .map(|r| r.id.clone())
-                .collect();
-
-            drop(svc); // release read lock before acquiring write lock
-
-            if matching_ids.is_empty() {
-                info!(
-                    repo = %repo_full_name,
-                    "No registered repo matches push event — skipping sync"
-                );
-                return;
-            }
+        let svc = self.sync_service.read().await;
+
+        // Find any registered repo whose remote_url ends with the GitHub
+        // full name (handles both HTTPS and SSH remote URL formats).
+        let matching_ids: Vec<String> = svc
+            .list_repos()
+            .iter()
+            .filter(|r| {
+                r.remote_url
+                    .as_deref()
+                    .map(|u| u.contains(repo_full_name.as_str()))
+                    .unwrap_or(false)
+            })
+            .map(|r| r.id.clone())
+            .collect();
+
+        drop(svc); // release read lock before acquiring write lock
+
+        if matching_ids.is_empty() {
+            info!(
+                repo = %repo_full_name,
+                "No registered repo matches push event — skipping sync"
+            );
+            return Ok(());
+        }
 
-            let mut svc = sync_service.write().await;
-            for repo_id in matching_ids {
-                info!(repo_id = %repo_id, "Triggering sync from push webhook");
-                match svc.sync(&repo_id).await {
-                    Ok(result) => info!(
-                        repo_id = %repo_id,
-                        files = result.files_walked,
-                        todos = result.todos_found,
-                        duration_ms = result.duration_ms,
-                        "Webhook-triggered sync complete"
-                    ),
-                    Err(e) => warn!(
+        let mut svc = self.sync_service.write().await;
+        let mut failures = Vec::new();
+        for repo_id in matching_ids {
+            info!(repo_id = %repo_id, "Triggering sync from push webhook");
+            match svc.sync(&repo_id).await {
+                Ok(result) => info!(
+                    repo_id = %repo_id,
+                    files = result.files_walked,
+                    todos = result.todos_found,
+                    duration_ms = result.duration_ms,
+                    "Webhook-triggered sync complete"
+                ),
+                Err(e) => {
+                    warn!(
                         repo_id = %repo_id,
                         error = %e,
                         "Webhook-triggered sync failed"
-                    ),
+                    );
+                    failures.push(format!("{}: {}", repo_id, e));
                 }
             }
-        });
-    }
+        }
 
-    (
-        StatusCode::OK,
-        Json(serde_json::json!({ "status": "accepted" })),
-    )
-        .into_response()
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(crate::github::GitHubError::ApiError(format!(
+                "Sync failed for {}",
+                failures.join("; ")
+            )))
+        }
+    }
 }
 
 /// Health check endpoint
@@ -504,7 +613,10 @@ async fn clone_repository(
     // 3. Exfiltrate data to attacker-controlled servers
     state.config.security.validate_git_url(&request.url)?;
 
-    let repo_path = state.git_manager.clone_repo(&request.url, None)?;
+    let repo_path = state
+        .git_manager
+        .clone_repo_async(&request.url, None)
+        .await?;
 
     if let Some(branch) = &request.branch {
         state.git_manager.checkout(&repo_path, branch)?;
@@ -542,7 +654,7 @@ async fn scan_tags(
     Ok(Json(TagsResponse {
         total: tags.len(),
         by_type,
-        tags,
+        tags: tags.iter().map(Into::into).collect(),
     }))
 }
 
@@ -553,10 +665,13 @@ async fn scan_static(
 ) -> Result<Json<StaticAnalysisResponse>> {
     info!("Running static analysis on: {}", request.path);
 
-    let scanner = Scanner::new(
-        std::path::PathBuf::from(&request.path),
-        state.config.scanner.max_file_size,
-        false,
+    let scanner = EnhancedScanner::from_config(
+        migrate_scanner_config(LegacyScannerConfig {
+            root: std::path::PathBuf::from(&request.path),
+            max_file_size: state.config.scanner.max_file_size,
+            include_tests: false,
+        }),
+        None,
     )?;
 
     let audit_request = AuditRequest {
@@ -573,7 +688,7 @@ async fn scan_static(
         total_files: report.summary.total_files,
         total_issues: report.summary.total_issues,
         critical_files: report.summary.critical_files,
-        issues_by_severity: report.issues_by_severity,
+        issues_by_severity: severity_counts(&report.issues_by_severity),
     }))
 }
 
@@ -607,7 +722,7 @@ struct ScanRequest {
 struct TagsResponse {
     total: usize,
     by_type: HashMap<String, usize>,
-    tags: Vec<AuditTag>,
+    tags: Vec<AuditTagDto>,
 }
 
 #[derive(Debug, Serialize)]
@@ -615,7 +730,7 @@ struct StaticAnalysisResponse {
     total_files: usize,
     total_issues: usize,
     critical_files: usize,
-    issues_by_severity: HashMap<crate::types::IssueSeverity, usize>,
+    issues_by_severity: BTreeMap<IssueSeverityDto, usize>,
 }
 
 // ===== Visualization Endpoints =====
@@ -630,7 +745,13 @@ impl IntoResponse for AuditError {
             AuditError::FileNotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
             AuditError::Config(_) => (StatusCode::BAD_REQUEST, self.to_string()),
             AuditError::InvalidApiKey { .. } => (StatusCode::UNAUTHORIZED, self.to_string()),
-            AuditError::RateLimitExceeded => (StatusCode::TOO_MANY_REQUESTS, self.to_string()),
+            AuditError::RateLimited { .. } | AuditError::BudgetExceeded => {
+                (StatusCode::TOO_MANY_REQUESTS, self.to_string())
+            }
+            // The upstream LLM failed, not this request
+            AuditError::AuthFailed | AuditError::ResponseParse { .. } => {
+                (StatusCode::BAD_GATEWAY, self.to_string())
+            }
             _ => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()),
         };
 
@@ -954,7 +1075,7 @@ async fn github_sync(
     let client = GitHubClient::new(token)
         .map_err(|e| AuditError::other(format!("Failed to create GitHub client: {}", e)))?;
 
-    let sync_engine = SyncEngine::new(client.clone(), state.db_pool.clone());
+    let sync_engine = SyncEngine::new(Box::new(client.clone()), state.db_pool.clone());
 
     let options = if params.full.unwrap_or(false) {
         SyncOptions::default().force_full()
@@ -83,6 +83,8 @@ pub enum SkipReason {
     TestOnly,
     /// File hasn't changed since last successful analysis and had 0 issues
     UnchangedClean,
+    /// A prompt router path override forces the file to be skipped
+    PathOverride,
 }
 
 impl std::fmt::Display for SkipReason {
@@ -94,6 +96,7 @@ impl std::fmt::Display for SkipReason {
             Self::DuplicateContent => write!(f, "duplicate content"),
             Self::TestOnly => write!(f, "test-only file"),
             Self::UnchangedClean => write!(f, "unchanged + clean"),
+            Self::PathOverride => write!(f, "path override"),
         }
     }
 }
@@ -178,6 +181,14 @@ pub struct QualitySignals {
     /// Whether the file has any `pub` items (is part of public API)
     pub has_public_api: bool,
 
+    // --- Async ---
+    /// Count of `async fn` definitions
+    #[serde(default)]
+    pub async_fn_count: usize,
+    /// Count of `async fn` bodies that never `.await`
+    #[serde(default)]
+    pub async_fn_without_await: usize,
+
     // --- Dependencies ---
     /// Number of `use` / `import` statements
     pub import_count: usize,
@@ -196,6 +207,10 @@ pub struct SecurityFinding {
     pub matched_text: String,
     /// Confidence level
     pub confidence: FindingConfidence,
+    /// Byte range of the secret value within the line, when it can be
+    /// isolated; used by [`crate::redaction`] to mask it
+    #[serde(default)]
+    pub span: Option<(usize, usize)>,
 }
 
 /// Confidence level for a security finding
@@ -231,79 +246,8 @@ pub struct StaticAnalysisResult {
     pub static_issue_count: usize,
 }
 
-/// Detected file language
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum FileLanguage {
-    Rust,
-    Kotlin,
-    Python,
-    TypeScript,
-    JavaScript,
-    Go,
-    Java,
-    Shell,
-    Swift,
-    Cpp,
-    C,
-    Unknown,
-}
-
-impl FileLanguage {
-    /// Detect language from file extension
-    pub fn from_extension(path: &str) -> Self {
-        let ext = path.rsplit('.').next().unwrap_or("");
-        match ext {
-            "rs" => Self::Rust,
-            "kt" | "kts" => Self::Kotlin,
-            "py" => Self::Python,
-            "ts" | "tsx" => Self::TypeScript,
-            "js" | "jsx" => Self::JavaScript,
-            "go" => Self::Go,
-            "java" => Self::Java,
-            "sh" | "bash" | "zsh" => Self::Shell,
-            "swift" => Self::Swift,
-            "cpp" | "cxx" | "cc" | "hpp" => Self::Cpp,
-            "c" | "h" => Self::C,
-            _ => Self::Unknown,
-        }
-    }
-
-    /// Get single-line comment prefix for this language
-    pub fn comment_prefix(&self) -> &'static str {
-        match self {
-            Self::Rust
-            | Self::Kotlin
-            | Self::TypeScript
-            | Self::JavaScript
-            | Self::Go
-            | Self::Java
-            | Self::Swift
-            | Self::Cpp
-            | Self::C => "//",
-            Self::Python | Self::Shell => "#",
-            Self::Unknown => "//",
-        }
-    }
-}
-
-impl std::fmt::Display for FileLanguage {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Rust => write!(f, "rust"),
-            Self::Kotlin => write!(f, "kotlin"),
-            Self::Python => write!(f, "python"),
-            Self::TypeScript => write!(f, "typescript"),
-            Self::JavaScript => write!(f, "javascript"),
-            Self::Go => write!(f, "go"),
-            Self::Java => write!(f, "java"),
-            Self::Shell => write!(f, "shell"),
-            Self::Swift => write!(f, "swift"),
-            Self::Cpp => write!(f, "cpp"),
-            Self::C => write!(f, "c"),
-            Self::Unknown => write!(f, "unknown"),
-        }
-    }
-}
+/// Detected file language (defined in [`crate::language`], shared by every module)
+pub use crate::language::FileLanguage;
 
 // ============================================================================
 // Configuration
@@ -382,6 +326,7 @@ struct AnalysisPatterns {
     api_key_pattern: Regex,
     password_pattern: Regex,
     token_pattern: Regex,
+    private_key_block: Regex,
     sql_concat: Regex,
 
     // Structure (Rust-focused, but works for similar languages)
@@ -423,21 +368,22 @@ impl AnalysisPatterns {
 
             // Security patterns — intentionally broad to catch false positives rather than miss real ones
             hardcoded_secret: Regex::new(
-                r#"(?i)(secret|private_key|api_secret|auth_token)\s*[:=]\s*["'][^"']{8,}["']"#,
+                r#"(?i)(secret|private_key|api_secret|auth_token)\s*[:=]\s*["'](?P<value>[^"']{8,})["']"#,
             )
             .unwrap(),
             api_key_pattern: Regex::new(
-                r#"(?i)(api[_-]?key|apikey)\s*[:=]\s*["'][A-Za-z0-9_\-]{16,}["']"#,
+                r#"(?i)(api[_-]?key|apikey)\s*[:=]\s*["'](?P<value>[A-Za-z0-9_\-]{16,})["']"#,
             )
             .unwrap(),
             password_pattern: Regex::new(
-                r#"(?i)(password|passwd|pwd)\s*[:=]\s*["'][^"']{4,}["']"#,
+                r#"(?i)(password|passwd|pwd)\s*[:=]\s*["'](?P<value>[^"']{4,})["']"#,
             )
             .unwrap(),
             token_pattern: Regex::new(
-                r#"(?i)\b(ghp_[A-Za-z0-9]{36}|sk-[A-Za-z0-9]{32,}|xox[bpas]-[A-Za-z0-9\-]+)"#,
+                r#"(?i)\b(?P<value>ghp_[A-Za-z0-9]{36}|sk-[A-Za-z0-9]{32,}|xox[bpas]-[A-Za-z0-9\-]+)"#,
             )
             .unwrap(),
+            private_key_block: Regex::new(r"-----BEGIN [A-Z ]*PRIVATE KEY-----").unwrap(),
             sql_concat: Regex::new(
                 r#"(?i)(format!|&format|\.push_str)\s*\(.*(?:SELECT|INSERT|UPDATE|DELETE|DROP|ALTER)\b"#,
             )
@@ -511,9 +457,15 @@ impl StaticAnalyzer {
         // --- Phase 8: Dependency analysis ---
         self.analyze_dependencies(content, &mut signals);
 
+        // --- Phase 9: Async functions that never await ---
+        self.audit_async_functions(content, &mut signals);
+
         // --- Determine recommendation ---
         let (recommendation, skip_reason) = self.determine_recommendation(file_path, &signals);
-        let estimated_llm_value = self.estimate_llm_value(&signals, &recommendation);
+        let mut estimated_llm_value = self.estimate_llm_value(&signals, &recommendation);
+        if signals.async_fn_without_await > 0 && recommendation != AnalysisRecommendation::Skip {
+            estimated_llm_value = (estimated_llm_value + 0.1).min(1.0);
+        }
         let static_issue_count = self.count_static_issues(&signals);
         let summary = self.generate_summary(file_path, &signals, &recommendation, &skip_reason);
 
@@ -684,6 +636,18 @@ impl StaticAnalyzer {
         Ok(self.analyze(&path_str, &content))
     }
 
+    /// Only the content-metrics phase of [`analyze`](Self::analyze): char
+    /// count and code, comment and blank line counts
+    pub fn content_metrics(&self, file_path: &str, content: &str) -> QualitySignals {
+        let mut signals = QualitySignals::default();
+        self.analyze_content_metrics(
+            content,
+            FileLanguage::from_extension(file_path),
+            &mut signals,
+        );
+        signals
+    }
+
     // ========================================================================
     // Phase 1: Content Metrics
     // ========================================================================
@@ -819,63 +783,70 @@ impl StaticAnalyzer {
     fn scan_security_patterns(&self, content: &str, signals: &mut QualitySignals) {
         for (line_num, line) in content.lines().enumerate() {
             let trimmed = line.trim();
+            let indent = line.len() - line.trim_start().len();
 
             // Skip comment-only lines (patterns in comments are usually docs/examples)
             if trimmed.starts_with("//") || trimmed.starts_with('#') || trimmed.starts_with("/*") {
                 continue;
             }
 
-            // Hardcoded secrets
-            if self.patterns.hardcoded_secret.is_match(trimmed) {
-                signals.potential_secrets.push(SecurityFinding {
+            let finding = |pattern: &Regex, name: &str, confidence: FindingConfidence| {
+                pattern.captures(trimmed).map(|caps| SecurityFinding {
                     line: line_num + 1,
-                    pattern: "hardcoded_secret".to_string(),
+                    pattern: name.to_string(),
                     matched_text: Self::redact_match(trimmed),
-                    confidence: FindingConfidence::Medium,
-                });
-            }
+                    confidence,
+                    span: caps
+                        .name("value")
+                        .map(|m| (indent + m.start(), indent + m.end())),
+                })
+            };
+
+            // Hardcoded secrets
+            signals.potential_secrets.extend(finding(
+                &self.patterns.hardcoded_secret,
+                "hardcoded_secret",
+                FindingConfidence::Medium,
+            ));
 
             // API keys
-            if self.patterns.api_key_pattern.is_match(trimmed) {
-                signals.potential_secrets.push(SecurityFinding {
-                    line: line_num + 1,
-                    pattern: "api_key".to_string(),
-                    matched_text: Self::redact_match(trimmed),
-                    confidence: FindingConfidence::High,
-                });
-            }
+            signals.potential_secrets.extend(finding(
+                &self.patterns.api_key_pattern,
+                "api_key",
+                FindingConfidence::High,
+            ));
 
             // Passwords
-            if self.patterns.password_pattern.is_match(trimmed) {
-                // Lower confidence — this pattern has many false positives in test code
-                let confidence = if trimmed.contains("test")
-                    || trimmed.contains("example")
-                    || trimmed.contains("placeholder")
-                    || trimmed.contains("changeme")
-                    || trimmed.contains("xxx")
-                {
-                    FindingConfidence::Low
-                } else {
-                    FindingConfidence::Medium
-                };
-
-                signals.potential_secrets.push(SecurityFinding {
-                    line: line_num + 1,
-                    pattern: "password".to_string(),
-                    matched_text: Self::redact_match(trimmed),
-                    confidence,
-                });
-            }
+            // Lower confidence — this pattern has many false positives in test code
+            let confidence = if trimmed.contains("test")
+                || trimmed.contains("example")
+                || trimmed.contains("placeholder")
+                || trimmed.contains("changeme")
+                || trimmed.contains("xxx")
+            {
+                FindingConfidence::Low
+            } else {
+                FindingConfidence::Medium
+            };
+            signals.potential_secrets.extend(finding(
+                &self.patterns.password_pattern,
+                "password",
+                confidence,
+            ));
 
             // Known token formats (GitHub, OpenAI, Slack)
-            if self.patterns.token_pattern.is_match(trimmed) {
-                signals.potential_secrets.push(SecurityFinding {
-                    line: line_num + 1,
-                    pattern: "known_token_format".to_string(),
-                    matched_text: Self::redact_match(trimmed),
-                    confidence: FindingConfidence::High,
-                });
-            }
+            signals.potential_secrets.extend(finding(
+                &self.patterns.token_pattern,
+                "known_token_format",
+                FindingConfidence::High,
+            ));
+
+            // PEM private keys; the key body follows on the next lines
+            signals.potential_secrets.extend(finding(
+                &self.patterns.private_key_block,
+                "private_key_block",
+                FindingConfidence::High,
+            ));
 
             // SQL injection via string concatenation
             if self.patterns.sql_concat.is_match(trimmed) {
@@ -985,6 +956,50 @@ impl StaticAnalyzer {
         signals.has_ffi_imports = self.patterns.ffi_import.is_match(content);
     }
 
+    // ========================================================================
+    // Phase 9: Async Without Await
+    // ========================================================================
+
+    fn audit_async_functions(&self, content: &str, signals: &mut QualitySignals) {
+        for m in self.patterns.function_def.find_iter(content) {
+            if !m.as_str().split_whitespace().any(|word| word == "async") {
+                continue;
+            }
+            signals.async_fn_count += 1;
+
+            if let Some(body) = Self::function_body(&content[m.end()..]) {
+                if !body.contains(".await") {
+                    signals.async_fn_without_await += 1;
+                }
+            }
+        }
+    }
+
+    /// Body of the function whose signature starts `rest`, found by tracking
+    /// brace depth from the first `{`. Returns `None` for bodiless
+    /// declarations (a `;` before any `{`) or unbalanced braces.
+    fn function_body(rest: &str) -> Option<&str> {
+        let open = rest.find(['{', ';'])?;
+        if rest.as_bytes()[open] == b';' {
+            return None;
+        }
+
+        let mut depth = 0usize;
+        for (i, ch) in rest[open..].char_indices() {
+            match ch {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(&rest[open..open + i + 1]);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
     // ========================================================================
     // Recommendation Engine
     // ========================================================================
@@ -1218,6 +1233,13 @@ impl StaticAnalyzer {
             ));
         }
 
+        if signals.async_fn_without_await > 0 {
+            parts.push(format!(
+                "  Async: {} of {} async fn never .await",
+                signals.async_fn_without_await, signals.async_fn_count
+            ));
+        }
+
         parts.push(format!(
             "  Complexity: ~{} functions, max nesting={}, complexity score={}",
             signals.function_count, signals.max_nesting_depth, signals.estimated_complexity
@@ -1884,6 +1906,73 @@ pub fn helper_c(x: i32) -> i32 { x + 1 }
         assert!(result.signals.max_nesting_depth >= 3);
     }
 
+    const ASYNC_AWAITS: &str = r#"
+pub async fn fetch(client: &Client, url: &str) -> Result<String, Error> {
+    let response = client.get(url).send().await?;
+    let body = response.text().await?;
+    if body.is_empty() {
+        return Err(Error::Empty);
+    }
+    Ok(body)
+}
+
+pub fn helper(x: i32) -> i32 {
+    x + 1
+}
+"#;
+
+    const ASYNC_NO_AWAIT: &str = r#"
+pub async fn fetch(client: &Client, url: &str) -> Result<String, Error> {
+    let response = client.get_blocking(url)?;
+    let body = response.text()?;
+    if body.is_empty() {
+        return Err(Error::Empty);
+    }
+    Ok(body)
+}
+
+pub fn helper(x: i32) -> i32 {
+    x + 1
+}
+"#;
+
+    #[test]
+    fn test_async_fn_with_await() {
+        let result = analyzer().analyze("client.rs", ASYNC_AWAITS);
+        assert_eq!(result.signals.async_fn_count, 1);
+        assert_eq!(result.signals.async_fn_without_await, 0);
+        assert!(!result.summary.contains("never .await"));
+    }
+
+    #[test]
+    fn test_async_fn_without_await() {
+        let a = analyzer();
+        let awaiting = a.analyze("client.rs", ASYNC_AWAITS);
+        let result = a.analyze("client.rs", ASYNC_NO_AWAIT);
+
+        assert_eq!(result.signals.async_fn_count, 1);
+        assert_eq!(result.signals.async_fn_without_await, 1);
+        assert!(result.summary.contains("1 of 1 async fn never .await"));
+        assert_eq!(result.recommendation, awaiting.recommendation);
+        assert!((result.estimated_llm_value - (awaiting.estimated_llm_value + 0.1)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_async_await_scoped_to_function_body() {
+        let content = r#"
+pub async fn idle() {
+    let x = 1;
+}
+
+pub async fn busy() {
+    tokio::task::yield_now().await;
+}
+"#;
+        let result = analyzer().analyze("tasks.rs", content);
+        assert_eq!(result.signals.async_fn_count, 2);
+        assert_eq!(result.signals.async_fn_without_await, 1);
+    }
+
     #[test]
     fn test_content_hash() {
         let hash1 = content_hash("fn main() {}");
@@ -9,6 +9,7 @@ use tokio::task::JoinSet;
 use tokio::time;
 use tracing::{error, info, warn};
 
+use crate::health::{Shutdown, WorkerHealth};
 use crate::repo_sync::RepoSyncService;
 
 // ---------------------------------------------------------------------------
@@ -45,16 +46,36 @@ impl Default for SyncSchedulerConfig {
 pub struct SyncScheduler {
     config: SyncSchedulerConfig,
     service: Arc<RwLock<RepoSyncService>>,
+    shutdown: Shutdown,
+    health: WorkerHealth,
 }
 
 impl SyncScheduler {
     pub fn new(config: SyncSchedulerConfig, service: Arc<RwLock<RepoSyncService>>) -> Self {
-        Self { config, service }
+        Self {
+            config,
+            service,
+            shutdown: Shutdown::new(),
+            health: WorkerHealth::new(),
+        }
+    }
+
+    /// Exit the loop (after any running pass completes) once `shutdown` fires
+    pub fn with_shutdown(mut self, shutdown: Shutdown) -> Self {
+        self.shutdown = shutdown;
+        self
+    }
+
+    /// Report liveness and in-flight passes through `health`
+    pub fn with_health(mut self, health: WorkerHealth) -> Self {
+        self.health = health;
+        self
     }
 
     /// Spawn the background sync loop. Call once at startup.
     pub fn start(self) -> tokio::task::JoinHandle<()> {
         tokio::spawn(async move {
+            let _running = self.health.start_run();
             info!(
                 interval_secs = self.config.interval.as_secs(),
                 concurrency = self.config.concurrency,
@@ -65,9 +86,14 @@ impl SyncScheduler {
             // disk before the server has finished starting up.
             ticker.tick().await;
             loop {
-                ticker.tick().await;
+                tokio::select! {
+                    _ = ticker.tick() => {}
+                    _ = self.shutdown.wait() => break,
+                }
+                let _job = self.health.start_job();
                 self.run_sync_pass().await;
             }
+            info!("SyncScheduler stopped");
         })
     }
 
@@ -3,9 +3,10 @@
 //! Provides a robust schema for categorizing code, tracking technical debt,
 //! and building a comprehensive directory tree of codebase status.
 
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Schema for audit tags with strict validation
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -67,6 +68,22 @@ impl TagCategory {
         ]
     }
 
+    /// Canonical tag name (matches the serde kebab-case form)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Organization => "organization",
+            Self::Security => "security",
+            Self::Performance => "performance",
+            Self::Risk => "risk",
+            Self::TechnicalDebt => "technical-debt",
+            Self::Documentation => "documentation",
+            Self::Testing => "testing",
+            Self::Legacy => "legacy",
+            Self::Experimental => "experimental",
+            Self::Configuration => "configuration",
+        }
+    }
+
     /// Get category from string
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
@@ -458,6 +475,88 @@ impl TagValidation {
     }
 }
 
+/// A changed file carrying an `@audit-freeze` tag that is still in force
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FrozenViolation {
+    /// The changed file, as passed to [`TagValidation::check_frozen`]
+    pub file: PathBuf,
+    /// 1-based line of the freeze tag
+    pub line: usize,
+    /// Last day the freeze applies (`@audit-freeze until=YYYY-MM-DD`)
+    pub until: Option<NaiveDate>,
+}
+
+impl TagValidation {
+    /// Changed files that carry an `@audit-freeze` tag in force today
+    ///
+    /// `changed_files` are relative to `repo_path` (or absolute). Files that
+    /// can't be read, e.g. deleted ones, have no tag to enforce, and files the
+    /// tag scanner ignores (tests, tag definitions) are never frozen.
+    pub fn check_frozen(repo_path: &Path, changed_files: &[PathBuf]) -> Vec<FrozenViolation> {
+        Self::check_frozen_at(repo_path, changed_files, chrono::Local::now().date_naive())
+    }
+
+    /// [`check_frozen`](Self::check_frozen) as of `today`. A freeze with
+    /// `until=` stops applying the day after that date.
+    pub fn check_frozen_at(
+        repo_path: &Path,
+        changed_files: &[PathBuf],
+        today: NaiveDate,
+    ) -> Vec<FrozenViolation> {
+        changed_files
+            .iter()
+            .filter(|file| crate::tags::should_scan_for_tags(file))
+            .filter_map(|file| {
+                let content = std::fs::read_to_string(repo_path.join(file)).ok()?;
+                let (line, until) = find_freeze(&content, today)?;
+                Some(FrozenViolation {
+                    file: file.clone(),
+                    line,
+                    until,
+                })
+            })
+            .collect()
+    }
+}
+
+/// The 1-based line and expiry of the first `@audit-freeze` tag in `content`
+/// that is still in force on `today`
+pub(crate) fn find_freeze(content: &str, today: NaiveDate) -> Option<(usize, Option<NaiveDate>)> {
+    content.lines().enumerate().find_map(|(i, line)| {
+        let until = parse_freeze(line)?;
+        match until {
+            Some(date) if date < today => None,
+            _ => Some((i + 1, until)),
+        }
+    })
+}
+
+/// Comment openers an `@audit-freeze` tag may follow
+const FREEZE_COMMENT_MARKERS: &[&str] = &["//", "///", "//!", "#", "/*", "/**", "*", "--", "<!--"];
+
+/// The expiry of an `@audit-freeze` tag on `line`: `None` when the line has
+/// no tag, `Some(None)` for a freeze without a (valid) `until=` date
+///
+/// The tag must open a comment (`// @audit-freeze`), so prose and string
+/// literals that merely mention it don't freeze a file.
+fn parse_freeze(line: &str) -> Option<Option<NaiveDate>> {
+    let (before, rest) = line.split_once("@audit-freeze")?;
+    if !FREEZE_COMMENT_MARKERS.contains(&before.trim()) {
+        return None;
+    }
+    // `@audit-freezer` or similar is a different word
+    if rest.starts_with(|c: char| c.is_alphanumeric() || c == '-' || c == '_') {
+        return None;
+    }
+    let until = rest
+        .split_whitespace()
+        .find_map(|word| word.strip_prefix("until="))
+        .and_then(|date| {
+            NaiveDate::parse_from_str(date.trim_end_matches(['*', '/', ',', ';']), "%Y-%m-%d").ok()
+        });
+    Some(until)
+}
+
 /// Validate a tag value against the schema
 pub fn validate_tag(tag_value: &str) -> TagValidation {
     let parts: Vec<&str> = tag_value.split(',').map(|s| s.trim()).collect();
@@ -540,4 +639,72 @@ mod tests {
         assert_eq!(summary.total(), 20);
         assert!(summary.has_critical_or_high());
     }
+
+    #[test]
+    fn test_check_frozen_reports_changed_frozen_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("src")).unwrap();
+        std::fs::write(
+            dir.path().join("src/ledger.rs"),
+            "//! Ledger\n// @audit-freeze\npub fn post() {}\n",
+        )
+        .unwrap();
+        // Mentions the tag without carrying it
+        std::fs::write(
+            dir.path().join("src/free.rs"),
+            "/// Honours `@audit-freeze` tags\nconst TAG: &str = \"// @audit-freeze\";\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("src/later.rs"),
+            "// @audit-freeze until=2026-06-01 */\n",
+        )
+        .unwrap();
+
+        let changed = vec![
+            PathBuf::from("src/ledger.rs"),
+            PathBuf::from("src/free.rs"),
+            PathBuf::from("src/later.rs"),
+            PathBuf::from("src/deleted.rs"),
+        ];
+        let today = NaiveDate::from_ymd_opt(2026, 6, 1).unwrap();
+        let violations = TagValidation::check_frozen_at(dir.path(), &changed, today);
+
+        assert_eq!(
+            violations,
+            vec![
+                FrozenViolation {
+                    file: PathBuf::from("src/ledger.rs"),
+                    line: 2,
+                    until: None,
+                },
+                // Still in force on the expiry date itself
+                FrozenViolation {
+                    file: PathBuf::from("src/later.rs"),
+                    line: 1,
+                    until: NaiveDate::from_ymd_opt(2026, 6, 1),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_frozen_ignores_expired_freeze() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("migrate.py"),
+            "# @audit-freeze until=2026-06-01\ndef run(): pass\n",
+        )
+        .unwrap();
+
+        let changed = vec![PathBuf::from("migrate.py")];
+        let after = NaiveDate::from_ymd_opt(2026, 6, 2).unwrap();
+        assert!(TagValidation::check_frozen_at(dir.path(), &changed, after).is_empty());
+
+        let before = NaiveDate::from_ymd_opt(2026, 5, 31).unwrap();
+        assert_eq!(
+            TagValidation::check_frozen_at(dir.path(), &changed, before).len(),
+            1
+        );
+    }
 }
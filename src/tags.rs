@@ -50,7 +50,7 @@ impl TagScanner {
     /// Scan a file for audit tags
     pub fn scan_file(&self, path: &Path) -> Result<Vec<AuditTag>> {
         // Skip files that define the tag system itself
-        if !self.should_scan_for_tags(path) {
+        if !should_scan_for_tags(path) {
             return Ok(Vec::new());
         }
 
@@ -157,25 +157,6 @@ impl TagScanner {
             || path_str.contains("dist/")
     }
 
-    /// Check if a file should be scanned for tags (exclude tag definition files)
-    fn should_scan_for_tags(&self, path: &Path) -> bool {
-        let path_str = path.to_string_lossy();
-
-        // Don't scan files that define the tag system
-        if path_str.contains("tags.rs")
-            || path_str.contains("types.rs")
-            || path_str.contains("/test")
-            || path_str.contains("_test.rs")
-            || path_str.ends_with("_test.py")
-            || path_str.contains("test_")
-            || path_str.contains("/tests/")
-        {
-            return false;
-        }
-
-        true
-    }
-
     /// Group tags by type
     pub fn group_by_type<'a>(
         &self,
@@ -221,6 +202,26 @@ impl TagScanner {
     }
 }
 
+/// Check if a file should be scanned for tags (exclude tag definition files
+/// and tests, whose fixtures quote tags)
+pub(crate) fn should_scan_for_tags(path: &Path) -> bool {
+    let path_str = path.to_string_lossy();
+
+    // Don't scan files that define the tag system
+    if path_str.contains("tags.rs")
+        || path_str.contains("types.rs")
+        || path_str.contains("/test")
+        || path_str.contains("_test.rs")
+        || path_str.ends_with("_test.py")
+        || path_str.contains("test_")
+        || path_str.contains("/tests/")
+    {
+        return false;
+    }
+
+    true
+}
+
 impl Default for TagScanner {
     fn default() -> Self {
         Self::new().expect("Failed to create default TagScanner")
@@ -3,14 +3,15 @@
 //! Groups related tasks for efficient batch processing and IDE handoff.
 //! Tasks can be grouped by: file, category, repository, or similarity.
 
+use crate::embeddings::{Embedding, EmbeddingGenerator};
 use crate::task::{Task, TaskGroup};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 // ============================================================================
 // Grouping Strategies
 // ============================================================================
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum GroupingStrategy {
     /// Group by source file (default for code tasks)
     ByFile,
@@ -20,8 +21,48 @@ pub enum GroupingStrategy {
     ByRepo,
     /// Smart grouping: file first, then category
     Smart,
+    /// Group tasks whose content is similar under the given metric and threshold
+    BySimilarity(SimilarityConfig),
 }
 
+/// How [`tasks_are_similar_with`] compares task content
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SimilarityMetric {
+    /// Shared words (longer than 3 chars) over the smaller word set
+    #[default]
+    WordOverlap,
+    /// Token Jaccard: shared words over all words
+    Jaccard,
+    /// Jaccard over character trigrams; tolerant of typos and word forms
+    Trigram,
+    /// Cosine similarity of content embeddings. Pairs without embeddings
+    /// fall back to the default word-overlap check.
+    EmbeddingCosine,
+}
+
+/// Similarity metric plus the score (0.0-1.0) above which tasks are similar
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimilarityConfig {
+    pub metric: SimilarityMetric,
+    pub threshold: f32,
+}
+
+impl SimilarityConfig {
+    pub fn new(metric: SimilarityMetric, threshold: f32) -> Self {
+        Self { metric, threshold }
+    }
+}
+
+impl Default for SimilarityConfig {
+    /// The original behaviour: more than 30% word overlap
+    fn default() -> Self {
+        Self::new(SimilarityMetric::WordOverlap, 0.3)
+    }
+}
+
+/// Content embeddings keyed by task id, for [`SimilarityMetric::EmbeddingCosine`]
+pub type TaskEmbeddings = HashMap<String, Embedding>;
+
 // ============================================================================
 // Core Grouping Functions
 // ============================================================================
@@ -33,6 +74,7 @@ pub fn group_tasks(tasks: Vec<Task>, strategy: GroupingStrategy) -> Vec<TaskGrou
         GroupingStrategy::ByCategory => group_by_category(tasks),
         GroupingStrategy::ByRepo => group_by_repo(tasks),
         GroupingStrategy::Smart => smart_grouping(tasks),
+        GroupingStrategy::BySimilarity(config) => group_by_similarity(tasks, &config, None),
     }
 }
 
@@ -100,6 +142,41 @@ pub fn group_by_repo(tasks: Vec<Task>) -> Vec<TaskGroup> {
     result
 }
 
+/// Group tasks that are similar (see [`tasks_are_similar_with`]) to the
+/// first task of an existing group; the rest start new groups
+pub fn group_by_similarity(
+    tasks: Vec<Task>,
+    config: &SimilarityConfig,
+    embeddings: Option<&TaskEmbeddings>,
+) -> Vec<TaskGroup> {
+    let mut clusters: Vec<Vec<Task>> = Vec::new();
+
+    for task in tasks {
+        match clusters
+            .iter_mut()
+            .find(|c| tasks_are_similar_with(&c[0], &task, config, embeddings))
+        {
+            Some(cluster) => cluster.push(task),
+            None => clusters.push(vec![task]),
+        }
+    }
+
+    let mut result: Vec<TaskGroup> = clusters
+        .into_iter()
+        .map(|tasks| {
+            let key = tasks[0]
+                .source_file
+                .clone()
+                .or_else(|| tasks[0].category.clone())
+                .unwrap_or_else(|| tasks[0].id.clone());
+            TaskGroup::new(key, tasks)
+        })
+        .collect();
+
+    result.sort_by(|a, b| b.combined_priority.cmp(&a.combined_priority));
+    result
+}
+
 /// Smart grouping: prioritizes file-based groups, then falls back to category
 /// Also identifies cross-cutting concerns that span multiple files
 pub fn smart_grouping(tasks: Vec<Task>) -> Vec<TaskGroup> {
@@ -178,40 +255,104 @@ pub fn get_top_groups(groups: &[TaskGroup], n: usize) -> Vec<&TaskGroup> {
 // Similarity Detection (for smarter grouping)
 // ============================================================================
 
-/// Check if two tasks are likely related based on content similarity
+/// Check if two tasks are likely related based on content similarity,
+/// using the default [`SimilarityConfig`]
 pub fn tasks_are_similar(task1: &Task, task2: &Task) -> bool {
+    tasks_are_similar_with(task1, task2, &SimilarityConfig::default(), None)
+}
+
+/// Check if two tasks are related: same file, or same category and repo with
+/// content similarity above `config.threshold`
+pub fn tasks_are_similar_with(
+    task1: &Task,
+    task2: &Task,
+    config: &SimilarityConfig,
+    embeddings: Option<&TaskEmbeddings>,
+) -> bool {
     // Same file = definitely related
     if task1.source_file.is_some() && task1.source_file == task2.source_file {
         return true;
     }
 
-    // Same category + same repo = likely related
-    if task1.category == task2.category && task1.source_repo == task2.source_repo {
-        // Check for keyword overlap
-        let content1_lower = task1.content.to_lowercase();
-        let words1: std::collections::HashSet<String> = content1_lower
-            .split_whitespace()
-            .filter(|w| w.len() > 3)
-            .map(|s| s.to_string())
-            .collect();
-
-        let content2_lower = task2.content.to_lowercase();
-        let words2: std::collections::HashSet<String> = content2_lower
-            .split_whitespace()
-            .filter(|w| w.len() > 3)
-            .map(|s| s.to_string())
-            .collect();
-
-        let overlap = words1.intersection(&words2).count();
-        let min_size = words1.len().min(words2.len());
-
-        // More than 30% word overlap = similar
-        if min_size > 0 && overlap as f32 / min_size as f32 > 0.3 {
-            return true;
-        }
+    // Same category + same repo = likely related if the content agrees
+    if task1.category != task2.category || task1.source_repo != task2.source_repo {
+        return false;
+    }
+
+    match content_similarity(task1, task2, config.metric, embeddings) {
+        Some(score) => score > config.threshold,
+        None => tasks_are_similar_with(task1, task2, &SimilarityConfig::default(), None),
     }
+}
+
+/// Content similarity in 0.0-1.0, or `None` when the metric needs
+/// embeddings that are missing for either task
+pub fn content_similarity(
+    task1: &Task,
+    task2: &Task,
+    metric: SimilarityMetric,
+    embeddings: Option<&TaskEmbeddings>,
+) -> Option<f32> {
+    let score = match metric {
+        SimilarityMetric::WordOverlap => {
+            let (words1, words2) = (words(&task1.content), words(&task2.content));
+            let min_size = words1.len().min(words2.len());
+            if min_size == 0 {
+                0.0
+            } else {
+                words1.intersection(&words2).count() as f32 / min_size as f32
+            }
+        }
+        SimilarityMetric::Jaccard => jaccard(&words(&task1.content), &words(&task2.content)),
+        SimilarityMetric::Trigram => jaccard(&trigrams(&task1.content), &trigrams(&task2.content)),
+        SimilarityMetric::EmbeddingCosine => {
+            let embeddings = embeddings?;
+            let (e1, e2) = (embeddings.get(&task1.id)?, embeddings.get(&task2.id)?);
+            e1.cosine_similarity(e2).ok()?
+        }
+    };
+    Some(score)
+}
 
-    false
+/// Embed every task's content for [`SimilarityMetric::EmbeddingCosine`]
+pub async fn embed_tasks(
+    generator: &EmbeddingGenerator,
+    tasks: &[Task],
+) -> anyhow::Result<TaskEmbeddings> {
+    let texts: Vec<&str> = tasks.iter().map(|t| t.content.as_str()).collect();
+    let embeddings = generator.embed_batch(&texts).await?;
+    Ok(tasks.iter().map(|t| t.id.clone()).zip(embeddings).collect())
+}
+
+/// Lowercased words longer than 3 chars
+fn words(content: &str) -> HashSet<String> {
+    content
+        .to_lowercase()
+        .split_whitespace()
+        .filter(|w| w.len() > 3)
+        .map(|s| s.to_string())
+        .collect()
+}
+
+/// Character trigrams of the lowercased, whitespace-normalized content
+fn trigrams(content: &str) -> HashSet<String> {
+    let normalized: Vec<char> = content
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .collect();
+    normalized.windows(3).map(|w| w.iter().collect()).collect()
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    let union = a.union(b).count();
+    if union == 0 {
+        0.0
+    } else {
+        a.intersection(b).count() as f32 / union as f32
+    }
 }
 
 /// Find all tasks similar to a given task
@@ -304,6 +445,73 @@ mod tests {
         assert_eq!(groups[0].combined_priority, 6);
     }
 
+    #[test]
+    fn test_similarity_threshold_controls_merging() {
+        let tasks = vec![
+            make_task(
+                "Handle timeout errors in the sync client",
+                None,
+                Some("bug"),
+                5,
+            ),
+            make_task(
+                "Handle parse errors in the config loader",
+                None,
+                Some("bug"),
+                4,
+            ),
+        ];
+
+        // Default behaviour: "handle" and "errors" overlap enough to merge
+        assert!(tasks_are_similar(&tasks[0], &tasks[1]));
+
+        let loose = SimilarityConfig::new(SimilarityMetric::Jaccard, 0.2);
+        let strict = SimilarityConfig::new(SimilarityMetric::Jaccard, 0.6);
+        assert!(tasks_are_similar_with(&tasks[0], &tasks[1], &loose, None));
+        assert!(!tasks_are_similar_with(&tasks[0], &tasks[1], &strict, None));
+
+        let merged = group_tasks(tasks.clone(), GroupingStrategy::BySimilarity(loose));
+        assert_eq!(merged.len(), 1);
+        let separate = group_tasks(tasks, GroupingStrategy::BySimilarity(strict));
+        assert_eq!(separate.len(), 2);
+    }
+
+    #[test]
+    fn test_embedding_metric_uses_cosine_and_falls_back_without_embeddings() {
+        let tasks = [
+            make_task("Connection pool exhausted under load", None, Some("bug"), 5),
+            make_task(
+                "Database runs out of connections at peak",
+                None,
+                Some("bug"),
+                4,
+            ),
+        ];
+        let config = SimilarityConfig::new(SimilarityMetric::EmbeddingCosine, 0.9);
+
+        // Without embeddings the word-overlap default applies: no shared words
+        assert!(!tasks_are_similar_with(&tasks[0], &tasks[1], &config, None));
+
+        let embeddings: TaskEmbeddings = [
+            (
+                tasks[0].id.clone(),
+                Embedding::new(vec![0.9, 0.1, 0.0], "test".into(), 3),
+            ),
+            (
+                tasks[1].id.clone(),
+                Embedding::new(vec![0.8, 0.2, 0.0], "test".into(), 3),
+            ),
+        ]
+        .into_iter()
+        .collect();
+        assert!(tasks_are_similar_with(
+            &tasks[0],
+            &tasks[1],
+            &config,
+            Some(&embeddings)
+        ));
+    }
+
     #[test]
     fn test_filter_by_priority() {
         let tasks = vec![
@@ -25,7 +25,8 @@ pub mod models;
 // Re-export commonly used types
 pub use grouping::{
     filter_by_priority, filter_ready_groups, get_next_group, get_top_groups, group_tasks,
-    tasks_are_similar, GroupingStrategy,
+    tasks_are_similar, tasks_are_similar_with, GroupingStrategy, SimilarityConfig,
+    SimilarityMetric,
 };
 
 pub use models::{
@@ -20,10 +20,58 @@ pub enum TaskStatus {
     Processing, // LLM currently working on it
     Review,     // Needs human review before IDE handoff
     Ready,      // Ready to send to IDE agent
+    InProgress, // Being worked on
     Done,       // Completed
     Failed,     // Processing failed, may retry
 }
 
+impl TaskStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskStatus::Pending => "pending",
+            TaskStatus::Processing => "processing",
+            TaskStatus::Review => "review",
+            TaskStatus::Ready => "ready",
+            TaskStatus::InProgress => "in_progress",
+            TaskStatus::Done => "done",
+            TaskStatus::Failed => "failed",
+        }
+    }
+
+    pub fn parse(status: &str) -> Option<Self> {
+        match status {
+            "pending" => Some(TaskStatus::Pending),
+            "processing" => Some(TaskStatus::Processing),
+            "review" => Some(TaskStatus::Review),
+            "ready" => Some(TaskStatus::Ready),
+            "in_progress" => Some(TaskStatus::InProgress),
+            "done" => Some(TaskStatus::Done),
+            "failed" => Some(TaskStatus::Failed),
+            _ => None,
+        }
+    }
+
+    /// Statuses a task may move to from this one
+    pub fn allowed_transitions(&self) -> &'static [TaskStatus] {
+        use TaskStatus::*;
+        match self {
+            Pending => &[Processing, Review, Ready, InProgress, Done, Failed],
+            Processing => &[Pending, Review, Ready, Failed],
+            Review => &[Pending, Ready, InProgress, Done],
+            Ready => &[Pending, Review, InProgress, Done],
+            InProgress => &[Pending, Ready, Done, Failed],
+            // Retry
+            Failed => &[Pending, Processing],
+            // Reopen
+            Done => &[Pending],
+        }
+    }
+
+    pub fn can_transition_to(&self, next: TaskStatus) -> bool {
+        self.allowed_transitions().contains(&next)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, Default)]
 #[sqlx(type_name = "TEXT", rename_all = "snake_case")]
 pub enum TaskSource {
@@ -154,15 +202,7 @@ impl Task {
     }
 
     pub fn status_enum(&self) -> TaskStatus {
-        match self.status.as_str() {
-            "pending" => TaskStatus::Pending,
-            "processing" => TaskStatus::Processing,
-            "review" => TaskStatus::Review,
-            "ready" => TaskStatus::Ready,
-            "done" => TaskStatus::Done,
-            "failed" => TaskStatus::Failed,
-            _ => TaskStatus::Pending,
-        }
+        TaskStatus::parse(&self.status).unwrap_or_default()
     }
 }
 
@@ -364,35 +404,9 @@ pub async fn get_tasks_by_status(
     Ok(tasks)
 }
 
+/// Move a task to `status`; see [`crate::db::update_task_status`]
 pub async fn update_task_status(pool: &PgPool, id: &str, status: TaskStatus) -> anyhow::Result<()> {
-    let status_str = format!("{:?}", status).to_lowercase();
-    let now = chrono::Utc::now().timestamp();
-
-    let mut query = String::from("UPDATE tasks SET status = ?, updated_at = ?");
-
-    if matches!(status, TaskStatus::Done) {
-        query.push_str(", completed_at = ?");
-    }
-
-    query.push_str(" WHERE id = ?");
-
-    if matches!(status, TaskStatus::Done) {
-        sqlx::query(&query)
-            .bind(&status_str)
-            .bind(now)
-            .bind(now)
-            .bind(id)
-            .execute(pool)
-            .await?;
-    } else {
-        sqlx::query(&query)
-            .bind(&status_str)
-            .bind(now)
-            .bind(id)
-            .execute(pool)
-            .await?;
-    }
-
+    crate::db::update_task_status(pool, id, status.as_str()).await?;
     Ok(())
 }
 
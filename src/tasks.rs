@@ -1,10 +1,12 @@
 //! Task generator for converting audit findings into actionable tasks
 
+use crate::dto::v1::TaskDto;
 use crate::error::{AuditError, Result};
+use crate::task::{tasks_are_similar, Task as QueueTask, TaskSource, TaskStatus};
 use crate::types::{
     AuditTag, AuditTagType, Category, FileAnalysis, Issue, IssueSeverity, Task, TaskPriority,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Task generator
 pub struct TaskGenerator {
@@ -12,6 +14,10 @@ pub struct TaskGenerator {
     counter: usize,
     /// Generated tasks
     tasks: Vec<Task>,
+    /// Open queue tasks that new tasks are deduplicated against
+    existing: Vec<QueueTask>,
+    /// Tasks skipped as duplicates of an existing task
+    skipped: usize,
 }
 
 impl TaskGenerator {
@@ -20,9 +26,83 @@ impl TaskGenerator {
         Self {
             counter: 0,
             tasks: Vec::new(),
+            existing: Vec::new(),
+            skipped: 0,
         }
     }
 
+    /// Skip generated tasks that duplicate one of the open `existing` tasks
+    ///
+    /// Applies to tasks already generated and to every task generated
+    /// afterwards. A task is a duplicate when its normalized title matches,
+    /// or when its affected files overlap an existing task's (a strict subset
+    /// counts) and [`tasks_are_similar`] agrees on the content. Done tasks are
+    /// ignored so a regression gets a fresh task.
+    pub fn dedup_against(&mut self, existing: &[QueueTask]) -> &mut Self {
+        self.existing = existing
+            .iter()
+            .filter(|t| t.status_enum() != TaskStatus::Done)
+            .cloned()
+            .collect();
+
+        let before = self.tasks.len();
+        let tasks = std::mem::take(&mut self.tasks);
+        self.tasks = tasks
+            .into_iter()
+            .filter(|t| self.find_duplicate(t).is_none())
+            .collect();
+        self.skipped += before - self.tasks.len();
+        self
+    }
+
+    /// Number of tasks skipped as duplicates since the last [`clear`](Self::clear)
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    /// The existing task that `task` duplicates, if any
+    fn find_duplicate(&self, task: &Task) -> Option<&QueueTask> {
+        let title = normalize_title(&task.title);
+        let files = affected_files(
+            Some(&task.file.to_string_lossy()),
+            Some(task.description.as_str()),
+        );
+
+        self.existing.iter().find(|existing| {
+            if normalize_title(&existing.content) == title {
+                return true;
+            }
+
+            let existing_files =
+                affected_files(existing.source_file.as_deref(), existing.context.as_deref());
+            if !files_overlap(&files, &existing_files) {
+                return false;
+            }
+
+            // The generator has no notion of repo or queue category, so borrow
+            // them from the task being compared and let the content decide
+            let mut candidate = QueueTask::new(task.title.clone(), TaskSource::Scan);
+            candidate.category = existing.category.clone();
+            candidate.source_repo = existing.source_repo.clone();
+            tasks_are_similar(&candidate, existing)
+        })
+    }
+
+    /// Queue a generated task unless it duplicates an existing one
+    fn push(&mut self, task: Task) {
+        if let Some(existing) = self.find_duplicate(&task) {
+            tracing::debug!(
+                "Skipping task '{}': duplicates open task {}",
+                task.title,
+                existing.id
+            );
+            self.skipped += 1;
+            return;
+        }
+        self.tasks.push(task);
+        self.counter += 1;
+    }
+
     /// Generate tasks from audit tags
     pub fn generate_from_tags(&mut self, tags: &[AuditTag]) -> Result<Vec<Task>> {
         for tag in tags {
@@ -107,8 +187,7 @@ impl TaskGenerator {
         .with_tag("todo")
         .with_tag("from-tag");
 
-        self.tasks.push(task);
-        self.counter += 1;
+        self.push(task);
         Ok(())
     }
 
@@ -126,8 +205,7 @@ impl TaskGenerator {
         .with_tag("implementation")
         .with_tag("from-tag");
 
-        self.tasks.push(task);
-        self.counter += 1;
+        self.push(task);
         Ok(())
     }
 
@@ -148,8 +226,7 @@ impl TaskGenerator {
         .with_tag("security")
         .with_tag("from-tag");
 
-        self.tasks.push(task);
-        self.counter += 1;
+        self.push(task);
         Ok(())
     }
 
@@ -166,8 +243,7 @@ impl TaskGenerator {
         .with_tag("review")
         .with_tag("from-tag");
 
-        self.tasks.push(task);
-        self.counter += 1;
+        self.push(task);
         Ok(())
     }
 
@@ -196,8 +272,7 @@ impl TaskGenerator {
             task.description = format!("{}\n\nSuggestion: {}", task.description, suggestion);
         }
 
-        self.tasks.push(task);
-        self.counter += 1;
+        self.push(task);
         Ok(())
     }
 
@@ -217,8 +292,7 @@ impl TaskGenerator {
         .with_tag("documentation")
         .with_tag("from-analysis");
 
-        self.tasks.push(task);
-        self.counter += 1;
+        self.push(task);
         Ok(())
     }
 
@@ -258,8 +332,7 @@ impl TaskGenerator {
         .with_tag("critical")
         .with_tag("audit-freeze");
 
-        self.tasks.push(task);
-        self.counter += 1;
+        self.push(task);
         Ok(())
     }
 
@@ -305,9 +378,10 @@ impl TaskGenerator {
         stats
     }
 
-    /// Export tasks to JSON
+    /// Export tasks to JSON, in the stable [`TaskDto`] format
     pub fn to_json(&self) -> Result<String> {
-        serde_json::to_string_pretty(&self.tasks).map_err(AuditError::Json)
+        let tasks: Vec<TaskDto> = self.tasks.iter().map(Into::into).collect();
+        serde_json::to_string_pretty(&tasks).map_err(AuditError::Json)
     }
 
     /// Export tasks to CSV
@@ -336,9 +410,50 @@ impl TaskGenerator {
     pub fn clear(&mut self) {
         self.tasks.clear();
         self.counter = 0;
+        self.skipped = 0;
     }
 }
 
+/// Lowercased title with punctuation dropped and whitespace collapsed
+fn normalize_title(title: &str) -> String {
+    title
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A task's file plus any listed on a `**Files:**` line of its description,
+/// the format project-review tasks are written in
+fn affected_files(file: Option<&str>, description: Option<&str>) -> HashSet<String> {
+    let listed = description
+        .into_iter()
+        .flat_map(str::lines)
+        .filter_map(|line| line.trim().strip_prefix("**Files:**"))
+        .flat_map(|files| files.split(','));
+
+    file.into_iter()
+        .chain(listed)
+        .map(|f| f.trim().trim_start_matches("./").to_string())
+        .filter(|f| !f.is_empty() && f != "N/A")
+        .collect()
+}
+
+/// Whether two file sets describe the same area of code: `files` is
+/// contained in `existing` (a narrower re-report of the same issue), or the
+/// sets share at least half their files
+fn files_overlap(files: &HashSet<String>, existing: &HashSet<String>) -> bool {
+    if files.is_empty() || existing.is_empty() {
+        return false;
+    }
+    if files.is_subset(existing) {
+        return true;
+    }
+    let shared = files.intersection(existing).count();
+    shared * 2 >= files.union(existing).count()
+}
+
 impl Default for TaskGenerator {
     fn default() -> Self {
         Self::new()
@@ -411,6 +526,106 @@ mod tests {
         assert!(tasks[0].tags.contains(&"security".to_string()));
     }
 
+    fn todo_tag(file: &str, value: &str) -> AuditTag {
+        AuditTag {
+            tag_type: AuditTagType::Todo,
+            file: PathBuf::from(file),
+            line: 10,
+            value: value.to_string(),
+            context: None,
+        }
+    }
+
+    #[test]
+    fn test_dedup_against_exact_title() {
+        let existing = vec![
+            QueueTask::new("todo:  Implement error handling!", TaskSource::Todo)
+                .with_source_file("audit", "other.rs", None),
+        ];
+
+        let mut generator = TaskGenerator::new();
+        generator.dedup_against(&existing);
+        let tasks = generator
+            .generate_from_tags(&[
+                todo_tag("test.rs", "Implement error handling"),
+                todo_tag("test.rs", "Add retry backoff"),
+            ])
+            .unwrap();
+
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].title, "TODO: Add retry backoff");
+        assert_eq!(generator.skipped(), 1);
+
+        // Completed tasks don't suppress new ones
+        let mut done = existing[0].clone();
+        done.status = TaskStatus::Done.as_str().to_string();
+        let mut generator = TaskGenerator::new();
+        generator.dedup_against(&[done]);
+        let tasks = generator
+            .generate_from_tags(&[todo_tag("test.rs", "Implement error handling")])
+            .unwrap();
+        assert_eq!(tasks.len(), 1);
+    }
+
+    #[test]
+    fn test_dedup_against_subset_files() {
+        let existing = vec![QueueTask::new(
+            "Harden the database connection pool handling",
+            TaskSource::Scan,
+        )
+        .with_source_file("audit", "src/db/pool.rs", None)
+        .with_context(
+            "Pool setup is fragile\n\n**Files:** src/db/pool.rs, src/db/config.rs, src/server.rs",
+        )];
+
+        let mut generator = TaskGenerator::new();
+        generator.tasks.push(Task::new(
+            "TODO: Harden connection pool handling for the database",
+            "Retry on pool exhaustion",
+            PathBuf::from("src/db/config.rs"),
+            Some(3),
+            TaskPriority::Medium,
+            Category::Janus,
+        ));
+        // Same files, unrelated content: kept
+        generator.tasks.push(Task::new(
+            "TODO: Rename config fields",
+            "Use snake_case names",
+            PathBuf::from("src/db/config.rs"),
+            Some(9),
+            TaskPriority::Low,
+            Category::Janus,
+        ));
+        // Related content, files outside the existing task: kept
+        generator.tasks.push(Task::new(
+            "TODO: Harden connection pool handling for the database",
+            "Retry on pool exhaustion",
+            PathBuf::from("src/cache/pool.rs"),
+            None,
+            TaskPriority::Medium,
+            Category::Janus,
+        ));
+
+        generator.dedup_against(&existing);
+
+        let titles: Vec<_> = generator
+            .tasks()
+            .iter()
+            .map(|t| (t.title.as_str(), t.file.to_string_lossy().to_string()))
+            .collect();
+        assert_eq!(
+            titles,
+            vec![
+                ("TODO: Rename config fields", "src/db/config.rs".to_string()),
+                (
+                    "TODO: Harden connection pool handling for the database",
+                    "src/cache/pool.rs".to_string()
+                ),
+            ]
+        );
+        assert_eq!(generator.skipped(), 1);
+    }
+
     #[test]
     fn test_statistics() {
         let mut generator = TaskGenerator::new();
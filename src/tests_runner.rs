@@ -10,6 +10,9 @@ use walkdir::WalkDir;
 // ── cargo test --format json event types ────────────────────────────────────
 
 /// A single line of `cargo test -- --format=json` output.
+///
+/// `cargo nextest run --message-format libtest-json` emits the same events,
+/// with test names prefixed by the binary id (`crate::bin$module::test`).
 #[derive(Debug, Deserialize)]
 #[serde(tag = "type", rename_all = "lowercase")]
 enum CargoTestEvent {
@@ -24,7 +27,6 @@ struct CargoTestEventTest {
     event: String, // "started" | "ok" | "failed" | "ignored"
     name: String,
     #[serde(default)]
-    #[allow(dead_code)]
     stdout: Option<String>,
 }
 
@@ -54,6 +56,33 @@ struct PytestTest {
 #[derive(Debug)]
 pub struct TestRunner {
     root: PathBuf,
+    rust_runner: RustTestRunner,
+}
+
+/// Which tool runs Rust tests
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RustTestRunner {
+    /// `cargo test`
+    #[default]
+    Cargo,
+    /// `cargo nextest run`, falling back to `cargo test` when nextest isn't installed
+    Nextest,
+}
+
+impl std::str::FromStr for RustTestRunner {
+    type Err = AuditError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "cargo" => Ok(Self::Cargo),
+            "nextest" => Ok(Self::Nextest),
+            other => Err(AuditError::config(format!(
+                "Unknown Rust test runner '{}' (expected 'cargo' or 'nextest')",
+                other
+            ))),
+        }
+    }
 }
 
 /// Test suite results
@@ -94,6 +123,9 @@ pub struct FileTestResult {
     pub failed: usize,
     /// Failed test names
     pub failures: Vec<String>,
+    /// Captured output of each failed test, by name (when the runner reports it)
+    #[serde(default)]
+    pub failure_messages: HashMap<String, String>,
 }
 
 /// Project type detected
@@ -111,7 +143,16 @@ pub enum ProjectType {
 impl TestRunner {
     /// Create a new test runner
     pub fn new(root: impl Into<PathBuf>) -> Self {
-        Self { root: root.into() }
+        Self {
+            root: root.into(),
+            rust_runner: RustTestRunner::default(),
+        }
+    }
+
+    /// Run Rust tests with `runner` instead of plain `cargo test`
+    pub fn with_rust_runner(mut self, runner: RustTestRunner) -> Self {
+        self.rust_runner = runner;
+        self
     }
 
     /// Detect project types in the repository
@@ -196,17 +237,40 @@ impl TestRunner {
         // Find all test files
         let test_files = self.find_rust_test_files()?;
 
-        // Run cargo test with JSON output.
-        // `--format=json` requires the nightly test harness flag on stable, so we
-        // pass `-Zunstable-options` to accommodate both channels gracefully.
-        let output = Command::new("cargo")
-            .arg("test")
-            .arg("--")
-            .arg("-Zunstable-options")
-            .arg("--format=json")
-            .current_dir(&self.root)
-            .output()
-            .map_err(AuditError::Io)?;
+        let use_nextest = match self.rust_runner {
+            RustTestRunner::Cargo => false,
+            RustTestRunner::Nextest if self.nextest_available() => true,
+            RustTestRunner::Nextest => {
+                tracing::warn!("cargo-nextest is not installed; falling back to cargo test");
+                false
+            }
+        };
+
+        let output = if use_nextest {
+            // nextest's libtest-json output is still gated behind an env var.
+            Command::new("cargo")
+                .arg("nextest")
+                .arg("run")
+                .arg("--no-fail-fast")
+                .arg("--message-format")
+                .arg("libtest-json")
+                .env("NEXTEST_EXPERIMENTAL_LIBTEST_JSON", "1")
+                .current_dir(&self.root)
+                .output()
+                .map_err(AuditError::Io)?
+        } else {
+            // Run cargo test with JSON output.
+            // `--format=json` requires the nightly test harness flag on stable, so we
+            // pass `-Zunstable-options` to accommodate both channels gracefully.
+            Command::new("cargo")
+                .arg("test")
+                .arg("--")
+                .arg("-Zunstable-options")
+                .arg("--format=json")
+                .current_dir(&self.root)
+                .output()
+                .map_err(AuditError::Io)?
+        };
 
         let duration = start.elapsed().as_secs_f64();
         // cargo test writes JSON events to stdout; human-readable summary to stderr.
@@ -245,6 +309,16 @@ impl TestRunner {
         })
     }
 
+    /// Whether `cargo nextest` can be run in this project
+    fn nextest_available(&self) -> bool {
+        Command::new("cargo")
+            .arg("nextest")
+            .arg("--version")
+            .current_dir(&self.root)
+            .output()
+            .is_ok_and(|output| output.status.success())
+    }
+
     /// Run Python tests using pytest
     fn run_python_tests(&self) -> Result<TestResults> {
         let start = std::time::Instant::now();
@@ -456,8 +530,8 @@ impl TestRunner {
         Ok(test_files)
     }
 
-    /// Parse cargo test output
-    /// Parse `cargo test -- --format=json` event stream into per-file results.
+    /// Parse `cargo test -- --format=json` (or nextest libtest-json) event
+    /// stream into per-file results.
     ///
     /// Returns `(results_by_file, total, passed, failed, skipped)`.
     /// On any parse error the map will be empty and counts will be 0 so the
@@ -488,10 +562,16 @@ impl TestRunner {
                     "ok" | "failed" | "ignored" => {
                         total += 1;
 
+                        // nextest prefixes names with the binary id: `crate::bin$mod::test`
+                        let name = t
+                            .name
+                            .split_once('$')
+                            .map_or(t.name.as_str(), |(_, name)| name);
+
                         // Derive the file path from the test name.
                         // `cargo test` names look like:  `module::sub::test_name`
                         // We map the leading module path to a .rs file under src/.
-                        let file_key = derive_rust_file_key(&t.name);
+                        let file_key = derive_rust_file_key(name);
 
                         let entry = by_file.entry(file_key.clone()).or_insert(FileTestResult {
                             file: file_key,
@@ -499,6 +579,7 @@ impl TestRunner {
                             passed: 0,
                             failed: 0,
                             failures: Vec::new(),
+                            failure_messages: HashMap::new(),
                         });
 
                         entry.tests += 1;
@@ -511,7 +592,10 @@ impl TestRunner {
                             "failed" => {
                                 failed += 1;
                                 entry.failed += 1;
-                                entry.failures.push(t.name.clone());
+                                entry.failures.push(name.to_string());
+                                if let Some(stdout) = t.stdout.filter(|s| !s.trim().is_empty()) {
+                                    entry.failure_messages.insert(name.to_string(), stdout);
+                                }
                             }
                             "ignored" => {
                                 skipped += 1;
@@ -570,6 +654,7 @@ impl TestRunner {
                 passed: 0,
                 failed: 0,
                 failures: Vec::new(),
+                failure_messages: HashMap::new(),
             });
 
             entry.tests += 1;
@@ -977,4 +1062,47 @@ mod tests {
         assert_eq!(total, 1);
         assert_eq!(passed, 1);
     }
+
+    // ── nextest libtest-json ─────────────────────────────────────────────────
+
+    #[test]
+    fn parse_nextest_libtest_json_stream() {
+        let runner = TestRunner::new(".").with_rust_runner(RustTestRunner::Nextest);
+
+        // Captured from `cargo nextest run --message-format libtest-json`
+        let json_events = r#"
+{"type":"suite","event":"started","test_count":3,"nextest":{"crate":"rustassistant","test_binary":"rustassistant","kind":"lib"}}
+{"type":"test","event":"started","name":"rustassistant$mod_a::tests::test_one"}
+{"type":"test","event":"ok","name":"rustassistant$mod_a::tests::test_one","exec_time":0.004}
+{"type":"test","event":"started","name":"rustassistant$mod_a::tests::test_two"}
+{"type":"test","event":"failed","name":"rustassistant$mod_a::tests::test_two","exec_time":0.003,"stdout":"thread 'mod_a::tests::test_two' panicked at src/mod_a.rs:10:9:\nassertion `left == right` failed\n"}
+{"type":"test","event":"ignored","name":"rustassistant$mod_b::tests::test_slow"}
+{"type":"suite","event":"failed","passed":1,"failed":1,"ignored":1,"measured":0,"filtered_out":0,"exec_time":0.011,"nextest":{"crate":"rustassistant","test_binary":"rustassistant","kind":"lib"}}
+{"type":"suite","event":"started","test_count":1,"nextest":{"crate":"rustassistant","test_binary":"api","kind":"test"}}
+{"type":"test","event":"ok","name":"rustassistant::api$mod_c::tests::test_health"}
+{"type":"suite","event":"ok","passed":1,"failed":0,"ignored":0,"measured":0,"filtered_out":0,"exec_time":0.002,"nextest":{"crate":"rustassistant","test_binary":"api","kind":"test"}}
+"#;
+
+        let (by_file, total, passed, failed, skipped) = runner.parse_cargo_test_json(json_events);
+        assert_eq!((total, passed, failed, skipped), (4, 2, 1, 1));
+
+        let mod_a = by_file.get("src/mod_a.rs").unwrap();
+        assert_eq!(mod_a.tests, 2);
+        assert_eq!(mod_a.failures, vec!["mod_a::tests::test_two".to_string()]);
+        assert!(mod_a.failure_messages["mod_a::tests::test_two"].contains("assertion"));
+        assert_eq!(by_file.get("src/mod_c.rs").map(|r| r.passed), Some(1));
+    }
+
+    #[test]
+    fn rust_test_runner_from_str() {
+        assert_eq!(
+            "nextest".parse::<RustTestRunner>().unwrap(),
+            RustTestRunner::Nextest
+        );
+        assert_eq!(
+            " Cargo ".parse::<RustTestRunner>().unwrap(),
+            RustTestRunner::Cargo
+        );
+        assert!("bazel".parse::<RustTestRunner>().is_err());
+    }
 }
@@ -1,11 +1,13 @@
 //! TODO scanner for detecting TODO comments and tasks in source code
 
 use crate::error::{AuditError, Result};
+use crate::git::blame_working_copy;
 use crate::types::Category;
 use regex::Regex;
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use tracing::debug;
 use walkdir::WalkDir;
 
 /// A TODO item found in code
@@ -23,6 +25,13 @@ pub struct TodoItem {
     pub context: Option<String>,
     /// Priority inferred from text (high/medium/low)
     pub priority: TodoPriority,
+    /// Author of the commit that last touched the line (see
+    /// [`TodoScanner::scan_file_with_blame`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// Author timestamp of that commit (seconds since epoch)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_date: Option<i64>,
 }
 
 /// Priority level for TODO items
@@ -76,6 +85,11 @@ impl TodoScanner {
         }
 
         let content = fs::read_to_string(path)?;
+        Ok(self.scan_content(path, &content))
+    }
+
+    /// Scan already-read file content for TODO items
+    pub fn scan_content(&self, path: &Path, content: &str) -> Vec<TodoItem> {
         let mut todos = Vec::new();
         let category = Category::from_path(&path.to_string_lossy());
 
@@ -91,8 +105,10 @@ impl TodoScanner {
                             line: line_num + 1,
                             text,
                             category,
-                            context: self.extract_context(&content, line_num),
+                            context: self.extract_context(content, line_num),
                             priority,
+                            author: None,
+                            commit_date: None,
                         };
 
                         todos.push(todo);
@@ -102,6 +118,52 @@ impl TodoScanner {
             }
         }
 
+        todos
+    }
+
+    /// Scan a file in a git repository and attribute each TODO to the author
+    /// of the commit that last touched its line
+    ///
+    /// `file_path` may be absolute or relative to `repo_path`. All TODO lines
+    /// are blamed with a single `git blame` over the working copy. TODOs on
+    /// uncommitted lines, or in files git does not track, keep `author` and
+    /// `commit_date` as `None`.
+    pub fn scan_file_with_blame(
+        &self,
+        repo_path: &Path,
+        file_path: &Path,
+    ) -> Result<Vec<TodoItem>> {
+        let full_path = repo_path.join(file_path);
+        let mut todos = self.scan_file(&full_path)?;
+
+        let (Some(first), Some(last)) = (
+            todos.iter().map(|t| t.line).min(),
+            todos.iter().map(|t| t.line).max(),
+        ) else {
+            return Ok(todos);
+        };
+
+        let rel_path = full_path.strip_prefix(repo_path).unwrap_or(file_path);
+        let blame = match blame_working_copy(repo_path, rel_path, first, last) {
+            Ok(blame) => blame,
+            Err(e) => {
+                debug!("No blame for {}: {}", rel_path.display(), e);
+                return Ok(todos);
+            }
+        };
+
+        let by_line: HashMap<usize, _> = blame
+            .into_iter()
+            .filter(|b| b.commit.bytes().any(|c| c != b'0'))
+            .map(|b| (b.line, b))
+            .collect();
+        for todo in &mut todos {
+            if let Some(b) = by_line.get(&todo.line) {
+                todo.author = Some(b.author.clone());
+                todo.commit_date = Some(b.author_time);
+            }
+        }
+
         Ok(todos)
     }
 
@@ -272,6 +334,11 @@ impl TodoScanner {
         let by_category = self.group_by_category(todos);
         let by_file = self.group_by_file(todos);
 
+        let mut by_author = HashMap::new();
+        for author in todos.iter().filter_map(|t| t.author.as_ref()) {
+            *by_author.entry(author.clone()).or_insert(0) += 1;
+        }
+
         TodoSummary {
             total,
             high_priority: by_priority.get(&TodoPriority::High).map_or(0, |v| v.len()),
@@ -281,6 +348,7 @@ impl TodoScanner {
             low_priority: by_priority.get(&TodoPriority::Low).map_or(0, |v| v.len()),
             by_category: by_category.into_iter().map(|(k, v)| (k, v.len())).collect(),
             files_with_todos: by_file.len(),
+            by_author,
         }
     }
 }
@@ -294,6 +362,9 @@ pub struct TodoSummary {
     pub low_priority: usize,
     pub by_category: HashMap<Category, usize>,
     pub files_with_todos: usize,
+    /// TODO count per blamed author; unattributed TODOs are not counted
+    #[serde(default)]
+    pub by_author: HashMap<String, usize>,
 }
 
 impl Default for TodoScanner {
@@ -374,6 +445,8 @@ const MAGIC: u32 = 42;
                 text: "Fix this".to_string(),
                 category: Category::from_path("test.rs"),
                 context: None,
+                author: None,
+                commit_date: None,
                 priority: TodoPriority::High,
             },
             TodoItem {
@@ -382,6 +455,8 @@ const MAGIC: u32 = 42;
                 text: "Refactor that".to_string(),
                 category: Category::from_path("test2.rs"),
                 context: None,
+                author: None,
+                commit_date: None,
                 priority: TodoPriority::Medium,
             },
             TodoItem {
@@ -390,6 +465,8 @@ const MAGIC: u32 = 42;
                 text: "Maybe improve".to_string(),
                 category: Category::from_path("test3.rs"),
                 context: None,
+                author: None,
+                commit_date: None,
                 priority: TodoPriority::Low,
             },
         ];
@@ -399,4 +476,60 @@ const MAGIC: u32 = 42;
         assert_eq!(grouped.get(&TodoPriority::Medium).unwrap().len(), 1);
         assert_eq!(grouped.get(&TodoPriority::Low).unwrap().len(), 1);
     }
+
+    #[test]
+    fn test_scan_file_with_blame_attributes_authors() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo = temp_dir.path();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .arg("-C")
+                .arg(repo)
+                .args([
+                    "-c",
+                    "user.name=Alice",
+                    "-c",
+                    "user.email=alice@example.com",
+                ])
+                .args(args)
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {:?} failed", args);
+        };
+
+        git(&["init", "-q"]);
+        std::fs::write(repo.join("lib.rs"), "// TODO: handle errors\nfn a() {}\n").unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "initial"]);
+
+        // One committed TODO, one uncommitted, and an untracked file
+        std::fs::write(
+            repo.join("lib.rs"),
+            "// TODO: handle errors\nfn a() {}\n// FIXME: not committed yet\n",
+        )
+        .unwrap();
+        std::fs::write(repo.join("new.rs"), "// TODO: brand new\n").unwrap();
+
+        let scanner = TodoScanner::new().unwrap();
+        let mut todos = scanner
+            .scan_file_with_blame(repo, Path::new("lib.rs"))
+            .unwrap();
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].author.as_deref(), Some("Alice"));
+        assert!(todos[0].commit_date.unwrap() > 0);
+        assert_eq!(todos[1].author, None);
+        assert_eq!(todos[1].commit_date, None);
+
+        let untracked = scanner
+            .scan_file_with_blame(repo, &repo.join("new.rs"))
+            .unwrap();
+        assert_eq!(untracked.len(), 1);
+        assert_eq!(untracked[0].author, None);
+
+        todos.extend(untracked);
+        let summary = scanner.generate_summary(&todos);
+        assert_eq!(summary.total, 3);
+        assert_eq!(summary.by_author.len(), 1);
+        assert_eq!(summary.by_author["Alice"], 1);
+    }
 }
@@ -437,6 +437,112 @@ pub struct SystemMap {
     pub mermaid_diagram: Option<String>,
 }
 
+impl SystemMap {
+    /// Render the map as a Mermaid `graph TD` diagram
+    ///
+    /// Each [`MainCategory`] is a subgraph holding one node per [`Category`]
+    /// with files, labeled with its file and line counts. Service
+    /// dependencies become edges; services that match a category name link
+    /// to that category's node. Nodes are classed `large`, `medium` or
+    /// `small` by their share of total lines.
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::from("graph TD\n");
+
+        let mut categories: Vec<(Category, usize)> = self
+            .files_by_category
+            .iter()
+            .filter(|(_, files)| **files > 0)
+            .map(|(category, files)| (*category, *files))
+            .collect();
+        categories.sort_by_key(|(category, _)| format!("{:?}", category));
+
+        if categories.is_empty() && self.dependencies.is_empty() {
+            out.push_str("    empty[\"No modules mapped\"]\n");
+            return out;
+        }
+
+        let total_lines: usize = self.lines_by_category.values().sum();
+
+        for group in [
+            MainCategory::Janus,
+            MainCategory::Execution,
+            MainCategory::Clients,
+            MainCategory::Audit,
+            MainCategory::Other,
+        ] {
+            let members: Vec<_> = categories
+                .iter()
+                .filter(|(category, _)| category.main_group() == group)
+                .collect();
+            if members.is_empty() {
+                continue;
+            }
+
+            out.push_str(&format!(
+                "    subgraph group_{}[\"{:?}\"]\n",
+                mermaid_id(&format!("{:?}", group)),
+                group
+            ));
+            for (category, files) in members {
+                let lines = self.lines_by_category.get(category).copied().unwrap_or(0);
+                out.push_str(&format!(
+                    "        {}[\"{:?}<br/>{} files, {} lines\"]:::{}\n",
+                    mermaid_id(&format!("{:?}", category)),
+                    category,
+                    files,
+                    lines,
+                    size_class(lines, total_lines)
+                ));
+            }
+            out.push_str("    end\n");
+        }
+
+        for dep in &self.dependencies {
+            out.push_str(&format!(
+                "    {} -->|{:?}| {}\n",
+                mermaid_id(&dep.from),
+                dep.dep_type,
+                mermaid_id(&dep.to)
+            ));
+        }
+
+        out.push_str("    classDef large fill:#f8d7da,stroke:#c0392b\n");
+        out.push_str("    classDef medium fill:#fff3cd,stroke:#d4a017\n");
+        out.push_str("    classDef small fill:#d4edda,stroke:#27ae60\n");
+        out
+    }
+}
+
+/// Mermaid node ID: lowercase alphanumerics and underscores
+fn mermaid_id(name: &str) -> String {
+    let id: String = name
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if id.is_empty() {
+        "unnamed".to_string()
+    } else {
+        id
+    }
+}
+
+/// Class for a node holding `lines` of `total` lines
+fn size_class(lines: usize, total: usize) -> &'static str {
+    let share = if total == 0 {
+        0.0
+    } else {
+        lines as f64 / total as f64
+    };
+    if share >= 0.4 {
+        "large"
+    } else if share >= 0.15 {
+        "medium"
+    } else {
+        "small"
+    }
+}
+
 /// Service dependency
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServiceDependency {
@@ -522,4 +628,67 @@ pub struct AuditSummary {
     pub test_pass_rate: Option<f64>,
     /// Code coverage percentage
     pub code_coverage: Option<f64>,
+    /// Files served from the static result cache (cached scans only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_hits: Option<usize>,
+    /// Share of scanned files served from the cache (0.0-1.0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_hit_rate: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(
+        categories: &[(Category, usize, usize)],
+        dependencies: Vec<ServiceDependency>,
+    ) -> SystemMap {
+        SystemMap {
+            total_files: categories.iter().map(|(_, files, _)| files).sum(),
+            files_by_category: categories.iter().map(|(c, f, _)| (*c, *f)).collect(),
+            lines_by_category: categories.iter().map(|(c, _, l)| (*c, *l)).collect(),
+            dependencies,
+            mermaid_diagram: None,
+        }
+    }
+
+    #[test]
+    fn test_system_map_to_mermaid() {
+        let diagram = map(
+            &[
+                (Category::Janus, 40, 8000),
+                (Category::Execution, 10, 1500),
+                (Category::Tests, 5, 500),
+            ],
+            vec![ServiceDependency {
+                from: "Janus".to_string(),
+                to: "Execution".to_string(),
+                dep_type: DependencyType::Grpc,
+            }],
+        )
+        .to_mermaid();
+
+        assert!(diagram.starts_with("graph TD\n"));
+        assert!(diagram.contains("subgraph group_janus[\"Janus\"]"));
+        assert!(diagram.contains("subgraph group_other[\"Other\"]"));
+        assert!(!diagram.contains("group_clients"));
+        assert!(diagram.contains("janus[\"Janus<br/>40 files, 8000 lines\"]:::large"));
+        assert!(diagram.contains("execution[\"Execution<br/>10 files, 1500 lines\"]:::medium"));
+        assert!(diagram.contains("tests[\"Tests<br/>5 files, 500 lines\"]:::small"));
+        assert!(diagram.contains("janus -->|Grpc| execution"));
+        assert!(diagram.contains("classDef large"));
+        assert_eq!(
+            diagram.matches("subgraph").count(),
+            diagram.matches("    end\n").count()
+        );
+    }
+
+    #[test]
+    fn test_empty_system_map_to_mermaid() {
+        assert_eq!(
+            map(&[], vec![]).to_mermaid(),
+            "graph TD\n    empty[\"No modules mapped\"]\n"
+        );
+    }
 }
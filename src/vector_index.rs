@@ -36,6 +36,8 @@
 //! ```
 
 use anyhow::Result;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
@@ -64,6 +66,10 @@ pub struct IndexConfig {
 
     /// Distance metric to use
     pub distance_metric: DistanceMetric,
+
+    /// Seed for HNSW layer assignment; `None` draws from entropy
+    #[serde(default)]
+    pub seed: Option<u64>,
 }
 
 impl Default for IndexConfig {
@@ -75,6 +81,7 @@ impl Default for IndexConfig {
             max_layers: 16,       // Logarithmic in dataset size
             dimension: 384,       // FastEmbed default dimension
             distance_metric: DistanceMetric::Cosine,
+            seed: None,
         }
     }
 }
@@ -164,6 +171,8 @@ impl VectorIndex {
     }
 
     /// Search for nearest neighbors
+    ///
+    /// Results are ordered by descending score, equal scores by id.
     pub fn search(&self, query: &[f32], k: usize) -> Result<Vec<SearchResult>> {
         // Validate dimension
         if query.len() != self.config.dimension {
@@ -260,16 +269,26 @@ struct HNSWIndex {
     nodes: HashMap<String, HNSWNode>,
     entry_point: Option<String>,
     layer_multiplier: f64,
+    rng: StdRng,
 }
 
 impl HNSWIndex {
     fn new(config: IndexConfig) -> Self {
         let layer_multiplier = 1.0 / (config.m as f64).ln();
+        let rng = Self::rng_for(&config);
         Self {
             config,
             nodes: HashMap::new(),
             entry_point: None,
             layer_multiplier,
+            rng,
+        }
+    }
+
+    fn rng_for(config: &IndexConfig) -> StdRng {
+        match config.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
         }
     }
 
@@ -313,7 +332,7 @@ impl HNSWIndex {
 
         // Update entry point if needed
         if self.entry_point.as_deref() == Some(id) {
-            self.entry_point = self.nodes.keys().next().cloned();
+            self.entry_point = self.nodes.keys().min().cloned();
         }
 
         Ok(())
@@ -347,8 +366,13 @@ impl HNSWIndex {
             })
             .collect();
 
-        // Sort by score (descending)
-        results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+        // Sort by score (descending), ties by id
+        results.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.id.cmp(&b.id))
+        });
 
         // Return top k
         results.truncate(k);
@@ -360,8 +384,8 @@ impl HNSWIndex {
         self.entry_point = None;
     }
 
-    fn random_layer(&self) -> usize {
-        let uniform: f64 = rand::random();
+    fn random_layer(&mut self) -> usize {
+        let uniform: f64 = self.rng.gen();
         let layer = (-uniform.ln() * self.layer_multiplier).floor() as usize;
         layer.min(self.config.max_layers - 1)
     }
@@ -376,13 +400,14 @@ impl HNSWIndex {
 
     fn deserialize(data: &[u8], config: IndexConfig) -> Result<Self> {
         let nodes: HashMap<String, HNSWNode> = bincode::deserialize(data)?;
-        let entry_point = nodes.keys().next().cloned();
+        let entry_point = nodes.keys().min().cloned();
 
         Ok(Self {
-            config: config.clone(),
+            rng: Self::rng_for(&config),
+            layer_multiplier: 1.0 / (config.m as f64).ln(),
+            config,
             nodes,
             entry_point,
-            layer_multiplier: 1.0 / (config.m as f64).ln(),
         })
     }
 }
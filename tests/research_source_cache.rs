@@ -0,0 +1,94 @@
+//! Integration tests for the research source cache
+//!
+//! Two workers researching the same subtopic retrieve the same RAG sources;
+//! only the first should pay to summarize each one.
+
+use rustassistant::research::source_cache::{CachedSource, SourceBundle, SourceCache};
+use rustassistant::research::worker::RagResult;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+const SUMMARY_TOKENS: usize = 100;
+
+fn hits() -> Vec<RagResult> {
+    vec![
+        RagResult {
+            content: "Tokio is an async runtime".to_string(),
+            source: "https://tokio.rs/tokio/tutorial".to_string(),
+            score: 0.9,
+        },
+        RagResult {
+            content: "Internal notes on executor design".to_string(),
+            source: "Executor notes".to_string(),
+            score: 0.8,
+        },
+    ]
+}
+
+async fn run_worker(
+    cache: Arc<SourceCache>,
+    summaries: Arc<AtomicUsize>,
+    research_id: &str,
+) -> SourceBundle {
+    let hits = hits();
+    cache
+        .collect(research_id, &hits, |hit| {
+            let summaries = summaries.clone();
+            let source = hit.source.clone();
+            let content = hit.content.clone();
+            async move {
+                // Simulate LLM latency so the two workers overlap
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                summaries.fetch_add(1, Ordering::SeqCst);
+                Ok(CachedSource {
+                    source,
+                    summary: format!("summary of {}", content),
+                    tokens: SUMMARY_TOKENS,
+                })
+            }
+        })
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_sibling_workers_share_sources() {
+    let cache = Arc::new(SourceCache::new());
+    let summaries = Arc::new(AtomicUsize::new(0));
+
+    let (a, b) = tokio::join!(
+        run_worker(cache.clone(), summaries.clone(), "research-1"),
+        run_worker(cache.clone(), summaries.clone(), "research-1"),
+    );
+
+    // Each source is summarized exactly once across both workers
+    assert_eq!(summaries.load(Ordering::SeqCst), 2);
+    assert_eq!(cache.len("research-1").await, 2);
+
+    // Both workers record every source they used
+    assert_eq!(a.sources, b.sources);
+    assert_eq!(a.sources.len(), 2);
+    assert_eq!(a.summaries, b.summaries);
+
+    // Token usage reflects the savings: the pair pays for two summaries, not four
+    assert_eq!(a.reused + b.reused, 2);
+    assert_eq!(a.tokens_used + b.tokens_used, 2 * SUMMARY_TOKENS);
+}
+
+#[tokio::test]
+async fn test_cache_is_partitioned_by_research_id() {
+    let cache = Arc::new(SourceCache::new());
+    let summaries = Arc::new(AtomicUsize::new(0));
+
+    let first = run_worker(cache.clone(), summaries.clone(), "research-a").await;
+    let second = run_worker(cache.clone(), summaries.clone(), "research-b").await;
+
+    assert_eq!(first.reused, 0);
+    assert_eq!(second.reused, 0);
+    assert_eq!(summaries.load(Ordering::SeqCst), 4);
+
+    cache.clear("research-a").await;
+    assert_eq!(cache.len("research-a").await, 0);
+    assert_eq!(cache.len("research-b").await, 2);
+}